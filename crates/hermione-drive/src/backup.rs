@@ -1,3 +1,4 @@
+use chrono::{DateTime, SecondsFormat, Utc};
 use eyre::Report;
 use hermione_nexus::{
     definitions::{
@@ -130,24 +131,37 @@ impl GetCommandsBackupCopy for NotionBackup {
                     })
                     .map_err(Error::backup)?;
 
-                let workspace_id: Uuid = page
-                    .properties
-                    .workspace_id
-                    .parse()
-                    .map_err(|err| {
-                        Report::new(err).wrap_err(format!(
-                            "Invalid backup data. Could not parse command's workspace ID: {}",
-                            page.properties.workspace_id
-                        ))
-                    })
-                    .map_err(Error::backup)?;
+                let workspace_id = if page.properties.workspace_id.is_empty() {
+                    None
+                } else {
+                    let workspace_id: Uuid = page
+                        .properties
+                        .workspace_id
+                        .parse()
+                        .map_err(|err| {
+                            Report::new(err).wrap_err(format!(
+                                "Invalid backup data. Could not parse command's workspace ID: {}",
+                                page.properties.workspace_id
+                            ))
+                        })
+                        .map_err(Error::backup)?;
+
+                    Some(WorkspaceId::new(workspace_id)?)
+                };
 
-                Command::new(CommandParameters {
+                Command::load(CommandParameters {
                     id,
+                    created_at: parse_timestamp(&page.properties.created_at)?,
+                    description: Some(page.properties.description),
                     last_execute_time: None,
+                    managed_by: None,
                     program: page.properties.program,
                     name: page.properties.name,
-                    workspace_id: WorkspaceId::new(workspace_id)?,
+                    pinned: page.properties.pinned,
+                    shell: None,
+                    tags: Vec::new(),
+                    updated_at: parse_timestamp(&page.properties.updated_at)?,
+                    workspace_id,
                 })
             })
             .collect::<Result<Vec<Command>>>()?;
@@ -207,11 +221,17 @@ impl GetWorkspacesBackupCopy for NotionBackup {
                     })
                     .map_err(Error::backup)?;
 
-                Workspace::new(WorkspaceParameters {
+                Workspace::load(WorkspaceParameters {
                     id,
+                    created_at: parse_timestamp(&page.properties.created_at)?,
                     last_access_time: None,
                     location: Some(page.properties.location),
                     name: page.properties.name,
+                    parent_id: None,
+                    pinned: page.properties.pinned,
+                    shell: None,
+                    tags: Vec::new(),
+                    updated_at: parse_timestamp(&page.properties.updated_at)?,
                 })
             })
             .collect::<Result<Vec<Workspace>>>()?;
@@ -265,7 +285,11 @@ impl BackupCommand for NotionBackup {
                             "Name": {"title": [{"text": {"content": command.name()}}]},
                             "External ID": {"rich_text": [{"text": {"content": command.id().to_string()}}]},
                             "Program": {"rich_text": [{"text": {"content": command.program()}}]},
-                            "Workspace ID": {"rich_text": [{"text": {"content": command.workspace_id().to_string()}}]}
+                            "Description": {"rich_text": [{"text": {"content": command.description().unwrap_or_default()}}]},
+                            "Workspace ID": {"rich_text": [{"text": {"content": command_workspace_id(&command)}}]},
+                            "Pinned": {"checkbox": command.is_pinned()},
+                            "Created At": {"rich_text": [{"text": {"content": format_timestamp(command.created_at())}}]},
+                            "Updated At": {"rich_text": [{"text": {"content": format_timestamp(command.updated_at())}}]}
                         }),
                     },
                 )
@@ -276,7 +300,14 @@ impl BackupCommand for NotionBackup {
             return Ok(());
         };
 
-        if command.name() != page.properties.name || command.program() != page.properties.program {
+        if command.name() != page.properties.name
+            || command.program() != page.properties.program
+            || command.description().unwrap_or_default() != page.properties.description
+            || command.is_pinned() != page.properties.pinned
+            || format_timestamp(command.created_at()) != page.properties.created_at
+            || format_timestamp(command.updated_at()) != page.properties.updated_at
+            || command_workspace_id(&command) != page.properties.workspace_id
+        {
             let api_call = || {
                 api::update_database_entry(
                     &self.client,
@@ -284,7 +315,12 @@ impl BackupCommand for NotionBackup {
                         entry_id: &page.page_id,
                         properties: serde_json::json!({
                             "Name": {"title": [{"text": {"content": command.name()}}]},
-                            "Program": {"rich_text": [{"text": {"content": command.program()}}]}
+                            "Program": {"rich_text": [{"text": {"content": command.program()}}]},
+                            "Description": {"rich_text": [{"text": {"content": command.description().unwrap_or_default()}}]},
+                            "Workspace ID": {"rich_text": [{"text": {"content": command_workspace_id(&command)}}]},
+                            "Pinned": {"checkbox": command.is_pinned()},
+                            "Created At": {"rich_text": [{"text": {"content": format_timestamp(command.created_at())}}]},
+                            "Updated At": {"rich_text": [{"text": {"content": format_timestamp(command.updated_at())}}]}
                         }),
                     },
                 )
@@ -350,7 +386,11 @@ impl BackupCommands for NotionBackup {
                                 "Name": {"title": [{"text": {"content": command.name()}}]},
                                 "External ID": {"rich_text": [{"text": {"content": command.id().to_string()}}]},
                                 "Program": {"rich_text": [{"text": {"content": command.program()}}]},
-                                "Workspace ID": {"rich_text": [{"text": {"content": command.workspace_id().to_string()}}]}
+                                "Description": {"rich_text": [{"text": {"content": command.description().unwrap_or_default()}}]},
+                                "Workspace ID": {"rich_text": [{"text": {"content": command_workspace_id(&command)}}]},
+                                "Pinned": {"checkbox": command.is_pinned()},
+                                "Created At": {"rich_text": [{"text": {"content": format_timestamp(command.created_at())}}]},
+                                "Updated At": {"rich_text": [{"text": {"content": format_timestamp(command.updated_at())}}]}
                             }),
                         },
                     )
@@ -363,6 +403,11 @@ impl BackupCommands for NotionBackup {
 
             if command.name() != page.properties.name
                 || command.program() != page.properties.program
+                || command.description().unwrap_or_default() != page.properties.description
+                || command.is_pinned() != page.properties.pinned
+                || format_timestamp(command.created_at()) != page.properties.created_at
+                || format_timestamp(command.updated_at()) != page.properties.updated_at
+                || command_workspace_id(&command) != page.properties.workspace_id
             {
                 let api_call = || {
                     api::update_database_entry(
@@ -371,7 +416,12 @@ impl BackupCommands for NotionBackup {
                             entry_id: &page.page_id,
                             properties: serde_json::json!({
                                 "Name": {"title": [{"text": {"content": command.name()}}]},
-                                "Program": {"rich_text": [{"text": {"content": command.program()}}]}
+                                "Program": {"rich_text": [{"text": {"content": command.program()}}]},
+                                "Description": {"rich_text": [{"text": {"content": command.description().unwrap_or_default()}}]},
+                                "Workspace ID": {"rich_text": [{"text": {"content": command_workspace_id(&command)}}]},
+                                "Pinned": {"checkbox": command.is_pinned()},
+                                "Created At": {"rich_text": [{"text": {"content": format_timestamp(command.created_at())}}]},
+                                "Updated At": {"rich_text": [{"text": {"content": format_timestamp(command.updated_at())}}]}
                             }),
                         },
                     )
@@ -426,7 +476,10 @@ impl BackupWorkspace for NotionBackup {
                         properties: serde_json::json!({
                             "Name": {"title": [{"text": {"content": workspace.name()}}]},
                             "External ID": {"rich_text": [{"text": {"content": workspace.id().to_string()}}]},
-                            "Location": {"rich_text": [{"text": {"content": workspace.location()}}]}
+                            "Location": {"rich_text": [{"text": {"content": workspace.location()}}]},
+                            "Pinned": {"checkbox": workspace.is_pinned()},
+                            "Created At": {"rich_text": [{"text": {"content": format_timestamp(workspace.created_at())}}]},
+                            "Updated At": {"rich_text": [{"text": {"content": format_timestamp(workspace.updated_at())}}]}
                         }),
                     },
                 )
@@ -439,6 +492,9 @@ impl BackupWorkspace for NotionBackup {
 
         if workspace.name() != page.properties.name
             || workspace.location().unwrap_or_default() != page.properties.location
+            || workspace.is_pinned() != page.properties.pinned
+            || format_timestamp(workspace.created_at()) != page.properties.created_at
+            || format_timestamp(workspace.updated_at()) != page.properties.updated_at
         {
             let api_call = || {
                 api::update_database_entry(
@@ -447,7 +503,10 @@ impl BackupWorkspace for NotionBackup {
                         entry_id: &page.page_id,
                         properties: serde_json::json!({
                             "Name": {"title": [{"text": {"content": workspace.name()}}]},
-                            "Location": {"rich_text": [{"text": {"content": workspace.location()}}]}
+                            "Location": {"rich_text": [{"text": {"content": workspace.location()}}]},
+                            "Pinned": {"checkbox": workspace.is_pinned()},
+                            "Created At": {"rich_text": [{"text": {"content": format_timestamp(workspace.created_at())}}]},
+                            "Updated At": {"rich_text": [{"text": {"content": format_timestamp(workspace.updated_at())}}]}
                         }),
                     },
                 )
@@ -512,7 +571,10 @@ impl BackupWorkspaces for NotionBackup {
                             properties: serde_json::json!({
                                 "Name": {"title": [{"text": {"content": workspace.name()}}]},
                                 "External ID": {"rich_text": [{"text": {"content": workspace.id().to_string()}}]},
-                                "Location": {"rich_text": [{"text": {"content": workspace.location()}}]}
+                                "Location": {"rich_text": [{"text": {"content": workspace.location()}}]},
+                                "Pinned": {"checkbox": workspace.is_pinned()},
+                                "Created At": {"rich_text": [{"text": {"content": format_timestamp(workspace.created_at())}}]},
+                                "Updated At": {"rich_text": [{"text": {"content": format_timestamp(workspace.updated_at())}}]}
                             }),
                         },
                     )
@@ -525,6 +587,9 @@ impl BackupWorkspaces for NotionBackup {
 
             if workspace.name() != page.properties.name
                 || workspace.location().unwrap_or_default() != page.properties.location
+                || workspace.is_pinned() != page.properties.pinned
+                || format_timestamp(workspace.created_at()) != page.properties.created_at
+                || format_timestamp(workspace.updated_at()) != page.properties.updated_at
             {
                 let api_call = || {
                     api::update_database_entry(
@@ -533,7 +598,10 @@ impl BackupWorkspaces for NotionBackup {
                             entry_id: &page.page_id,
                             properties: serde_json::json!({
                                 "Name": {"title": [{"text": {"content": workspace.name()}}]},
-                                "Location": {"rich_text": [{"text": {"content": workspace.location()}}]}
+                                "Location": {"rich_text": [{"text": {"content": workspace.location()}}]},
+                                "Pinned": {"checkbox": workspace.is_pinned()},
+                                "Created At": {"rich_text": [{"text": {"content": format_timestamp(workspace.created_at())}}]},
+                                "Updated At": {"rich_text": [{"text": {"content": format_timestamp(workspace.updated_at())}}]}
                             }),
                         },
                     )
@@ -587,6 +655,38 @@ impl VerifyBackupCredentials for NotionBackup {
     }
 }
 
+/// Global commands are stored with an empty workspace ID.
+fn command_workspace_id(command: &Command) -> String {
+    command
+        .workspace_id()
+        .map(|id| id.to_string())
+        .unwrap_or_default()
+}
+
+/// Timestamps are kept as RFC 3339 text, unknown ones as empty text.
+fn format_timestamp(timestamp: Option<&DateTime<Utc>>) -> String {
+    timestamp
+        .map(|timestamp| timestamp.to_rfc3339_opts(SecondsFormat::Micros, true))
+        .unwrap_or_default()
+}
+
+fn parse_timestamp(value: &str) -> Result<Option<DateTime<Utc>>> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    let timestamp = DateTime::parse_from_rfc3339(value)
+        .map_err(|err| {
+            Report::new(err).wrap_err(format!(
+                "Invalid backup data. Could not parse timestamp: {}",
+                value
+            ))
+        })
+        .map_err(Error::backup)?;
+
+    Ok(Some(timestamp.with_timezone(&Utc)))
+}
+
 fn send_with_retries(f: impl Fn() -> api::Result<Response>) -> Result<Response> {
     api::send_with_retries(f, thread::sleep)
         .map_err(|err| Report::new(err).wrap_err("Notion API request failure"))
@@ -1,12 +1,16 @@
 mod backup;
+mod secrets;
 mod storage;
 mod system;
 
 pub use backup::*;
+pub use secrets::*;
 pub use storage::*;
 pub use system::*;
 
-use hermione_internals::{file_system::AppLocation, powershell::PowerShellProcess, sqlite};
+use hermione_internals::{
+    cipher::SecretCipher, file_system::AppLocation, powershell::PowerShellProcess, sqlite,
+};
 use rusqlite::Connection;
 use std::path::Path;
 use tracing_appender::{
@@ -20,13 +24,18 @@ pub struct Engine {
 }
 
 pub struct ServiceFactory {
-    powershell: PowerShellProcess,
+    powershell: Option<PowerShellProcess>,
+    cipher: SecretCipher,
     conn: Connection,
 }
 
 impl ServiceFactory {
+    pub fn secrets(&self) -> Secrets {
+        Secrets::new(&self.conn, &self.cipher)
+    }
+
     pub fn system(&self) -> System {
-        System::new(&self.powershell)
+        System::new(self.powershell.as_ref())
     }
 
     pub fn storage(&self) -> Storage {
@@ -38,15 +47,21 @@ pub fn start() -> anyhow::Result<Engine> {
     let location = AppLocation::locate()?;
     let directory = location.directory();
     let logs_worker_guard = init_tracing(directory)?;
-    let powershell = PowerShellProcess::spawn()?;
+    let powershell = PowerShellProcess::spawn().ok();
     let conn = Connection::open(directory.join("hermione.db3"))?;
+    let cipher = SecretCipher::load_or_create(&directory.join("hermione.key"))?;
 
     sqlite::create_workspaces_table_if_not_exists(&conn)?;
     sqlite::create_commands_table_if_not_exists(&conn)?;
     sqlite::create_backup_credentials_table_if_not_exists(&conn)?;
+    sqlite::create_secrets_table_if_not_exists(&conn)?;
 
     Ok(Engine {
-        service_factory: ServiceFactory { powershell, conn },
+        service_factory: ServiceFactory {
+            powershell,
+            cipher,
+            conn,
+        },
         logs_worker_guard,
     })
 }
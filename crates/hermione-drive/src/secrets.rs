@@ -0,0 +1,60 @@
+use hermione_internals::{
+    cipher::SecretCipher,
+    sqlite::{self, SecretRecord},
+};
+use hermione_nexus::{
+    definitions::Secret,
+    services::{DeleteSecret, FindSecret, ListSecretNames, SaveSecret, SecretStore},
+    Error, Result,
+};
+use rusqlite::Connection;
+
+pub struct Secrets<'a> {
+    cipher: &'a SecretCipher,
+    conn: &'a Connection,
+}
+
+impl<'a> Secrets<'a> {
+    pub fn new(conn: &'a Connection, cipher: &'a SecretCipher) -> Self {
+        Self { cipher, conn }
+    }
+}
+
+fn internal_error(err: rusqlite::Error) -> Error {
+    Error::storage(eyre::Error::new(err))
+}
+
+impl SecretStore for Secrets<'_> {}
+
+impl DeleteSecret for Secrets<'_> {
+    fn delete_secret(&self, name: &str) -> Result<()> {
+        sqlite::delete_secret(self.conn, name).map_err(internal_error)?;
+
+        Ok(())
+    }
+}
+
+impl FindSecret for Secrets<'_> {
+    fn find_secret(&self, name: &str) -> Result<Option<Secret>> {
+        sqlite::find_secret(self.conn, name)
+            .map_err(internal_error)?
+            .map(|record| record.decrypt(self.cipher))
+            .transpose()
+    }
+}
+
+impl ListSecretNames for Secrets<'_> {
+    fn list_secret_names(&self) -> Result<Vec<String>> {
+        sqlite::list_secret_names(self.conn).map_err(internal_error)
+    }
+}
+
+impl SaveSecret for Secrets<'_> {
+    fn save_secret(&self, secret: &Secret) -> Result<()> {
+        let record = SecretRecord::encrypt(self.cipher, secret)?;
+
+        sqlite::upsert_secret(self.conn, record).map_err(internal_error)?;
+
+        Ok(())
+    }
+}
@@ -1,24 +1,43 @@
 use chrono::Utc;
 use hermione_internals::sqlite::{
-    self, BackupCredentialsRecord, CommandRecord, ListCommandsQuery, ListWorkspacesQueryOptions,
-    OptionalValue, UpdateCommandQueryOptions, UpdateWorkspaceQueryOptions, WorkspaceRecord,
+    self, BackupCredentialsRecord, CloneWorkspaceQuery, CommandChainRecord, CommandExecutionRecord,
+    CommandRecord, CommandScopeQuery, CopyCommandQuery, ListCommandChainsQuery,
+    ListCommandExecutionsQuery, ListCommandRevisionsQuery, ListCommandsQuery, ListTrashQuery,
+    ListWorkspacesQueryOptions, MoveCommandQuery, NewCommandRevisionQuery, OptionalValue,
+    SortQuery, SyncCommandsQuery, TagsQuery, UpdateCommandQueryOptions,
+    UpdateWorkspaceQueryOptions, WorkspaceRecord, WorkspaceScopeQuery,
 };
 use hermione_nexus::{
     definitions::{
-        BackupCredentials, BackupProviderKind, Command, CommandId, Workspace, WorkspaceId,
+        BackupCredentials, BackupProviderKind, Command, CommandChain, CommandChainId,
+        CommandExecution, CommandId, CommandManager, CommandRevision, CommandRevisionId,
+        EnvironmentVariable, TrashItem, TrashItemId, Workspace, WorkspaceId,
     },
     services::{
-        CreateCommand, CreateWorkspace, DeleteBackupCredentials, DeleteCommand, DeleteWorkspace,
-        DeleteWorkspaceCommands, EditCommandParameters, EditWorkspaceParameters,
-        FilterCommandsParameters, FilterWorkspacesParameters, FindBackupCredentials, FindCommand,
-        FindWorkspace, ListBackupCredentials, ListCommands, ListWorkspaces, NewCommandParameters,
-        NewWorkspaceParameters, SaveBackupCredentials, StorageService, TrackCommandExecuteTime,
-        TrackWorkspaceAccessTime, UpdateCommand, UpdateWorkspace, UpsertCommands, UpsertWorkspaces,
+        CloneWorkspace, CommandScope, CopyCommand, CreateCommand, CreateCommandChain,
+        CreateCommandRevision, CreateWorkspace, DeleteBackupCredentials, DeleteCommand,
+        DeleteCommandChain, DeleteWorkspace, DeleteWorkspaceCommands, EditCommandParameters,
+        EditCommandPinnedParameters, EditCommandWorkspaceParameters,
+        EditWorkspaceEnvironmentVariablesParameters, EditWorkspaceParameters,
+        EditWorkspacePinnedParameters, FilterCommandChainsParameters,
+        FilterCommandExecutionsParameters, FilterCommandRevisionsParameters,
+        FilterCommandsParameters, FilterTrashParameters, FilterWorkspacesParameters,
+        FindBackupCredentials, FindCommand, FindCommandChain, FindCommandRevision, FindTrashItem,
+        FindWorkspace, ListBackupCredentials, ListCommandChains, ListCommandExecutions,
+        ListCommandRevisions, ListCommands, ListTrash, ListWorkspaceEnvironmentVariables,
+        ListWorkspaces, MoveCommand, MoveToTrash, NewCommandChainParameters,
+        NewCommandCopyParameters, NewCommandExecutionParameters, NewCommandParameters,
+        NewCommandRevisionParameters, NewWorkspaceCloneParameters, NewWorkspaceParameters,
+        PurgeTrash, PurgeTrashScope, RestoreFromTrash, SaveBackupCredentials, SortOrder,
+        StorageService, SyncCommands, SyncCommandsParameters, TagsFilter, TrackCommandExecuteTime,
+        TrackCommandExecution, TrackWorkspaceAccessTime, UpdateCommand, UpdateCommandPinned,
+        UpdateWorkspace, UpdateWorkspaceEnvironmentVariables, UpdateWorkspacePinned,
+        UpsertCommands, UpsertWorkspaces, WorkspaceScope,
     },
     Error, Result,
 };
 use rusqlite::Connection;
-use uuid::Uuid;
+use uuid::{Bytes, Uuid};
 
 pub struct Storage<'a> {
     conn: &'a Connection,
@@ -36,46 +55,151 @@ fn internal_error(err: rusqlite::Error) -> Error {
 
 impl StorageService for Storage<'_> {}
 
+impl CloneWorkspace for Storage<'_> {
+    fn clone_workspace(&self, parameters: NewWorkspaceCloneParameters) -> Result<Workspace> {
+        let NewWorkspaceCloneParameters { id, location, name } = parameters;
+
+        let clone_id = Uuid::new_v4().into_bytes();
+
+        sqlite::clone_workspace(
+            self.conn,
+            CloneWorkspaceQuery {
+                id: id.into_bytes(),
+                clone_id,
+                location,
+                name,
+            },
+        )
+        .map_err(internal_error)?;
+
+        sqlite::find_workspace(self.conn, &clone_id)
+            .map_err(internal_error)?
+            .ok_or_else(|| Error::storage(eyre::eyre!("Could not find clone of workspace {}", id)))?
+            .try_into()
+    }
+}
+
+impl CopyCommand for Storage<'_> {
+    fn copy_command(&self, parameters: NewCommandCopyParameters) -> Result<Command> {
+        let NewCommandCopyParameters {
+            id,
+            keep_history,
+            workspace_id,
+        } = parameters;
+
+        let copy_id = Uuid::new_v4().into_bytes();
+
+        sqlite::copy_command(
+            self.conn,
+            CopyCommandQuery {
+                id: id.into_bytes(),
+                copy_id,
+                keep_history,
+                workspace_id: workspace_id.map(WorkspaceId::into_bytes),
+            },
+        )
+        .map_err(internal_error)?;
+
+        sqlite::find_command(self.conn, &copy_id)
+            .map_err(internal_error)?
+            .ok_or_else(|| Error::storage(eyre::eyre!("Could not find copy of command {}", id)))?
+            .try_into()
+    }
+}
+
 impl CreateCommand for Storage<'_> {
     fn create_command(&self, parameters: NewCommandParameters) -> Result<Command> {
-        let NewCommandParameters {
+        let record = new_command_record(parameters, None);
+        let id = record.id;
+
+        sqlite::insert_command(self.conn, record).map_err(internal_error)?;
+
+        sqlite::find_command(self.conn, &id)
+            .map_err(internal_error)?
+            .ok_or_else(|| Error::storage(eyre::eyre!("Could not find created command")))?
+            .try_into()
+    }
+}
+
+impl CreateCommandChain for Storage<'_> {
+    fn create_command_chain(&self, parameters: NewCommandChainParameters) -> Result<CommandChain> {
+        let NewCommandChainParameters {
             name,
-            program,
+            steps,
             workspace_id,
         } = parameters;
 
-        let record = CommandRecord {
+        let record = CommandChainRecord {
             id: Uuid::new_v4().into_bytes(),
-            last_execute_time: None,
             name,
-            program,
+            steps: steps.into_iter().map(CommandId::into_bytes).collect(),
             workspace_id: workspace_id.into_bytes(),
         };
 
-        let command = record.clone().try_into()?;
+        let command_chain = record.clone().try_into()?;
 
-        sqlite::insert_command(self.conn, record).map_err(internal_error)?;
+        sqlite::insert_command_chain(self.conn, record).map_err(internal_error)?;
 
-        Ok(command)
+        Ok(command_chain)
+    }
+}
+
+impl CreateCommandRevision for Storage<'_> {
+    fn create_command_revision(
+        &self,
+        parameters: NewCommandRevisionParameters,
+    ) -> Result<CommandRevision> {
+        let NewCommandRevisionParameters {
+            command_id,
+            name,
+            program,
+        } = parameters;
+
+        sqlite::insert_command_revision(
+            self.conn,
+            NewCommandRevisionQuery {
+                id: Uuid::new_v4().into_bytes(),
+                command_id: command_id.into_bytes(),
+                name: name.to_string(),
+                program: program.to_string(),
+            },
+        )
+        .map_err(internal_error)?
+        .try_into()
     }
 }
 
 impl CreateWorkspace for Storage<'_> {
     fn create_workspace(&self, parameters: NewWorkspaceParameters) -> Result<Workspace> {
-        let NewWorkspaceParameters { name, location } = parameters;
+        let NewWorkspaceParameters {
+            name,
+            location,
+            parent_id,
+            shell,
+            tags,
+        } = parameters;
+
+        let id = Uuid::new_v4().into_bytes();
 
         let record = WorkspaceRecord {
-            id: Uuid::new_v4().into_bytes(),
+            id,
+            created_at: None,
             last_access_time: None,
             location,
             name,
+            parent_id: parent_id.map(WorkspaceId::into_bytes),
+            pinned: false,
+            shell: shell.map(|shell| shell.as_str().to_string()),
+            tags,
+            updated_at: None,
         };
 
-        let workspace = record.clone().try_into()?;
-
         sqlite::insert_workspace(self.conn, record).map_err(internal_error)?;
 
-        Ok(workspace)
+        sqlite::find_workspace(self.conn, &id)
+            .map_err(internal_error)?
+            .ok_or_else(|| Error::storage(eyre::eyre!("Could not find created workspace")))?
+            .try_into()
     }
 }
 
@@ -95,6 +219,14 @@ impl DeleteCommand for Storage<'_> {
     }
 }
 
+impl DeleteCommandChain for Storage<'_> {
+    fn delete_command_chain(&self, id: CommandChainId) -> Result<()> {
+        sqlite::delete_command_chain(self.conn, id.as_bytes()).map_err(internal_error)?;
+
+        Ok(())
+    }
+}
+
 impl DeleteWorkspace for Storage<'_> {
     fn delete_workspace(&self, id: WorkspaceId) -> Result<()> {
         sqlite::delete_workspace(self.conn, id.as_bytes()).map_err(internal_error)?;
@@ -132,6 +264,36 @@ impl FindCommand for Storage<'_> {
     }
 }
 
+impl FindCommandChain for Storage<'_> {
+    fn find_command_chain(&self, id: CommandChainId) -> Result<Option<CommandChain>> {
+        sqlite::find_command_chain(self.conn, id.as_bytes())
+            .map_err(internal_error)?
+            .map(TryFrom::try_from)
+            .transpose()
+    }
+}
+
+impl FindCommandRevision for Storage<'_> {
+    fn find_command_revision(&self, id: CommandRevisionId) -> Result<Option<CommandRevision>> {
+        sqlite::find_command_revision(self.conn, id.as_bytes())
+            .map_err(internal_error)?
+            .map(TryFrom::try_from)
+            .transpose()
+    }
+}
+
+impl FindTrashItem for Storage<'_> {
+    fn find_trash_item(&self, id: TrashItemId) -> Result<Option<TrashItem>> {
+        let record = match id {
+            TrashItemId::Command(id) => sqlite::find_trashed_command(self.conn, id.as_bytes()),
+            TrashItemId::Workspace(id) => sqlite::find_trashed_workspace(self.conn, id.as_bytes()),
+        }
+        .map_err(internal_error)?;
+
+        record.map(TryFrom::try_from).transpose()
+    }
+}
+
 impl FindWorkspace for Storage<'_> {
     fn find_workspace(&self, id: WorkspaceId) -> Result<Option<Workspace>> {
         sqlite::find_workspace(self.conn, id.as_bytes())
@@ -151,20 +313,131 @@ impl ListBackupCredentials for Storage<'_> {
     }
 }
 
+impl ListCommandChains for Storage<'_> {
+    fn list_command_chains(
+        &self,
+        parameters: FilterCommandChainsParameters,
+    ) -> Result<Vec<CommandChain>> {
+        let FilterCommandChainsParameters {
+            page_number,
+            page_size,
+            workspace_id,
+        } = parameters;
+
+        sqlite::list_command_chains(
+            self.conn,
+            ListCommandChainsQuery {
+                workspace_id: workspace_id.map(|id| id.into_bytes()),
+                offset: page_number,
+                limit: page_size,
+            },
+        )
+        .map_err(internal_error)?
+        .into_iter()
+        .map(TryFrom::try_from)
+        .collect::<Result<Vec<_>>>()
+    }
+}
+
+impl ListCommandExecutions for Storage<'_> {
+    fn list_command_executions(
+        &self,
+        parameters: FilterCommandExecutionsParameters,
+    ) -> Result<Vec<CommandExecution>> {
+        let FilterCommandExecutionsParameters {
+            command_id,
+            page_number,
+            page_size,
+            workspace_id,
+        } = parameters;
+
+        sqlite::list_command_executions(
+            self.conn,
+            ListCommandExecutionsQuery {
+                command_id: command_id.map(|id| id.into_bytes()),
+                workspace_id: workspace_id.map(|id| id.into_bytes()),
+                offset: page_number,
+                limit: page_size,
+            },
+        )
+        .map_err(internal_error)?
+        .into_iter()
+        .map(TryFrom::try_from)
+        .collect::<Result<Vec<_>>>()
+    }
+}
+
+impl ListCommandRevisions for Storage<'_> {
+    fn list_command_revisions(
+        &self,
+        parameters: FilterCommandRevisionsParameters,
+    ) -> Result<Vec<CommandRevision>> {
+        let FilterCommandRevisionsParameters {
+            command_id,
+            page_number,
+            page_size,
+        } = parameters;
+
+        sqlite::list_command_revisions(
+            self.conn,
+            ListCommandRevisionsQuery {
+                command_id: command_id.into_bytes(),
+                offset: page_number,
+                limit: page_size,
+            },
+        )
+        .map_err(internal_error)?
+        .into_iter()
+        .map(TryFrom::try_from)
+        .collect::<Result<Vec<_>>>()
+    }
+}
+
+impl ListTrash for Storage<'_> {
+    fn list_trash(&self, parameters: FilterTrashParameters) -> Result<Vec<TrashItem>> {
+        let FilterTrashParameters {
+            page_number,
+            page_size,
+        } = parameters;
+
+        sqlite::list_trash(
+            self.conn,
+            ListTrashQuery {
+                limit: page_size,
+                offset: page_number,
+            },
+        )
+        .map_err(internal_error)?
+        .into_iter()
+        .map(TryFrom::try_from)
+        .collect::<Result<Vec<_>>>()
+    }
+}
+
 impl ListCommands for Storage<'_> {
     fn list_commands(&self, parameters: FilterCommandsParameters) -> Result<Vec<Command>> {
         let FilterCommandsParameters {
+            created_after,
+            description_contains,
             program_contains,
             page_number,
             page_size,
-            workspace_id,
+            scope,
+            sort,
+            tags,
+            updated_after,
         } = parameters;
 
         sqlite::list_commands(
             self.conn,
             ListCommandsQuery {
+                created_after: created_after.map(|time| time.timestamp_micros()),
+                description_contains,
                 program_contains: program_contains.unwrap_or_default(),
-                workspace_id: workspace_id.map(|id| id.into_bytes()),
+                scope: scope_query(scope),
+                sort: sort_query(sort),
+                tags: tags.map(tags_query),
+                updated_after: updated_after.map(|time| time.timestamp_micros()),
                 offset: page_number,
                 limit: page_size,
             },
@@ -176,20 +449,43 @@ impl ListCommands for Storage<'_> {
     }
 }
 
+impl ListWorkspaceEnvironmentVariables for Storage<'_> {
+    fn list_workspace_environment_variables(
+        &self,
+        workspace_id: WorkspaceId,
+    ) -> Result<Vec<EnvironmentVariable>> {
+        sqlite::list_workspace_environment_variables(self.conn, workspace_id.as_bytes())
+            .map_err(internal_error)?
+            .into_iter()
+            .map(TryFrom::try_from)
+            .collect::<Result<Vec<_>>>()
+    }
+}
+
 impl ListWorkspaces for Storage<'_> {
     fn list_workspaces(&self, parameters: FilterWorkspacesParameters) -> Result<Vec<Workspace>> {
         let FilterWorkspacesParameters {
+            created_after,
             name_contains,
             page_number,
             page_size,
+            scope,
+            sort,
+            tags,
+            updated_after,
         } = parameters;
 
         sqlite::list_workspaces(
             self.conn,
             ListWorkspacesQueryOptions {
+                created_after: created_after.map(|time| time.timestamp_micros()),
                 name_contains: name_contains.unwrap_or_default(),
                 limit: page_size,
                 offset: page_number,
+                scope: workspace_scope_query(scope),
+                sort: sort_query(sort),
+                tags: tags.map(tags_query),
+                updated_after: updated_after.map(|time| time.timestamp_micros()),
             },
         )
         .map_err(internal_error)?
@@ -199,6 +495,80 @@ impl ListWorkspaces for Storage<'_> {
     }
 }
 
+impl MoveCommand for Storage<'_> {
+    fn move_command(&self, parameters: EditCommandWorkspaceParameters) -> Result<()> {
+        let EditCommandWorkspaceParameters {
+            id,
+            keep_history,
+            workspace_id,
+        } = parameters;
+
+        sqlite::move_command(
+            self.conn,
+            MoveCommandQuery {
+                id: id.into_bytes(),
+                keep_history,
+                workspace_id: workspace_id.map(WorkspaceId::into_bytes),
+            },
+        )
+        .map_err(internal_error)?;
+
+        Ok(())
+    }
+}
+
+impl MoveToTrash for Storage<'_> {
+    fn move_to_trash(&self, id: TrashItemId) -> Result<()> {
+        match id {
+            TrashItemId::Command(id) => {
+                sqlite::trash_command(self.conn, id.as_bytes(), timestamp_micros())
+            }
+            TrashItemId::Workspace(id) => {
+                sqlite::trash_workspace(self.conn, id.as_bytes(), timestamp_micros())
+            }
+        }
+        .map_err(internal_error)?;
+
+        Ok(())
+    }
+}
+
+impl PurgeTrash for Storage<'_> {
+    fn purge_trash(&self, scope: PurgeTrashScope) -> Result<()> {
+        match scope {
+            PurgeTrashScope::All => sqlite::purge_trash(self.conn, None),
+            PurgeTrashScope::Item(TrashItemId::Command(id)) => {
+                sqlite::purge_trashed_command(self.conn, id.as_bytes())
+            }
+            PurgeTrashScope::Item(TrashItemId::Workspace(id)) => {
+                sqlite::purge_trashed_workspace(self.conn, id.as_bytes())
+            }
+            PurgeTrashScope::OlderThan(period) => {
+                let period = i64::try_from(period.as_micros()).unwrap_or(i64::MAX);
+
+                sqlite::purge_trash(self.conn, Some(timestamp_micros().saturating_sub(period)))
+            }
+        }
+        .map_err(internal_error)?;
+
+        Ok(())
+    }
+}
+
+impl RestoreFromTrash for Storage<'_> {
+    fn restore_from_trash(&self, id: TrashItemId) -> Result<()> {
+        match id {
+            TrashItemId::Command(id) => sqlite::restore_trashed_command(self.conn, id.as_bytes()),
+            TrashItemId::Workspace(id) => {
+                sqlite::restore_trashed_workspace(self.conn, id.as_bytes())
+            }
+        }
+        .map_err(internal_error)?;
+
+        Ok(())
+    }
+}
+
 impl SaveBackupCredentials for Storage<'_> {
     fn save_backup_credentials(&self, credentials: &BackupCredentials) -> Result<()> {
         let kind = match credentials {
@@ -220,19 +590,89 @@ impl SaveBackupCredentials for Storage<'_> {
     }
 }
 
+impl SyncCommands for Storage<'_> {
+    fn sync_commands(&self, parameters: SyncCommandsParameters) -> Result<Vec<Command>> {
+        let SyncCommandsParameters {
+            created,
+            managed_by,
+            removed,
+            updated,
+        } = parameters;
+
+        let inserted: Vec<CommandRecord> = created
+            .into_iter()
+            .map(|parameters| new_command_record(parameters, Some(managed_by)))
+            .collect();
+
+        let created_ids: Vec<Bytes> = inserted.iter().map(|record| record.id).collect();
+
+        sqlite::sync_commands(
+            self.conn,
+            SyncCommandsQuery {
+                deleted: removed.into_iter().map(CommandId::into_bytes).collect(),
+                inserted,
+                updated: updated.into_iter().map(From::from).collect(),
+            },
+        )
+        .map_err(internal_error)?;
+
+        created_ids
+            .iter()
+            .map(|id| {
+                sqlite::find_command(self.conn, id)
+                    .map_err(internal_error)?
+                    .ok_or_else(|| Error::storage(eyre::eyre!("Could not find created command")))?
+                    .try_into()
+            })
+            .collect()
+    }
+}
+
+impl TrackCommandExecution for Storage<'_> {
+    fn track_command_execution(&self, parameters: NewCommandExecutionParameters) -> Result<()> {
+        let NewCommandExecutionParameters {
+            command_id,
+            location,
+            program,
+            workspace_id,
+        } = parameters;
+
+        sqlite::insert_command_execution(
+            self.conn,
+            CommandExecutionRecord {
+                command_id: Some(command_id.into_bytes()),
+                location: location.map(ToString::to_string),
+                program: program.to_string(),
+                start_time: timestamp_micros(),
+                workspace_id: workspace_id.map(WorkspaceId::into_bytes),
+            },
+        )
+        .map_err(internal_error)?;
+
+        Ok(())
+    }
+}
+
 impl TrackCommandExecuteTime for Storage<'_> {
     fn track_command_execute_time(&self, id: CommandId) -> Result<()> {
         sqlite::update_command(
             self.conn,
             UpdateCommandQueryOptions {
                 id: id.into_bytes(),
+                description: None,
                 last_execute_time: Some(OptionalValue::Value(timestamp_micros())),
                 name: None,
+                pinned: None,
                 program: None,
+                shell: None,
+                tags: None,
             },
         )
         .map_err(internal_error)?;
 
+        sqlite::increment_command_execute_count(self.conn, id.as_bytes())
+            .map_err(internal_error)?;
+
         Ok(())
     }
 }
@@ -246,25 +686,66 @@ impl TrackWorkspaceAccessTime for Storage<'_> {
                 last_access_time: Some(OptionalValue::Value(timestamp_micros())),
                 location: None,
                 name: None,
+                parent_id: None,
+                pinned: None,
+                shell: None,
+                tags: None,
             },
         )
         .map_err(internal_error)?;
 
+        sqlite::increment_workspace_access_count(self.conn, id.as_bytes())
+            .map_err(internal_error)?;
+
         Ok(())
     }
 }
 
 impl UpdateCommand for Storage<'_> {
     fn update_command(&self, parameters: EditCommandParameters) -> Result<()> {
-        let EditCommandParameters { id, name, program } = parameters;
+        let EditCommandParameters {
+            id,
+            description,
+            name,
+            program,
+            shell,
+            tags,
+        } = parameters;
 
         sqlite::update_command(
             self.conn,
             UpdateCommandQueryOptions {
                 id: id.into_bytes(),
+                description: Some(description.map(ToString::to_string).into()),
                 last_execute_time: None,
                 name: Some(name.to_string()),
+                pinned: None,
                 program: Some(program.to_string()),
+                shell: Some(shell.map(|shell| shell.as_str().to_string()).into()),
+                tags: Some(tags.to_vec()),
+            },
+        )
+        .map_err(internal_error)?;
+
+        Ok(())
+    }
+}
+
+impl UpdateCommandPinned for Storage<'_> {
+    fn update_command_pinned(&self, parameters: EditCommandPinnedParameters) -> Result<()> {
+        let EditCommandPinnedParameters { id, pinned } = parameters;
+
+        sqlite::update_command(
+            self.conn,
+            UpdateCommandQueryOptions {
+                id: id.into_bytes(),
+                description: None,
+                last_execute_time: None,
+                name: None,
+                pinned: Some(pinned),
+                program: None,
+                shell: None,
+                tags: None,
             },
         )
         .map_err(internal_error)?;
@@ -281,6 +762,29 @@ impl UpsertCommands for Storage<'_> {
     }
 }
 
+impl UpdateWorkspacePinned for Storage<'_> {
+    fn update_workspace_pinned(&self, parameters: EditWorkspacePinnedParameters) -> Result<()> {
+        let EditWorkspacePinnedParameters { id, pinned } = parameters;
+
+        sqlite::update_workspace(
+            self.conn,
+            UpdateWorkspaceQueryOptions {
+                id: id.into_bytes(),
+                last_access_time: None,
+                location: None,
+                name: None,
+                parent_id: None,
+                pinned: Some(pinned),
+                shell: None,
+                tags: None,
+            },
+        )
+        .map_err(internal_error)?;
+
+        Ok(())
+    }
+}
+
 impl UpsertWorkspaces for Storage<'_> {
     fn upsert_workspaces(&self, workspaces: Vec<Workspace>) -> Result<()> {
         let records = workspaces.into_iter().map(From::from).collect();
@@ -291,9 +795,17 @@ impl UpsertWorkspaces for Storage<'_> {
 
 impl UpdateWorkspace for Storage<'_> {
     fn update_workspace(&self, parameters: EditWorkspaceParameters) -> Result<()> {
-        let EditWorkspaceParameters { id, location, name } = parameters;
+        let EditWorkspaceParameters {
+            id,
+            location,
+            name,
+            parent_id,
+            shell,
+            tags,
+        } = parameters;
 
         let location = location.map(ToString::to_string);
+        let shell = shell.map(|shell| shell.as_str().to_string());
 
         sqlite::update_workspace(
             self.conn,
@@ -302,6 +814,10 @@ impl UpdateWorkspace for Storage<'_> {
                 last_access_time: None,
                 location: Some(location.into()),
                 name: Some(name.to_string()),
+                parent_id: Some(parent_id.map(WorkspaceId::into_bytes).into()),
+                pinned: None,
+                shell: Some(shell.into()),
+                tags: Some(tags.to_vec()),
             },
         )
         .map_err(internal_error)?;
@@ -310,6 +826,90 @@ impl UpdateWorkspace for Storage<'_> {
     }
 }
 
+impl UpdateWorkspaceEnvironmentVariables for Storage<'_> {
+    fn update_workspace_environment_variables(
+        &self,
+        parameters: EditWorkspaceEnvironmentVariablesParameters,
+    ) -> Result<()> {
+        let EditWorkspaceEnvironmentVariablesParameters {
+            environment_variables,
+            workspace_id,
+        } = parameters;
+
+        let records = environment_variables.iter().map(From::from).collect();
+
+        sqlite::replace_workspace_environment_variables(self.conn, workspace_id.as_bytes(), records)
+            .map_err(internal_error)
+    }
+}
+
+fn new_command_record(
+    parameters: NewCommandParameters,
+    managed_by: Option<CommandManager>,
+) -> CommandRecord {
+    let NewCommandParameters {
+        description,
+        name,
+        program,
+        shell,
+        tags,
+        workspace_id,
+    } = parameters;
+
+    CommandRecord {
+        id: Uuid::new_v4().into_bytes(),
+        created_at: None,
+        description,
+        last_execute_time: None,
+        managed_by: managed_by.map(|manager| manager.as_str().to_string()),
+        name,
+        pinned: false,
+        program,
+        shell: shell.map(|shell| shell.as_str().to_string()),
+        tags,
+        updated_at: None,
+        workspace_id: workspace_id.map(WorkspaceId::into_bytes),
+    }
+}
+
+fn scope_query(scope: CommandScope) -> CommandScopeQuery {
+    match scope {
+        CommandScope::All => CommandScopeQuery::All,
+        CommandScope::Global => CommandScopeQuery::Global,
+        CommandScope::Inherited(id) => CommandScopeQuery::Inherited(id.into_bytes()),
+        CommandScope::Workspace(id) => CommandScopeQuery::Workspace(id.into_bytes()),
+        CommandScope::WorkspaceAndGlobal(id) => {
+            CommandScopeQuery::WorkspaceAndGlobal(id.into_bytes())
+        }
+    }
+}
+
+fn workspace_scope_query(scope: WorkspaceScope) -> WorkspaceScopeQuery {
+    match scope {
+        WorkspaceScope::All => WorkspaceScopeQuery::All,
+        WorkspaceScope::Roots => WorkspaceScopeQuery::Roots,
+        WorkspaceScope::Children(id) => WorkspaceScopeQuery::Children(id.into_bytes()),
+    }
+}
+
+fn sort_query(sort: SortOrder) -> SortQuery {
+    match sort {
+        SortOrder::Created => SortQuery::Created,
+        SortOrder::Frecency => SortQuery::Frecency {
+            now: timestamp_micros(),
+        },
+        SortOrder::Recency => SortQuery::Recency,
+        SortOrder::Updated => SortQuery::Updated,
+    }
+}
+
+fn tags_query(filter: TagsFilter) -> TagsQuery {
+    match filter {
+        TagsFilter::AllOf(tags) => TagsQuery::AllOf(tags),
+        TagsFilter::AnyOf(tags) => TagsQuery::AnyOf(tags),
+    }
+}
+
 fn timestamp_micros() -> i64 {
     Utc::now().timestamp_micros()
 }
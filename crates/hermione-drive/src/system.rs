@@ -1,18 +1,31 @@
-use hermione_internals::powershell::{self, PowerShellParameters, PowerShellProcess};
+use eyre::eyre;
+use hermione_internals::{
+    bundle, file_system,
+    powershell::{self, PowerShellProcess},
+    project_tasks, repository_commands,
+    terminal::{self, TerminalChainParameters, TerminalParameters, TerminalStep},
+};
 use hermione_nexus::{
+    definitions::{Bundle, ProjectTask, RepositoryCommand, Shell},
     services::{
-        InvokeCommand, InvokeCommandParameters, SetClipboardContent, SetLocation, SystemService,
+        DirectoryEntry, InvokeCommand, InvokeCommandChain, InvokeCommandChainParameters,
+        InvokeCommandChainStep, InvokeCommandParameters, ListDirectory, ListProjectTasks,
+        ListRepositoryCommands, ReadBundle, ReadFile, SetClipboardContent, SetLocation,
+        SetLocationParameters, SystemService, WriteBundle, WriteFile,
     },
     Error, Result,
 };
+use std::{env, fs, io, path::Path};
+
+const SHELL_ENV_VAR: &str = "SHELL";
 
 pub struct System<'a> {
-    process: &'a PowerShellProcess,
+    process: Option<&'a PowerShellProcess>,
     no_exit: bool,
 }
 
 impl<'a> System<'a> {
-    pub fn new(process: &'a PowerShellProcess) -> Self {
+    pub fn new(process: Option<&'a PowerShellProcess>) -> Self {
         System {
             process,
             no_exit: true,
@@ -30,37 +43,207 @@ impl InvokeCommand for System<'_> {
     fn invoke_command(&self, parameters: InvokeCommandParameters) -> Result<()> {
         let InvokeCommandParameters {
             command,
+            environment_variables,
             location: working_directory,
+            shell,
         } = parameters;
 
-        powershell::open_windows_terminal(
-            self.process,
-            Some(PowerShellParameters {
-                command: Some(command),
-                no_exit: self.no_exit,
-                working_directory,
-            }),
-        )
+        let environment_variables = environment_variables
+            .iter()
+            .map(|variable| (variable.name(), variable.value()))
+            .collect();
+
+        terminal::open(TerminalParameters {
+            command: Some(command),
+            environment_variables,
+            no_exit: self.no_exit,
+            shell: shell.unwrap_or_else(default_shell),
+            working_directory,
+        })
         .map_err(Error::system)
     }
 }
 
+impl InvokeCommandChain for System<'_> {
+    fn invoke_command_chain(&self, parameters: InvokeCommandChainParameters) -> Result<()> {
+        let InvokeCommandChainParameters { steps, shell } = parameters;
+
+        let steps = steps
+            .iter()
+            .map(|step| {
+                let InvokeCommandChainStep {
+                    command,
+                    environment_variables,
+                    location: working_directory,
+                    shell,
+                } = step;
+
+                TerminalStep {
+                    command,
+                    environment_variables: environment_variables
+                        .iter()
+                        .map(|variable| (variable.name(), variable.value()))
+                        .collect(),
+                    shell: shell.unwrap_or_else(default_shell),
+                    working_directory: *working_directory,
+                }
+            })
+            .collect();
+
+        terminal::open_chain(TerminalChainParameters {
+            no_exit: self.no_exit,
+            shell: shell.unwrap_or_else(default_shell),
+            steps,
+        })
+        .map_err(Error::system)
+    }
+}
+
+impl ListDirectory for System<'_> {
+    fn list_directory(&self, path: &str) -> Result<Vec<DirectoryEntry>> {
+        let path = file_system::expand_home_directory(path)
+            .map_err(|err| Error::system(eyre::Error::new(err)))?;
+
+        let entries = fs::read_dir(&path).map_err(|err| {
+            Error::system(
+                eyre::Error::new(err)
+                    .wrap_err(format!("Failed to read directory {}", path.display())),
+            )
+        })?;
+
+        let mut directory_entries = Vec::new();
+
+        for entry in entries {
+            let entry = entry.map_err(|err| Error::system(eyre::Error::new(err)))?;
+            let path = entry.path();
+
+            // Follows symbolic links, so linked checkouts are discovered too.
+            directory_entries.push(DirectoryEntry {
+                name: entry.file_name().to_string_lossy().to_string(),
+                is_directory: path.is_dir(),
+                path: path.to_string_lossy().to_string(),
+            });
+        }
+
+        Ok(directory_entries)
+    }
+}
+
+impl ListProjectTasks for System<'_> {
+    fn list_project_tasks(&self, location: &str) -> Result<Vec<ProjectTask>> {
+        let location = file_system::expand_home_directory(location)
+            .map_err(|err| Error::system(eyre::Error::new(err)))?;
+
+        project_tasks::list_project_tasks(&location)
+            .map_err(|err| Error::system(eyre::Error::new(err)))
+    }
+}
+
+impl ListRepositoryCommands for System<'_> {
+    fn list_repository_commands(&self, location: &str) -> Result<Option<Vec<RepositoryCommand>>> {
+        let location = file_system::expand_home_directory(location)
+            .map_err(|err| Error::system(eyre::Error::new(err)))?;
+
+        repository_commands::list_repository_commands(&location)
+            .map_err(|err| Error::system(eyre::Error::new(err)))
+    }
+}
+
+impl ReadBundle for System<'_> {
+    fn read_bundle(&self, path: &str) -> Result<Bundle> {
+        let content = self
+            .read_file(path)?
+            .ok_or_else(|| Error::not_found(eyre!("Could not find bundle {}", path)))?;
+
+        bundle::from_json(&content)
+    }
+}
+
+impl ReadFile for System<'_> {
+    fn read_file(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let path = file_system::expand_home_directory(path)
+            .map_err(|err| Error::system(eyre::Error::new(err)))?;
+
+        match fs::read(&path) {
+            Ok(content) => Ok(Some(content)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(Error::system(
+                eyre::Error::new(err).wrap_err(format!("Failed to read file {}", path.display())),
+            )),
+        }
+    }
+}
+
 impl SetClipboardContent for System<'_> {
     fn set_clipboard_content(&self, text: &str) -> Result<()> {
-        powershell::copy_to_clipboard(self.process, text).map_err(Error::system)
+        let process = self.process.ok_or_else(|| {
+            Error::system(eyre!(
+                "Clipboard access requires PowerShell to be installed"
+            ))
+        })?;
+
+        powershell::copy_to_clipboard(process, text).map_err(Error::system)
     }
 }
 
 impl SetLocation for System<'_> {
-    fn set_location(&self, working_directory: Option<&str>) -> Result<()> {
-        powershell::open_windows_terminal(
-            self.process,
-            Some(PowerShellParameters {
-                command: None,
-                no_exit: self.no_exit,
-                working_directory,
-            }),
-        )
+    fn set_location(&self, parameters: SetLocationParameters) -> Result<()> {
+        let SetLocationParameters {
+            location: working_directory,
+            shell,
+        } = parameters;
+
+        terminal::open(TerminalParameters {
+            command: None,
+            environment_variables: Vec::new(),
+            no_exit: self.no_exit,
+            shell: shell.unwrap_or_else(default_shell),
+            working_directory,
+        })
         .map_err(Error::system)
     }
 }
+
+impl WriteBundle for System<'_> {
+    fn write_bundle(&self, path: &str, bundle: &Bundle) -> Result<()> {
+        let path = file_system::expand_home_directory(path)
+            .map_err(|err| Error::system(eyre::Error::new(err)))?;
+
+        fs::write(&path, bundle::to_json(bundle)?).map_err(|err| {
+            Error::system(
+                eyre::Error::new(err).wrap_err(format!("Failed to write file {}", path.display())),
+            )
+        })
+    }
+}
+
+impl WriteFile for System<'_> {
+    fn write_file(&self, path: &str, content: &str) -> Result<()> {
+        let path = file_system::expand_home_directory(path)
+            .map_err(|err| Error::system(eyre::Error::new(err)))?;
+
+        fs::write(&path, content).map_err(|err| {
+            Error::system(
+                eyre::Error::new(err).wrap_err(format!("Failed to write file {}", path.display())),
+            )
+        })
+    }
+}
+
+/// Shell used when neither the command nor its workspace declare one:
+/// PowerShell on Windows, the login shell from `SHELL` elsewhere.
+fn default_shell() -> Shell {
+    if cfg!(windows) {
+        return Shell::Pwsh;
+    }
+
+    env::var(SHELL_ENV_VAR)
+        .ok()
+        .and_then(|path| {
+            Path::new(&path)
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| Shell::parse_str(name).ok())
+        })
+        .unwrap_or(Shell::Sh)
+}
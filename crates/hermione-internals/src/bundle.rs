@@ -0,0 +1,310 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use eyre::eyre;
+use hermione_nexus::{
+    definitions::{
+        Bundle, BundleParameters, Command, CommandId, CommandParameters, Shell, Workspace,
+        WorkspaceId, WorkspaceParameters,
+    },
+    Error, Result,
+};
+use serde::{Deserialize, Serialize};
+
+/// Leading part of every bundle, read before the rest so files written by
+/// a newer build are rejected with a clear message.
+#[derive(Deserialize)]
+struct BundleHeader {
+    schema_version: u32,
+}
+
+#[derive(Deserialize, Serialize)]
+struct BundleRecord {
+    schema_version: u32,
+    workspaces: Vec<BundleWorkspaceRecord>,
+    commands: Vec<BundleCommandRecord>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct BundleCommandRecord {
+    id: String,
+    workspace_id: Option<String>,
+    name: String,
+    program: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shell: Option<String>,
+    #[serde(default)]
+    pinned: bool,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    updated_at: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct BundleWorkspaceRecord {
+    id: String,
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shell: Option<String>,
+    #[serde(default)]
+    pinned: bool,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    updated_at: Option<String>,
+}
+
+/// Parses bundle JSON. Bundles written before timestamps were exported leave
+/// them to the storage.
+pub fn from_json(content: &[u8]) -> Result<Bundle> {
+    let header: BundleHeader = serde_json::from_slice(content).map_err(invalid_bundle)?;
+
+    Bundle::check_schema_version(header.schema_version)?;
+
+    let record: BundleRecord = serde_json::from_slice(content).map_err(invalid_bundle)?;
+
+    Bundle::try_from(record)
+}
+
+pub fn to_json(bundle: &Bundle) -> Result<Vec<u8>> {
+    serde_json::to_vec_pretty(&BundleRecord::from(bundle))
+        .map_err(|err| Error::system(eyre::Error::new(err).wrap_err("Failed to write bundle")))
+}
+
+fn invalid_bundle(err: serde_json::Error) -> Error {
+    Error::invalid_argument(eyre::Error::new(err).wrap_err("Invalid bundle"))
+}
+
+fn format_timestamp(timestamp: Option<&DateTime<Utc>>) -> Option<String> {
+    timestamp.map(|timestamp| timestamp.to_rfc3339_opts(SecondsFormat::Micros, true))
+}
+
+fn parse_timestamp(value: Option<String>) -> Result<Option<DateTime<Utc>>> {
+    value
+        .map(|value| {
+            DateTime::parse_from_rfc3339(&value)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .map_err(|err| {
+                    Error::invalid_argument(
+                        eyre::Error::new(err)
+                            .wrap_err(format!("Invalid bundle timestamp: {value}")),
+                    )
+                })
+        })
+        .transpose()
+}
+
+fn parse_shell(shell: Option<String>) -> Result<Option<Shell>> {
+    shell.as_deref().map(Shell::parse_str).transpose()
+}
+
+impl From<&Bundle> for BundleRecord {
+    fn from(value: &Bundle) -> Self {
+        BundleRecord {
+            schema_version: value.schema_version(),
+            workspaces: value
+                .workspaces()
+                .iter()
+                .map(BundleWorkspaceRecord::from)
+                .collect(),
+            commands: value
+                .commands()
+                .iter()
+                .map(BundleCommandRecord::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&Command> for BundleCommandRecord {
+    fn from(value: &Command) -> Self {
+        BundleCommandRecord {
+            id: value.id().to_string(),
+            workspace_id: value.workspace_id().map(|id| id.to_string()),
+            name: value.name().to_string(),
+            program: value.program().to_string(),
+            description: value.description().map(ToString::to_string),
+            shell: value.shell().map(|shell| shell.as_str().to_string()),
+            pinned: value.is_pinned(),
+            tags: value.tags().to_vec(),
+            created_at: format_timestamp(value.created_at()),
+            updated_at: format_timestamp(value.updated_at()),
+        }
+    }
+}
+
+impl From<&Workspace> for BundleWorkspaceRecord {
+    fn from(value: &Workspace) -> Self {
+        BundleWorkspaceRecord {
+            id: value.id().to_string(),
+            name: value.name().to_string(),
+            location: value.location().map(ToString::to_string),
+            parent_id: value.parent_id().map(|id| id.to_string()),
+            shell: value.shell().map(|shell| shell.as_str().to_string()),
+            pinned: value.is_pinned(),
+            tags: value.tags().to_vec(),
+            created_at: format_timestamp(value.created_at()),
+            updated_at: format_timestamp(value.updated_at()),
+        }
+    }
+}
+
+impl TryFrom<BundleRecord> for Bundle {
+    type Error = Error;
+
+    fn try_from(value: BundleRecord) -> Result<Self> {
+        let BundleRecord {
+            schema_version,
+            workspaces,
+            commands,
+        } = value;
+
+        Bundle::new(BundleParameters {
+            commands: commands
+                .into_iter()
+                .map(Command::try_from)
+                .collect::<Result<Vec<_>>>()?,
+            schema_version,
+            workspaces: workspaces
+                .into_iter()
+                .map(Workspace::try_from)
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+}
+
+impl TryFrom<BundleCommandRecord> for Command {
+    type Error = Error;
+
+    fn try_from(value: BundleCommandRecord) -> Result<Self> {
+        let BundleCommandRecord {
+            id,
+            workspace_id,
+            name,
+            program,
+            description,
+            shell,
+            pinned,
+            tags,
+            created_at,
+            updated_at,
+        } = value;
+
+        Command::new(CommandParameters {
+            id: CommandId::parse_str(&id)?.as_uuid(),
+            created_at: parse_timestamp(created_at)?,
+            description,
+            last_execute_time: None,
+            managed_by: None,
+            name,
+            pinned,
+            program,
+            shell: parse_shell(shell)?,
+            tags,
+            updated_at: parse_timestamp(updated_at)?,
+            workspace_id: workspace_id
+                .as_deref()
+                .map(WorkspaceId::parse_str)
+                .transpose()?,
+        })
+    }
+}
+
+impl TryFrom<BundleWorkspaceRecord> for Workspace {
+    type Error = Error;
+
+    fn try_from(value: BundleWorkspaceRecord) -> Result<Self> {
+        let BundleWorkspaceRecord {
+            id,
+            name,
+            location,
+            parent_id,
+            shell,
+            pinned,
+            tags,
+            created_at,
+            updated_at,
+        } = value;
+
+        if parent_id.as_ref() == Some(&id) {
+            return Err(Error::invalid_argument(eyre!(
+                "Workspace {} is its own parent",
+                id
+            )));
+        }
+
+        Workspace::new(WorkspaceParameters {
+            id: WorkspaceId::parse_str(&id)?.as_uuid(),
+            created_at: parse_timestamp(created_at)?,
+            last_access_time: None,
+            location,
+            name,
+            parent_id: parent_id
+                .as_deref()
+                .map(WorkspaceId::parse_str)
+                .transpose()?,
+            pinned,
+            shell: parse_shell(shell)?,
+            tags,
+            updated_at: parse_timestamp(updated_at)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let content = r#"{
+            "schema_version": 1,
+            "workspaces": [
+                {"id": "5f0ec6d4-4b4b-4f55-a7d8-1b7d3b1c6f10", "name": "Hermione", "location": "~/hermione", "shell": "zsh", "tags": ["rust"]}
+            ],
+            "commands": [
+                {"id": "0b8a8b43-63f3-4c1e-9f2e-1f6bd0d5d2a1", "workspace_id": "5f0ec6d4-4b4b-4f55-a7d8-1b7d3b1c6f10", "name": "Test", "program": "cargo test", "pinned": true, "updated_at": "2023-11-14T22:13:20.123456Z"}
+            ]
+        }"#;
+
+        let bundle = from_json(content.as_bytes())?;
+        let bundle = from_json(&to_json(&bundle)?)?;
+
+        assert_eq!(bundle.schema_version(), 1);
+        assert_eq!(bundle.workspaces()[0].name(), "Hermione");
+        assert_eq!(bundle.workspaces()[0].shell(), Some(Shell::Zsh));
+        assert_eq!(bundle.commands()[0].program(), "cargo test");
+        assert_eq!(
+            bundle.commands()[0].workspace_id(),
+            Some(bundle.workspaces()[0].id())
+        );
+        assert!(bundle.commands()[0].is_pinned());
+        assert_eq!(
+            bundle.commands()[0]
+                .updated_at()
+                .map(|timestamp| timestamp.timestamp_micros()),
+            Some(1_700_000_000_123_456)
+        );
+        assert_eq!(bundle.workspaces()[0].updated_at(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_newer_schema_version() {
+        let content = r#"{"schema_version": 2, "layout": "unknown"}"#;
+
+        let result = from_json(content.as_bytes());
+
+        assert!(result.is_err_and(|err| err.is_invalid_argument()));
+    }
+}
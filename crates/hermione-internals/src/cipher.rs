@@ -0,0 +1,122 @@
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+};
+
+const KEY_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
+
+pub type SecretCipherResult<T> = Result<T, SecretCipherError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SecretCipherError {
+    #[error(transparent)]
+    IO(#[from] io::Error),
+
+    #[error("Secret key file is corrupted")]
+    CorruptedKey,
+
+    #[error("Failed to decrypt secret {0}")]
+    Decryption(String),
+
+    #[error("Failed to encrypt secret {0}")]
+    Encryption(String),
+}
+
+/// Encrypts secret values with a key that never leaves the local machine.
+pub struct SecretCipher {
+    cipher: ChaCha20Poly1305,
+}
+
+pub struct EncryptedSecret {
+    pub ciphertext: Vec<u8>,
+    pub nonce: Vec<u8>,
+}
+
+impl SecretCipher {
+    /// The secret name is authenticated together with the value, so an
+    /// encrypted value cannot be swapped between two secrets.
+    pub fn decrypt(&self, name: &str, secret: &EncryptedSecret) -> SecretCipherResult<String> {
+        if secret.nonce.len() != NONCE_LENGTH {
+            return Err(SecretCipherError::Decryption(name.to_string()));
+        }
+
+        let plaintext = self
+            .cipher
+            .decrypt(
+                Nonce::from_slice(&secret.nonce),
+                Payload {
+                    msg: &secret.ciphertext,
+                    aad: name.as_bytes(),
+                },
+            )
+            .map_err(|_err| SecretCipherError::Decryption(name.to_string()))?;
+
+        String::from_utf8(plaintext).map_err(|_err| SecretCipherError::Decryption(name.to_string()))
+    }
+
+    pub fn encrypt(&self, name: &str, value: &str) -> SecretCipherResult<EncryptedSecret> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: value.as_bytes(),
+                    aad: name.as_bytes(),
+                },
+            )
+            .map_err(|_err| SecretCipherError::Encryption(name.to_string()))?;
+
+        Ok(EncryptedSecret {
+            ciphertext,
+            nonce: nonce.to_vec(),
+        })
+    }
+
+    pub fn generate() -> Self {
+        Self {
+            cipher: ChaCha20Poly1305::new(&ChaCha20Poly1305::generate_key(&mut OsRng)),
+        }
+    }
+
+    /// Reads the key from `path`, generating and writing a new one on the
+    /// first run. Losing the key file makes stored secrets unreadable.
+    pub fn load_or_create(path: &Path) -> SecretCipherResult<Self> {
+        if path.try_exists()? {
+            let key = fs::read(path)?;
+
+            if key.len() != KEY_LENGTH {
+                return Err(SecretCipherError::CorruptedKey);
+            }
+
+            return Ok(Self {
+                cipher: ChaCha20Poly1305::new(Key::from_slice(&key)),
+            });
+        }
+
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+
+            options.mode(0o600);
+        }
+
+        options.open(path)?.write_all(&key)?;
+
+        Ok(Self {
+            cipher: ChaCha20Poly1305::new(&key),
+        })
+    }
+}
@@ -47,6 +47,19 @@ impl AppLocation {
     }
 }
 
+/// Replaces a leading `~` with the user home directory.
+pub fn expand_home_directory(path: &str) -> AppLocationResult<PathBuf> {
+    let Some(rest) = path.strip_prefix('~') else {
+        return Ok(PathBuf::from(path));
+    };
+
+    if !rest.is_empty() && !rest.starts_with(['/', '\\']) {
+        return Ok(PathBuf::from(path));
+    }
+
+    Ok(user_path()?.join(rest.trim_start_matches(['/', '\\'])))
+}
+
 fn development_path() -> AppLocationResult<PathBuf> {
     let output = Command::new("cargo")
         .args(["locate-project", "--workspace", "--message-format", "plain"])
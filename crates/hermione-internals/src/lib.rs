@@ -1,7 +1,12 @@
+pub mod bundle;
+pub mod cipher;
 pub mod file_system;
 pub mod notion;
 pub mod powershell;
+pub mod project_tasks;
+pub mod repository_commands;
 pub mod sqlite;
+pub mod terminal;
 
 const APPLICATION_STATE: ApplicationState = ApplicationState::evaluate();
 
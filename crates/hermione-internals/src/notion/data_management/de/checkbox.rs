@@ -0,0 +1,72 @@
+use serde::{
+    de::{Error, MapAccess, Visitor},
+    Deserializer,
+};
+use std::fmt;
+
+pub fn deserializer<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(CheckboxVisitor)
+}
+
+struct CheckboxVisitor;
+
+impl<'de> Visitor<'de> for CheckboxVisitor {
+    type Value = bool;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map with id, type, and checkbox fields")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut checkbox: Option<bool> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "id" | "type" => {
+                    map.next_value::<String>()?;
+                }
+                "checkbox" => {
+                    checkbox = Some(map.next_value::<bool>()?);
+                }
+                _ => return Err(Error::unknown_field(&key, &["id", "type", "checkbox"])),
+            }
+        }
+
+        checkbox.ok_or(Error::missing_field("checkbox"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Record {
+        #[serde(rename(deserialize = "Pinned"), deserialize_with = "deserializer")]
+        pinned: bool,
+    }
+
+    #[test]
+    fn test_deserializer() -> Result<(), serde_json::Error> {
+        let json = r#"{
+            "Pinned": {
+                "id": "Kw%3Aq",
+                "type": "checkbox",
+                "checkbox": true
+            }
+        }"#;
+
+        let record: Record = serde_json::from_str(json)?;
+
+        assert!(record.pinned);
+
+        Ok(())
+    }
+}
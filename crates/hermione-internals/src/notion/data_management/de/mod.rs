@@ -1,3 +1,4 @@
+pub mod checkbox;
 pub mod rich_text;
 pub mod title;
 
@@ -15,6 +15,7 @@ pub struct DatabaseProperty {
 pub enum DatabasePropertyKind {
     Title,
     RichText,
+    Checkbox,
     CreatedTime,
     LastEditedTime,
 }
@@ -44,6 +45,31 @@ pub struct NotionCommandProperties {
         deserialize_with = "de::rich_text::deserializer"
     )]
     pub program: String,
+
+    #[serde(
+        rename(deserialize = "Description"),
+        deserialize_with = "de::rich_text::deserializer"
+    )]
+    pub description: String,
+
+    #[serde(
+        rename(deserialize = "Pinned"),
+        deserialize_with = "de::checkbox::deserializer"
+    )]
+    pub pinned: bool,
+
+    /// RFC 3339 time of the local record, empty when it is unknown.
+    #[serde(
+        rename(deserialize = "Created At"),
+        deserialize_with = "de::rich_text::deserializer"
+    )]
+    pub created_at: String,
+
+    #[serde(
+        rename(deserialize = "Updated At"),
+        deserialize_with = "de::rich_text::deserializer"
+    )]
+    pub updated_at: String,
 }
 
 #[derive(Deserialize)]
@@ -65,6 +91,25 @@ pub struct NotionWorkspaceProperties {
         deserialize_with = "de::rich_text::deserializer"
     )]
     pub location: String,
+
+    #[serde(
+        rename(deserialize = "Pinned"),
+        deserialize_with = "de::checkbox::deserializer"
+    )]
+    pub pinned: bool,
+
+    /// RFC 3339 time of the local record, empty when it is unknown.
+    #[serde(
+        rename(deserialize = "Created At"),
+        deserialize_with = "de::rich_text::deserializer"
+    )]
+    pub created_at: String,
+
+    #[serde(
+        rename(deserialize = "Updated At"),
+        deserialize_with = "de::rich_text::deserializer"
+    )]
+    pub updated_at: String,
 }
 
 #[derive(Deserialize)]
@@ -168,10 +213,26 @@ fn commands_database_properties() -> Vec<DatabaseProperty> {
             name: "Program".into(),
             kind: DatabasePropertyKind::RichText,
         },
+        DatabaseProperty {
+            name: "Description".into(),
+            kind: DatabasePropertyKind::RichText,
+        },
         DatabaseProperty {
             name: "Workspace ID".into(),
             kind: DatabasePropertyKind::RichText,
         },
+        DatabaseProperty {
+            name: "Pinned".into(),
+            kind: DatabasePropertyKind::Checkbox,
+        },
+        DatabaseProperty {
+            name: "Created At".into(),
+            kind: DatabasePropertyKind::RichText,
+        },
+        DatabaseProperty {
+            name: "Updated At".into(),
+            kind: DatabasePropertyKind::RichText,
+        },
     ]
 }
 
@@ -189,6 +250,18 @@ fn workspaces_database_properties() -> Vec<DatabaseProperty> {
             name: "Location".into(),
             kind: DatabasePropertyKind::RichText,
         },
+        DatabaseProperty {
+            name: "Pinned".into(),
+            kind: DatabasePropertyKind::Checkbox,
+        },
+        DatabaseProperty {
+            name: "Created At".into(),
+            kind: DatabasePropertyKind::RichText,
+        },
+        DatabaseProperty {
+            name: "Updated At".into(),
+            kind: DatabasePropertyKind::RichText,
+        },
     ]
 }
 
@@ -219,6 +292,7 @@ impl FromStr for DatabasePropertyKind {
 
     fn from_str(s: &str) -> Result<Self> {
         let kind = match s {
+            "checkbox" => Self::Checkbox,
             "created_time" => Self::CreatedTime,
             "rich_text" => Self::RichText,
             "title" => Self::Title,
@@ -233,6 +307,7 @@ impl FromStr for DatabasePropertyKind {
 impl Display for DatabasePropertyKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
+            Self::Checkbox => "checkbox",
             Self::CreatedTime => "created_time",
             Self::RichText => "rich_text",
             Self::Title => "title",
@@ -0,0 +1,416 @@
+use hermione_nexus::definitions::{ProjectTask, ProjectTaskParameters, ProjectTaskSource};
+use serde_json::Value;
+use std::{
+    collections::HashSet,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const CARGO_CONFIG_FILES: [&str; 2] = [".cargo/config.toml", ".cargo/config"];
+const JUSTFILE_FILES: [&str; 3] = ["justfile", "Justfile", ".justfile"];
+const JUSTFILE_KEYWORDS: [&str; 5] = ["alias", "export", "import", "mod", "set"];
+const MAKEFILE_FILES: [&str; 3] = ["GNUmakefile", "makefile", "Makefile"];
+const PACKAGE_JSON_FILE: &str = "package.json";
+const VSCODE_TASKS_FILE: &str = ".vscode/tasks.json";
+
+pub type ProjectTasksResult<T> = Result<T, ProjectTasksError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProjectTasksError {
+    #[error(transparent)]
+    IO(#[from] io::Error),
+
+    #[error("Failed to parse {path}: {message}")]
+    Parse { path: PathBuf, message: String },
+}
+
+/// Collects tasks of every known project file found at the location, in the
+/// order make, just, npm, cargo and VS Code.
+pub fn list_project_tasks(location: &Path) -> ProjectTasksResult<Vec<ProjectTask>> {
+    let mut tasks = Vec::new();
+
+    if let Some((_path, content)) = read_first(location, &MAKEFILE_FILES)? {
+        tasks.extend(parse_makefile(&content));
+    }
+
+    if let Some((_path, content)) = read_first(location, &JUSTFILE_FILES)? {
+        tasks.extend(parse_justfile(&content));
+    }
+
+    if let Some((path, content)) = read_first(location, &[PACKAGE_JSON_FILE])? {
+        tasks.extend(parse_package_json(&content).map_err(|message| parse_error(path, message))?);
+    }
+
+    if let Some((path, content)) = read_first(location, &CARGO_CONFIG_FILES)? {
+        tasks.extend(parse_cargo_config(&content).map_err(|message| parse_error(path, message))?);
+    }
+
+    if let Some((path, content)) = read_first(location, &[VSCODE_TASKS_FILE])? {
+        tasks.extend(parse_vscode_tasks(&content).map_err(|message| parse_error(path, message))?);
+    }
+
+    Ok(tasks)
+}
+
+/// Aliases of the `[alias]` table, run through `cargo`.
+pub fn parse_cargo_config(content: &str) -> Result<Vec<ProjectTask>, String> {
+    let config: toml::Table = toml::from_str(content).map_err(|err| err.to_string())?;
+
+    let Some(aliases) = config.get("alias").and_then(toml::Value::as_table) else {
+        return Ok(vec![]);
+    };
+
+    Ok(aliases
+        .keys()
+        .map(|name| {
+            task(
+                ProjectTaskSource::CargoAlias,
+                name,
+                format!("cargo {}", name),
+            )
+        })
+        .collect())
+}
+
+/// Public recipes, the ones starting with an underscore are private.
+pub fn parse_justfile(content: &str) -> Vec<ProjectTask> {
+    let mut names = Vec::new();
+
+    for line in content.lines() {
+        if line.is_empty() || line.starts_with([' ', '\t', '#', '[']) {
+            continue;
+        }
+
+        let Some((head, rest)) = line.split_once(':') else {
+            continue;
+        };
+
+        // `name := value` assigns a variable.
+        if rest.starts_with('=') {
+            continue;
+        }
+
+        let mut words = head.split_whitespace();
+
+        let Some(name) = words.next().map(|name| name.trim_start_matches('@')) else {
+            continue;
+        };
+
+        if JUSTFILE_KEYWORDS.contains(&name) && words.next().is_some() {
+            continue;
+        }
+
+        if name.starts_with('_') || !is_task_name(name) {
+            continue;
+        }
+
+        names.push(name);
+    }
+
+    unique(names)
+        .map(|name| task(ProjectTaskSource::Justfile, name, format!("just {}", name)))
+        .collect()
+}
+
+/// Explicit targets, special targets like `.PHONY`, pattern rules and
+/// variable assignments are skipped.
+pub fn parse_makefile(content: &str) -> Vec<ProjectTask> {
+    let mut names = Vec::new();
+
+    for line in content.lines() {
+        if line.is_empty() || line.starts_with([' ', '\t', '#', '.']) {
+            continue;
+        }
+
+        let Some((targets, rest)) = line.split_once(':') else {
+            continue;
+        };
+
+        // `:=` and `::=` assign variables.
+        if rest.starts_with('=') || rest.starts_with(":=") {
+            continue;
+        }
+
+        if targets.contains(['=', '$', '%']) {
+            continue;
+        }
+
+        names.extend(targets.split_whitespace().filter(|name| is_task_name(name)));
+    }
+
+    unique(names)
+        .map(|name| task(ProjectTaskSource::Makefile, name, format!("make {}", name)))
+        .collect()
+}
+
+/// Entries of `scripts`, run through `npm run`.
+pub fn parse_package_json(content: &str) -> Result<Vec<ProjectTask>, String> {
+    let package: Value = serde_json::from_str(content).map_err(|err| err.to_string())?;
+
+    let Some(scripts) = package.get("scripts").and_then(Value::as_object) else {
+        return Ok(vec![]);
+    };
+
+    Ok(scripts
+        .keys()
+        .map(|name| {
+            task(
+                ProjectTaskSource::NpmScript,
+                name,
+                format!("npm run {}", name),
+            )
+        })
+        .collect())
+}
+
+/// Tasks with a `command`, or npm tasks with a `script`. The file may
+/// contain comments and trailing commas.
+pub fn parse_vscode_tasks(content: &str) -> Result<Vec<ProjectTask>, String> {
+    let tasks: Value =
+        serde_json::from_str(&strip_json_extensions(content)).map_err(|err| err.to_string())?;
+
+    let Some(tasks) = tasks.get("tasks").and_then(Value::as_array) else {
+        return Ok(vec![]);
+    };
+
+    Ok(tasks
+        .iter()
+        .filter_map(|definition| {
+            let label = definition.get("label").and_then(Value::as_str)?;
+            let program = vscode_task_program(definition)?;
+
+            Some(task(ProjectTaskSource::VsCodeTask, label, program))
+        })
+        .collect())
+}
+
+fn is_task_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'))
+}
+
+fn parse_error(path: PathBuf, message: String) -> ProjectTasksError {
+    ProjectTasksError::Parse { path, message }
+}
+
+fn read_first(location: &Path, names: &[&str]) -> ProjectTasksResult<Option<(PathBuf, String)>> {
+    for name in names {
+        let path = location.join(name);
+
+        match fs::read_to_string(&path) {
+            Ok(content) => return Ok(Some((path, content))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(None)
+}
+
+/// Drops comments and trailing commas so the content parses as plain JSON.
+fn strip_json_extensions(content: &str) -> String {
+    let mut stripped = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            stripped.push(c);
+
+            match c {
+                '\\' => stripped.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                stripped.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        stripped.push(c);
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+
+                while let Some(c) = chars.next() {
+                    if c == '*' && chars.peek() == Some(&'/') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            (']' | '}', _) => {
+                let trimmed = stripped.trim_end().len();
+
+                if stripped[..trimmed].ends_with(',') {
+                    stripped.truncate(trimmed - 1);
+                }
+
+                stripped.push(c);
+            }
+            _ => stripped.push(c),
+        }
+    }
+
+    stripped
+}
+
+fn task(source: ProjectTaskSource, name: &str, program: String) -> ProjectTask {
+    ProjectTask::new(ProjectTaskParameters {
+        name: name.to_string(),
+        program,
+        source,
+    })
+}
+
+fn unique(names: Vec<&str>) -> impl Iterator<Item = &str> {
+    let mut seen = HashSet::new();
+
+    names.into_iter().filter(move |name| seen.insert(*name))
+}
+
+fn vscode_task_program(definition: &Value) -> Option<String> {
+    if let Some(command) = definition.get("command").and_then(Value::as_str) {
+        let args = definition
+            .get("args")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|arg| arg.as_str().or_else(|| arg.get("value")?.as_str()))
+            .map(|arg| {
+                if arg.contains(char::is_whitespace) {
+                    format!("\"{}\"", arg)
+                } else {
+                    arg.to_string()
+                }
+            });
+
+        return Some(
+            std::iter::once(command.to_string())
+                .chain(args)
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+    }
+
+    if definition.get("type").and_then(Value::as_str) == Some("npm") {
+        let script = definition.get("script").and_then(Value::as_str)?;
+
+        return Some(format!("npm run {}", script));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn programs(tasks: Vec<ProjectTask>) -> Vec<(String, String)> {
+        tasks
+            .into_iter()
+            .map(|task| (task.command_name(), task.program().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_makefile() {
+        let content = ".PHONY: build test\nCC := gcc\nVERSION = 1.0\n\nbuild: deps\n\t$(CC) main.c\n\ntest lint:\n\tmake check\n%.o: %.c\n\t$(CC) -c $<\nbuild:\n";
+
+        assert_eq!(
+            programs(parse_makefile(content)),
+            vec![
+                ("make build".to_string(), "make build".to_string()),
+                ("make test".to_string(), "make test".to_string()),
+                ("make lint".to_string(), "make lint".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_justfile() {
+        let content = "set shell := [\"bash\", \"-c\"]\nversion := \"1.0\"\nalias b := build\n\n# Builds the project\n@build target=\"debug\": lint\n    cargo build\n\n[private]\n_setup:\n    echo setup\n\nlint:\n    cargo clippy\n";
+
+        assert_eq!(
+            programs(parse_justfile(content)),
+            vec![
+                ("just build".to_string(), "just build".to_string()),
+                ("just lint".to_string(), "just lint".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_package_json() -> Result<(), String> {
+        let content = r#"{"name": "app", "scripts": {"test": "jest", "dev": "vite"}}"#;
+
+        assert_eq!(
+            programs(parse_package_json(content)?),
+            vec![
+                ("npm dev".to_string(), "npm run dev".to_string()),
+                ("npm test".to_string(), "npm run test".to_string()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_cargo_config() -> Result<(), String> {
+        let content = "[build]\njobs = 4\n\n[alias]\nxtask = \"run --package xtask --\"\nb = [\"build\", \"--release\"]\n";
+
+        assert_eq!(
+            programs(parse_cargo_config(content)?),
+            vec![
+                ("cargo b".to_string(), "cargo b".to_string()),
+                ("cargo xtask".to_string(), "cargo xtask".to_string()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_vscode_tasks() -> Result<(), String> {
+        let content = r#"{
+            // See https://go.microsoft.com/fwlink/?LinkId=733558
+            "version": "2.0.0",
+            "tasks": [
+                {
+                    "label": "Serve docs",
+                    "type": "shell",
+                    "command": "mkdocs",
+                    "args": ["serve", {"value": "--dev-addr 0.0.0.0:8000", "quoting": "strong"}],
+                },
+                /* npm tasks have no command */
+                { "label": "Lint", "type": "npm", "script": "lint" },
+                { "label": "Compound", "dependsOn": ["Lint"] },
+            ],
+        }"#;
+
+        assert_eq!(
+            programs(parse_vscode_tasks(content)?),
+            vec![
+                (
+                    "vscode Serve docs".to_string(),
+                    "mkdocs serve \"--dev-addr 0.0.0.0:8000\"".to_string()
+                ),
+                ("vscode Lint".to_string(), "npm run lint".to_string()),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use hermione_nexus::definitions::{
+    RepositoryCommand, RepositoryCommandParameters, Shell, REPOSITORY_COMMANDS_FILE,
+};
+use serde::Deserialize;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub type RepositoryCommandsResult<T> = Result<T, RepositoryCommandsError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RepositoryCommandsError {
+    #[error(transparent)]
+    IO(#[from] io::Error),
+
+    #[error("Failed to parse {path}: {message}")]
+    Parse { path: PathBuf, message: String },
+}
+
+/// Layout of the repository command file:
+///
+/// ```toml
+/// [[commands]]
+/// name = "Test"
+/// program = "cargo test --workspace"
+/// description = "Runs every test"
+/// shell = "bash"
+/// tags = ["ci"]
+/// ```
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RepositoryCommandsFile {
+    #[serde(default)]
+    commands: Vec<RepositoryCommandEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RepositoryCommandEntry {
+    name: String,
+    program: String,
+    description: Option<String>,
+    shell: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Commands of the repository command file at the location, `None` when
+/// there is no such file.
+pub fn list_repository_commands(
+    location: &Path,
+) -> RepositoryCommandsResult<Option<Vec<RepositoryCommand>>> {
+    let path = location.join(REPOSITORY_COMMANDS_FILE);
+
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    parse_repository_commands(&content)
+        .map(Some)
+        .map_err(|message| RepositoryCommandsError::Parse { path, message })
+}
+
+/// Names must be unique within the file, later duplicates are rejected so a
+/// typo does not silently shadow a command.
+pub fn parse_repository_commands(content: &str) -> Result<Vec<RepositoryCommand>, String> {
+    let file: RepositoryCommandsFile = toml::from_str(content).map_err(|err| err.to_string())?;
+
+    let mut commands: Vec<RepositoryCommand> = Vec::with_capacity(file.commands.len());
+
+    for entry in file.commands {
+        let RepositoryCommandEntry {
+            name,
+            program,
+            description,
+            shell,
+            tags,
+        } = entry;
+
+        let shell = shell
+            .as_deref()
+            .map(Shell::parse_str)
+            .transpose()
+            .map_err(|err| err.to_string())?;
+
+        let command = RepositoryCommand::new(RepositoryCommandParameters {
+            description,
+            name,
+            program,
+            shell,
+            tags,
+        });
+
+        if command.name().is_empty() || command.program().is_empty() {
+            return Err("Commands need a name and a program".to_string());
+        }
+
+        if commands
+            .iter()
+            .any(|existing| existing.name() == command.name())
+        {
+            return Err(format!("Command {} is declared twice", command.name()));
+        }
+
+        commands.push(command);
+    }
+
+    Ok(commands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_repository_commands() -> Result<(), String> {
+        let content = "[[commands]]\nname = \"Test\"\nprogram = \"cargo test\"\ntags = [\"CI\"]\n\n[[commands]]\nname = \"Serve\"\nprogram = \"mkdocs serve\"\ndescription = \"Docs preview\"\nshell = \"bash\"\n";
+
+        let commands = parse_repository_commands(content)?;
+
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].name(), "Test");
+        assert_eq!(commands[0].tags(), ["ci", "repo"]);
+        assert_eq!(commands[1].description(), Some("Docs preview"));
+        assert_eq!(commands[1].shell(), Some(Shell::Bash));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_repository_commands_rejects_duplicates() {
+        let content = "[[commands]]\nname = \"Test\"\nprogram = \"cargo test\"\n\n[[commands]]\nname = \"Test\"\nprogram = \"cargo nextest run\"\n";
+
+        assert!(parse_repository_commands(content).is_err());
+    }
+}
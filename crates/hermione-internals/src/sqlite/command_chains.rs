@@ -0,0 +1,252 @@
+use hermione_nexus::definitions::{CommandChain, CommandChainParameters, CommandId, WorkspaceId};
+use rusqlite::{named_params, params, Connection, OptionalExtension, Result};
+use uuid::{Bytes, Uuid};
+
+#[derive(Clone)]
+pub struct CommandChainRecord {
+    pub id: Bytes,
+    pub name: String,
+    pub steps: Vec<Bytes>,
+    pub workspace_id: Bytes,
+}
+
+pub struct ListCommandChainsQuery {
+    pub workspace_id: Option<Bytes>,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+pub fn create_command_chains_table_if_not_exists(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS command_chains (
+            id BLOB PRIMARY KEY,
+            name TEXT NOT NULL,
+            workspace_id BLOB NOT NULL
+        )",
+        (),
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS
+        command_chains_workspace_id_idx
+        ON command_chains(workspace_id)",
+        (),
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS command_chain_steps (
+            command_chain_id BLOB NOT NULL,
+            position INTEGER NOT NULL,
+            command_id BLOB NOT NULL,
+            PRIMARY KEY (command_chain_id, position)
+        )",
+        (),
+    )?;
+
+    Ok(())
+}
+
+pub fn delete_command_chain(conn: &Connection, id: &Bytes) -> Result<usize> {
+    conn.prepare("DELETE FROM command_chain_steps WHERE command_chain_id = ?1")?
+        .execute(params![id])?;
+
+    conn.prepare("DELETE FROM command_chains WHERE id = ?1")?
+        .execute(params![id])
+}
+
+/// Removes the command from every chain, chains left without steps are
+/// deleted.
+pub(crate) fn delete_command_chain_steps(conn: &Connection, command_id: &Bytes) -> Result<usize> {
+    conn.prepare("DELETE FROM command_chain_steps WHERE command_id = ?1")?
+        .execute(params![command_id])?;
+
+    delete_empty_command_chains(conn)
+}
+
+/// Removes commands of the workspace from every chain, chains left without
+/// steps are deleted.
+pub(crate) fn delete_workspace_commands_chain_steps(
+    conn: &Connection,
+    workspace_id: &Bytes,
+) -> Result<usize> {
+    conn.prepare(
+        "DELETE FROM command_chain_steps
+        WHERE command_id IN (SELECT id FROM commands WHERE workspace_id = ?1)",
+    )?
+    .execute(params![workspace_id])?;
+
+    delete_empty_command_chains(conn)
+}
+
+pub(crate) fn delete_workspace_command_chains(
+    conn: &Connection,
+    workspace_id: &Bytes,
+) -> Result<usize> {
+    conn.prepare(
+        "DELETE FROM command_chain_steps
+        WHERE command_chain_id IN (SELECT id FROM command_chains WHERE workspace_id = ?1)",
+    )?
+    .execute(params![workspace_id])?;
+
+    conn.prepare("DELETE FROM command_chains WHERE workspace_id = ?1")?
+        .execute(params![workspace_id])
+}
+
+fn delete_empty_command_chains(conn: &Connection) -> Result<usize> {
+    conn.prepare(
+        "DELETE FROM command_chains
+        WHERE id NOT IN (SELECT command_chain_id FROM command_chain_steps)",
+    )?
+    .execute([])
+}
+
+pub fn find_command_chain(conn: &Connection, id: &Bytes) -> Result<Option<CommandChainRecord>> {
+    let record = conn
+        .prepare(
+            "SELECT
+                id,
+                name,
+                workspace_id
+            FROM command_chains
+            WHERE id = ?1 AND workspace_id NOT IN (
+                SELECT id FROM workspaces WHERE deleted_at IS NOT NULL
+            )",
+        )?
+        .query_row(params![id], |row| {
+            Ok(CommandChainRecord {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                steps: Vec::new(),
+                workspace_id: row.get(2)?,
+            })
+        })
+        .optional()?;
+
+    record.map(|record| with_steps(conn, record)).transpose()
+}
+
+pub fn insert_command_chain(conn: &Connection, record: CommandChainRecord) -> Result<usize> {
+    let CommandChainRecord {
+        id,
+        name,
+        steps,
+        workspace_id,
+    } = record;
+
+    let inserted = conn
+        .prepare(
+            "INSERT INTO command_chains (
+                id,
+                name,
+                workspace_id
+            ) VALUES (:id, :name, :workspace_id)",
+        )?
+        .execute(named_params![
+            ":id": id,
+            ":name": name,
+            ":workspace_id": workspace_id
+        ])?;
+
+    let mut statement = conn.prepare(
+        "INSERT INTO command_chain_steps (
+            command_chain_id,
+            position,
+            command_id
+        ) VALUES (:command_chain_id, :position, :command_id)",
+    )?;
+
+    for (position, command_id) in steps.iter().enumerate() {
+        statement.execute(named_params![
+            ":command_chain_id": id,
+            ":position": position,
+            ":command_id": command_id
+        ])?;
+    }
+
+    Ok(inserted)
+}
+
+pub fn list_command_chains(
+    conn: &Connection,
+    query: ListCommandChainsQuery,
+) -> Result<Vec<CommandChainRecord>> {
+    let ListCommandChainsQuery {
+        workspace_id,
+        offset,
+        limit,
+    } = query;
+
+    let records = conn
+        .prepare(
+            "SELECT
+                id,
+                name,
+                workspace_id
+            FROM command_chains
+            WHERE
+                (workspace_id = :workspace_id OR :workspace_id IS NULL)
+                AND workspace_id NOT IN (
+                    SELECT id FROM workspaces WHERE deleted_at IS NOT NULL
+                )
+            ORDER BY name
+            LIMIT :limit OFFSET :offset",
+        )?
+        .query_map(
+            named_params![
+                ":limit": limit,
+                ":offset": limit * offset,
+                ":workspace_id": workspace_id,
+            ],
+            |row| {
+                Ok(CommandChainRecord {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    steps: Vec::new(),
+                    workspace_id: row.get(2)?,
+                })
+            },
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    records
+        .into_iter()
+        .map(|record| with_steps(conn, record))
+        .collect()
+}
+
+fn with_steps(conn: &Connection, mut record: CommandChainRecord) -> Result<CommandChainRecord> {
+    record.steps = conn
+        .prepare(
+            "SELECT command_id
+            FROM command_chain_steps
+            WHERE command_chain_id = ?1
+            ORDER BY position",
+        )?
+        .query_map(params![record.id], |row| row.get(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(record)
+}
+
+impl TryFrom<CommandChainRecord> for CommandChain {
+    type Error = hermione_nexus::Error;
+
+    fn try_from(value: CommandChainRecord) -> hermione_nexus::Result<Self> {
+        let CommandChainRecord {
+            id,
+            name,
+            steps,
+            workspace_id,
+        } = value;
+
+        CommandChain::new(CommandChainParameters {
+            id: Uuid::from_bytes(id),
+            name,
+            steps: steps
+                .into_iter()
+                .map(|id| CommandId::new(Uuid::from_bytes(id)))
+                .collect::<hermione_nexus::Result<Vec<_>>>()?,
+            workspace_id: WorkspaceId::new(Uuid::from_bytes(workspace_id))?,
+        })
+    }
+}
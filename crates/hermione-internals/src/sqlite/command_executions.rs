@@ -0,0 +1,217 @@
+use super::drop_not_null_constraint;
+use chrono::DateTime;
+use eyre::eyre;
+use hermione_nexus::{
+    definitions::{CommandExecution, CommandExecutionParameters, CommandId, WorkspaceId},
+    Error,
+};
+use rusqlite::{named_params, params, Connection, Result};
+use uuid::{Bytes, Uuid};
+
+pub struct CommandExecutionRecord {
+    pub command_id: Option<Bytes>,
+    pub location: Option<String>,
+    pub program: String,
+    pub start_time: i64,
+    pub workspace_id: Option<Bytes>,
+}
+
+pub struct ListCommandExecutionsQuery {
+    pub command_id: Option<Bytes>,
+    pub workspace_id: Option<Bytes>,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+const COMMAND_EXECUTIONS_TABLE_DEFINITION: &str = "
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    command_id BLOB,
+    location TEXT,
+    program TEXT NOT NULL,
+    start_time INTEGER NOT NULL,
+    workspace_id BLOB
+";
+
+pub(crate) fn create_command_executions_table_if_not_exists(conn: &Connection) -> Result<()> {
+    conn.execute(
+        &format!(
+            "CREATE TABLE IF NOT EXISTS command_executions ({})",
+            COMMAND_EXECUTIONS_TABLE_DEFINITION
+        ),
+        (),
+    )?;
+
+    drop_not_null_constraint(
+        conn,
+        "command_executions",
+        "workspace_id",
+        COMMAND_EXECUTIONS_TABLE_DEFINITION,
+    )?;
+    drop_not_null_constraint(
+        conn,
+        "command_executions",
+        "command_id",
+        COMMAND_EXECUTIONS_TABLE_DEFINITION,
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS
+        command_executions_command_id_idx
+        ON command_executions(command_id)",
+        (),
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS
+        command_executions_workspace_id_idx
+        ON command_executions(workspace_id)",
+        (),
+    )?;
+
+    Ok(())
+}
+
+/// The history is append-only, so executions of a deleted command stay and
+/// only lose the reference to it.
+pub(crate) fn detach_command_executions(conn: &Connection, command_id: &Bytes) -> Result<usize> {
+    conn.prepare("UPDATE command_executions SET command_id = NULL WHERE command_id = ?1")?
+        .execute(params![command_id])
+}
+
+pub(crate) fn detach_workspace_commands_executions(
+    conn: &Connection,
+    workspace_id: &Bytes,
+) -> Result<usize> {
+    conn.prepare(
+        "UPDATE command_executions SET command_id = NULL
+        WHERE command_id IN (SELECT id FROM commands WHERE workspace_id = ?1)",
+    )?
+    .execute(params![workspace_id])
+}
+
+pub(crate) fn detach_workspace_executions(
+    conn: &Connection,
+    workspace_id: &Bytes,
+) -> Result<usize> {
+    conn.prepare("UPDATE command_executions SET workspace_id = NULL WHERE workspace_id = ?1")?
+        .execute(params![workspace_id])
+}
+
+pub fn insert_command_execution(
+    conn: &Connection,
+    record: CommandExecutionRecord,
+) -> Result<usize> {
+    let CommandExecutionRecord {
+        command_id,
+        location,
+        program,
+        start_time,
+        workspace_id,
+    } = record;
+
+    let mut statement = conn.prepare(
+        "INSERT INTO command_executions (
+            command_id,
+            location,
+            program,
+            start_time,
+            workspace_id
+        ) VALUES (
+            :command_id,
+            :location,
+            :program,
+            :start_time,
+            :workspace_id
+        )",
+    )?;
+
+    statement.execute(named_params![
+        ":command_id": command_id,
+        ":location": location,
+        ":program": program,
+        ":start_time": start_time,
+        ":workspace_id": workspace_id
+    ])
+}
+
+pub fn list_command_executions(
+    conn: &Connection,
+    query: ListCommandExecutionsQuery,
+) -> Result<Vec<CommandExecutionRecord>> {
+    let ListCommandExecutionsQuery {
+        command_id,
+        workspace_id,
+        offset,
+        limit,
+    } = query;
+
+    let mut statement = conn.prepare(
+        "SELECT
+            command_id,
+            location,
+            program,
+            start_time,
+            workspace_id
+        FROM command_executions
+        WHERE
+            (command_id = :command_id OR :command_id IS NULL)
+            AND (workspace_id = :workspace_id OR :workspace_id IS NULL)
+        ORDER BY start_time DESC, id DESC
+        LIMIT :limit OFFSET :offset",
+    )?;
+
+    let records = statement
+        .query_map(
+            named_params![
+                ":command_id": command_id,
+                ":limit": limit,
+                ":offset": limit * offset,
+                ":workspace_id": workspace_id,
+            ],
+            |row| {
+                Ok(CommandExecutionRecord {
+                    command_id: row.get(0)?,
+                    location: row.get(1)?,
+                    program: row.get(2)?,
+                    start_time: row.get(3)?,
+                    workspace_id: row.get(4)?,
+                })
+            },
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(records)
+}
+
+impl TryFrom<CommandExecutionRecord> for CommandExecution {
+    type Error = hermione_nexus::Error;
+
+    fn try_from(value: CommandExecutionRecord) -> hermione_nexus::Result<Self> {
+        let CommandExecutionRecord {
+            command_id,
+            location,
+            program,
+            start_time,
+            workspace_id,
+        } = value;
+
+        let start_time = DateTime::from_timestamp_micros(start_time).ok_or_else(|| {
+            Error::storage(eyre!(
+                "Invalid command execution start time: {}",
+                start_time
+            ))
+        })?;
+
+        Ok(CommandExecution::new(CommandExecutionParameters {
+            command_id: command_id
+                .map(|id| CommandId::new(Uuid::from_bytes(id)))
+                .transpose()?,
+            location,
+            program,
+            start_time,
+            workspace_id: workspace_id
+                .map(|id| WorkspaceId::new(Uuid::from_bytes(id)))
+                .transpose()?,
+        }))
+    }
+}
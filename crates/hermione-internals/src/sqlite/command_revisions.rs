@@ -0,0 +1,215 @@
+use super::now_micros;
+use chrono::DateTime;
+use eyre::eyre;
+use hermione_nexus::{
+    definitions::{CommandId, CommandRevision, CommandRevisionParameters},
+    Error,
+};
+use rusqlite::{named_params, params, Connection, OptionalExtension, Result};
+use uuid::{Bytes, Uuid};
+
+pub struct CommandRevisionRecord {
+    pub id: Bytes,
+    pub command_id: Bytes,
+    pub created_at: i64,
+    pub name: String,
+    pub program: String,
+}
+
+pub struct ListCommandRevisionsQuery {
+    pub command_id: Bytes,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+pub struct NewCommandRevisionQuery {
+    pub id: Bytes,
+    pub command_id: Bytes,
+    pub name: String,
+    pub program: String,
+}
+
+pub(crate) fn create_command_revisions_table_if_not_exists(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS command_revisions (
+            id BLOB PRIMARY KEY,
+            command_id BLOB NOT NULL,
+            created_at INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            program TEXT NOT NULL
+        )",
+        (),
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS
+        command_revisions_command_id_idx
+        ON command_revisions(command_id)",
+        (),
+    )?;
+
+    Ok(())
+}
+
+pub(crate) fn delete_command_revisions(conn: &Connection, command_id: &Bytes) -> Result<usize> {
+    conn.prepare("DELETE FROM command_revisions WHERE command_id = ?1")?
+        .execute(params![command_id])
+}
+
+pub(crate) fn delete_workspace_commands_revisions(
+    conn: &Connection,
+    workspace_id: &Bytes,
+) -> Result<usize> {
+    conn.prepare(
+        "DELETE FROM command_revisions
+        WHERE command_id IN (SELECT id FROM commands WHERE workspace_id = ?1)",
+    )?
+    .execute(params![workspace_id])
+}
+
+pub fn find_command_revision(
+    conn: &Connection,
+    id: &Bytes,
+) -> Result<Option<CommandRevisionRecord>> {
+    conn.prepare(
+        "SELECT
+            id,
+            command_id,
+            created_at,
+            name,
+            program
+        FROM command_revisions
+        WHERE id = ?1",
+    )?
+    .query_row(params![id], |row| {
+        Ok(CommandRevisionRecord {
+            id: row.get(0)?,
+            command_id: row.get(1)?,
+            created_at: row.get(2)?,
+            name: row.get(3)?,
+            program: row.get(4)?,
+        })
+    })
+    .optional()
+}
+
+/// The revision gets the current time, so revisions of a command are ordered
+/// by when they were replaced.
+pub fn insert_command_revision(
+    conn: &Connection,
+    query: NewCommandRevisionQuery,
+) -> Result<CommandRevisionRecord> {
+    let NewCommandRevisionQuery {
+        id,
+        command_id,
+        name,
+        program,
+    } = query;
+
+    let created_at = now_micros();
+
+    conn.prepare(
+        "INSERT INTO command_revisions (
+            id,
+            command_id,
+            created_at,
+            name,
+            program
+        ) VALUES (
+            :id,
+            :command_id,
+            :created_at,
+            :name,
+            :program
+        )",
+    )?
+    .execute(named_params![
+        ":id": id,
+        ":command_id": command_id,
+        ":created_at": created_at,
+        ":name": name,
+        ":program": program,
+    ])?;
+
+    Ok(CommandRevisionRecord {
+        id,
+        command_id,
+        created_at,
+        name,
+        program,
+    })
+}
+
+pub fn list_command_revisions(
+    conn: &Connection,
+    query: ListCommandRevisionsQuery,
+) -> Result<Vec<CommandRevisionRecord>> {
+    let ListCommandRevisionsQuery {
+        command_id,
+        offset,
+        limit,
+    } = query;
+
+    let mut statement = conn.prepare(
+        "SELECT
+            id,
+            command_id,
+            created_at,
+            name,
+            program
+        FROM command_revisions
+        WHERE command_id = :command_id
+        ORDER BY created_at DESC, rowid DESC
+        LIMIT :limit OFFSET :offset",
+    )?;
+
+    let records = statement
+        .query_map(
+            named_params![
+                ":command_id": command_id,
+                ":limit": limit,
+                ":offset": limit * offset,
+            ],
+            |row| {
+                Ok(CommandRevisionRecord {
+                    id: row.get(0)?,
+                    command_id: row.get(1)?,
+                    created_at: row.get(2)?,
+                    name: row.get(3)?,
+                    program: row.get(4)?,
+                })
+            },
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(records)
+}
+
+impl TryFrom<CommandRevisionRecord> for CommandRevision {
+    type Error = hermione_nexus::Error;
+
+    fn try_from(value: CommandRevisionRecord) -> hermione_nexus::Result<Self> {
+        let CommandRevisionRecord {
+            id,
+            command_id,
+            created_at,
+            name,
+            program,
+        } = value;
+
+        let created_at = DateTime::from_timestamp_micros(created_at).ok_or_else(|| {
+            Error::storage(eyre!(
+                "Invalid command revision creation time: {}",
+                created_at
+            ))
+        })?;
+
+        CommandRevision::new(CommandRevisionParameters {
+            id: Uuid::from_bytes(id),
+            command_id: CommandId::new(Uuid::from_bytes(command_id))?,
+            created_at,
+            name,
+            program,
+        })
+    }
+}
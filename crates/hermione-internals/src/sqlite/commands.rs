@@ -1,50 +1,200 @@
-use super::OptionalValue;
+use super::{
+    add_column_if_not_exists, command_chains, command_executions, command_revisions,
+    drop_not_null_constraint, now_micros, tags, with_savepoint, OptionalValue, SortQuery,
+    TagsQuery, TagsQueryParameters,
+};
 use chrono::DateTime;
-use hermione_nexus::definitions::{Command, CommandParameters, WorkspaceId};
+use hermione_nexus::definitions::{Command, CommandManager, CommandParameters, Shell, WorkspaceId};
 use rusqlite::{named_params, params, Connection, OptionalExtension, Result};
 use uuid::{Bytes, Uuid};
 
 #[derive(Clone)]
 pub struct CommandRecord {
     pub id: Bytes,
+    pub created_at: Option<i64>,
+    pub description: Option<String>,
     pub last_execute_time: Option<i64>,
+    pub managed_by: Option<String>,
     pub name: String,
+    pub pinned: bool,
     pub program: String,
-    pub workspace_id: Bytes,
+    pub shell: Option<String>,
+    pub tags: Vec<String>,
+    pub updated_at: Option<i64>,
+    pub workspace_id: Option<Bytes>,
+}
+
+pub enum CommandScopeQuery {
+    All,
+    Global,
+    Inherited(Bytes),
+    Workspace(Bytes),
+    WorkspaceAndGlobal(Bytes),
+}
+
+pub struct CopyCommandQuery {
+    pub id: Bytes,
+    pub copy_id: Bytes,
+    pub keep_history: bool,
+    pub workspace_id: Option<Bytes>,
 }
 
 pub struct ListCommandsQuery<'a> {
+    pub created_after: Option<i64>,
+    pub description_contains: Option<&'a str>,
     pub program_contains: &'a str,
-    pub workspace_id: Option<Bytes>,
+    pub scope: CommandScopeQuery,
+    pub sort: SortQuery,
+    pub tags: Option<TagsQuery>,
+    pub updated_after: Option<i64>,
     pub offset: u32,
     pub limit: u32,
 }
 
+pub struct MoveCommandQuery {
+    pub id: Bytes,
+    pub keep_history: bool,
+    pub workspace_id: Option<Bytes>,
+}
+
+pub struct SyncCommandsQuery {
+    pub deleted: Vec<Bytes>,
+    pub inserted: Vec<CommandRecord>,
+    pub updated: Vec<CommandRecord>,
+}
+
 pub struct UpdateCommandQueryOptions {
     pub id: Bytes,
+    pub description: Option<OptionalValue<String>>,
     pub last_execute_time: Option<OptionalValue<i64>>,
     pub name: Option<String>,
+    pub pinned: Option<bool>,
     pub program: Option<String>,
+    pub shell: Option<OptionalValue<String>>,
+    pub tags: Option<Vec<String>>,
 }
 
 impl UpdateCommandQueryOptions {
     fn is_empty(&self) -> bool {
-        self.last_execute_time.is_none() && self.name.is_none() && self.program.is_none()
+        self.last_execute_time.is_none() && !self.is_modified()
+    }
+
+    fn is_modified(&self) -> bool {
+        self.description.is_some()
+            || self.name.is_some()
+            || self.pinned.is_some()
+            || self.program.is_some()
+            || self.shell.is_some()
+            || self.tags.is_some()
+    }
+}
+
+const COMMANDS_TABLE_DEFINITION: &str = "
+    id BLOB PRIMARY KEY,
+    description TEXT,
+    execute_count INTEGER NOT NULL DEFAULT 0,
+    last_execute_time INTEGER,
+    name TEXT NOT NULL,
+    pinned INTEGER NOT NULL DEFAULT 0,
+    program TEXT NOT NULL,
+    shell TEXT,
+    workspace_id BLOB,
+    deleted_at INTEGER,
+    created_at INTEGER,
+    updated_at INTEGER,
+    managed_by TEXT
+";
+
+impl CommandScopeQuery {
+    fn parameters(&self) -> (bool, bool, bool, Option<&Bytes>) {
+        match self {
+            CommandScopeQuery::All => (true, true, false, None),
+            CommandScopeQuery::Global => (false, true, false, None),
+            CommandScopeQuery::Inherited(id) => (false, true, true, Some(id)),
+            CommandScopeQuery::Workspace(id) => (false, false, false, Some(id)),
+            CommandScopeQuery::WorkspaceAndGlobal(id) => (false, true, false, Some(id)),
+        }
     }
 }
 
+/// The copy is not managed by any synchronization, even if the original is.
+/// Keeping the history carries over the execute count and time for ranking
+/// only, the executions stay with the original.
+pub fn copy_command(conn: &Connection, query: CopyCommandQuery) -> Result<usize> {
+    let CopyCommandQuery {
+        id,
+        copy_id,
+        keep_history,
+        workspace_id,
+    } = query;
+
+    let copied = conn
+        .prepare(
+            "INSERT INTO commands (
+                id,
+                description,
+                execute_count,
+                last_execute_time,
+                name,
+                pinned,
+                program,
+                shell,
+                workspace_id,
+                created_at,
+                updated_at
+            )
+            SELECT
+                :copy_id,
+                description,
+                CASE WHEN :keep_history THEN execute_count ELSE 0 END,
+                CASE WHEN :keep_history THEN last_execute_time ELSE NULL END,
+                name,
+                pinned,
+                program,
+                shell,
+                :workspace_id,
+                :now,
+                :now
+            FROM commands
+            WHERE id = :id",
+        )?
+        .execute(named_params![
+            ":id": id,
+            ":copy_id": copy_id,
+            ":keep_history": keep_history,
+            ":now": now_micros(),
+            ":workspace_id": workspace_id,
+        ])?;
+
+    tags::copy_command_tags(conn, &id, &copy_id)?;
+
+    Ok(copied)
+}
+
 pub fn create_commands_table_if_not_exists(conn: &Connection) -> Result<()> {
     conn.execute(
-        "CREATE TABLE IF NOT EXISTS commands (
-            id BLOB PRIMARY KEY,
-            last_execute_time INTEGER,
-            name TEXT NOT NULL,
-            program TEXT NOT NULL,
-            workspace_id BLOB NOT NULL
-        )",
+        &format!(
+            "CREATE TABLE IF NOT EXISTS commands ({})",
+            COMMANDS_TABLE_DEFINITION
+        ),
         (),
     )?;
 
+    add_column_if_not_exists(conn, "commands", "description", "TEXT")?;
+    add_column_if_not_exists(conn, "commands", "shell", "TEXT")?;
+    add_column_if_not_exists(
+        conn,
+        "commands",
+        "execute_count",
+        "INTEGER NOT NULL DEFAULT 0",
+    )?;
+    add_column_if_not_exists(conn, "commands", "pinned", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column_if_not_exists(conn, "commands", "deleted_at", "INTEGER")?;
+    add_column_if_not_exists(conn, "commands", "created_at", "INTEGER")?;
+    add_column_if_not_exists(conn, "commands", "updated_at", "INTEGER")?;
+    add_column_if_not_exists(conn, "commands", "managed_by", "TEXT")?;
+    drop_not_null_constraint(conn, "commands", "workspace_id", COMMANDS_TABLE_DEFINITION)?;
+
     conn.execute(
         "CREATE INDEX IF NOT EXISTS
         commands_workspace_id_idx
@@ -52,6 +202,11 @@ pub fn create_commands_table_if_not_exists(conn: &Connection) -> Result<()> {
         (),
     )?;
 
+    tags::create_command_tags_table_if_not_exists(conn)?;
+    command_executions::create_command_executions_table_if_not_exists(conn)?;
+    command_revisions::create_command_revisions_table_if_not_exists(conn)?;
+    command_chains::create_command_chains_table_if_not_exists(conn)?;
+
     Ok(())
 }
 
@@ -59,102 +214,278 @@ pub fn find_command(conn: &Connection, id: &Bytes) -> Result<Option<CommandRecor
     conn.prepare(
         "SELECT
             id,
+            description,
             last_execute_time,
             name,
+            pinned,
             program,
-            workspace_id
+            shell,
+            workspace_id,
+            (
+                SELECT GROUP_CONCAT(command_tags.name)
+                FROM command_tags
+                WHERE command_id = commands.id
+            ),
+            created_at,
+            updated_at,
+            managed_by
         FROM commands
-        WHERE id = ?1",
+        WHERE id = ?1 AND deleted_at IS NULL",
     )?
     .query_row(params![id], |row| {
         Ok(CommandRecord {
             id: row.get(0)?,
-            last_execute_time: row.get(1)?,
-            name: row.get(2)?,
-            program: row.get(3)?,
-            workspace_id: row.get(4)?,
+            created_at: row.get(9)?,
+            description: row.get(1)?,
+            last_execute_time: row.get(2)?,
+            name: row.get(3)?,
+            pinned: row.get(4)?,
+            program: row.get(5)?,
+            shell: row.get(6)?,
+            workspace_id: row.get(7)?,
+            tags: tags::split_tags(row.get(8)?),
+            updated_at: row.get(10)?,
+            managed_by: row.get(11)?,
         })
     })
     .optional()
 }
 
 pub fn delete_command(conn: &Connection, id: &Bytes) -> Result<usize> {
+    tags::delete_command_tags(conn, id)?;
+    command_revisions::delete_command_revisions(conn, id)?;
+    command_chains::delete_command_chain_steps(conn, id)?;
+    command_executions::detach_command_executions(conn, id)?;
+
     conn.prepare("DELETE FROM commands WHERE id = ?1")?
         .execute(params![id])
 }
 
 pub fn delete_workspace_commands(conn: &Connection, workspace_id: &Bytes) -> Result<usize> {
+    tags::delete_workspace_commands_tags(conn, workspace_id)?;
+    command_revisions::delete_workspace_commands_revisions(conn, workspace_id)?;
+    command_chains::delete_workspace_commands_chain_steps(conn, workspace_id)?;
+    command_executions::detach_workspace_commands_executions(conn, workspace_id)?;
+
     conn.prepare("DELETE FROM commands WHERE workspace_id = ?1")?
         .execute(params![workspace_id])
 }
 
+pub fn increment_command_execute_count(conn: &Connection, id: &Bytes) -> Result<usize> {
+    conn.prepare("UPDATE commands SET execute_count = execute_count + 1 WHERE id = ?1")?
+        .execute(params![id])
+}
+
+/// Rows without creation or modification time get the current time.
 pub fn insert_command(conn: &Connection, record: CommandRecord) -> Result<usize> {
     let CommandRecord {
         id,
+        created_at,
+        description,
         last_execute_time,
+        managed_by,
         name,
+        pinned,
         program,
+        shell,
+        tags,
+        updated_at,
         workspace_id,
     } = record;
 
+    tags::insert_command_tags(conn, &id, &tags)?;
+
     conn.prepare(
         "INSERT INTO commands (
             id,
+            created_at,
+            description,
             last_execute_time,
+            managed_by,
             name,
+            pinned,
             program,
+            shell,
+            updated_at,
             workspace_id
-        ) VALUES (:id, :last_execute_time, :name, :program, :workspace_id)",
+        ) VALUES (
+            :id,
+            COALESCE(:created_at, :now),
+            :description,
+            :last_execute_time,
+            :managed_by,
+            :name,
+            :pinned,
+            :program,
+            :shell,
+            COALESCE(:updated_at, :created_at, :now),
+            :workspace_id
+        )",
     )?
     .execute(named_params![
         ":id": id,
+        ":created_at": created_at,
+        ":description": description,
         ":last_execute_time": last_execute_time,
+        ":managed_by": managed_by,
         ":name": name,
+        ":now": now_micros(),
+        ":pinned": pinned,
         ":program": program,
+        ":shell": shell,
+        ":updated_at": updated_at,
         ":workspace_id": workspace_id
     ])
 }
 
+pub(crate) fn list_workspace_command_ids(
+    conn: &Connection,
+    workspace_id: &Bytes,
+) -> Result<Vec<Bytes>> {
+    conn.prepare("SELECT id FROM commands WHERE workspace_id = ?1 AND deleted_at IS NULL")?
+        .query_map(params![workspace_id], |row| row.get(0))?
+        .collect()
+}
+
 pub fn list_commands(conn: &Connection, query: ListCommandsQuery) -> Result<Vec<CommandRecord>> {
     let ListCommandsQuery {
+        created_after,
+        description_contains,
         program_contains,
-        workspace_id,
+        scope,
+        sort,
+        tags,
+        updated_after,
         offset,
         limit,
     } = query;
 
+    let (all_scopes, include_global, include_ancestors, workspace_id) = scope.parameters();
+    let frecency_now = sort.frecency_now();
+    let timestamp_column = sort.timestamp_column();
+    let description_contains =
+        description_contains.map(|value| format!("%{}%", value.to_lowercase()));
     let program_contains = format!("%{}%", program_contains.to_lowercase());
+    let TagsQueryParameters {
+        pattern: tags_pattern,
+        required_matches: tags_required_matches,
+    } = TagsQueryParameters::new(tags);
 
     let mut statement = conn.prepare(
         "SELECT
             id,
+            description,
             last_execute_time,
             name,
+            pinned,
             program,
-            workspace_id
+            shell,
+            workspace_id,
+            (
+                SELECT GROUP_CONCAT(command_tags.name)
+                FROM command_tags
+                WHERE command_id = commands.id
+            ),
+            created_at,
+            updated_at,
+            managed_by
         FROM commands
         WHERE
-            LOWER(program) LIKE :program_contains
-            AND (workspace_id = :workspace_id OR :workspace_id IS NULL)
-        ORDER BY last_execute_time DESC, program ASC
+            deleted_at IS NULL
+            AND LOWER(program) LIKE :program_contains
+            AND (:created_after IS NULL OR created_at > :created_after)
+            AND (:updated_after IS NULL OR updated_at > :updated_after)
+            AND (
+                :description_contains IS NULL
+                OR LOWER(description) LIKE :description_contains
+            )
+            AND (
+                :all_scopes
+                OR (:include_global AND workspace_id IS NULL)
+                OR workspace_id = :workspace_id
+                OR (
+                    :include_ancestors
+                    AND workspace_id IN (
+                        WITH RECURSIVE ancestors(id) AS (
+                            SELECT parent.id
+                            FROM workspaces AS child
+                            JOIN workspaces AS parent ON parent.id = child.parent_id
+                            WHERE child.id = :workspace_id AND parent.deleted_at IS NULL
+                            UNION
+                            SELECT parent.id
+                            FROM ancestors
+                            JOIN workspaces AS child ON child.id = ancestors.id
+                            JOIN workspaces AS parent ON parent.id = child.parent_id
+                            WHERE parent.deleted_at IS NULL
+                        )
+                        SELECT id FROM ancestors
+                    )
+                )
+            )
+            AND (
+                :tags_pattern IS NULL
+                OR (
+                    SELECT COUNT(*)
+                    FROM command_tags
+                    WHERE
+                        command_id = commands.id
+                        AND INSTR(:tags_pattern, ',' || command_tags.name || ',') > 0
+                ) >= :tags_required_matches
+            )
+        ORDER BY
+            pinned DESC,
+            CASE :timestamp_column
+                WHEN 'created_at' THEN created_at
+                WHEN 'updated_at' THEN updated_at
+            END DESC,
+            CASE
+                WHEN :frecency_now IS NULL OR last_execute_time IS NULL THEN 0
+                ELSE execute_count * (
+                    CASE
+                        WHEN :frecency_now - last_execute_time < 3600000000 THEN 4.0
+                        WHEN :frecency_now - last_execute_time < 86400000000 THEN 2.0
+                        WHEN :frecency_now - last_execute_time < 604800000000 THEN 0.5
+                        ELSE 0.25
+                    END
+                )
+            END DESC,
+            last_execute_time DESC,
+            program ASC
         LIMIT :limit OFFSET :offset",
     )?;
 
     let records = statement
         .query_map(
             named_params![
+                ":all_scopes": all_scopes,
+                ":created_after": created_after,
+                ":description_contains": description_contains,
+                ":include_ancestors": include_ancestors,
+                ":include_global": include_global,
+                ":frecency_now": frecency_now,
                 ":limit": limit,
                 ":offset": limit * offset,
                 ":program_contains": program_contains,
+                ":tags_pattern": tags_pattern,
+                ":tags_required_matches": tags_required_matches,
+                ":timestamp_column": timestamp_column,
+                ":updated_after": updated_after,
                 ":workspace_id": workspace_id,
             ],
             |row| {
                 Ok(CommandRecord {
                     id: row.get(0)?,
-                    last_execute_time: row.get(1)?,
-                    name: row.get(2)?,
-                    program: row.get(3)?,
-                    workspace_id: row.get(4)?,
+                    created_at: row.get(9)?,
+                    description: row.get(1)?,
+                    last_execute_time: row.get(2)?,
+                    name: row.get(3)?,
+                    pinned: row.get(4)?,
+                    program: row.get(5)?,
+                    shell: row.get(6)?,
+                    workspace_id: row.get(7)?,
+                    tags: tags::split_tags(row.get(8)?),
+                    updated_at: row.get(10)?,
+                    managed_by: row.get(11)?,
                 })
             },
         )?
@@ -163,70 +494,216 @@ pub fn list_commands(conn: &Connection, query: ListCommandsQuery) -> Result<Vec<
     Ok(records)
 }
 
+/// Without the history the executions stay in the append-only log and only
+/// lose the reference to the command.
+pub fn move_command(conn: &Connection, query: MoveCommandQuery) -> Result<usize> {
+    let MoveCommandQuery {
+        id,
+        keep_history,
+        workspace_id,
+    } = query;
+
+    if !keep_history {
+        command_executions::detach_command_executions(conn, &id)?;
+    }
+
+    conn.prepare(
+        "UPDATE commands
+        SET
+            execute_count = CASE WHEN :keep_history THEN execute_count ELSE 0 END,
+            last_execute_time = CASE
+                WHEN :keep_history THEN last_execute_time
+                ELSE NULL
+            END,
+            updated_at = :now,
+            workspace_id = :workspace_id
+        WHERE id = :id",
+    )?
+    .execute(named_params![
+        ":id": id,
+        ":keep_history": keep_history,
+        ":now": now_micros(),
+        ":workspace_id": workspace_id,
+    ])
+}
+
+/// Restored rows keep their own timestamps when they carry them. Otherwise
+/// new rows count as created or modified now and existing rows keep their
+/// local timestamps. Existing rows keep their manager when the restored one
+/// has none, backups do not carry it. Restored rows get exactly the tags of
+/// their records.
 pub fn restore_commands(conn: &Connection, records: Vec<CommandRecord>) -> Result<()> {
     let mut statement = conn.prepare(
-        "INSERT INTO commands
-        VALUES (:id, :last_execute_time, :name, :program, :workspace_id)
+        "INSERT INTO commands (
+            id,
+            created_at,
+            description,
+            last_execute_time,
+            managed_by,
+            name,
+            pinned,
+            program,
+            shell,
+            updated_at,
+            workspace_id
+        ) VALUES (
+            :id,
+            COALESCE(:created_at, :now),
+            :description,
+            :last_execute_time,
+            :managed_by,
+            :name,
+            :pinned,
+            :program,
+            :shell,
+            COALESCE(:updated_at, :now),
+            :workspace_id
+        )
         ON CONFLICT (id) DO UPDATE SET
+            created_at = COALESCE(:created_at, created_at, excluded.created_at),
+            description = excluded.description,
             last_execute_time = excluded.last_execute_time,
+            managed_by = COALESCE(excluded.managed_by, managed_by),
             name = excluded.name,
+            pinned = excluded.pinned,
             program = excluded.program,
+            shell = excluded.shell,
+            updated_at = COALESCE(:updated_at, updated_at),
             workspace_id = excluded.workspace_id",
     )?;
 
+    let now = now_micros();
+
     for record in records {
         let CommandRecord {
             id,
+            created_at,
+            description,
             last_execute_time,
+            managed_by,
             name,
+            pinned,
             program,
+            shell,
+            tags,
+            updated_at,
             workspace_id,
         } = record;
 
         statement.execute(named_params![
             ":id": id,
+            ":created_at": created_at,
+            ":description": description,
             ":last_execute_time": last_execute_time,
+            ":managed_by": managed_by,
             ":name": name,
+            ":now": now,
+            ":pinned": pinned,
             ":program": program,
+            ":shell": shell,
+            ":updated_at": updated_at,
             ":workspace_id": workspace_id
         ])?;
+
+        tags::delete_command_tags(conn, &id)?;
+        tags::insert_command_tags(conn, &id, &tags)?;
     }
 
     Ok(())
 }
 
+/// Stores all changes of a synchronization or none of them.
+pub fn sync_commands(conn: &Connection, query: SyncCommandsQuery) -> Result<()> {
+    let SyncCommandsQuery {
+        deleted,
+        inserted,
+        updated,
+    } = query;
+
+    with_savepoint(conn, "sync_commands", || {
+        for record in inserted {
+            insert_command(conn, record)?;
+        }
+
+        restore_commands(conn, updated)?;
+
+        for id in &deleted {
+            delete_command(conn, id)?;
+        }
+
+        Ok(())
+    })
+}
+
+/// Any change other than the execution time counts as a modification.
 pub fn update_command(conn: &Connection, options: UpdateCommandQueryOptions) -> Result<usize> {
     if options.is_empty() {
         return Ok(0);
     }
 
+    let is_modified = options.is_modified();
+
     let UpdateCommandQueryOptions {
         id,
+        description,
         last_execute_time,
         name,
+        pinned,
         program,
+        shell,
+        tags,
     } = options;
 
+    if let Some(tags) = tags {
+        tags::delete_command_tags(conn, &id)?;
+        tags::insert_command_tags(conn, &id, &tags)?;
+    }
+
+    let skip_description_update = description.is_none();
     let skip_last_execute_time_update = last_execute_time.is_none();
+    let skip_shell_update = shell.is_none();
+
+    let description: Option<String> = description.and_then(Into::into);
     let last_execute_time: Option<i64> = last_execute_time.and_then(Into::into);
+    let shell: Option<String> = shell.and_then(Into::into);
 
     conn.prepare(
         "UPDATE commands
         SET
+            description = CASE
+                WHEN :skip_description_update THEN description
+                ELSE :description
+            END,
             last_execute_time = CASE
                 WHEN :skip_last_execute_time_update THEN last_execute_time
                 ELSE :last_execute_time
             END,
             name = COALESCE(:name, name),
-            program = COALESCE(:program, program)
+            pinned = COALESCE(:pinned, pinned),
+            program = COALESCE(:program, program),
+            shell = CASE
+                WHEN :skip_shell_update THEN shell
+                ELSE :shell
+            END,
+            updated_at = CASE
+                WHEN :is_modified THEN :now
+                ELSE updated_at
+            END
         WHERE id = :id",
     )?
     .execute(named_params![
         ":id": id,
+        ":is_modified": is_modified,
+        ":now": now_micros(),
+        ":skip_description_update": skip_description_update,
+        ":description": description,
         ":skip_last_execute_time_update": skip_last_execute_time_update,
         ":last_execute_time": last_execute_time,
         ":name": name,
-        ":program": program
+        ":pinned": pinned,
+        ":program": program,
+        ":skip_shell_update": skip_shell_update,
+        ":shell": shell
     ])
 }
 
@@ -238,10 +715,23 @@ impl From<Command> for CommandRecord {
 
         CommandRecord {
             id: value.id().into_bytes(),
+            created_at: value
+                .created_at()
+                .map(|date_time| date_time.timestamp_micros()),
+            description: value.description().map(ToString::to_string),
             last_execute_time,
+            managed_by: value
+                .managed_by()
+                .map(|manager| manager.as_str().to_string()),
             name: value.name().to_string(),
+            pinned: value.is_pinned(),
             program: value.program().to_string(),
-            workspace_id: value.workspace_id().into_bytes(),
+            shell: value.shell().map(|shell| shell.as_str().to_string()),
+            tags: value.tags().to_vec(),
+            updated_at: value
+                .updated_at()
+                .map(|date_time| date_time.timestamp_micros()),
+            workspace_id: value.workspace_id().map(WorkspaceId::into_bytes),
         }
     }
 }
@@ -252,22 +742,45 @@ impl TryFrom<CommandRecord> for Command {
     fn try_from(value: CommandRecord) -> hermione_nexus::Result<Self> {
         let CommandRecord {
             id,
+            created_at,
+            description,
             last_execute_time,
+            managed_by,
             name,
+            pinned,
             program,
+            shell,
+            tags,
+            updated_at,
             workspace_id,
         } = value;
 
+        let created_at = created_at.and_then(DateTime::from_timestamp_micros);
         let last_execute_time = last_execute_time.and_then(DateTime::from_timestamp_micros);
+        let updated_at = updated_at.and_then(DateTime::from_timestamp_micros);
         let id = Uuid::from_bytes(id);
-        let workspace_id = Uuid::from_bytes(workspace_id);
+        let workspace_id = workspace_id
+            .map(|id| WorkspaceId::new(Uuid::from_bytes(id)))
+            .transpose()?;
+        let shell = shell.as_deref().map(Shell::parse_str).transpose()?;
+        let managed_by = managed_by
+            .as_deref()
+            .map(CommandManager::parse_str)
+            .transpose()?;
 
-        Command::new(CommandParameters {
+        Command::load(CommandParameters {
             id,
+            created_at,
+            description,
             last_execute_time,
+            managed_by,
             name,
+            pinned,
             program,
-            workspace_id: WorkspaceId::new(workspace_id)?,
+            shell,
+            tags,
+            updated_at,
+            workspace_id,
         })
     }
 }
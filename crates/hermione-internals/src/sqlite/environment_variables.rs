@@ -0,0 +1,116 @@
+use hermione_nexus::definitions::{EnvironmentVariable, EnvironmentVariableParameters};
+use rusqlite::{named_params, params, Connection, Result};
+use uuid::Bytes;
+
+pub struct EnvironmentVariableRecord {
+    pub name: String,
+    pub value: String,
+}
+
+pub(crate) fn copy_workspace_environment_variables(
+    conn: &Connection,
+    from: &Bytes,
+    to: &Bytes,
+) -> Result<usize> {
+    conn.prepare(
+        "INSERT INTO workspace_environment_variables (workspace_id, name, value)
+        SELECT :to, name, value FROM workspace_environment_variables WHERE workspace_id = :from",
+    )?
+    .execute(named_params![":from": from, ":to": to])
+}
+
+pub(crate) fn create_workspace_environment_variables_table_if_not_exists(
+    conn: &Connection,
+) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS workspace_environment_variables (
+            workspace_id BLOB NOT NULL,
+            name TEXT NOT NULL,
+            value TEXT NOT NULL,
+            PRIMARY KEY (workspace_id, name)
+        )",
+        (),
+    )?;
+
+    Ok(())
+}
+
+pub fn delete_workspace_environment_variables(
+    conn: &Connection,
+    workspace_id: &Bytes,
+) -> Result<usize> {
+    conn.prepare("DELETE FROM workspace_environment_variables WHERE workspace_id = ?1")?
+        .execute(params![workspace_id])
+}
+
+pub fn list_workspace_environment_variables(
+    conn: &Connection,
+    workspace_id: &Bytes,
+) -> Result<Vec<EnvironmentVariableRecord>> {
+    let mut statement = conn.prepare(
+        "SELECT
+            name,
+            value
+        FROM workspace_environment_variables
+        WHERE workspace_id = ?1
+        ORDER BY name ASC",
+    )?;
+
+    let records = statement
+        .query_map(params![workspace_id], |row| {
+            Ok(EnvironmentVariableRecord {
+                name: row.get(0)?,
+                value: row.get(1)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(records)
+}
+
+pub fn replace_workspace_environment_variables(
+    conn: &Connection,
+    workspace_id: &Bytes,
+    records: Vec<EnvironmentVariableRecord>,
+) -> Result<()> {
+    delete_workspace_environment_variables(conn, workspace_id)?;
+
+    let mut statement = conn.prepare(
+        "INSERT INTO workspace_environment_variables (
+            workspace_id,
+            name,
+            value
+        ) VALUES (:workspace_id, :name, :value)",
+    )?;
+
+    for record in records {
+        let EnvironmentVariableRecord { name, value } = record;
+
+        statement.execute(named_params![
+            ":workspace_id": workspace_id,
+            ":name": name,
+            ":value": value
+        ])?;
+    }
+
+    Ok(())
+}
+
+impl From<&EnvironmentVariable> for EnvironmentVariableRecord {
+    fn from(value: &EnvironmentVariable) -> Self {
+        EnvironmentVariableRecord {
+            name: value.name().to_string(),
+            value: value.value().to_string(),
+        }
+    }
+}
+
+impl TryFrom<EnvironmentVariableRecord> for EnvironmentVariable {
+    type Error = hermione_nexus::Error;
+
+    fn try_from(value: EnvironmentVariableRecord) -> hermione_nexus::Result<Self> {
+        let EnvironmentVariableRecord { name, value } = value;
+
+        EnvironmentVariable::new(EnvironmentVariableParameters { name, value })
+    }
+}
@@ -1,11 +1,63 @@
 mod backup_credentials;
+mod command_chains;
+mod command_executions;
+mod command_revisions;
 mod commands;
+mod environment_variables;
+mod secrets;
+mod tags;
+mod trash;
 mod workspaces;
 
 pub use backup_credentials::*;
+pub use command_chains::*;
+pub use command_executions::*;
+pub use command_revisions::*;
 pub use commands::*;
+pub use environment_variables::*;
+pub use secrets::*;
+pub use tags::TagsQuery;
+pub use trash::*;
 pub use workspaces::*;
 
+use tags::TagsQueryParameters;
+
+use chrono::Utc;
+use rusqlite::{params, Connection, Result};
+
+pub enum SortQuery {
+    /// Newest rows first by creation time.
+    Created,
+
+    /// Ranks rows by usage count weighted by the age of the last use,
+    /// measured against `now` in microseconds.
+    Frecency {
+        now: i64,
+    },
+    Recency,
+
+    /// Most recently modified rows first.
+    Updated,
+}
+
+impl SortQuery {
+    fn frecency_now(&self) -> Option<i64> {
+        match self {
+            SortQuery::Frecency { now } => Some(*now),
+            SortQuery::Created | SortQuery::Recency | SortQuery::Updated => None,
+        }
+    }
+
+    /// Timestamp column the rows are ordered by before the usage columns.
+    fn timestamp_column(&self) -> Option<&'static str> {
+        match self {
+            SortQuery::Created => Some("created_at"),
+            SortQuery::Updated => Some("updated_at"),
+            SortQuery::Frecency { .. } | SortQuery::Recency => None,
+        }
+    }
+}
+
 pub enum OptionalValue<T> {
     Null,
     Value(T),
@@ -28,3 +80,82 @@ impl<T> From<Option<T>> for OptionalValue<T> {
         }
     }
 }
+
+/// Creation and modification times are set here rather than by callers, so
+/// every writer of a row keeps them consistent.
+fn now_micros() -> i64 {
+    Utc::now().timestamp_micros()
+}
+
+fn add_column_if_not_exists(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<()> {
+    let exists: bool = conn
+        .prepare("SELECT COUNT(*) > 0 FROM pragma_table_info(?1) WHERE name = ?2")?
+        .query_row(params![table, column], |row| row.get(0))?;
+
+    if !exists {
+        conn.execute(
+            &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition),
+            (),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Runs `f` inside a savepoint, so either all of its statements are applied
+/// or none of them are. Works both standalone and inside an outer transaction.
+fn with_savepoint<T>(conn: &Connection, name: &str, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    conn.execute_batch(&format!("SAVEPOINT {name}"))?;
+
+    match f() {
+        Ok(value) => {
+            conn.execute_batch(&format!("RELEASE {name}"))?;
+
+            Ok(value)
+        }
+        Err(err) => {
+            conn.execute_batch(&format!("ROLLBACK TO {name}; RELEASE {name}"))?;
+
+            Err(err)
+        }
+    }
+}
+
+/// SQLite cannot drop a NOT NULL constraint in place, so the table is
+/// recreated from `definition` and existing rows are copied over.
+fn drop_not_null_constraint(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<()> {
+    let not_null: bool = conn
+        .prepare(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info(?1) WHERE name = ?2 AND \"notnull\" = 1",
+        )?
+        .query_row(params![table, column], |row| row.get(0))?;
+
+    if !not_null {
+        return Ok(());
+    }
+
+    let columns = conn
+        .prepare("SELECT name FROM pragma_table_info(?1)")?
+        .query_map(params![table], |row| row.get(0))?
+        .collect::<Result<Vec<String>>>()?
+        .join(", ");
+
+    with_savepoint(conn, "drop_not_null_constraint", || {
+        conn.execute_batch(&format!(
+            "ALTER TABLE {table} RENAME TO {table}_old;
+            CREATE TABLE {table} ({definition});
+            INSERT INTO {table} ({columns}) SELECT {columns} FROM {table}_old;
+            DROP TABLE {table}_old;"
+        ))
+    })
+}
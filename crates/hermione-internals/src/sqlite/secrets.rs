@@ -0,0 +1,115 @@
+use crate::cipher::{EncryptedSecret, SecretCipher};
+use hermione_nexus::{
+    definitions::{Secret, SecretParameters},
+    Error,
+};
+use rusqlite::{named_params, params, Connection, OptionalExtension, Result};
+
+/// Secret values are only ever stored encrypted, the plain text never
+/// reaches the database.
+pub struct SecretRecord {
+    pub name: String,
+    pub ciphertext: Vec<u8>,
+    pub nonce: Vec<u8>,
+}
+
+pub fn create_secrets_table_if_not_exists(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS secrets (
+            name TEXT PRIMARY KEY,
+            ciphertext BLOB NOT NULL,
+            nonce BLOB NOT NULL
+        )",
+        (),
+    )?;
+
+    Ok(())
+}
+
+pub fn delete_secret(conn: &Connection, name: &str) -> Result<usize> {
+    conn.prepare("DELETE FROM secrets WHERE name = ?1")?
+        .execute(params![name])
+}
+
+pub fn find_secret(conn: &Connection, name: &str) -> Result<Option<SecretRecord>> {
+    conn.prepare(
+        "SELECT
+            name,
+            ciphertext,
+            nonce
+        FROM secrets
+        WHERE name = ?1",
+    )?
+    .query_row(params![name], |row| {
+        Ok(SecretRecord {
+            name: row.get(0)?,
+            ciphertext: row.get(1)?,
+            nonce: row.get(2)?,
+        })
+    })
+    .optional()
+}
+
+pub fn list_secret_names(conn: &Connection) -> Result<Vec<String>> {
+    let mut statement = conn.prepare("SELECT name FROM secrets ORDER BY name ASC")?;
+
+    let names = statement
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(names)
+}
+
+pub fn upsert_secret(conn: &Connection, record: SecretRecord) -> Result<usize> {
+    let SecretRecord {
+        name,
+        ciphertext,
+        nonce,
+    } = record;
+
+    conn.prepare(
+        "INSERT INTO secrets (
+            name,
+            ciphertext,
+            nonce
+        ) VALUES (:name, :ciphertext, :nonce)
+        ON CONFLICT (name) DO UPDATE SET
+            ciphertext = excluded.ciphertext,
+            nonce = excluded.nonce",
+    )?
+    .execute(named_params![
+        ":name": name,
+        ":ciphertext": ciphertext,
+        ":nonce": nonce,
+    ])
+}
+
+impl SecretRecord {
+    pub fn decrypt(self, cipher: &SecretCipher) -> hermione_nexus::Result<Secret> {
+        let SecretRecord {
+            name,
+            ciphertext,
+            nonce,
+        } = self;
+
+        let value = cipher
+            .decrypt(&name, &EncryptedSecret { ciphertext, nonce })
+            .map_err(|err| eyre::Error::new(err).wrap_err("Corrupted storage data"))
+            .map_err(Error::storage)?;
+
+        Secret::new(SecretParameters { name, value })
+    }
+
+    pub fn encrypt(cipher: &SecretCipher, secret: &Secret) -> hermione_nexus::Result<Self> {
+        let EncryptedSecret { ciphertext, nonce } = cipher
+            .encrypt(secret.name(), secret.value())
+            .map_err(eyre::Error::new)
+            .map_err(Error::storage)?;
+
+        Ok(Self {
+            name: secret.name().to_string(),
+            ciphertext,
+            nonce,
+        })
+    }
+}
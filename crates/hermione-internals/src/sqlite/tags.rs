@@ -0,0 +1,159 @@
+use rusqlite::{named_params, params, Connection, Result};
+use uuid::Bytes;
+
+const TAGS_SEPARATOR: &str = ",";
+
+pub enum TagsQuery {
+    AllOf(Vec<String>),
+    AnyOf(Vec<String>),
+}
+
+pub(crate) struct TagsQueryParameters {
+    pub pattern: Option<String>,
+    pub required_matches: usize,
+}
+
+pub(crate) fn copy_command_tags(conn: &Connection, from: &Bytes, to: &Bytes) -> Result<usize> {
+    conn.prepare(
+        "INSERT INTO command_tags (command_id, name)
+        SELECT :to, name FROM command_tags WHERE command_id = :from
+        ON CONFLICT (command_id, name) DO NOTHING",
+    )?
+    .execute(named_params![":from": from, ":to": to])
+}
+
+pub(crate) fn copy_workspace_tags(conn: &Connection, from: &Bytes, to: &Bytes) -> Result<usize> {
+    conn.prepare(
+        "INSERT INTO workspace_tags (workspace_id, name)
+        SELECT :to, name FROM workspace_tags WHERE workspace_id = :from
+        ON CONFLICT (workspace_id, name) DO NOTHING",
+    )?
+    .execute(named_params![":from": from, ":to": to])
+}
+
+pub(crate) fn create_command_tags_table_if_not_exists(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS command_tags (
+            command_id BLOB NOT NULL,
+            name TEXT NOT NULL,
+            PRIMARY KEY (command_id, name)
+        )",
+        (),
+    )?;
+
+    Ok(())
+}
+
+pub(crate) fn create_workspace_tags_table_if_not_exists(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS workspace_tags (
+            workspace_id BLOB NOT NULL,
+            name TEXT NOT NULL,
+            PRIMARY KEY (workspace_id, name)
+        )",
+        (),
+    )?;
+
+    Ok(())
+}
+
+pub(crate) fn delete_command_tags(conn: &Connection, command_id: &Bytes) -> Result<usize> {
+    conn.prepare("DELETE FROM command_tags WHERE command_id = ?1")?
+        .execute(params![command_id])
+}
+
+pub(crate) fn delete_workspace_commands_tags(
+    conn: &Connection,
+    workspace_id: &Bytes,
+) -> Result<usize> {
+    conn.prepare(
+        "DELETE FROM command_tags
+        WHERE command_id IN (SELECT id FROM commands WHERE workspace_id = ?1)",
+    )?
+    .execute(params![workspace_id])
+}
+
+pub(crate) fn delete_workspace_tags(conn: &Connection, workspace_id: &Bytes) -> Result<usize> {
+    conn.prepare("DELETE FROM workspace_tags WHERE workspace_id = ?1")?
+        .execute(params![workspace_id])
+}
+
+pub(crate) fn insert_command_tags(
+    conn: &Connection,
+    command_id: &Bytes,
+    tags: &[String],
+) -> Result<()> {
+    let mut statement = conn.prepare(
+        "INSERT INTO command_tags (command_id, name)
+        VALUES (:command_id, :name)
+        ON CONFLICT (command_id, name) DO NOTHING",
+    )?;
+
+    for tag in tags {
+        statement.execute(named_params![":command_id": command_id, ":name": tag])?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn insert_workspace_tags(
+    conn: &Connection,
+    workspace_id: &Bytes,
+    tags: &[String],
+) -> Result<()> {
+    let mut statement = conn.prepare(
+        "INSERT INTO workspace_tags (workspace_id, name)
+        VALUES (:workspace_id, :name)
+        ON CONFLICT (workspace_id, name) DO NOTHING",
+    )?;
+
+    for tag in tags {
+        statement.execute(named_params![":workspace_id": workspace_id, ":name": tag])?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn split_tags(value: Option<String>) -> Vec<String> {
+    let Some(value) = value else {
+        return Vec::new();
+    };
+
+    let mut tags: Vec<String> = value
+        .split(TAGS_SEPARATOR)
+        .filter(|tag| !tag.is_empty())
+        .map(ToString::to_string)
+        .collect();
+
+    tags.sort();
+
+    tags
+}
+
+impl TagsQueryParameters {
+    pub(crate) fn new(query: Option<TagsQuery>) -> Self {
+        let (tags, all_of) = match query {
+            None => (Vec::new(), false),
+            Some(TagsQuery::AllOf(tags)) => (tags, true),
+            Some(TagsQuery::AnyOf(tags)) => (tags, false),
+        };
+
+        if tags.is_empty() {
+            return Self {
+                pattern: None,
+                required_matches: 0,
+            };
+        }
+
+        let required_matches = if all_of { tags.len() } else { 1 };
+
+        Self {
+            pattern: Some(format!(
+                "{separator}{}{separator}",
+                tags.join(TAGS_SEPARATOR),
+                separator = TAGS_SEPARATOR
+            )),
+            required_matches,
+        }
+    }
+}
@@ -0,0 +1,355 @@
+use super::{commands, tags, with_savepoint, workspaces, CommandRecord, WorkspaceRecord};
+use chrono::DateTime;
+use eyre::eyre;
+use hermione_nexus::definitions::{TrashEntry, TrashItem, TrashItemParameters};
+use rusqlite::{named_params, params, Connection, OptionalExtension, Result, Row};
+use uuid::Bytes;
+
+pub enum TrashRecord {
+    Command {
+        deleted_at: i64,
+        record: CommandRecord,
+    },
+    Workspace {
+        deleted_at: i64,
+        record: WorkspaceRecord,
+    },
+}
+
+pub struct ListTrashQuery {
+    pub limit: u32,
+    pub offset: u32,
+}
+
+const TRASHED_COMMANDS_QUERY: &str = "SELECT
+        id,
+        description,
+        last_execute_time,
+        name,
+        pinned,
+        program,
+        shell,
+        workspace_id,
+        (
+            SELECT GROUP_CONCAT(command_tags.name)
+            FROM command_tags
+            WHERE command_id = commands.id
+        ),
+        deleted_at,
+        created_at,
+        updated_at,
+        managed_by
+    FROM commands";
+
+/// Commands of a trashed workspace belong to the workspace's trash entry.
+const TRASHED_COMMANDS_CONDITION: &str = "deleted_at IS NOT NULL
+    AND (
+        workspace_id IS NULL
+        OR workspace_id NOT IN (SELECT id FROM workspaces WHERE deleted_at IS NOT NULL)
+    )";
+
+const TRASHED_WORKSPACES_QUERY: &str = "SELECT
+        id,
+        last_access_time,
+        location,
+        name,
+        parent_id,
+        pinned,
+        shell,
+        (
+            SELECT GROUP_CONCAT(workspace_tags.name)
+            FROM workspace_tags
+            WHERE workspace_id = workspaces.id
+        ),
+        deleted_at,
+        created_at,
+        updated_at
+    FROM workspaces";
+
+/// Descendants trashed together with their parent belong to the parent's
+/// trash entry.
+const TRASHED_WORKSPACES_CONDITION: &str = "deleted_at IS NOT NULL
+    AND (
+        parent_id IS NULL
+        OR parent_id NOT IN (
+            SELECT parents.id FROM workspaces AS parents
+            WHERE parents.deleted_at = workspaces.deleted_at
+        )
+    )";
+
+impl TrashRecord {
+    fn deleted_at(&self) -> i64 {
+        match self {
+            TrashRecord::Command { deleted_at, .. } | TrashRecord::Workspace { deleted_at, .. } => {
+                *deleted_at
+            }
+        }
+    }
+}
+
+pub fn find_trashed_command(conn: &Connection, id: &Bytes) -> Result<Option<TrashRecord>> {
+    conn.prepare(&format!(
+        "{TRASHED_COMMANDS_QUERY} WHERE {TRASHED_COMMANDS_CONDITION} AND id = ?1"
+    ))?
+    .query_row(params![id], trashed_command)
+    .optional()
+}
+
+pub fn find_trashed_workspace(conn: &Connection, id: &Bytes) -> Result<Option<TrashRecord>> {
+    conn.prepare(&format!(
+        "{TRASHED_WORKSPACES_QUERY} WHERE {TRASHED_WORKSPACES_CONDITION} AND id = ?1"
+    ))?
+    .query_row(params![id], trashed_workspace)
+    .optional()
+}
+
+/// Lists trashed workspaces and trashed commands, most recently deleted
+/// first. Commands of a trashed workspace are listed under the workspace.
+pub fn list_trash(conn: &Connection, query: ListTrashQuery) -> Result<Vec<TrashRecord>> {
+    let ListTrashQuery { limit, offset } = query;
+
+    let entries = conn
+        .prepare(&format!(
+            "SELECT is_workspace, id FROM (
+                SELECT TRUE AS is_workspace, id, deleted_at
+                FROM workspaces
+                WHERE {TRASHED_WORKSPACES_CONDITION}
+                UNION ALL
+                SELECT FALSE AS is_workspace, id, deleted_at
+                FROM commands
+                WHERE {TRASHED_COMMANDS_CONDITION}
+            )
+            ORDER BY deleted_at DESC, is_workspace DESC, id ASC
+            LIMIT :limit OFFSET :offset"
+        ))?
+        .query_map(
+            named_params![
+                ":limit": limit,
+                ":offset": limit * offset,
+            ],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?
+        .collect::<Result<Vec<(bool, Bytes)>>>()?;
+
+    let mut records = Vec::with_capacity(entries.len());
+
+    for (is_workspace, id) in entries {
+        let record = if is_workspace {
+            find_trashed_workspace(conn, &id)?
+        } else {
+            find_trashed_command(conn, &id)?
+        };
+
+        records.extend(record);
+    }
+
+    Ok(records)
+}
+
+pub fn purge_trash(conn: &Connection, deleted_before: Option<i64>) -> Result<usize> {
+    with_savepoint(conn, "purge_trash", || {
+        let workspace_ids = conn
+            .prepare(
+                "SELECT id FROM workspaces
+                WHERE deleted_at IS NOT NULL AND (?1 IS NULL OR deleted_at < ?1)",
+            )?
+            .query_map(params![deleted_before], |row| row.get(0))?
+            .collect::<Result<Vec<Bytes>>>()?;
+
+        let command_ids = conn
+            .prepare(
+                "SELECT id FROM commands
+                WHERE deleted_at IS NOT NULL AND (?1 IS NULL OR deleted_at < ?1)",
+            )?
+            .query_map(params![deleted_before], |row| row.get(0))?
+            .collect::<Result<Vec<Bytes>>>()?;
+
+        let mut purged = 0;
+
+        for id in workspace_ids {
+            purged += purge_trashed_workspace(conn, &id)?;
+        }
+
+        for id in command_ids {
+            purged += commands::delete_command(conn, &id)?;
+        }
+
+        Ok(purged)
+    })
+}
+
+pub fn purge_trashed_command(conn: &Connection, id: &Bytes) -> Result<usize> {
+    if find_trashed_command(conn, id)?.is_none() {
+        return Ok(0);
+    }
+
+    commands::delete_command(conn, id)
+}
+
+pub fn purge_trashed_workspace(conn: &Connection, id: &Bytes) -> Result<usize> {
+    if find_trashed_workspace(conn, id)?.is_none() {
+        return Ok(0);
+    }
+
+    with_savepoint(conn, "purge_trashed_workspace", || {
+        let mut purged = 0;
+
+        for id in trashed_workspace_tree(conn, id)? {
+            commands::delete_workspace_commands(conn, &id)?;
+            purged += workspaces::delete_workspace(conn, &id)?;
+        }
+
+        Ok(purged)
+    })
+}
+
+pub fn restore_trashed_command(conn: &Connection, id: &Bytes) -> Result<usize> {
+    conn.prepare("UPDATE commands SET deleted_at = NULL WHERE id = ?1")?
+        .execute(params![id])
+}
+
+/// Restores the workspace together with the descendants and commands that
+/// were trashed with it.
+pub fn restore_trashed_workspace(conn: &Connection, id: &Bytes) -> Result<usize> {
+    with_savepoint(conn, "restore_trashed_workspace", || {
+        let Some(deleted_at) = conn
+            .prepare("SELECT deleted_at FROM workspaces WHERE id = ?1")?
+            .query_row(params![id], |row| row.get::<_, Option<i64>>(0))
+            .optional()?
+            .flatten()
+        else {
+            return Ok(0);
+        };
+
+        let mut restored = 0;
+
+        for id in trashed_workspace_tree(conn, id)? {
+            conn.prepare(
+                "UPDATE commands SET deleted_at = NULL
+                WHERE workspace_id = ?1 AND deleted_at = ?2",
+            )?
+            .execute(params![id, deleted_at])?;
+
+            restored += conn
+                .prepare("UPDATE workspaces SET deleted_at = NULL WHERE id = ?1")?
+                .execute(params![id])?;
+        }
+
+        Ok(restored)
+    })
+}
+
+pub fn trash_command(conn: &Connection, id: &Bytes, deleted_at: i64) -> Result<usize> {
+    conn.prepare("UPDATE commands SET deleted_at = ?2 WHERE id = ?1 AND deleted_at IS NULL")?
+        .execute(params![id, deleted_at])
+}
+
+/// Moves the workspace together with its descendants and all their commands
+/// to the trash. They share the deletion time, which is how they are
+/// restored together later.
+pub fn trash_workspace(conn: &Connection, id: &Bytes, deleted_at: i64) -> Result<usize> {
+    with_savepoint(conn, "trash_workspace", || {
+        let ids = conn
+            .prepare(
+                "WITH RECURSIVE tree(id) AS (
+                    SELECT id FROM workspaces WHERE id = ?1 AND deleted_at IS NULL
+                    UNION
+                    SELECT workspaces.id FROM workspaces
+                    JOIN tree ON workspaces.parent_id = tree.id
+                    WHERE workspaces.deleted_at IS NULL
+                )
+                SELECT id FROM tree",
+            )?
+            .query_map(params![id], |row| row.get(0))?
+            .collect::<Result<Vec<Bytes>>>()?;
+
+        let mut trashed = 0;
+
+        for id in ids {
+            conn.prepare(
+                "UPDATE commands SET deleted_at = ?2
+                WHERE workspace_id = ?1 AND deleted_at IS NULL",
+            )?
+            .execute(params![id, deleted_at])?;
+
+            trashed += conn
+                .prepare("UPDATE workspaces SET deleted_at = ?2 WHERE id = ?1")?
+                .execute(params![id, deleted_at])?;
+        }
+
+        Ok(trashed)
+    })
+}
+
+/// The trashed workspace followed by the descendants trashed together with
+/// it.
+fn trashed_workspace_tree(conn: &Connection, id: &Bytes) -> Result<Vec<Bytes>> {
+    conn.prepare(
+        "WITH RECURSIVE tree(id, deleted_at) AS (
+            SELECT id, deleted_at FROM workspaces WHERE id = ?1 AND deleted_at IS NOT NULL
+            UNION
+            SELECT workspaces.id, workspaces.deleted_at FROM workspaces
+            JOIN tree ON workspaces.parent_id = tree.id
+            WHERE workspaces.deleted_at = tree.deleted_at
+        )
+        SELECT id FROM tree",
+    )?
+    .query_map(params![id], |row| row.get(0))?
+    .collect()
+}
+
+fn trashed_command(row: &Row) -> Result<TrashRecord> {
+    Ok(TrashRecord::Command {
+        record: CommandRecord {
+            id: row.get(0)?,
+            created_at: row.get(10)?,
+            description: row.get(1)?,
+            last_execute_time: row.get(2)?,
+            name: row.get(3)?,
+            pinned: row.get(4)?,
+            program: row.get(5)?,
+            shell: row.get(6)?,
+            workspace_id: row.get(7)?,
+            tags: tags::split_tags(row.get(8)?),
+            updated_at: row.get(11)?,
+            managed_by: row.get(12)?,
+        },
+        deleted_at: row.get(9)?,
+    })
+}
+
+fn trashed_workspace(row: &Row) -> Result<TrashRecord> {
+    Ok(TrashRecord::Workspace {
+        record: WorkspaceRecord {
+            id: row.get(0)?,
+            created_at: row.get(9)?,
+            last_access_time: row.get(1)?,
+            location: row.get(2)?,
+            name: row.get(3)?,
+            parent_id: row.get(4)?,
+            pinned: row.get(5)?,
+            shell: row.get(6)?,
+            tags: tags::split_tags(row.get(7)?),
+            updated_at: row.get(10)?,
+        },
+        deleted_at: row.get(8)?,
+    })
+}
+
+impl TryFrom<TrashRecord> for TrashItem {
+    type Error = hermione_nexus::Error;
+
+    fn try_from(value: TrashRecord) -> hermione_nexus::Result<Self> {
+        let deleted_at = value.deleted_at();
+        let deleted_at = DateTime::from_timestamp_micros(deleted_at).ok_or_else(|| {
+            hermione_nexus::Error::storage(eyre!("Invalid deletion time: {}", deleted_at))
+        })?;
+
+        let entry = match value {
+            TrashRecord::Command { record, .. } => TrashEntry::Command(record.try_into()?),
+            TrashRecord::Workspace { record, .. } => TrashEntry::Workspace(record.try_into()?),
+        };
+
+        Ok(TrashItem::new(TrashItemParameters { deleted_at, entry }))
+    }
+}
@@ -1,21 +1,43 @@
-use super::OptionalValue;
+use super::{
+    add_column_if_not_exists, command_chains, command_executions, commands, environment_variables,
+    now_micros, tags, with_savepoint, CopyCommandQuery, OptionalValue, SortQuery, TagsQuery,
+    TagsQueryParameters,
+};
 use chrono::DateTime;
-use hermione_nexus::definitions::{Workspace, WorkspaceParameters};
+use hermione_nexus::definitions::{Shell, Workspace, WorkspaceId, WorkspaceParameters};
 use rusqlite::{named_params, params, Connection, OptionalExtension, Result};
 use uuid::{Bytes, Uuid};
 
 #[derive(Clone)]
 pub struct WorkspaceRecord {
     pub id: Bytes,
+    pub created_at: Option<i64>,
     pub last_access_time: Option<i64>,
     pub location: Option<String>,
     pub name: String,
+    pub parent_id: Option<Bytes>,
+    pub pinned: bool,
+    pub shell: Option<String>,
+    pub tags: Vec<String>,
+    pub updated_at: Option<i64>,
+}
+
+pub struct CloneWorkspaceQuery {
+    pub id: Bytes,
+    pub clone_id: Bytes,
+    pub location: Option<String>,
+    pub name: String,
 }
 
 pub struct ListWorkspacesQueryOptions<'a> {
+    pub created_after: Option<i64>,
     pub name_contains: &'a str,
     pub limit: u32,
     pub offset: u32,
+    pub scope: WorkspaceScopeQuery,
+    pub sort: SortQuery,
+    pub tags: Option<TagsQuery>,
+    pub updated_after: Option<i64>,
 }
 
 pub struct UpdateWorkspaceQueryOptions {
@@ -23,67 +45,251 @@ pub struct UpdateWorkspaceQueryOptions {
     pub last_access_time: Option<OptionalValue<i64>>,
     pub location: Option<OptionalValue<String>>,
     pub name: Option<String>,
+    pub parent_id: Option<OptionalValue<Bytes>>,
+    pub pinned: Option<bool>,
+    pub shell: Option<OptionalValue<String>>,
+    pub tags: Option<Vec<String>>,
+}
+
+pub enum WorkspaceScopeQuery {
+    All,
+    Roots,
+    Children(Bytes),
+}
+
+impl WorkspaceScopeQuery {
+    fn parameters(&self) -> (bool, bool, Option<&Bytes>) {
+        match self {
+            WorkspaceScopeQuery::All => (true, false, None),
+            WorkspaceScopeQuery::Roots => (false, true, None),
+            WorkspaceScopeQuery::Children(id) => (false, false, Some(id)),
+        }
+    }
 }
 
 impl UpdateWorkspaceQueryOptions {
     pub fn is_empty(&self) -> bool {
-        self.last_access_time.is_none() && self.location.is_none() && self.name.is_none()
+        self.last_access_time.is_none() && !self.is_modified()
+    }
+
+    fn is_modified(&self) -> bool {
+        self.location.is_some()
+            || self.name.is_some()
+            || self.parent_id.is_some()
+            || self.pinned.is_some()
+            || self.shell.is_some()
+            || self.tags.is_some()
     }
 }
 
+pub fn clone_workspace(conn: &Connection, query: CloneWorkspaceQuery) -> Result<usize> {
+    let CloneWorkspaceQuery {
+        id,
+        clone_id,
+        location,
+        name,
+    } = query;
+
+    with_savepoint(conn, "clone_workspace", || {
+        let cloned = conn
+            .prepare(
+                "INSERT INTO workspaces (
+                    id,
+                    created_at,
+                    location,
+                    name,
+                    parent_id,
+                    shell,
+                    updated_at
+                )
+                SELECT
+                    :clone_id,
+                    :now,
+                    :location,
+                    :name,
+                    parent_id,
+                    shell,
+                    :now
+                FROM workspaces
+                WHERE id = :id AND deleted_at IS NULL",
+            )?
+            .execute(named_params![
+                ":id": id,
+                ":clone_id": clone_id,
+                ":location": location,
+                ":name": name,
+                ":now": now_micros(),
+            ])?;
+
+        if cloned == 0 {
+            return Ok(cloned);
+        }
+
+        tags::copy_workspace_tags(conn, &id, &clone_id)?;
+        environment_variables::copy_workspace_environment_variables(conn, &id, &clone_id)?;
+
+        for command_id in commands::list_workspace_command_ids(conn, &id)? {
+            commands::copy_command(
+                conn,
+                CopyCommandQuery {
+                    id: command_id,
+                    copy_id: Uuid::new_v4().into_bytes(),
+                    keep_history: false,
+                    workspace_id: Some(clone_id),
+                },
+            )?;
+        }
+
+        Ok(cloned)
+    })
+}
+
 pub fn create_workspaces_table_if_not_exists(conn: &Connection) -> Result<()> {
     conn.execute(
         "CREATE TABLE IF NOT EXISTS workspaces (
             id BLOB PRIMARY KEY,
+            access_count INTEGER NOT NULL DEFAULT 0,
             last_access_time INTEGER,
             location TEXT,
-            name TEXT NOT NULL
+            name TEXT NOT NULL,
+            pinned INTEGER NOT NULL DEFAULT 0,
+            shell TEXT,
+            deleted_at INTEGER,
+            parent_id BLOB,
+            created_at INTEGER,
+            updated_at INTEGER
         )",
         (),
     )?;
 
+    add_column_if_not_exists(conn, "workspaces", "shell", "TEXT")?;
+    add_column_if_not_exists(
+        conn,
+        "workspaces",
+        "access_count",
+        "INTEGER NOT NULL DEFAULT 0",
+    )?;
+    add_column_if_not_exists(conn, "workspaces", "pinned", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column_if_not_exists(conn, "workspaces", "deleted_at", "INTEGER")?;
+    add_column_if_not_exists(conn, "workspaces", "parent_id", "BLOB")?;
+    add_column_if_not_exists(conn, "workspaces", "created_at", "INTEGER")?;
+    add_column_if_not_exists(conn, "workspaces", "updated_at", "INTEGER")?;
+
+    tags::create_workspace_tags_table_if_not_exists(conn)?;
+    environment_variables::create_workspace_environment_variables_table_if_not_exists(conn)?;
+
     Ok(())
 }
 
+/// Command chains of the workspace are deleted with it. Child workspaces and
+/// executions that ran in the workspace are detached from it rather than
+/// deleted.
 pub fn delete_workspace(conn: &Connection, id: &Bytes) -> Result<usize> {
+    tags::delete_workspace_tags(conn, id)?;
+    environment_variables::delete_workspace_environment_variables(conn, id)?;
+    command_executions::detach_workspace_executions(conn, id)?;
+    command_chains::delete_workspace_command_chains(conn, id)?;
+
+    conn.prepare("UPDATE workspaces SET parent_id = NULL WHERE parent_id = ?1")?
+        .execute(params![id])?;
+
     conn.prepare("DELETE FROM workspaces WHERE id = ?1")?
         .execute(params![id])
 }
 
+pub fn increment_workspace_access_count(conn: &Connection, id: &Bytes) -> Result<usize> {
+    conn.prepare("UPDATE workspaces SET access_count = access_count + 1 WHERE id = ?1")?
+        .execute(params![id])
+}
+
 pub fn find_workspace(conn: &Connection, id: &Bytes) -> Result<Option<WorkspaceRecord>> {
-    conn.prepare("SELECT id, last_access_time, location, name FROM workspaces WHERE id = ?1")?
-        .query_row(params![id], |row| {
-            Ok(WorkspaceRecord {
-                id: row.get(0)?,
-                last_access_time: row.get(1)?,
-                location: row.get(2)?,
-                name: row.get(3)?,
-            })
+    conn.prepare(
+        "SELECT
+            id,
+            last_access_time,
+            location,
+            name,
+            parent_id,
+            pinned,
+            shell,
+            (
+                SELECT GROUP_CONCAT(workspace_tags.name)
+                FROM workspace_tags
+                WHERE workspace_id = workspaces.id
+            ),
+            created_at,
+            updated_at
+        FROM workspaces
+        WHERE id = ?1 AND deleted_at IS NULL",
+    )?
+    .query_row(params![id], |row| {
+        Ok(WorkspaceRecord {
+            id: row.get(0)?,
+            created_at: row.get(8)?,
+            last_access_time: row.get(1)?,
+            location: row.get(2)?,
+            name: row.get(3)?,
+            parent_id: row.get(4)?,
+            pinned: row.get(5)?,
+            shell: row.get(6)?,
+            tags: tags::split_tags(row.get(7)?),
+            updated_at: row.get(9)?,
         })
-        .optional()
+    })
+    .optional()
 }
 
+/// Rows without creation or modification time get the current time.
 pub fn insert_workspace(conn: &Connection, record: WorkspaceRecord) -> Result<usize> {
     let WorkspaceRecord {
         id,
+        created_at,
         last_access_time,
         location,
         name,
+        parent_id,
+        pinned,
+        shell,
+        tags,
+        updated_at,
     } = record;
 
+    tags::insert_workspace_tags(conn, &id, &tags)?;
+
     conn.prepare(
         "INSERT INTO workspaces (
             id,
+            created_at,
             last_access_time,
             location,
-            name
-        ) VALUES (:id, :last_access_time, :location, :name)",
+            name,
+            parent_id,
+            pinned,
+            shell,
+            updated_at
+        ) VALUES (
+            :id,
+            COALESCE(:created_at, :now),
+            :last_access_time,
+            :location,
+            :name,
+            :parent_id,
+            :pinned,
+            :shell,
+            COALESCE(:updated_at, :created_at, :now)
+        )",
     )?
     .execute(named_params![
         ":id": id,
+        ":created_at": created_at,
         ":last_access_time": last_access_time,
         ":location": location,
-        ":name": name
+        ":name": name,
+        ":now": now_micros(),
+        ":parent_id": parent_id,
+        ":pinned": pinned,
+        ":shell": shell,
+        ":updated_at": updated_at
     ])
 }
 
@@ -92,39 +298,119 @@ pub fn list_workspaces(
     query: ListWorkspacesQueryOptions,
 ) -> Result<Vec<WorkspaceRecord>> {
     let ListWorkspacesQueryOptions {
+        created_after,
         name_contains,
         limit,
         offset,
+        scope,
+        sort,
+        tags,
+        updated_after,
     } = query;
 
+    let (all_scopes, roots_only, parent_id) = scope.parameters();
+    let frecency_now = sort.frecency_now();
+    let timestamp_column = sort.timestamp_column();
     let name_contains = format!("%{}%", name_contains.to_lowercase());
+    let TagsQueryParameters {
+        pattern: tags_pattern,
+        required_matches: tags_required_matches,
+    } = TagsQueryParameters::new(tags);
 
     let mut statement = conn.prepare(
         "SELECT
             id,
             last_access_time,
             location,
-            name
+            name,
+            parent_id,
+            pinned,
+            shell,
+            (
+                SELECT GROUP_CONCAT(workspace_tags.name)
+                FROM workspace_tags
+                WHERE workspace_id = workspaces.id
+            ),
+            created_at,
+            updated_at
         FROM workspaces
         WHERE
-            LOWER(name) LIKE :name_contains
-        ORDER BY last_access_time DESC, name ASC
+            deleted_at IS NULL
+            AND LOWER(name) LIKE :name_contains
+            AND (:created_after IS NULL OR created_at > :created_after)
+            AND (:updated_after IS NULL OR updated_at > :updated_after)
+            AND (
+                :all_scopes
+                OR (
+                    :roots_only
+                    AND NOT EXISTS (
+                        SELECT 1
+                        FROM workspaces AS parent
+                        WHERE parent.id = workspaces.parent_id AND parent.deleted_at IS NULL
+                    )
+                )
+                OR parent_id = :parent_id
+            )
+            AND (
+                :tags_pattern IS NULL
+                OR (
+                    SELECT COUNT(*)
+                    FROM workspace_tags
+                    WHERE
+                        workspace_id = workspaces.id
+                        AND INSTR(:tags_pattern, ',' || workspace_tags.name || ',') > 0
+                ) >= :tags_required_matches
+            )
+        ORDER BY
+            pinned DESC,
+            CASE :timestamp_column
+                WHEN 'created_at' THEN created_at
+                WHEN 'updated_at' THEN updated_at
+            END DESC,
+            CASE
+                WHEN :frecency_now IS NULL OR last_access_time IS NULL THEN 0
+                ELSE access_count * (
+                    CASE
+                        WHEN :frecency_now - last_access_time < 3600000000 THEN 4.0
+                        WHEN :frecency_now - last_access_time < 86400000000 THEN 2.0
+                        WHEN :frecency_now - last_access_time < 604800000000 THEN 0.5
+                        ELSE 0.25
+                    END
+                )
+            END DESC,
+            last_access_time DESC,
+            name ASC
         LIMIT :limit OFFSET :offset",
     )?;
 
     let records = statement
         .query_map(
             named_params![
+                ":all_scopes": all_scopes,
+                ":created_after": created_after,
+                ":frecency_now": frecency_now,
                 ":name_contains": name_contains,
                 ":limit": limit,
-                ":offset": offset * limit
+                ":offset": offset * limit,
+                ":parent_id": parent_id,
+                ":roots_only": roots_only,
+                ":tags_pattern": tags_pattern,
+                ":tags_required_matches": tags_required_matches,
+                ":timestamp_column": timestamp_column,
+                ":updated_after": updated_after
             ],
             |row| {
                 Ok(WorkspaceRecord {
                     id: row.get(0)?,
+                    created_at: row.get(8)?,
                     last_access_time: row.get(1)?,
                     location: row.get(2)?,
                     name: row.get(3)?,
+                    parent_id: row.get(4)?,
+                    pinned: row.get(5)?,
+                    shell: row.get(6)?,
+                    tags: tags::split_tags(row.get(7)?),
+                    updated_at: row.get(9)?,
                 })
             },
         )?
@@ -133,52 +419,112 @@ pub fn list_workspaces(
     Ok(records)
 }
 
+/// Restored rows keep their own timestamps when they carry them. Otherwise
+/// new rows count as created or modified now and existing rows keep their
+/// local timestamps. Restored rows get exactly the tags of their records.
 pub fn restore_workspaces(conn: &Connection, records: Vec<WorkspaceRecord>) -> Result<()> {
     let mut statement = conn.prepare(
-        "INSERT INTO workspaces
-        VALUES (:id, :last_access_time, :location, :name)
+        "INSERT INTO workspaces (
+            id,
+            created_at,
+            last_access_time,
+            location,
+            name,
+            parent_id,
+            pinned,
+            shell,
+            updated_at
+        ) VALUES (
+            :id,
+            COALESCE(:created_at, :now),
+            :last_access_time,
+            :location,
+            :name,
+            :parent_id,
+            :pinned,
+            :shell,
+            COALESCE(:updated_at, :now)
+        )
         ON CONFLICT (id) DO UPDATE SET
+            created_at = COALESCE(:created_at, created_at, excluded.created_at),
             last_access_time = excluded.last_access_time,
             location = excluded.location,
-            name = excluded.name",
+            name = excluded.name,
+            parent_id = excluded.parent_id,
+            pinned = excluded.pinned,
+            shell = excluded.shell,
+            updated_at = COALESCE(:updated_at, updated_at)",
     )?;
 
+    let now = now_micros();
+
     for record in records {
         let WorkspaceRecord {
             id,
+            created_at,
             last_access_time,
             location,
             name,
+            parent_id,
+            pinned,
+            shell,
+            tags,
+            updated_at,
         } = record;
 
         statement.execute(named_params![
             ":id": id,
+            ":created_at": created_at,
             ":last_access_time": last_access_time,
             ":location": location,
-            ":name": name
+            ":name": name,
+            ":now": now,
+            ":parent_id": parent_id,
+            ":pinned": pinned,
+            ":shell": shell,
+            ":updated_at": updated_at
         ])?;
+
+        tags::delete_workspace_tags(conn, &id)?;
+        tags::insert_workspace_tags(conn, &id, &tags)?;
     }
 
     Ok(())
 }
 
+/// Any change other than the access time counts as a modification.
 pub fn update_workspace(conn: &Connection, options: UpdateWorkspaceQueryOptions) -> Result<usize> {
     if options.is_empty() {
         return Ok(0);
     }
 
+    let is_modified = options.is_modified();
+
     let UpdateWorkspaceQueryOptions {
         id,
         last_access_time,
         location,
         name,
+        parent_id,
+        pinned,
+        shell,
+        tags,
     } = options;
 
+    if let Some(tags) = tags {
+        tags::delete_workspace_tags(conn, &id)?;
+        tags::insert_workspace_tags(conn, &id, &tags)?;
+    }
+
     let skip_last_access_time_update = last_access_time.is_none();
     let skip_location_update = location.is_none();
+    let skip_parent_id_update = parent_id.is_none();
+    let skip_shell_update = shell.is_none();
 
     let last_access_time: Option<i64> = last_access_time.and_then(Into::into);
     let location: Option<String> = location.and_then(Into::into);
+    let parent_id: Option<Bytes> = parent_id.and_then(Into::into);
+    let shell: Option<String> = shell.and_then(Into::into);
 
     conn.prepare(
         "UPDATE workspaces
@@ -191,16 +537,36 @@ pub fn update_workspace(conn: &Connection, options: UpdateWorkspaceQueryOptions)
                 WHEN :skip_location_update THEN location
                 ELSE :location
             END,
-            name = COALESCE(:name, name)
+            name = COALESCE(:name, name),
+            parent_id = CASE
+                WHEN :skip_parent_id_update THEN parent_id
+                ELSE :parent_id
+            END,
+            pinned = COALESCE(:pinned, pinned),
+            shell = CASE
+                WHEN :skip_shell_update THEN shell
+                ELSE :shell
+            END,
+            updated_at = CASE
+                WHEN :is_modified THEN :now
+                ELSE updated_at
+            END
         WHERE id = :id",
     )?
     .execute(named_params![
         ":id": id,
+        ":is_modified": is_modified,
+        ":now": now_micros(),
         ":skip_last_access_time_update": skip_last_access_time_update,
         ":skip_location_update": skip_location_update,
+        ":skip_parent_id_update": skip_parent_id_update,
+        ":skip_shell_update": skip_shell_update,
         ":last_access_time": last_access_time,
         ":location": location,
-        ":name": name
+        ":name": name,
+        ":parent_id": parent_id,
+        ":pinned": pinned,
+        ":shell": shell
     ])
 }
 
@@ -212,9 +578,19 @@ impl From<Workspace> for WorkspaceRecord {
 
         WorkspaceRecord {
             id: value.id().into_bytes(),
+            created_at: value
+                .created_at()
+                .map(|date_time| date_time.timestamp_micros()),
             last_access_time,
             location: value.location().map(ToString::to_string),
             name: value.name().to_string(),
+            parent_id: value.parent_id().map(WorkspaceId::into_bytes),
+            pinned: value.is_pinned(),
+            shell: value.shell().map(|shell| shell.as_str().to_string()),
+            tags: value.tags().to_vec(),
+            updated_at: value
+                .updated_at()
+                .map(|date_time| date_time.timestamp_micros()),
         }
     }
 }
@@ -225,19 +601,37 @@ impl TryFrom<WorkspaceRecord> for Workspace {
     fn try_from(value: WorkspaceRecord) -> hermione_nexus::Result<Self> {
         let WorkspaceRecord {
             id,
+            created_at,
             last_access_time,
             location,
             name,
+            parent_id,
+            pinned,
+            shell,
+            tags,
+            updated_at,
         } = value;
 
         let id = Uuid::from_bytes(id);
+        let parent_id = parent_id
+            .map(|id| WorkspaceId::new(Uuid::from_bytes(id)))
+            .transpose()?;
+        let created_at = created_at.and_then(DateTime::from_timestamp_micros);
         let last_access_time = last_access_time.and_then(DateTime::from_timestamp_micros);
+        let updated_at = updated_at.and_then(DateTime::from_timestamp_micros);
+        let shell = shell.as_deref().map(Shell::parse_str).transpose()?;
 
-        Workspace::new(WorkspaceParameters {
+        Workspace::load(WorkspaceParameters {
             id,
+            created_at,
             last_access_time,
             location,
             name,
+            parent_id,
+            pinned,
+            shell,
+            tags,
+            updated_at,
         })
     }
 }
@@ -0,0 +1,336 @@
+use eyre::{Error, Result};
+use hermione_nexus::definitions::Shell;
+use std::{env, process::Command};
+
+const DEFAULT_TERMINAL_EMULATOR: &str = "x-terminal-emulator";
+const TERMINAL_ENV_VAR: &str = "TERMINAL";
+const WINDOWS_TERMINAL: &str = "wt";
+
+pub struct TerminalParameters<'a> {
+    /// Program executed by the shell right after the start.
+    pub command: Option<&'a str>,
+
+    /// Environment variables set by the shell before the command runs.
+    pub environment_variables: Vec<(&'a str, &'a str)>,
+
+    /// Keeps the shell open after the command completes.
+    pub no_exit: bool,
+
+    /// Shell started inside of the terminal window.
+    pub shell: Shell,
+
+    /// Initial working directory of the shell.
+    pub working_directory: Option<&'a str>,
+}
+
+pub struct TerminalChainParameters<'a> {
+    /// Keeps the shell open after the chain completes or fails.
+    pub no_exit: bool,
+
+    /// Shell started inside of the terminal window, it starts every step.
+    pub shell: Shell,
+
+    /// Steps run one after another, the first failing step stops the rest.
+    pub steps: Vec<TerminalStep<'a>>,
+}
+
+/// Step of a chain, it runs in a separate process of its own shell.
+pub struct TerminalStep<'a> {
+    pub command: &'a str,
+
+    /// Environment variables set right before the step runs. They are not
+    /// visible to the following steps.
+    pub environment_variables: Vec<(&'a str, &'a str)>,
+
+    /// Shell that runs the step.
+    pub shell: Shell,
+
+    /// Directory the shell changes to right before the step runs.
+    pub working_directory: Option<&'a str>,
+}
+
+/// Opens a new terminal window with the requested shell.
+///
+/// Windows Terminal is used on Windows, elsewhere the emulator from the
+/// `TERMINAL` environment variable with `x-terminal-emulator` as a fallback.
+pub fn open(parameters: TerminalParameters) -> Result<()> {
+    spawn(&shell_args(&parameters), parameters.working_directory)
+}
+
+/// Opens a new terminal window that runs all steps in a single script.
+pub fn open_chain(parameters: TerminalChainParameters) -> Result<()> {
+    spawn(&chain_args(&parameters), None)
+}
+
+fn spawn(shell_args: &[String], working_directory: Option<&str>) -> Result<()> {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new(WINDOWS_TERMINAL);
+
+        if let Some(working_directory) = working_directory {
+            cmd.args(["-d", working_directory]);
+        }
+
+        // Windows Terminal treats `;` as a delimiter of its own subcommands
+        cmd.args(shell_args.iter().map(|arg| arg.replace(';', "\\;")));
+        cmd
+    } else {
+        let emulator =
+            env::var(TERMINAL_ENV_VAR).unwrap_or_else(|_| DEFAULT_TERMINAL_EMULATOR.to_string());
+
+        let mut cmd = Command::new(emulator);
+        cmd.arg("-e").args(shell_args);
+
+        if let Some(working_directory) = working_directory {
+            cmd.current_dir(working_directory);
+        }
+
+        cmd
+    };
+
+    cmd.spawn().map_err(Error::new)?;
+
+    Ok(())
+}
+
+fn shell_args(parameters: &TerminalParameters) -> Vec<String> {
+    let TerminalParameters {
+        command,
+        environment_variables,
+        no_exit,
+        shell,
+        working_directory: _,
+    } = parameters;
+
+    let mut script: Vec<String> = environment_variables
+        .iter()
+        .map(|(name, value)| set_environment_variable_script(*shell, name, value))
+        .collect();
+
+    if let Some(command) = command {
+        script.push(command.to_string());
+    }
+
+    if script.is_empty() {
+        return vec![shell.as_str().to_string()];
+    }
+
+    script_args(*shell, script.join(separator(*shell)), *no_exit)
+}
+
+fn chain_args(parameters: &TerminalChainParameters) -> Vec<String> {
+    let TerminalChainParameters {
+        no_exit,
+        shell,
+        steps,
+    } = parameters;
+
+    let script: Vec<String> = steps.iter().map(|step| step_script(*shell, step)).collect();
+
+    let script = match shell {
+        Shell::Bash | Shell::Cmd | Shell::Fish | Shell::Sh | Shell::Zsh => script.join(" && "),
+
+        // `&&` needs PowerShell 7, `$?` tells whether the previous command
+        // succeeded in every version
+        Shell::Pwsh => script
+            .into_iter()
+            .rev()
+            .reduce(|rest, command| format!("{}; if ($?) {{ {} }}", command, rest))
+            .unwrap_or_default(),
+    };
+
+    script_args(*shell, script, *no_exit)
+}
+
+/// Starts the step in a child process of its own shell, so separators in
+/// its program, its working directory and its environment variables do not
+/// reach the following steps. The chain sees the exit status of the child.
+fn step_script(chain_shell: Shell, step: &TerminalStep) -> String {
+    let TerminalStep {
+        command,
+        environment_variables,
+        shell,
+        working_directory,
+    } = step;
+
+    let script: Vec<String> = working_directory
+        .map(|directory| change_directory_script(*shell, directory))
+        .into_iter()
+        .chain(
+            environment_variables
+                .iter()
+                .map(|(name, value)| set_environment_variable_script(*shell, name, value)),
+        )
+        .chain([command.to_string()])
+        .collect();
+
+    let mut args = script_args(*shell, script.join(separator(*shell)), false);
+    let script = args.pop().unwrap_or_default();
+
+    format!("{} {}", args.join(" "), quote(chain_shell, &script))
+}
+
+fn script_args(shell: Shell, script: String, no_exit: bool) -> Vec<String> {
+    let mut args = vec![shell.as_str().to_string()];
+
+    match shell {
+        Shell::Bash | Shell::Sh | Shell::Zsh => {
+            args.push("-c".to_string());
+
+            if no_exit {
+                args.push(format!("{}; exec {}", script, shell.as_str()));
+            } else {
+                args.push(script);
+            }
+        }
+        Shell::Cmd => {
+            args.push(if no_exit { "/K" } else { "/C" }.to_string());
+            args.push(script);
+        }
+        Shell::Fish => {
+            args.push(if no_exit { "-C" } else { "-c" }.to_string());
+            args.push(script);
+        }
+        Shell::Pwsh => {
+            if no_exit {
+                args.push("-NoExit".to_string());
+            }
+
+            args.push("-Command".to_string());
+            args.push(script);
+        }
+    }
+
+    args
+}
+
+fn change_directory_script(shell: Shell, directory: &str) -> String {
+    match shell {
+        Shell::Bash | Shell::Fish | Shell::Sh | Shell::Zsh => {
+            format!("cd {}", quote(shell, directory))
+        }
+        Shell::Cmd => format!("cd /d {}", quote(shell, directory)),
+        Shell::Pwsh => format!("Set-Location -LiteralPath {}", quote(shell, directory)),
+    }
+}
+
+fn quote(shell: Shell, value: &str) -> String {
+    match shell {
+        Shell::Bash | Shell::Sh | Shell::Zsh => format!("'{}'", value.replace('\'', "'\\''")),
+        Shell::Cmd => format!("\"{}\"", value),
+        Shell::Fish => format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'")),
+        Shell::Pwsh => format!("'{}'", value.replace('\'', "''")),
+    }
+}
+
+fn separator(shell: Shell) -> &'static str {
+    match shell {
+        Shell::Cmd => " & ",
+        Shell::Bash | Shell::Fish | Shell::Pwsh | Shell::Sh | Shell::Zsh => "; ",
+    }
+}
+
+fn set_environment_variable_script(shell: Shell, name: &str, value: &str) -> String {
+    match shell {
+        Shell::Bash | Shell::Sh | Shell::Zsh => format!("export {}={}", name, quote(shell, value)),
+        Shell::Cmd => format!("set \"{}={}\"", name, value),
+        Shell::Fish => format!("set -gx {} {}", name, quote(shell, value)),
+        Shell::Pwsh => format!("$env:{} = {}", name, quote(shell, value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameters(shell: Shell, no_exit: bool) -> TerminalParameters<'static> {
+        TerminalParameters {
+            command: Some("echo $GREETING"),
+            environment_variables: vec![("GREETING", "it's me")],
+            no_exit,
+            shell,
+            working_directory: None,
+        }
+    }
+
+    #[test]
+    fn it_builds_bash_args() {
+        assert_eq!(
+            shell_args(&parameters(Shell::Bash, true)),
+            vec![
+                "bash",
+                "-c",
+                "export GREETING='it'\\''s me'; echo $GREETING; exec bash"
+            ]
+        );
+    }
+
+    fn chain_parameters(shell: Shell) -> TerminalChainParameters<'static> {
+        TerminalChainParameters {
+            no_exit: false,
+            shell,
+            steps: vec![
+                TerminalStep {
+                    command: "cargo test",
+                    environment_variables: vec![("RUST_LOG", "debug")],
+                    shell,
+                    working_directory: Some("/home/ironman"),
+                },
+                TerminalStep {
+                    command: "git tag v1.0.0",
+                    environment_variables: Vec::new(),
+                    shell: Shell::Sh,
+                    working_directory: Some("/home/tony's"),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn it_builds_bash_chain_args() {
+        assert_eq!(
+            chain_args(&chain_parameters(Shell::Bash)),
+            vec![
+                "bash",
+                "-c",
+                "bash -c 'cd '\\''/home/ironman'\\''; export RUST_LOG='\\''debug'\\''; cargo test' && sh -c 'cd '\\''/home/tony'\\''\\'\\'''\\''s'\\''; git tag v1.0.0'"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_builds_pwsh_chain_args() {
+        assert_eq!(
+            chain_args(&chain_parameters(Shell::Pwsh)),
+            vec![
+                "pwsh",
+                "-Command",
+                "pwsh -Command 'Set-Location -LiteralPath ''/home/ironman''; $env:RUST_LOG = ''debug''; cargo test'; if ($?) { sh -c 'cd ''/home/tony''\\''''s''; git tag v1.0.0' }"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_builds_pwsh_args() {
+        assert_eq!(
+            shell_args(&parameters(Shell::Pwsh, false)),
+            vec![
+                "pwsh",
+                "-Command",
+                "$env:GREETING = 'it''s me'; echo $GREETING"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_starts_plain_shell_without_script() {
+        let parameters = TerminalParameters {
+            command: None,
+            environment_variables: Vec::new(),
+            no_exit: true,
+            shell: Shell::Fish,
+            working_directory: Some("/home"),
+        };
+
+        assert_eq!(shell_args(&parameters), vec!["fish"]);
+    }
+}
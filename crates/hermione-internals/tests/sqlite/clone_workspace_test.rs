@@ -0,0 +1,160 @@
+use crate::support::{
+    command_record_fixture, workspace_record_fixture, CommandRecordFixtureParameters,
+    WorkspaceRecordFixtureParameters,
+};
+use hermione_internals::sqlite::{
+    self, CloneWorkspaceQuery, CommandScopeQuery, EnvironmentVariableRecord, ListCommandsQuery,
+    WorkspaceRecord,
+};
+use rusqlite::{Connection, Result};
+use uuid::{Bytes, Uuid};
+
+struct CloneWorkspaceTestContext {
+    conn: Connection,
+    workspace: WorkspaceRecord,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(CloneWorkspaceTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let workspace = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+        location: Some("/home/ironman".to_string()),
+        shell: Some("pwsh".to_string()),
+        tags: vec!["rust".to_string()],
+        ..Default::default()
+    });
+
+    sqlite::insert_workspace(&conn, workspace.clone())?;
+    sqlite::replace_workspace_environment_variables(
+        &conn,
+        &workspace.id,
+        vec![EnvironmentVariableRecord {
+            name: "RUST_LOG".to_string(),
+            value: "info".to_string(),
+        }],
+    )?;
+
+    for program in ["cargo build", "cargo test"] {
+        sqlite::insert_command(
+            &conn,
+            command_record_fixture(
+                &workspace,
+                CommandRecordFixtureParameters {
+                    last_execute_time: Some(10),
+                    program: Some(program.to_string()),
+                    tags: vec!["cargo".to_string()],
+                    ..Default::default()
+                },
+            ),
+        )?;
+    }
+
+    test_fn(CloneWorkspaceTestContext { conn, workspace })
+}
+
+fn list_workspace_programs(conn: &Connection, workspace_id: Bytes) -> Result<Vec<String>> {
+    let mut programs: Vec<String> = sqlite::list_commands(
+        conn,
+        ListCommandsQuery {
+            created_after: None,
+            description_contains: None,
+            program_contains: "",
+            scope: CommandScopeQuery::Workspace(workspace_id),
+            sort: sqlite::SortQuery::Recency,
+            tags: None,
+            updated_after: None,
+            offset: 0,
+            limit: 10,
+        },
+    )?
+    .into_iter()
+    .map(|record| record.program)
+    .collect();
+
+    programs.sort();
+
+    Ok(programs)
+}
+
+#[test]
+fn it_clones_workspace_with_commands() -> Result<()> {
+    with_context(|ctx| {
+        let CloneWorkspaceTestContext { conn, workspace } = ctx;
+
+        let clone_id = Uuid::new_v4().into_bytes();
+
+        let count = sqlite::clone_workspace(
+            &conn,
+            CloneWorkspaceQuery {
+                id: workspace.id,
+                clone_id,
+                location: Some("/home/warmachine".to_string()),
+                name: "Warmachine".to_string(),
+            },
+        )?;
+
+        assert_eq!(count, 1);
+
+        let clone = sqlite::find_workspace(&conn, &clone_id)?.unwrap();
+
+        assert_eq!(clone.name, "Warmachine");
+        assert_eq!(clone.location.as_deref(), Some("/home/warmachine"));
+        assert_eq!(clone.shell.as_deref(), Some("pwsh"));
+        assert_eq!(clone.tags, vec!["rust".to_string()]);
+
+        let environment_variables = sqlite::list_workspace_environment_variables(&conn, &clone_id)?;
+
+        assert_eq!(environment_variables.len(), 1);
+        assert_eq!(environment_variables[0].name, "RUST_LOG");
+        assert_eq!(environment_variables[0].value, "info");
+
+        assert_eq!(
+            list_workspace_programs(&conn, clone_id)?,
+            vec!["cargo build", "cargo test"]
+        );
+        assert_eq!(
+            list_workspace_programs(&conn, workspace.id)?,
+            vec!["cargo build", "cargo test"]
+        );
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_leaves_nothing_behind_when_cloning_fails() -> Result<()> {
+    with_context(|ctx| {
+        let CloneWorkspaceTestContext { conn, workspace } = ctx;
+
+        conn.execute("DROP TABLE command_tags", ())?;
+
+        let clone_id = Uuid::new_v4().into_bytes();
+
+        let result = sqlite::clone_workspace(
+            &conn,
+            CloneWorkspaceQuery {
+                id: workspace.id,
+                clone_id,
+                location: None,
+                name: "Warmachine".to_string(),
+            },
+        );
+
+        assert!(result.is_err());
+        assert!(sqlite::find_workspace(&conn, &clone_id)?.is_none());
+        assert!(sqlite::list_workspace_environment_variables(&conn, &clone_id)?.is_empty());
+
+        let commands_count: usize =
+            conn.query_row("SELECT COUNT(*) FROM commands", (), |row| row.get(0))?;
+
+        assert_eq!(commands_count, 2);
+
+        Ok(())
+    })
+}
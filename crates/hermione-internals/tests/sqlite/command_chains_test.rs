@@ -0,0 +1,239 @@
+use crate::support::{workspace_record_fixture, WorkspaceRecordFixtureParameters};
+use hermione_internals::sqlite::{self, CommandChainRecord, ListCommandChainsQuery};
+use rusqlite::{Connection, Result};
+use uuid::{Bytes, Uuid};
+
+struct CommandChainsTestContext {
+    conn: Connection,
+    id: Bytes,
+    steps: Vec<Bytes>,
+    workspace_id: Bytes,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(CommandChainsTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+    sqlite::create_command_chains_table_if_not_exists(&conn)?;
+
+    let id = Uuid::new_v4().into_bytes();
+    let workspace_id = Uuid::new_v4().into_bytes();
+    let steps = vec![
+        Uuid::new_v4().into_bytes(),
+        Uuid::new_v4().into_bytes(),
+        Uuid::new_v4().into_bytes(),
+    ];
+
+    sqlite::insert_command_chain(
+        &conn,
+        CommandChainRecord {
+            id,
+            name: "Release".to_string(),
+            steps: steps.clone(),
+            workspace_id,
+        },
+    )?;
+
+    sqlite::insert_command_chain(
+        &conn,
+        CommandChainRecord {
+            id: Uuid::new_v4().into_bytes(),
+            name: "Deploy".to_string(),
+            steps: vec![Uuid::new_v4().into_bytes()],
+            workspace_id: Uuid::new_v4().into_bytes(),
+        },
+    )?;
+
+    test_fn(CommandChainsTestContext {
+        conn,
+        id,
+        steps,
+        workspace_id,
+    })
+}
+
+#[test]
+fn it_finds_command_chain_with_ordered_steps() -> Result<()> {
+    with_context(|ctx| {
+        let CommandChainsTestContext {
+            conn,
+            id,
+            steps,
+            workspace_id,
+        } = ctx;
+
+        let record = sqlite::find_command_chain(&conn, &id)?.unwrap();
+
+        assert_eq!(record.name, "Release");
+        assert_eq!(record.steps, steps);
+        assert_eq!(record.workspace_id, workspace_id);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_lists_command_chains_by_workspace() -> Result<()> {
+    with_context(|ctx| {
+        let CommandChainsTestContext {
+            conn,
+            id,
+            steps: _,
+            workspace_id,
+        } = ctx;
+
+        let records = sqlite::list_command_chains(
+            &conn,
+            ListCommandChainsQuery {
+                workspace_id: Some(workspace_id),
+                offset: 0,
+                limit: 10,
+            },
+        )?;
+
+        assert_eq!(
+            records.into_iter().map(|r| r.id).collect::<Vec<_>>(),
+            vec![id]
+        );
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_deletes_command_chain_with_steps() -> Result<()> {
+    with_context(|ctx| {
+        let CommandChainsTestContext {
+            conn,
+            id,
+            steps: _,
+            workspace_id: _,
+        } = ctx;
+
+        sqlite::delete_command_chain(&conn, &id)?;
+
+        let steps_count: usize = conn.query_row(
+            "SELECT COUNT(*) FROM command_chain_steps WHERE command_chain_id = ?1",
+            [id],
+            |row| row.get(0),
+        )?;
+
+        assert!(sqlite::find_command_chain(&conn, &id)?.is_none());
+        assert_eq!(steps_count, 0);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_removes_deleted_command_from_command_chains() -> Result<()> {
+    with_context(|ctx| {
+        let CommandChainsTestContext {
+            conn,
+            id,
+            steps,
+            workspace_id: _,
+        } = ctx;
+
+        sqlite::delete_command(&conn, &steps[1])?;
+
+        let record = sqlite::find_command_chain(&conn, &id)?.unwrap();
+
+        assert_eq!(record.steps, vec![steps[0], steps[2]]);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_deletes_command_chain_left_without_steps() -> Result<()> {
+    with_context(|ctx| {
+        let CommandChainsTestContext {
+            conn,
+            id,
+            steps,
+            workspace_id: _,
+        } = ctx;
+
+        for step in &steps {
+            sqlite::delete_command(&conn, step)?;
+        }
+
+        let chains_count: usize =
+            conn.query_row("SELECT COUNT(*) FROM command_chains", [], |row| row.get(0))?;
+
+        assert!(sqlite::find_command_chain(&conn, &id)?.is_none());
+        assert_eq!(chains_count, 1);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_deletes_command_chains_with_workspace() -> Result<()> {
+    with_context(|ctx| {
+        let CommandChainsTestContext {
+            conn,
+            id,
+            steps: _,
+            workspace_id,
+        } = ctx;
+
+        sqlite::delete_workspace(&conn, &workspace_id)?;
+
+        let steps_count: usize = conn.query_row(
+            "SELECT COUNT(*) FROM command_chain_steps WHERE command_chain_id = ?1",
+            [id],
+            |row| row.get(0),
+        )?;
+
+        assert!(sqlite::find_command_chain(&conn, &id)?.is_none());
+        assert_eq!(steps_count, 0);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_hides_command_chains_of_trashed_workspace() -> Result<()> {
+    with_context(|ctx| {
+        let CommandChainsTestContext {
+            conn,
+            id,
+            workspace_id,
+            ..
+        } = ctx;
+
+        sqlite::insert_workspace(
+            &conn,
+            workspace_record_fixture(WorkspaceRecordFixtureParameters {
+                id: Some(workspace_id),
+                ..Default::default()
+            }),
+        )?;
+        sqlite::trash_workspace(&conn, &workspace_id, 10)?;
+
+        let records = sqlite::list_command_chains(
+            &conn,
+            ListCommandChainsQuery {
+                workspace_id: None,
+                offset: 0,
+                limit: 10,
+            },
+        )?;
+
+        assert_eq!(
+            records
+                .into_iter()
+                .map(|record| record.name)
+                .collect::<Vec<_>>(),
+            vec!["Deploy"]
+        );
+        assert!(sqlite::find_command_chain(&conn, &id)?.is_none());
+
+        Ok(())
+    })
+}
@@ -0,0 +1,107 @@
+use hermione_internals::sqlite::{self, ListCommandRevisionsQuery, NewCommandRevisionQuery};
+use rusqlite::{Connection, Result};
+use uuid::{Bytes, Uuid};
+
+struct CommandRevisionsTestContext {
+    conn: Connection,
+    command_id: Bytes,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(CommandRevisionsTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let command_id = Uuid::new_v4().into_bytes();
+
+    for (command_id, program) in [
+        (command_id, "ping 1.1.1.1"),
+        (Uuid::new_v4().into_bytes(), "cargo test"),
+        (command_id, "ping 8.8.8.8"),
+    ] {
+        sqlite::insert_command_revision(
+            &conn,
+            NewCommandRevisionQuery {
+                id: Uuid::new_v4().into_bytes(),
+                command_id,
+                name: "Ping".to_string(),
+                program: program.to_string(),
+            },
+        )?;
+    }
+
+    test_fn(CommandRevisionsTestContext { conn, command_id })
+}
+
+#[test]
+fn it_lists_command_revisions_newest_first() -> Result<()> {
+    with_context(|ctx| {
+        let CommandRevisionsTestContext { conn, command_id } = ctx;
+
+        let records = sqlite::list_command_revisions(
+            &conn,
+            ListCommandRevisionsQuery {
+                command_id,
+                offset: 0,
+                limit: 10,
+            },
+        )?;
+
+        assert_eq!(
+            records.into_iter().map(|r| r.program).collect::<Vec<_>>(),
+            vec!["ping 8.8.8.8", "ping 1.1.1.1"]
+        );
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_finds_command_revision() -> Result<()> {
+    with_context(|ctx| {
+        let CommandRevisionsTestContext { conn, command_id } = ctx;
+
+        let record = sqlite::insert_command_revision(
+            &conn,
+            NewCommandRevisionQuery {
+                id: Uuid::new_v4().into_bytes(),
+                command_id,
+                name: "Ping Google".to_string(),
+                program: "ping -c 4 8.8.8.8".to_string(),
+            },
+        )?;
+
+        let found = sqlite::find_command_revision(&conn, &record.id)?.unwrap();
+
+        assert_eq!(found.command_id, command_id);
+        assert_eq!(found.created_at, record.created_at);
+        assert_eq!(found.name, "Ping Google");
+        assert_eq!(found.program, "ping -c 4 8.8.8.8");
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_deletes_command_revisions_with_command() -> Result<()> {
+    with_context(|ctx| {
+        let CommandRevisionsTestContext { conn, command_id } = ctx;
+
+        sqlite::delete_command(&conn, &command_id)?;
+
+        let records = sqlite::list_command_revisions(
+            &conn,
+            ListCommandRevisionsQuery {
+                command_id,
+                offset: 0,
+                limit: 10,
+            },
+        )?;
+
+        assert!(records.is_empty());
+
+        Ok(())
+    })
+}
@@ -0,0 +1,140 @@
+use crate::support::{
+    command_record_fixture, workspace_record_fixture, CommandRecordFixtureParameters,
+};
+use hermione_internals::sqlite::{
+    self, CommandExecutionRecord, CommandRecord, CopyCommandQuery, ListCommandExecutionsQuery,
+    WorkspaceRecord,
+};
+use rusqlite::{Connection, Result};
+use uuid::{Bytes, Uuid};
+
+struct CopyCommandTestContext {
+    conn: Connection,
+    command: CommandRecord,
+    target_workspace: WorkspaceRecord,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(CopyCommandTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let workspace = workspace_record_fixture(Default::default());
+    let target_workspace = workspace_record_fixture(Default::default());
+    sqlite::insert_workspace(&conn, workspace.clone())?;
+    sqlite::insert_workspace(&conn, target_workspace.clone())?;
+
+    let command = command_record_fixture(
+        &workspace,
+        CommandRecordFixtureParameters {
+            last_execute_time: Some(10),
+            tags: vec!["network".to_string()],
+            ..Default::default()
+        },
+    );
+
+    sqlite::insert_command(&conn, command.clone())?;
+    sqlite::insert_command_execution(
+        &conn,
+        CommandExecutionRecord {
+            command_id: Some(command.id),
+            location: None,
+            program: command.program.clone(),
+            start_time: 10,
+            workspace_id: Some(workspace.id),
+        },
+    )?;
+
+    test_fn(CopyCommandTestContext {
+        conn,
+        command,
+        target_workspace,
+    })
+}
+
+fn count_executions(conn: &Connection, command_id: Bytes) -> Result<usize> {
+    let records = sqlite::list_command_executions(
+        conn,
+        ListCommandExecutionsQuery {
+            command_id: Some(command_id),
+            workspace_id: None,
+            offset: 0,
+            limit: 10,
+        },
+    )?;
+
+    Ok(records.len())
+}
+
+#[test]
+fn it_copies_command_with_history() -> Result<()> {
+    with_context(|ctx| {
+        let CopyCommandTestContext {
+            conn,
+            command,
+            target_workspace,
+        } = ctx;
+
+        let copy_id = Uuid::new_v4().into_bytes();
+
+        let count = sqlite::copy_command(
+            &conn,
+            CopyCommandQuery {
+                id: command.id,
+                copy_id,
+                keep_history: true,
+                workspace_id: Some(target_workspace.id),
+            },
+        )?;
+
+        assert_eq!(count, 1);
+
+        let copy = sqlite::find_command(&conn, &copy_id)?.unwrap();
+
+        assert_eq!(copy.name, command.name);
+        assert_eq!(copy.program, command.program);
+        assert_eq!(copy.last_execute_time, Some(10));
+        assert_eq!(copy.tags, vec!["network".to_string()]);
+        assert_eq!(copy.workspace_id, Some(target_workspace.id));
+        assert_eq!(count_executions(&conn, copy_id)?, 0);
+        assert_eq!(count_executions(&conn, command.id)?, 1);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_copies_command_without_history() -> Result<()> {
+    with_context(|ctx| {
+        let CopyCommandTestContext {
+            conn,
+            command,
+            target_workspace: _,
+        } = ctx;
+
+        let copy_id = Uuid::new_v4().into_bytes();
+
+        sqlite::copy_command(
+            &conn,
+            CopyCommandQuery {
+                id: command.id,
+                copy_id,
+                keep_history: false,
+                workspace_id: None,
+            },
+        )?;
+
+        let copy = sqlite::find_command(&conn, &copy_id)?.unwrap();
+
+        assert_eq!(copy.last_execute_time, None);
+        assert_eq!(copy.tags, vec!["network".to_string()]);
+        assert_eq!(copy.workspace_id, None);
+        assert_eq!(count_executions(&conn, copy_id)?, 0);
+
+        Ok(())
+    })
+}
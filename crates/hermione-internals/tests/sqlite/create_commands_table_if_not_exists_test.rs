@@ -0,0 +1,53 @@
+use crate::support::{query_command, query_table_schema};
+use hermione_internals::sqlite;
+use rusqlite::{params, Connection, Result};
+use uuid::Uuid;
+
+#[test]
+fn it_makes_workspace_id_optional_in_existing_table() -> Result<()> {
+    let connection = Connection::open_in_memory()?;
+    let id = Uuid::new_v4().into_bytes();
+    let workspace_id = Uuid::new_v4().into_bytes();
+
+    connection.execute(
+        "CREATE TABLE commands (
+            id BLOB PRIMARY KEY,
+            last_execute_time INTEGER,
+            name TEXT NOT NULL,
+            program TEXT NOT NULL,
+            workspace_id BLOB NOT NULL
+        )",
+        (),
+    )?;
+
+    connection.execute(
+        "INSERT INTO commands (id, name, program, workspace_id) VALUES (?1, ?2, ?3, ?4)",
+        params![id, "Ping", "ping 1.1.1.1", workspace_id],
+    )?;
+
+    sqlite::create_commands_table_if_not_exists(&connection)?;
+
+    let workspace_id_column = query_table_schema(&connection, "commands")?
+        .into_iter()
+        .find(|column| column.name == "workspace_id")
+        .unwrap();
+
+    assert!(!workspace_id_column.not_null);
+
+    let record = query_command(&connection, &id)?;
+
+    assert_eq!(record.program, "ping 1.1.1.1");
+    assert_eq!(record.workspace_id, Some(workspace_id));
+
+    Ok(())
+}
+
+#[test]
+fn it_does_not_fail_if_table_already_exists() -> Result<()> {
+    let connection = Connection::open_in_memory()?;
+
+    sqlite::create_commands_table_if_not_exists(&connection)?;
+    sqlite::create_commands_table_if_not_exists(&connection)?;
+
+    Ok(())
+}
@@ -14,6 +14,7 @@ where
 {
     let conn = Connection::open_in_memory()?;
     sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
 
     let record = workspace_record_fixture(Default::default());
     sqlite::insert_workspace(&conn, record.clone())?;
@@ -26,15 +26,23 @@ fn it_returns_workspace() -> Result<()> {
             name: Some("Workspace 1".to_string()),
             location: Some("Location 1".to_string()),
             last_access_time: Some(1),
+            pinned: true,
+            shell: Some("bash".to_string()),
             ..Default::default()
         });
         sqlite::insert_workspace(&conn, record.clone())?;
 
         let Some(WorkspaceRecord {
             id,
+            created_at,
             last_access_time,
             location,
             name,
+            parent_id,
+            pinned,
+            shell,
+            tags,
+            updated_at,
         }) = sqlite::find_workspace(&conn, &record.id)?
         else {
             unreachable!("Expected record to be found")
@@ -44,6 +52,12 @@ fn it_returns_workspace() -> Result<()> {
         assert_eq!(last_access_time, Some(1));
         assert_eq!(location.as_deref(), Some("Location 1"));
         assert_eq!(name, "Workspace 1");
+        assert_eq!(parent_id, None);
+        assert!(pinned);
+        assert_eq!(shell.as_deref(), Some("bash"));
+        assert!(tags.is_empty());
+        assert!(created_at.is_some());
+        assert_eq!(updated_at, created_at);
 
         Ok(())
     })
@@ -28,9 +28,15 @@ fn it_inserts_workspace() -> Result<()> {
             &conn,
             WorkspaceRecord {
                 id: Uuid::new_v4().into_bytes(),
+                created_at: None,
                 last_access_time: None,
                 location: None,
                 name: "Test workspace".to_string(),
+                parent_id: None,
+                pinned: false,
+                shell: None,
+                tags: Vec::new(),
+                updated_at: None,
             },
         )?;
 
@@ -0,0 +1,98 @@
+use hermione_internals::sqlite::{self, CommandExecutionRecord, ListCommandExecutionsQuery};
+use rusqlite::{Connection, Result};
+use uuid::{Bytes, Uuid};
+
+struct ListCommandExecutionsTestContext {
+    conn: Connection,
+    command_id: Bytes,
+    workspace_id: Bytes,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(ListCommandExecutionsTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let command_id = Uuid::new_v4().into_bytes();
+    let workspace_id = Uuid::new_v4().into_bytes();
+
+    for (start_time, command_id, program) in [
+        (1, command_id, "ping 1.1.1.1"),
+        (2, Uuid::new_v4().into_bytes(), "cargo test"),
+        (3, command_id, "ping 8.8.8.8"),
+    ] {
+        sqlite::insert_command_execution(
+            &conn,
+            CommandExecutionRecord {
+                command_id: Some(command_id),
+                location: Some("/home/ironman".to_string()),
+                program: program.to_string(),
+                start_time,
+                workspace_id: Some(workspace_id),
+            },
+        )?;
+    }
+
+    test_fn(ListCommandExecutionsTestContext {
+        conn,
+        command_id,
+        workspace_id,
+    })
+}
+
+#[test]
+fn it_lists_command_executions_newest_first() -> Result<()> {
+    with_context(|ctx| {
+        let ListCommandExecutionsTestContext {
+            conn,
+            command_id,
+            workspace_id: _,
+        } = ctx;
+
+        let records = sqlite::list_command_executions(
+            &conn,
+            ListCommandExecutionsQuery {
+                command_id: Some(command_id),
+                workspace_id: None,
+                offset: 0,
+                limit: 10,
+            },
+        )?;
+
+        assert_eq!(
+            records.into_iter().map(|r| r.program).collect::<Vec<_>>(),
+            vec!["ping 8.8.8.8", "ping 1.1.1.1"]
+        );
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_keeps_command_executions_after_command_deletion() -> Result<()> {
+    with_context(|ctx| {
+        let ListCommandExecutionsTestContext {
+            conn,
+            command_id,
+            workspace_id,
+        } = ctx;
+
+        sqlite::delete_command(&conn, &command_id)?;
+
+        let records = sqlite::list_command_executions(
+            &conn,
+            ListCommandExecutionsQuery {
+                command_id: None,
+                workspace_id: Some(workspace_id),
+                offset: 0,
+                limit: 10,
+            },
+        )?;
+
+        assert_eq!(records.len(), 3);
+
+        Ok(())
+    })
+}
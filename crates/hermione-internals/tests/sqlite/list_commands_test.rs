@@ -0,0 +1,196 @@
+use hermione_internals::sqlite::{
+    self, CommandRecord, CommandScopeQuery, ListCommandsQuery, SortQuery, WorkspaceRecord,
+};
+use hermione_nexus::definitions::Command;
+use rusqlite::{Connection, Result};
+use uuid::Uuid;
+
+fn list_programs(conn: &Connection, scope: CommandScopeQuery) -> Result<Vec<String>> {
+    let records = sqlite::list_commands(
+        conn,
+        ListCommandsQuery {
+            created_after: None,
+            description_contains: None,
+            program_contains: "",
+            scope,
+            sort: SortQuery::Recency,
+            tags: None,
+            updated_after: None,
+            offset: 0,
+            limit: 10,
+        },
+    )?;
+
+    Ok(records.into_iter().map(|record| record.program).collect())
+}
+
+#[test]
+fn it_filters_commands_by_scope() -> Result<()> {
+    let conn = Connection::open_in_memory()?;
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let workspace_id = Uuid::new_v4().into_bytes();
+
+    for (program, workspace_id) in [
+        ("cargo test", Some(workspace_id)),
+        ("docker system prune", None),
+        ("npm test", Some(Uuid::new_v4().into_bytes())),
+    ] {
+        sqlite::insert_command(
+            &conn,
+            CommandRecord {
+                id: Uuid::new_v4().into_bytes(),
+                created_at: None,
+                description: None,
+                last_execute_time: None,
+                managed_by: None,
+                name: program.to_string(),
+                pinned: false,
+                program: program.to_string(),
+                shell: None,
+                tags: vec![],
+                updated_at: None,
+                workspace_id,
+            },
+        )?;
+    }
+
+    assert_eq!(
+        list_programs(&conn, CommandScopeQuery::All)?,
+        vec!["cargo test", "docker system prune", "npm test"]
+    );
+    assert_eq!(
+        list_programs(&conn, CommandScopeQuery::Global)?,
+        vec!["docker system prune"]
+    );
+    assert_eq!(
+        list_programs(&conn, CommandScopeQuery::Workspace(workspace_id))?,
+        vec!["cargo test"]
+    );
+    assert_eq!(
+        list_programs(&conn, CommandScopeQuery::WorkspaceAndGlobal(workspace_id))?,
+        vec!["cargo test", "docker system prune"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn it_lists_commands_inherited_from_ancestor_workspaces() -> Result<()> {
+    let conn = Connection::open_in_memory()?;
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let clients_id = Uuid::new_v4().into_bytes();
+    let acme_id = Uuid::new_v4().into_bytes();
+    let api_id = Uuid::new_v4().into_bytes();
+
+    for (id, name, parent_id) in [
+        (clients_id, "clients", None),
+        (acme_id, "acme", Some(clients_id)),
+        (api_id, "api", Some(acme_id)),
+    ] {
+        sqlite::insert_workspace(
+            &conn,
+            WorkspaceRecord {
+                id,
+                created_at: None,
+                last_access_time: None,
+                location: None,
+                name: name.to_string(),
+                parent_id,
+                pinned: false,
+                shell: None,
+                tags: vec![],
+                updated_at: None,
+            },
+        )?;
+    }
+
+    for (program, workspace_id) in [
+        ("cargo test", Some(api_id)),
+        ("docker system prune", None),
+        ("make deploy", Some(clients_id)),
+        ("npm test", Some(Uuid::new_v4().into_bytes())),
+    ] {
+        sqlite::insert_command(
+            &conn,
+            CommandRecord {
+                id: Uuid::new_v4().into_bytes(),
+                created_at: None,
+                description: None,
+                last_execute_time: None,
+                managed_by: None,
+                name: program.to_string(),
+                pinned: false,
+                program: program.to_string(),
+                shell: None,
+                tags: vec![],
+                updated_at: None,
+                workspace_id,
+            },
+        )?;
+    }
+
+    assert_eq!(
+        list_programs(&conn, CommandScopeQuery::Inherited(api_id))?,
+        vec!["cargo test", "docker system prune", "make deploy"]
+    );
+    assert_eq!(
+        list_programs(&conn, CommandScopeQuery::Inherited(clients_id))?,
+        vec!["docker system prune", "make deploy"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn it_lists_stored_commands_that_no_longer_pass_validation() -> Result<()> {
+    let conn = Connection::open_in_memory()?;
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    sqlite::insert_command(
+        &conn,
+        CommandRecord {
+            id: Uuid::new_v4().into_bytes(),
+            created_at: None,
+            description: None,
+            last_execute_time: None,
+            managed_by: None,
+            name: "Ping\tCloudflare".to_string(),
+            pinned: false,
+            program: " ".to_string(),
+            shell: None,
+            tags: vec![],
+            updated_at: None,
+            workspace_id: None,
+        },
+    )?;
+
+    let commands = sqlite::list_commands(
+        &conn,
+        ListCommandsQuery {
+            created_after: None,
+            description_contains: None,
+            program_contains: "",
+            scope: CommandScopeQuery::All,
+            sort: SortQuery::Recency,
+            tags: None,
+            updated_after: None,
+            offset: 0,
+            limit: 10,
+        },
+    )?
+    .into_iter()
+    .map(Command::try_from)
+    .collect::<hermione_nexus::Result<Vec<_>>>()
+    .unwrap();
+
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].name(), "Ping\tCloudflare");
+    assert_eq!(commands[0].program(), " ");
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use chrono::Utc;
-use hermione_internals::sqlite::{self, ListWorkspacesQueryOptions};
+use hermione_internals::sqlite::{
+    self, ListWorkspacesQueryOptions, SortQuery, TagsQuery, WorkspaceRecord, WorkspaceScopeQuery,
+};
+use hermione_nexus::definitions::Workspace;
 use rusqlite::{Connection, Result};
 
 use crate::support::{workspace_record_fixture, WorkspaceRecordFixtureParameters};
@@ -35,9 +38,14 @@ fn it_paginates_workspaces() -> Result<()> {
         let workspaces = sqlite::list_workspaces(
             &conn,
             ListWorkspacesQueryOptions {
+                created_after: None,
                 name_contains: "",
                 limit: 2,
                 offset: 3,
+                scope: WorkspaceScopeQuery::All,
+                sort: SortQuery::Recency,
+                tags: None,
+                updated_after: None,
             },
         )?;
 
@@ -66,9 +74,14 @@ fn it_sorts_workspaces_by_last_access_time_and_name() -> Result<()> {
         let workspaces = sqlite::list_workspaces(
             &conn,
             ListWorkspacesQueryOptions {
+                created_after: None,
                 name_contains: "",
                 limit: 4,
                 offset: 0,
+                scope: WorkspaceScopeQuery::All,
+                sort: SortQuery::Recency,
+                tags: None,
+                updated_after: None,
             },
         )?;
 
@@ -81,6 +94,96 @@ fn it_sorts_workspaces_by_last_access_time_and_name() -> Result<()> {
     })
 }
 
+#[test]
+fn it_sorts_workspaces_by_frecency() -> Result<()> {
+    with_context(|ctx| {
+        let ListWorkspacesTestContest { conn } = ctx;
+
+        let now = Utc::now().timestamp_micros();
+        let minute = 60_000_000;
+
+        for (name, last_access_time, access_count) in [
+            ("Workspace 9", now - minute, 1),
+            ("Workspace 10", now - 48 * 60 * minute, 10),
+        ] {
+            let record = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+                name: Some(name.to_string()),
+                last_access_time: Some(last_access_time),
+                ..Default::default()
+            });
+
+            sqlite::insert_workspace(&conn, record.clone())?;
+
+            for _ in 0..access_count {
+                sqlite::increment_workspace_access_count(&conn, &record.id)?;
+            }
+        }
+
+        let list_workspaces = |sort| {
+            sqlite::list_workspaces(
+                &conn,
+                ListWorkspacesQueryOptions {
+                    created_after: None,
+                    name_contains: "",
+                    limit: 2,
+                    offset: 0,
+                    scope: WorkspaceScopeQuery::All,
+                    sort,
+                    tags: None,
+                    updated_after: None,
+                },
+            )
+            .map(|workspaces| workspaces.into_iter().map(|w| w.name).collect::<Vec<_>>())
+        };
+
+        assert_eq!(
+            list_workspaces(SortQuery::Recency)?,
+            vec!["Workspace 9", "Workspace 10"]
+        );
+
+        assert_eq!(
+            list_workspaces(SortQuery::Frecency { now })?,
+            vec!["Workspace 10", "Workspace 9"]
+        );
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_lists_pinned_workspaces_first() -> Result<()> {
+    with_context(|ctx| {
+        let ListWorkspacesTestContest { conn } = ctx;
+
+        let record = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+            name: Some("Pinned workspace".to_string()),
+            pinned: true,
+            ..Default::default()
+        });
+        sqlite::insert_workspace(&conn, record)?;
+
+        let workspaces = sqlite::list_workspaces(
+            &conn,
+            ListWorkspacesQueryOptions {
+                created_after: None,
+                name_contains: "",
+                limit: 2,
+                offset: 0,
+                scope: WorkspaceScopeQuery::All,
+                sort: SortQuery::Recency,
+                tags: None,
+                updated_after: None,
+            },
+        )?;
+
+        assert_eq!(workspaces[0].name, "Pinned workspace");
+        assert!(workspaces[0].pinned);
+        assert!(!workspaces[1].pinned);
+
+        Ok(())
+    })
+}
+
 #[test]
 fn it_filters_workspaces_by_name() -> Result<()> {
     with_context(|ctx| {
@@ -89,9 +192,14 @@ fn it_filters_workspaces_by_name() -> Result<()> {
         let workspaces = sqlite::list_workspaces(
             &conn,
             ListWorkspacesQueryOptions {
+                created_after: None,
                 name_contains: "4",
                 limit: 4,
                 offset: 0,
+                scope: WorkspaceScopeQuery::All,
+                sort: SortQuery::Recency,
+                tags: None,
+                updated_after: None,
             },
         )?;
 
@@ -103,3 +211,216 @@ fn it_filters_workspaces_by_name() -> Result<()> {
         Ok(())
     })
 }
+
+#[test]
+fn it_filters_workspaces_by_tags() -> Result<()> {
+    with_context(|ctx| {
+        let ListWorkspacesTestContest { conn } = ctx;
+
+        for (name, tags) in [
+            ("Workspace 9", vec!["backend", "rust"]),
+            ("Workspace 10", vec!["frontend"]),
+            ("Workspace 11", vec!["rust"]),
+        ] {
+            let record = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+                name: Some(name.to_string()),
+                tags: tags.into_iter().map(ToString::to_string).collect(),
+                ..Default::default()
+            });
+
+            sqlite::insert_workspace(&conn, record)?;
+        }
+
+        let list_workspaces = |tags| {
+            sqlite::list_workspaces(
+                &conn,
+                ListWorkspacesQueryOptions {
+                    created_after: None,
+                    name_contains: "",
+                    limit: 10,
+                    offset: 0,
+                    scope: WorkspaceScopeQuery::All,
+                    sort: SortQuery::Recency,
+                    tags: Some(tags),
+                    updated_after: None,
+                },
+            )
+        };
+
+        let workspaces = list_workspaces(TagsQuery::AllOf(vec![
+            "backend".to_string(),
+            "rust".to_string(),
+        ]))?;
+
+        assert_eq!(
+            workspaces
+                .into_iter()
+                .map(|w| (w.name, w.tags))
+                .collect::<Vec<_>>(),
+            vec![(
+                "Workspace 9".to_string(),
+                vec!["backend".to_string(), "rust".to_string()]
+            )]
+        );
+
+        let workspaces = list_workspaces(TagsQuery::AnyOf(vec![
+            "frontend".to_string(),
+            "rust".to_string(),
+        ]))?;
+
+        assert_eq!(
+            workspaces.into_iter().map(|w| w.name).collect::<Vec<_>>(),
+            vec!["Workspace 10", "Workspace 11", "Workspace 9"]
+        );
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_filters_workspaces_by_scope() -> Result<()> {
+    with_context(|ctx| {
+        let ListWorkspacesTestContest { conn } = ctx;
+
+        let parent = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+            name: Some("Clients".to_string()),
+            ..Default::default()
+        });
+        let child = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+            name: Some("Acme".to_string()),
+            parent_id: Some(parent.id),
+            ..Default::default()
+        });
+        let parent_id = parent.id;
+
+        sqlite::insert_workspace(&conn, parent)?;
+        sqlite::insert_workspace(&conn, child)?;
+
+        let list_workspaces = |scope| {
+            sqlite::list_workspaces(
+                &conn,
+                ListWorkspacesQueryOptions {
+                    created_after: None,
+                    name_contains: "",
+                    limit: 10,
+                    offset: 0,
+                    scope,
+                    sort: SortQuery::Recency,
+                    tags: None,
+                    updated_after: None,
+                },
+            )
+            .map(|workspaces| workspaces.into_iter().map(|w| w.name).collect::<Vec<_>>())
+        };
+
+        assert_eq!(
+            list_workspaces(WorkspaceScopeQuery::Children(parent_id))?,
+            vec!["Acme"]
+        );
+        assert!(!list_workspaces(WorkspaceScopeQuery::Roots)?.contains(&"Acme".to_string()));
+        assert_eq!(list_workspaces(WorkspaceScopeQuery::Roots)?.len(), 9);
+        assert_eq!(list_workspaces(WorkspaceScopeQuery::All)?.len(), 10);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_sorts_and_filters_workspaces_by_timestamps() -> Result<()> {
+    let conn = Connection::open_in_memory()?;
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+
+    for (name, created_at, updated_at) in [
+        ("Workspace 1", 10, 40),
+        ("Workspace 2", 20, 30),
+        ("Workspace 3", 30, 30),
+    ] {
+        let record = WorkspaceRecord {
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+            ..workspace_record_fixture(WorkspaceRecordFixtureParameters {
+                name: Some(name.to_string()),
+                ..Default::default()
+            })
+        };
+
+        sqlite::insert_workspace(&conn, record)?;
+    }
+
+    let list_workspaces = |sort, created_after, updated_after| {
+        sqlite::list_workspaces(
+            &conn,
+            ListWorkspacesQueryOptions {
+                created_after,
+                name_contains: "",
+                limit: 10,
+                offset: 0,
+                scope: WorkspaceScopeQuery::All,
+                sort,
+                tags: None,
+                updated_after,
+            },
+        )
+        .map(|workspaces| workspaces.into_iter().map(|w| w.name).collect::<Vec<_>>())
+    };
+
+    assert_eq!(
+        list_workspaces(SortQuery::Created, None, None)?,
+        vec!["Workspace 3", "Workspace 2", "Workspace 1"]
+    );
+    assert_eq!(
+        list_workspaces(SortQuery::Updated, None, None)?,
+        vec!["Workspace 1", "Workspace 2", "Workspace 3"]
+    );
+    assert_eq!(
+        list_workspaces(SortQuery::Created, Some(10), None)?,
+        vec!["Workspace 3", "Workspace 2"]
+    );
+    assert_eq!(
+        list_workspaces(SortQuery::Created, None, Some(30))?,
+        vec!["Workspace 1"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn it_lists_stored_workspaces_that_no_longer_pass_validation() -> Result<()> {
+    with_context(|ctx| {
+        let ListWorkspacesTestContest { conn } = ctx;
+
+        sqlite::insert_workspace(
+            &conn,
+            workspace_record_fixture(WorkspaceRecordFixtureParameters {
+                name: Some("Workspace\n9".to_string()),
+                location: Some("/home/ironman\u{7}".to_string()),
+                ..Default::default()
+            }),
+        )?;
+
+        let workspaces = sqlite::list_workspaces(
+            &conn,
+            ListWorkspacesQueryOptions {
+                created_after: None,
+                name_contains: "",
+                limit: 10,
+                offset: 0,
+                scope: WorkspaceScopeQuery::All,
+                sort: SortQuery::Recency,
+                tags: None,
+                updated_after: None,
+            },
+        )?
+        .into_iter()
+        .map(Workspace::try_from)
+        .collect::<hermione_nexus::Result<Vec<_>>>()
+        .unwrap();
+
+        assert_eq!(workspaces.len(), 9);
+        assert!(workspaces
+            .iter()
+            .any(|workspace| workspace.name() == "Workspace\n9"));
+
+        Ok(())
+    })
+}
@@ -1,10 +1,23 @@
 pub mod support;
 
+mod clone_workspace_test;
+mod command_chains_test;
+mod command_revisions_test;
+mod copy_command_test;
+mod create_commands_table_if_not_exists_test;
 mod create_workspaces_table_if_not_exists_test;
 mod delete_workspace_test;
 mod find_workspace_test;
 mod insert_workspace_test;
+mod list_command_executions_test;
+mod list_commands_test;
 mod list_workspaces_test;
+mod move_command_test;
+mod replace_workspace_environment_variables_test;
+mod restore_commands_test;
 mod restore_workspace_test;
+mod secrets_test;
+mod sync_commands_test;
+mod trash_test;
 mod update_command_test;
 mod update_workspace_test;
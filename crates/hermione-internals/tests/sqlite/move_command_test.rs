@@ -0,0 +1,128 @@
+use crate::support::{
+    command_record_fixture, workspace_record_fixture, CommandRecordFixtureParameters,
+};
+use hermione_internals::sqlite::{
+    self, CommandExecutionRecord, CommandRecord, ListCommandExecutionsQuery, MoveCommandQuery,
+    WorkspaceRecord,
+};
+use rusqlite::{Connection, Result};
+
+struct MoveCommandTestContext {
+    conn: Connection,
+    command: CommandRecord,
+    target_workspace: WorkspaceRecord,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(MoveCommandTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let workspace = workspace_record_fixture(Default::default());
+    let target_workspace = workspace_record_fixture(Default::default());
+    sqlite::insert_workspace(&conn, workspace.clone())?;
+    sqlite::insert_workspace(&conn, target_workspace.clone())?;
+
+    let command = command_record_fixture(
+        &workspace,
+        CommandRecordFixtureParameters {
+            last_execute_time: Some(10),
+            ..Default::default()
+        },
+    );
+
+    sqlite::insert_command(&conn, command.clone())?;
+    sqlite::insert_command_execution(
+        &conn,
+        CommandExecutionRecord {
+            command_id: Some(command.id),
+            location: None,
+            program: command.program.clone(),
+            start_time: 10,
+            workspace_id: Some(workspace.id),
+        },
+    )?;
+
+    test_fn(MoveCommandTestContext {
+        conn,
+        command,
+        target_workspace,
+    })
+}
+
+fn count_executions(conn: &Connection, command: Option<&CommandRecord>) -> Result<usize> {
+    let records = sqlite::list_command_executions(
+        conn,
+        ListCommandExecutionsQuery {
+            command_id: command.map(|command| command.id),
+            workspace_id: None,
+            offset: 0,
+            limit: 10,
+        },
+    )?;
+
+    Ok(records.len())
+}
+
+#[test]
+fn it_moves_command_with_history() -> Result<()> {
+    with_context(|ctx| {
+        let MoveCommandTestContext {
+            conn,
+            command,
+            target_workspace,
+        } = ctx;
+
+        let count = sqlite::move_command(
+            &conn,
+            MoveCommandQuery {
+                id: command.id,
+                keep_history: true,
+                workspace_id: Some(target_workspace.id),
+            },
+        )?;
+
+        assert_eq!(count, 1);
+
+        let moved = sqlite::find_command(&conn, &command.id)?.unwrap();
+
+        assert_eq!(moved.last_execute_time, Some(10));
+        assert_eq!(moved.workspace_id, Some(target_workspace.id));
+        assert_eq!(count_executions(&conn, Some(&command))?, 1);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_moves_command_without_history_and_keeps_executions() -> Result<()> {
+    with_context(|ctx| {
+        let MoveCommandTestContext {
+            conn,
+            command,
+            target_workspace: _,
+        } = ctx;
+
+        sqlite::move_command(
+            &conn,
+            MoveCommandQuery {
+                id: command.id,
+                keep_history: false,
+                workspace_id: None,
+            },
+        )?;
+
+        let moved = sqlite::find_command(&conn, &command.id)?.unwrap();
+
+        assert_eq!(moved.last_execute_time, None);
+        assert_eq!(moved.workspace_id, None);
+        assert_eq!(count_executions(&conn, Some(&command))?, 0);
+        assert_eq!(count_executions(&conn, None)?, 1);
+
+        Ok(())
+    })
+}
@@ -0,0 +1,84 @@
+use crate::support::workspace_record_fixture;
+use hermione_internals::sqlite::{self, EnvironmentVariableRecord, WorkspaceRecord};
+use rusqlite::{Connection, Result};
+
+struct ReplaceWorkspaceEnvironmentVariablesTestContext {
+    conn: Connection,
+    record: WorkspaceRecord,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(ReplaceWorkspaceEnvironmentVariablesTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let record = workspace_record_fixture(Default::default());
+    sqlite::insert_workspace(&conn, record.clone())?;
+
+    sqlite::replace_workspace_environment_variables(
+        &conn,
+        &record.id,
+        vec![EnvironmentVariableRecord {
+            name: "AWS_PROFILE".to_string(),
+            value: "default".to_string(),
+        }],
+    )?;
+
+    test_fn(ReplaceWorkspaceEnvironmentVariablesTestContext { conn, record })
+}
+
+#[test]
+fn it_replaces_workspace_environment_variables() -> Result<()> {
+    with_context(|ctx| {
+        let ReplaceWorkspaceEnvironmentVariablesTestContext { conn, record } = ctx;
+
+        sqlite::replace_workspace_environment_variables(
+            &conn,
+            &record.id,
+            vec![
+                EnvironmentVariableRecord {
+                    name: "KUBECONFIG".to_string(),
+                    value: "~/.kube/config".to_string(),
+                },
+                EnvironmentVariableRecord {
+                    name: "AWS_PROFILE".to_string(),
+                    value: "stark".to_string(),
+                },
+            ],
+        )?;
+
+        let records: Vec<(String, String)> =
+            sqlite::list_workspace_environment_variables(&conn, &record.id)?
+                .into_iter()
+                .map(|record| (record.name, record.value))
+                .collect();
+
+        assert_eq!(
+            records,
+            vec![
+                ("AWS_PROFILE".to_string(), "stark".to_string()),
+                ("KUBECONFIG".to_string(), "~/.kube/config".to_string()),
+            ]
+        );
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_deletes_environment_variables_with_workspace() -> Result<()> {
+    with_context(|ctx| {
+        let ReplaceWorkspaceEnvironmentVariablesTestContext { conn, record } = ctx;
+
+        sqlite::delete_workspace(&conn, &record.id)?;
+
+        let records = sqlite::list_workspace_environment_variables(&conn, &record.id)?;
+
+        assert!(records.is_empty());
+
+        Ok(())
+    })
+}
@@ -0,0 +1,87 @@
+use crate::support::{
+    self, command_record_fixture, workspace_record_fixture, CommandRecordFixtureParameters,
+};
+use hermione_internals::sqlite::{self, CommandRecord, WorkspaceRecord};
+use rusqlite::{Connection, Result};
+
+struct RestoreCommandsTestContext {
+    conn: Connection,
+    workspace: WorkspaceRecord,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(RestoreCommandsTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let workspace = workspace_record_fixture(Default::default());
+    sqlite::insert_workspace(&conn, workspace.clone())?;
+
+    test_fn(RestoreCommandsTestContext { conn, workspace })
+}
+
+#[test]
+fn it_replaces_tags_of_existing_commands() -> Result<()> {
+    with_context(|ctx| {
+        let RestoreCommandsTestContext { conn, workspace } = ctx;
+
+        let command = command_record_fixture(
+            &workspace,
+            CommandRecordFixtureParameters {
+                tags: vec!["network".to_string(), "slow".to_string()],
+                ..Default::default()
+            },
+        );
+
+        sqlite::insert_command(&conn, command.clone())?;
+
+        sqlite::restore_commands(
+            &conn,
+            vec![CommandRecord {
+                tags: vec!["network".to_string()],
+                ..command.clone()
+            }],
+        )?;
+
+        let restored = support::query_command(&conn, &command.id)?;
+
+        assert_eq!(restored.tags, vec!["network".to_string()]);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_clears_shell_when_restored_without_one() -> Result<()> {
+    with_context(|ctx| {
+        let RestoreCommandsTestContext { conn, workspace } = ctx;
+
+        let command = command_record_fixture(
+            &workspace,
+            CommandRecordFixtureParameters {
+                shell: Some("fish".to_string()),
+                ..Default::default()
+            },
+        );
+
+        sqlite::insert_command(&conn, command.clone())?;
+
+        sqlite::restore_commands(
+            &conn,
+            vec![CommandRecord {
+                shell: None,
+                ..command.clone()
+            }],
+        )?;
+
+        let restored = support::query_command(&conn, &command.id)?;
+
+        assert_eq!(restored.shell, None);
+
+        Ok(())
+    })
+}
@@ -1,7 +1,9 @@
 use crate::support::{
     count_workspaces, workspace_record_fixture, WorkspaceRecordFixtureParameters,
 };
-use hermione_internals::sqlite::{self, ListWorkspacesQueryOptions, WorkspaceRecord};
+use hermione_internals::sqlite::{
+    self, ListWorkspacesQueryOptions, SortQuery, WorkspaceRecord, WorkspaceScopeQuery,
+};
 use rusqlite::{Connection, Result};
 
 struct RestoreWorkspacesTestContest {
@@ -41,9 +43,14 @@ fn it_inserts_workspace() -> Result<()> {
         let workspaces = sqlite::list_workspaces(
             &conn,
             ListWorkspacesQueryOptions {
+                created_after: None,
                 name_contains: "",
                 limit: 10,
                 offset: 0,
+                scope: WorkspaceScopeQuery::All,
+                sort: SortQuery::Recency,
+                tags: None,
+                updated_after: None,
             },
         )?;
 
@@ -75,6 +82,7 @@ fn it_updates_existing_workspaces() -> Result<()> {
 
         let workspace1 = WorkspaceRecord {
             name: "Workspace 9".to_string(),
+            pinned: false,
             ..workspace1
         };
 
@@ -83,9 +91,14 @@ fn it_updates_existing_workspaces() -> Result<()> {
         let workspaces = sqlite::list_workspaces(
             &conn,
             ListWorkspacesQueryOptions {
+                created_after: None,
                 name_contains: "",
                 limit: 10,
                 offset: 0,
+                scope: WorkspaceScopeQuery::All,
+                sort: SortQuery::Recency,
+                tags: None,
+                updated_after: None,
             },
         )?;
 
@@ -97,3 +110,147 @@ fn it_updates_existing_workspaces() -> Result<()> {
         Ok(())
     })
 }
+
+#[test]
+fn it_clears_shell_when_restored_without_one() -> Result<()> {
+    with_context(|ctx| {
+        let RestoreWorkspacesTestContest { conn } = ctx;
+
+        let workspace = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+            shell: Some("fish".to_string()),
+            ..Default::default()
+        });
+
+        sqlite::insert_workspace(&conn, workspace.clone())?;
+
+        sqlite::restore_workspaces(
+            &conn,
+            vec![WorkspaceRecord {
+                shell: None,
+                ..workspace.clone()
+            }],
+        )?;
+
+        let workspace = crate::support::query_workspace(&conn, &workspace.id)?;
+
+        assert_eq!(workspace.shell, None);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_keeps_timestamps_when_restored_without_them() -> Result<()> {
+    with_context(|ctx| {
+        let RestoreWorkspacesTestContest { conn } = ctx;
+
+        let workspace = WorkspaceRecord {
+            created_at: Some(1_700_000_000_000_000),
+            updated_at: Some(1_700_000_100_000_000),
+            ..workspace_record_fixture(Default::default())
+        };
+
+        sqlite::insert_workspace(&conn, workspace.clone())?;
+
+        sqlite::restore_workspaces(
+            &conn,
+            vec![WorkspaceRecord {
+                created_at: None,
+                updated_at: None,
+                ..workspace.clone()
+            }],
+        )?;
+
+        let restored = crate::support::query_workspace(&conn, &workspace.id)?;
+
+        assert_eq!(restored.created_at, Some(1_700_000_000_000_000));
+        assert_eq!(restored.updated_at, Some(1_700_000_100_000_000));
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_takes_timestamps_of_restored_workspaces() -> Result<()> {
+    with_context(|ctx| {
+        let RestoreWorkspacesTestContest { conn } = ctx;
+
+        let workspace = workspace_record_fixture(Default::default());
+
+        sqlite::insert_workspace(&conn, workspace.clone())?;
+
+        sqlite::restore_workspaces(
+            &conn,
+            vec![WorkspaceRecord {
+                created_at: Some(1_700_000_000_000_000),
+                updated_at: Some(1_700_000_100_000_000),
+                ..workspace.clone()
+            }],
+        )?;
+
+        let restored = crate::support::query_workspace(&conn, &workspace.id)?;
+
+        assert_eq!(restored.created_at, Some(1_700_000_000_000_000));
+        assert_eq!(restored.updated_at, Some(1_700_000_100_000_000));
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_replaces_tags_of_existing_workspaces() -> Result<()> {
+    with_context(|ctx| {
+        let RestoreWorkspacesTestContest { conn } = ctx;
+
+        let workspace = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+            tags: vec!["rust".to_string(), "work".to_string()],
+            ..Default::default()
+        });
+
+        sqlite::insert_workspace(&conn, workspace.clone())?;
+
+        sqlite::restore_workspaces(
+            &conn,
+            vec![WorkspaceRecord {
+                tags: vec!["rust".to_string()],
+                ..workspace.clone()
+            }],
+        )?;
+
+        let restored = crate::support::query_workspace(&conn, &workspace.id)?;
+
+        assert_eq!(restored.tags, vec!["rust".to_string()]);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_moves_restored_workspace_to_root() -> Result<()> {
+    with_context(|ctx| {
+        let RestoreWorkspacesTestContest { conn } = ctx;
+
+        let parent = workspace_record_fixture(Default::default());
+        let workspace = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+            parent_id: Some(parent.id),
+            ..Default::default()
+        });
+
+        sqlite::insert_workspace(&conn, parent)?;
+        sqlite::insert_workspace(&conn, workspace.clone())?;
+
+        sqlite::restore_workspaces(
+            &conn,
+            vec![WorkspaceRecord {
+                parent_id: None,
+                ..workspace.clone()
+            }],
+        )?;
+
+        let restored = crate::support::query_workspace(&conn, &workspace.id)?;
+
+        assert_eq!(restored.parent_id, None);
+
+        Ok(())
+    })
+}
@@ -0,0 +1,114 @@
+use hermione_internals::{
+    cipher::SecretCipher,
+    sqlite::{self, SecretRecord},
+};
+use hermione_nexus::definitions::{Secret, SecretParameters};
+use rusqlite::{Connection, Result};
+
+struct SecretsTestContext {
+    cipher: SecretCipher,
+    conn: Connection,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(SecretsTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+    sqlite::create_secrets_table_if_not_exists(&conn)?;
+
+    test_fn(SecretsTestContext {
+        cipher: SecretCipher::generate(),
+        conn,
+    })
+}
+
+fn save_secret(ctx: &SecretsTestContext, name: &str, value: &str) -> Result<()> {
+    let secret = Secret::new(SecretParameters {
+        name: name.to_string(),
+        value: value.to_string(),
+    })
+    .unwrap();
+
+    sqlite::upsert_secret(
+        &ctx.conn,
+        SecretRecord::encrypt(&ctx.cipher, &secret).unwrap(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn it_stores_encrypted_secret() -> Result<()> {
+    with_context(|ctx| {
+        save_secret(&ctx, "db_pw", "jarvis")?;
+
+        let record = sqlite::find_secret(&ctx.conn, "db_pw")?.unwrap();
+
+        assert!(!record
+            .ciphertext
+            .windows("jarvis".len())
+            .any(|window| window == b"jarvis"));
+
+        let secret = record.decrypt(&ctx.cipher).unwrap();
+
+        assert_eq!(secret.name(), "db_pw");
+        assert_eq!(secret.value(), "jarvis");
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_replaces_secret_value() -> Result<()> {
+    with_context(|ctx| {
+        save_secret(&ctx, "db_pw", "jarvis")?;
+        save_secret(&ctx, "db_pw", "friday")?;
+
+        let secret = sqlite::find_secret(&ctx.conn, "db_pw")?
+            .unwrap()
+            .decrypt(&ctx.cipher)
+            .unwrap();
+
+        assert_eq!(secret.value(), "friday");
+        assert_eq!(sqlite::list_secret_names(&ctx.conn)?, vec!["db_pw"]);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_lists_and_deletes_secrets() -> Result<()> {
+    with_context(|ctx| {
+        save_secret(&ctx, "token", "mark-42")?;
+        save_secret(&ctx, "db_pw", "jarvis")?;
+
+        assert_eq!(
+            sqlite::list_secret_names(&ctx.conn)?,
+            vec!["db_pw", "token"]
+        );
+
+        sqlite::delete_secret(&ctx.conn, "db_pw")?;
+
+        assert!(sqlite::find_secret(&ctx.conn, "db_pw")?.is_none());
+        assert_eq!(sqlite::list_secret_names(&ctx.conn)?, vec!["token"]);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_rejects_secret_encrypted_for_another_name() -> Result<()> {
+    with_context(|ctx| {
+        save_secret(&ctx, "db_pw", "jarvis")?;
+
+        let mut record = sqlite::find_secret(&ctx.conn, "db_pw")?.unwrap();
+        record.name = "token".to_string();
+
+        assert!(record
+            .decrypt(&ctx.cipher)
+            .is_err_and(|err| err.is_storage()));
+
+        Ok(())
+    })
+}
@@ -4,9 +4,13 @@ use uuid::{Bytes, Uuid};
 #[derive(Default)]
 pub struct CommandRecordFixtureParameters {
     pub id: Option<Bytes>,
+    pub description: Option<String>,
     pub last_execute_time: Option<i64>,
     pub name: Option<String>,
+    pub pinned: bool,
     pub program: Option<String>,
+    pub shell: Option<String>,
+    pub tags: Vec<String>,
 }
 
 #[derive(Default)]
@@ -15,6 +19,10 @@ pub struct WorkspaceRecordFixtureParameters {
     pub last_access_time: Option<i64>,
     pub location: Option<String>,
     pub name: Option<String>,
+    pub parent_id: Option<Bytes>,
+    pub pinned: bool,
+    pub shell: Option<String>,
+    pub tags: Vec<String>,
 }
 
 pub fn command_record_fixture(
@@ -23,17 +31,28 @@ pub fn command_record_fixture(
 ) -> CommandRecord {
     let CommandRecordFixtureParameters {
         id,
+        description,
         last_execute_time,
         name,
+        pinned,
         program,
+        shell,
+        tags,
     } = parameters;
 
     CommandRecord {
         id: id.unwrap_or_else(|| Uuid::new_v4().into_bytes()),
+        created_at: None,
+        description,
         last_execute_time,
+        managed_by: None,
         name: name.unwrap_or_else(|| "Test command".into()),
+        pinned,
         program: program.unwrap_or_else(|| "echo \"Hello, world!\"".into()),
-        workspace_id: workspace.id,
+        shell,
+        tags,
+        updated_at: None,
+        workspace_id: Some(workspace.id),
     }
 }
 
@@ -43,12 +62,22 @@ pub fn workspace_record_fixture(parameters: WorkspaceRecordFixtureParameters) ->
         last_access_time,
         location,
         name,
+        parent_id,
+        pinned,
+        shell,
+        tags,
     } = parameters;
 
     WorkspaceRecord {
         id: id.unwrap_or_else(|| Uuid::new_v4().into_bytes()),
+        created_at: None,
         last_access_time,
         location,
         name: name.unwrap_or_else(|| "Test workspace".into()),
+        parent_id,
+        pinned,
+        shell,
+        tags,
+        updated_at: None,
     }
 }
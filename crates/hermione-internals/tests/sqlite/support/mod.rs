@@ -23,35 +23,77 @@ pub fn count_workspaces(conn: &Connection) -> Result<usize> {
 }
 
 pub fn query_workspace(conn: &Connection, id: &Bytes) -> Result<WorkspaceRecord> {
-    conn.prepare("SELECT id, last_access_time, location, name FROM workspaces WHERE id = ?1")?
-        .query_row(params![id], |row| {
-            Ok(WorkspaceRecord {
-                id: row.get(0)?,
-                last_access_time: row.get(1)?,
-                location: row.get(2)?,
-                name: row.get(3)?,
-            })
+    conn.prepare(
+        "SELECT
+            id,
+            last_access_time,
+            location,
+            name,
+            parent_id,
+            pinned,
+            shell,
+            (
+                SELECT GROUP_CONCAT(workspace_tags.name)
+                FROM workspace_tags
+                WHERE workspace_id = workspaces.id
+            ),
+            created_at,
+            updated_at
+        FROM workspaces
+        WHERE id = ?1",
+    )?
+    .query_row(params![id], |row| {
+        Ok(WorkspaceRecord {
+            id: row.get(0)?,
+            created_at: row.get(8)?,
+            last_access_time: row.get(1)?,
+            location: row.get(2)?,
+            name: row.get(3)?,
+            parent_id: row.get(4)?,
+            pinned: row.get(5)?,
+            shell: row.get(6)?,
+            tags: split_tags(row.get(7)?),
+            updated_at: row.get(9)?,
         })
+    })
 }
 
 pub fn query_command(conn: &Connection, id: &Bytes) -> Result<CommandRecord> {
     conn.prepare(
         "SELECT
             id,
+            description,
             last_execute_time,
             name,
+            pinned,
             program,
-            workspace_id
+            shell,
+            workspace_id,
+            (
+                SELECT GROUP_CONCAT(command_tags.name)
+                FROM command_tags
+                WHERE command_id = commands.id
+            ),
+            created_at,
+            updated_at,
+            managed_by
         FROM commands
         WHERE id = ?1",
     )?
     .query_row(params![id], |row| {
         Ok(CommandRecord {
             id: row.get(0)?,
-            last_execute_time: row.get(1)?,
-            name: row.get(2)?,
-            program: row.get(3)?,
-            workspace_id: row.get(4)?,
+            created_at: row.get(9)?,
+            description: row.get(1)?,
+            last_execute_time: row.get(2)?,
+            managed_by: row.get(11)?,
+            name: row.get(3)?,
+            pinned: row.get(4)?,
+            program: row.get(5)?,
+            shell: row.get(6)?,
+            updated_at: row.get(10)?,
+            workspace_id: row.get(7)?,
+            tags: split_tags(row.get(8)?),
         })
     })
 }
@@ -71,6 +113,16 @@ pub fn query_table_schema(connection: &Connection, table_name: &str) -> Result<V
         .collect::<Result<Vec<_>, _>>()
 }
 
+fn split_tags(value: Option<String>) -> Vec<String> {
+    let mut tags: Vec<String> = value
+        .map(|value| value.split(',').map(ToString::to_string).collect())
+        .unwrap_or_default();
+
+    tags.sort();
+
+    tags
+}
+
 pub fn expected_workspaces_table_schema() -> Vec<ColumnInfo> {
     vec![
         ColumnInfo {
@@ -80,6 +132,13 @@ pub fn expected_workspaces_table_schema() -> Vec<ColumnInfo> {
             default_value: None,
             primary_key: true,
         },
+        ColumnInfo {
+            name: "access_count".to_string(),
+            type_name: "INTEGER".to_string(),
+            not_null: true,
+            default_value: Some("0".to_string()),
+            primary_key: false,
+        },
         ColumnInfo {
             name: "last_access_time".to_string(),
             type_name: "INTEGER".to_string(),
@@ -101,5 +160,47 @@ pub fn expected_workspaces_table_schema() -> Vec<ColumnInfo> {
             default_value: None,
             primary_key: false,
         },
+        ColumnInfo {
+            name: "pinned".to_string(),
+            type_name: "INTEGER".to_string(),
+            not_null: true,
+            default_value: Some("0".to_string()),
+            primary_key: false,
+        },
+        ColumnInfo {
+            name: "shell".to_string(),
+            type_name: "TEXT".to_string(),
+            not_null: false,
+            default_value: None,
+            primary_key: false,
+        },
+        ColumnInfo {
+            name: "deleted_at".to_string(),
+            type_name: "INTEGER".to_string(),
+            not_null: false,
+            default_value: None,
+            primary_key: false,
+        },
+        ColumnInfo {
+            name: "parent_id".to_string(),
+            type_name: "BLOB".to_string(),
+            not_null: false,
+            default_value: None,
+            primary_key: false,
+        },
+        ColumnInfo {
+            name: "created_at".to_string(),
+            type_name: "INTEGER".to_string(),
+            not_null: false,
+            default_value: None,
+            primary_key: false,
+        },
+        ColumnInfo {
+            name: "updated_at".to_string(),
+            type_name: "INTEGER".to_string(),
+            not_null: false,
+            default_value: None,
+            primary_key: false,
+        },
     ]
 }
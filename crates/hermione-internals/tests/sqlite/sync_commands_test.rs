@@ -0,0 +1,123 @@
+use crate::support::{
+    self, command_record_fixture, workspace_record_fixture, CommandRecordFixtureParameters,
+};
+use hermione_internals::sqlite::{self, CommandRecord, SyncCommandsQuery};
+use rusqlite::{Connection, Result};
+
+struct SyncCommandsTestContext {
+    conn: Connection,
+    managed: CommandRecord,
+    stale: CommandRecord,
+    task: CommandRecord,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(SyncCommandsTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let workspace = workspace_record_fixture(Default::default());
+    sqlite::insert_workspace(&conn, workspace.clone())?;
+
+    let managed_command = |name: &str, program: &str| CommandRecord {
+        managed_by: Some("project_tasks".to_string()),
+        ..command_record_fixture(
+            &workspace,
+            CommandRecordFixtureParameters {
+                name: Some(name.to_string()),
+                program: Some(program.to_string()),
+                tags: vec!["task".to_string()],
+                ..Default::default()
+            },
+        )
+    };
+
+    let managed = managed_command("make lint", "make lint");
+    let stale = managed_command("make docs", "make docs");
+    let task = managed_command("make test", "make test");
+
+    sqlite::insert_command(&conn, managed.clone())?;
+    sqlite::insert_command(&conn, stale.clone())?;
+
+    test_fn(SyncCommandsTestContext {
+        conn,
+        managed,
+        stale,
+        task,
+    })
+}
+
+#[test]
+fn it_applies_all_changes() -> Result<()> {
+    with_context(|ctx| {
+        let SyncCommandsTestContext {
+            conn,
+            managed,
+            stale,
+            task,
+        } = ctx;
+
+        sqlite::sync_commands(
+            &conn,
+            SyncCommandsQuery {
+                deleted: vec![stale.id],
+                inserted: vec![task.clone()],
+                updated: vec![CommandRecord {
+                    program: "make lint --fix".to_string(),
+                    tags: vec![],
+                    ..managed.clone()
+                }],
+            },
+        )?;
+
+        let updated = support::query_command(&conn, &managed.id)?;
+        let inserted = support::query_command(&conn, &task.id)?;
+
+        assert_eq!(updated.program, "make lint --fix");
+        assert_eq!(updated.managed_by.as_deref(), Some("project_tasks"));
+        assert!(updated.tags.is_empty());
+        assert_eq!(inserted.name, "make test");
+        assert_eq!(inserted.managed_by.as_deref(), Some("project_tasks"));
+        assert!(support::query_command(&conn, &stale.id).is_err());
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_applies_nothing_when_a_change_fails() -> Result<()> {
+    with_context(|ctx| {
+        let SyncCommandsTestContext {
+            conn,
+            managed,
+            stale,
+            task,
+        } = ctx;
+
+        let result = sqlite::sync_commands(
+            &conn,
+            SyncCommandsQuery {
+                deleted: vec![stale.id],
+                inserted: vec![task.clone(), task.clone()],
+                updated: vec![CommandRecord {
+                    program: "make lint --fix".to_string(),
+                    ..managed.clone()
+                }],
+            },
+        );
+
+        assert!(result.is_err());
+        assert!(support::query_command(&conn, &task.id).is_err());
+        assert_eq!(
+            support::query_command(&conn, &managed.id)?.program,
+            "make lint"
+        );
+        assert_eq!(support::query_command(&conn, &stale.id)?.name, "make docs");
+
+        Ok(())
+    })
+}
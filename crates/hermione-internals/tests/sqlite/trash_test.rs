@@ -0,0 +1,359 @@
+use crate::support::{
+    command_record_fixture, count_workspaces, query_workspace, workspace_record_fixture,
+    WorkspaceRecordFixtureParameters,
+};
+use hermione_internals::sqlite::{
+    self, CommandExecutionRecord, CommandRecord, ListCommandExecutionsQuery,
+    ListCommandRevisionsQuery, ListTrashQuery, NewCommandRevisionQuery, TrashRecord,
+    WorkspaceRecord,
+};
+use rusqlite::{Connection, Result};
+use uuid::Uuid;
+
+struct TrashTestContext {
+    conn: Connection,
+    command: CommandRecord,
+    workspace: WorkspaceRecord,
+}
+
+fn with_context<T>(test_fn: T) -> Result<()>
+where
+    T: FnOnce(TrashTestContext) -> Result<()>,
+{
+    let conn = Connection::open_in_memory()?;
+
+    sqlite::create_workspaces_table_if_not_exists(&conn)?;
+    sqlite::create_commands_table_if_not_exists(&conn)?;
+
+    let workspace = workspace_record_fixture(Default::default());
+    sqlite::insert_workspace(&conn, workspace.clone())?;
+
+    let command = command_record_fixture(&workspace, Default::default());
+    sqlite::insert_command(&conn, command.clone())?;
+
+    test_fn(TrashTestContext {
+        conn,
+        command,
+        workspace,
+    })
+}
+
+fn list_trash(conn: &Connection) -> Result<Vec<TrashRecord>> {
+    sqlite::list_trash(
+        conn,
+        ListTrashQuery {
+            limit: 10,
+            offset: 0,
+        },
+    )
+}
+
+#[test]
+fn it_hides_trashed_command() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext { conn, command, .. } = ctx;
+
+        sqlite::trash_command(&conn, &command.id, 10)?;
+
+        assert!(sqlite::find_command(&conn, &command.id)?.is_none());
+        assert!(sqlite::find_trashed_command(&conn, &command.id)?.is_some());
+        assert_eq!(list_trash(&conn)?.len(), 1);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_restores_trashed_command() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext { conn, command, .. } = ctx;
+
+        sqlite::trash_command(&conn, &command.id, 10)?;
+        sqlite::restore_trashed_command(&conn, &command.id)?;
+
+        assert!(sqlite::find_command(&conn, &command.id)?.is_some());
+        assert!(list_trash(&conn)?.is_empty());
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_trashes_workspace_with_commands() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext {
+            conn,
+            command,
+            workspace,
+        } = ctx;
+
+        sqlite::trash_workspace(&conn, &workspace.id, 10)?;
+
+        assert!(sqlite::find_workspace(&conn, &workspace.id)?.is_none());
+        assert!(sqlite::find_command(&conn, &command.id)?.is_none());
+        assert!(sqlite::find_trashed_command(&conn, &command.id)?.is_none());
+
+        let trash = list_trash(&conn)?;
+        assert_eq!(trash.len(), 1);
+        assert!(matches!(
+            trash[0],
+            TrashRecord::Workspace { deleted_at: 10, .. }
+        ));
+
+        sqlite::restore_trashed_workspace(&conn, &workspace.id)?;
+
+        assert!(sqlite::find_workspace(&conn, &workspace.id)?.is_some());
+        assert!(sqlite::find_command(&conn, &command.id)?.is_some());
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_keeps_earlier_trashed_commands_when_restoring_workspace() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext {
+            conn,
+            command,
+            workspace,
+        } = ctx;
+
+        sqlite::trash_command(&conn, &command.id, 5)?;
+        sqlite::trash_workspace(&conn, &workspace.id, 10)?;
+        sqlite::restore_trashed_workspace(&conn, &workspace.id)?;
+
+        assert!(sqlite::find_command(&conn, &command.id)?.is_none());
+        assert!(sqlite::find_trashed_command(&conn, &command.id)?.is_some());
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_purges_trash_deleted_before_cutoff() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext {
+            conn,
+            command,
+            workspace,
+        } = ctx;
+
+        sqlite::trash_command(&conn, &command.id, 5)?;
+        sqlite::trash_workspace(&conn, &workspace.id, 10)?;
+
+        sqlite::purge_trash(&conn, Some(8))?;
+
+        assert!(sqlite::find_trashed_command(&conn, &command.id)?.is_none());
+        assert!(sqlite::find_trashed_workspace(&conn, &workspace.id)?.is_some());
+
+        sqlite::purge_trashed_workspace(&conn, &workspace.id)?;
+
+        assert!(list_trash(&conn)?.is_empty());
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_pages_trash_by_deletion_time() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext {
+            conn,
+            command,
+            workspace,
+        } = ctx;
+
+        let global_command = CommandRecord {
+            id: Uuid::new_v4().into_bytes(),
+            workspace_id: None,
+            ..command.clone()
+        };
+
+        sqlite::insert_command(&conn, global_command.clone())?;
+
+        sqlite::trash_command(&conn, &command.id, 5)?;
+        sqlite::trash_command(&conn, &global_command.id, 20)?;
+        sqlite::trash_workspace(&conn, &workspace.id, 10)?;
+
+        let list_page = |offset| {
+            sqlite::list_trash(&conn, ListTrashQuery { limit: 1, offset }).map(|records| {
+                records
+                    .into_iter()
+                    .map(|record| match record {
+                        TrashRecord::Command { deleted_at, .. }
+                        | TrashRecord::Workspace { deleted_at, .. } => deleted_at,
+                    })
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        assert_eq!(list_page(0)?, vec![20]);
+        assert_eq!(list_page(1)?, vec![10]);
+        assert_eq!(list_page(2)?, Vec::<i64>::new());
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_purges_command_revisions() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext { conn, command, .. } = ctx;
+
+        sqlite::insert_command_revision(
+            &conn,
+            NewCommandRevisionQuery {
+                id: Uuid::new_v4().into_bytes(),
+                command_id: command.id,
+                name: command.name.clone(),
+                program: command.program.clone(),
+            },
+        )?;
+
+        sqlite::trash_command(&conn, &command.id, 10)?;
+        sqlite::purge_trashed_command(&conn, &command.id)?;
+
+        let revisions = sqlite::list_command_revisions(
+            &conn,
+            ListCommandRevisionsQuery {
+                command_id: command.id,
+                offset: 0,
+                limit: 10,
+            },
+        )?;
+
+        assert!(revisions.is_empty());
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_detaches_executions_from_purged_workspace() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext {
+            conn,
+            command,
+            workspace,
+        } = ctx;
+
+        sqlite::insert_command_execution(
+            &conn,
+            CommandExecutionRecord {
+                command_id: Some(command.id),
+                location: None,
+                program: command.program.clone(),
+                start_time: 1,
+                workspace_id: Some(workspace.id),
+            },
+        )?;
+
+        sqlite::trash_workspace(&conn, &workspace.id, 10)?;
+        sqlite::purge_trash(&conn, None)?;
+
+        let executions = sqlite::list_command_executions(
+            &conn,
+            ListCommandExecutionsQuery {
+                command_id: None,
+                workspace_id: None,
+                offset: 0,
+                limit: 10,
+            },
+        )?;
+
+        assert_eq!(executions.len(), 1);
+        assert_eq!(executions[0].command_id, None);
+        assert_eq!(executions[0].workspace_id, None);
+        assert_eq!(executions[0].program, command.program);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_detaches_earlier_trashed_child_workspaces_from_purged_workspace() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext {
+            conn, workspace, ..
+        } = ctx;
+
+        let child = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+            parent_id: Some(workspace.id),
+            ..Default::default()
+        });
+
+        sqlite::insert_workspace(&conn, child.clone())?;
+
+        sqlite::trash_workspace(&conn, &child.id, 5)?;
+        sqlite::trash_workspace(&conn, &workspace.id, 10)?;
+        sqlite::purge_trashed_workspace(&conn, &workspace.id)?;
+
+        assert_eq!(query_workspace(&conn, &child.id)?.parent_id, None);
+        assert!(sqlite::find_trashed_workspace(&conn, &child.id)?.is_some());
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_trashes_and_restores_descendant_workspaces_with_parent() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext {
+            conn, workspace, ..
+        } = ctx;
+
+        let child = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+            parent_id: Some(workspace.id),
+            ..Default::default()
+        });
+        let grandchild = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+            parent_id: Some(child.id),
+            ..Default::default()
+        });
+        let child_command = command_record_fixture(&child, Default::default());
+
+        sqlite::insert_workspace(&conn, child.clone())?;
+        sqlite::insert_workspace(&conn, grandchild.clone())?;
+        sqlite::insert_command(&conn, child_command.clone())?;
+
+        sqlite::trash_workspace(&conn, &workspace.id, 10)?;
+
+        assert!(sqlite::find_workspace(&conn, &child.id)?.is_none());
+        assert!(sqlite::find_workspace(&conn, &grandchild.id)?.is_none());
+        assert!(sqlite::find_command(&conn, &child_command.id)?.is_none());
+        assert!(sqlite::find_trashed_workspace(&conn, &child.id)?.is_none());
+        assert_eq!(list_trash(&conn)?.len(), 1);
+
+        sqlite::restore_trashed_workspace(&conn, &workspace.id)?;
+
+        assert!(sqlite::find_workspace(&conn, &child.id)?.is_some());
+        assert!(sqlite::find_workspace(&conn, &grandchild.id)?.is_some());
+        assert!(sqlite::find_command(&conn, &child_command.id)?.is_some());
+        assert!(list_trash(&conn)?.is_empty());
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_purges_descendant_workspaces_with_parent() -> Result<()> {
+    with_context(|ctx| {
+        let TrashTestContext {
+            conn, workspace, ..
+        } = ctx;
+
+        let child = workspace_record_fixture(WorkspaceRecordFixtureParameters {
+            parent_id: Some(workspace.id),
+            ..Default::default()
+        });
+
+        sqlite::insert_workspace(&conn, child.clone())?;
+
+        sqlite::trash_workspace(&conn, &workspace.id, 10)?;
+        sqlite::purge_trash(&conn, None)?;
+
+        assert_eq!(count_workspaces(&conn)?, 0);
+
+        Ok(())
+    })
+}
@@ -3,6 +3,7 @@ use crate::support::{
 };
 use hermione_internals::sqlite::{self, CommandRecord, OptionalValue};
 use rusqlite::{Connection, Result};
+use uuid::Uuid;
 
 struct UpdateCommandTestContext {
     conn: Connection,
@@ -47,9 +48,13 @@ fn it_updates_command_name() -> Result<()> {
             &conn,
             sqlite::UpdateCommandQueryOptions {
                 id: command.id,
+                description: None,
                 last_execute_time: None,
                 name: Some("Spaceship".to_string()),
+                pinned: None,
                 program: None,
+                shell: None,
+                tags: None,
             },
         )?;
 
@@ -76,9 +81,13 @@ fn it_updates_command_program() -> Result<()> {
             &conn,
             sqlite::UpdateCommandQueryOptions {
                 id: command.id,
+                description: None,
                 last_execute_time: None,
                 name: None,
+                pinned: None,
                 program: Some("echo \"Hello, universe!\"".to_string()),
+                shell: None,
+                tags: None,
             },
         )?;
 
@@ -105,9 +114,13 @@ fn it_updates_last_execute_time() -> Result<()> {
             &conn,
             sqlite::UpdateCommandQueryOptions {
                 id: command.id,
+                description: None,
                 last_execute_time: Some(sqlite::OptionalValue::Value(20)),
                 name: None,
+                pinned: None,
                 program: None,
+                shell: None,
+                tags: None,
             },
         )?;
 
@@ -123,6 +136,44 @@ fn it_updates_last_execute_time() -> Result<()> {
     })
 }
 
+#[test]
+fn it_updates_command_description() -> Result<()> {
+    with_context(|ctx| {
+        let UpdateCommandTestContext { conn, command } = ctx;
+
+        assert_eq!(command.description, None);
+
+        let count = sqlite::update_command(
+            &conn,
+            sqlite::UpdateCommandQueryOptions {
+                id: command.id,
+                description: Some(OptionalValue::Value(
+                    "Greets the world.\nTwice.".to_string(),
+                )),
+                last_execute_time: None,
+                name: None,
+                pinned: None,
+                program: None,
+                shell: None,
+                tags: None,
+            },
+        )?;
+
+        assert_eq!(count, 1);
+
+        let command = support::query_command(&conn, &command.id)?;
+
+        assert_eq!(
+            command.description.as_deref(),
+            Some("Greets the world.\nTwice.")
+        );
+        assert_eq!(command.name, "Test command");
+        assert_eq!(command.program, "echo \"Hello, world!\"");
+
+        Ok(())
+    })
+}
+
 #[test]
 fn it_updates_last_execute_time_with_none() -> Result<()> {
     with_context(|ctx| {
@@ -134,9 +185,13 @@ fn it_updates_last_execute_time_with_none() -> Result<()> {
             &conn,
             sqlite::UpdateCommandQueryOptions {
                 id: command.id,
+                description: None,
                 last_execute_time: Some(OptionalValue::Null),
                 name: None,
+                pinned: None,
                 program: None,
+                shell: None,
+                tags: None,
             },
         )?;
 
@@ -165,9 +220,13 @@ fn it_does_not_update_command_when_nothing_changed() -> Result<()> {
             &conn,
             sqlite::UpdateCommandQueryOptions {
                 id: command.id,
+                description: None,
                 last_execute_time: None,
                 name: None,
+                pinned: None,
                 program: None,
+                shell: None,
+                tags: None,
             },
         )?;
 
@@ -182,3 +241,51 @@ fn it_does_not_update_command_when_nothing_changed() -> Result<()> {
         Ok(())
     })
 }
+
+#[test]
+fn it_touches_update_time_on_changes_but_not_on_execution() -> Result<()> {
+    with_context(|ctx| {
+        let UpdateCommandTestContext { conn, command } = ctx;
+
+        let command = CommandRecord {
+            id: Uuid::new_v4().into_bytes(),
+            created_at: Some(1),
+            updated_at: Some(1),
+            ..command
+        };
+
+        sqlite::insert_command(&conn, command.clone())?;
+
+        let update = |last_execute_time, name| {
+            sqlite::update_command(
+                &conn,
+                sqlite::UpdateCommandQueryOptions {
+                    id: command.id,
+                    description: None,
+                    last_execute_time,
+                    name,
+                    pinned: None,
+                    program: None,
+                    shell: None,
+                    tags: None,
+                },
+            )
+        };
+
+        update(Some(OptionalValue::Value(20)), None)?;
+
+        let executed = support::query_command(&conn, &command.id)?;
+
+        assert_eq!(executed.created_at, Some(1));
+        assert_eq!(executed.updated_at, Some(1));
+
+        update(None, Some("Spaceship".to_string()))?;
+
+        let renamed = support::query_command(&conn, &command.id)?;
+
+        assert_eq!(renamed.created_at, Some(1));
+        assert!(renamed.updated_at > Some(1));
+
+        Ok(())
+    })
+}
@@ -42,6 +42,10 @@ fn it_updates_workspace_name() -> Result<()> {
                 last_access_time: None,
                 location: None,
                 name: Some("Spaceship".to_string()),
+                parent_id: None,
+                pinned: None,
+                shell: None,
+                tags: None,
             },
         )?;
 
@@ -57,6 +61,39 @@ fn it_updates_workspace_name() -> Result<()> {
     })
 }
 
+#[test]
+fn it_updates_workspace_shell() -> Result<()> {
+    with_context(|ctx| {
+        let UpdateWorkspaceTestContext { conn, workspace } = ctx;
+
+        assert_eq!(workspace.shell, None);
+
+        let count = sqlite::update_workspace(
+            &conn,
+            UpdateWorkspaceQueryOptions {
+                id: workspace.id,
+                last_access_time: None,
+                location: None,
+                name: None,
+                parent_id: None,
+                pinned: None,
+                shell: Some(OptionalValue::Value("zsh".to_string())),
+                tags: None,
+            },
+        )?;
+
+        assert_eq!(count, 1);
+
+        let workspace = support::query_workspace(&conn, &workspace.id)?;
+
+        assert_eq!(workspace.shell.as_deref(), Some("zsh"));
+        assert_eq!(workspace.name, "Workspace 1");
+        assert_eq!(workspace.location.as_deref(), Some("Location 1"));
+
+        Ok(())
+    })
+}
+
 #[test]
 fn it_updates_workspace_location() -> Result<()> {
     with_context(|ctx| {
@@ -71,6 +108,10 @@ fn it_updates_workspace_location() -> Result<()> {
                 last_access_time: None,
                 location: Some(OptionalValue::Value("/home/ironman".to_string())),
                 name: None,
+                parent_id: None,
+                pinned: None,
+                shell: None,
+                tags: None,
             },
         )?;
 
@@ -100,6 +141,10 @@ fn it_updates_workspace_location_with_none() -> Result<()> {
                 last_access_time: None,
                 location: Some(OptionalValue::Null),
                 name: None,
+                parent_id: None,
+                pinned: None,
+                shell: None,
+                tags: None,
             },
         )?;
 
@@ -129,6 +174,10 @@ fn it_updates_last_access_time() -> Result<()> {
                 last_access_time: Some(OptionalValue::Value(20)),
                 location: None,
                 name: None,
+                parent_id: None,
+                pinned: None,
+                shell: None,
+                tags: None,
             },
         )?;
 
@@ -158,6 +207,10 @@ fn it_updates_last_access_time_with_none() -> Result<()> {
                 last_access_time: Some(OptionalValue::Null),
                 location: None,
                 name: None,
+                parent_id: None,
+                pinned: None,
+                shell: None,
+                tags: None,
             },
         )?;
 
@@ -189,6 +242,10 @@ fn it_does_not_update_workspace_when_nothing_changed() -> Result<()> {
                 last_access_time: None,
                 location: None,
                 name: None,
+                parent_id: None,
+                pinned: None,
+                shell: None,
+                tags: None,
             },
         )?;
 
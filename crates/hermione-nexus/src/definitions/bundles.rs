@@ -0,0 +1,83 @@
+use crate::{
+    definitions::{Command, Workspace},
+    Error, Result,
+};
+use eyre::eyre;
+
+/// Version of the bundle layout written by this build, older layouts can
+/// still be read.
+pub const BUNDLE_SCHEMA_VERSION: u32 = 1;
+
+/// Portable copy of workspaces and their commands, exchanged as a file.
+#[derive(Clone)]
+pub struct Bundle {
+    commands: Vec<Command>,
+    schema_version: u32,
+    workspaces: Vec<Workspace>,
+}
+
+pub struct BundleParameters {
+    pub commands: Vec<Command>,
+    pub schema_version: u32,
+    pub workspaces: Vec<Workspace>,
+}
+
+/// What happens to a bundle item whose ID is already taken locally.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum BundleConflictStrategy {
+    /// The item is stored once more under a new ID.
+    Duplicate,
+
+    /// The local item stays as it is.
+    #[default]
+    KeepLocal,
+
+    /// The local item is replaced with the bundled one.
+    Overwrite,
+}
+
+impl Bundle {
+    /// Fails for bundles written by a newer build.
+    pub fn check_schema_version(schema_version: u32) -> Result<()> {
+        if schema_version == 0 || schema_version > BUNDLE_SCHEMA_VERSION {
+            return Err(Error::invalid_argument(eyre!(
+                "Unsupported bundle schema version: {}",
+                schema_version
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    pub fn into_parts(self) -> (Vec<Workspace>, Vec<Command>) {
+        (self.workspaces, self.commands)
+    }
+
+    pub fn new(parameters: BundleParameters) -> Result<Self> {
+        let BundleParameters {
+            commands,
+            schema_version,
+            workspaces,
+        } = parameters;
+
+        Self::check_schema_version(schema_version)?;
+
+        Ok(Self {
+            commands,
+            schema_version,
+            workspaces,
+        })
+    }
+
+    pub fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    pub fn workspaces(&self) -> &[Workspace] {
+        &self.workspaces
+    }
+}
@@ -0,0 +1,166 @@
+use crate::definitions::{Command, Shell};
+
+const LAST_RUN_DATE_FORMAT: &str = "%Y-%m-%d";
+const MARKDOWN_CHARACTERS: &str = "\\`*_{}[]<>#|~&";
+const MIN_FENCE_LENGTH: usize = 3;
+const UNKNOWN_SHELL_LANGUAGE: &str = "shell";
+
+/// Markdown overview of the commands of a workspace and its descendants.
+pub struct CheatSheet {
+    sections: Vec<CheatSheetSection>,
+    title: String,
+}
+
+pub struct CheatSheetParameters {
+    pub sections: Vec<CheatSheetSection>,
+    pub title: String,
+}
+
+/// Commands of a single workspace, titled with the workspace path relative
+/// to the exported one.
+pub struct CheatSheetSection {
+    pub commands: Vec<Command>,
+    pub location: Option<String>,
+    pub shell: Option<Shell>,
+    pub title: String,
+}
+
+impl CheatSheet {
+    pub fn new(parameters: CheatSheetParameters) -> Self {
+        let CheatSheetParameters { sections, title } = parameters;
+
+        Self { sections, title }
+    }
+
+    /// Renders one heading per workspace and per command, programs go into
+    /// fenced code blocks highlighted for their shell. Names and
+    /// descriptions are escaped, so they show up as written.
+    pub fn render_markdown(&self) -> String {
+        let mut markdown = format!("# {}\n", heading(&self.title));
+
+        for section in &self.sections {
+            markdown.push_str(&format!("\n## {}\n", heading(&section.title)));
+
+            if let Some(location) = &section.location {
+                markdown.push_str(&format!("\nLocation: {}\n", inline_code(location)));
+            }
+
+            if section.commands.is_empty() {
+                markdown.push_str("\n_No commands_\n");
+            }
+
+            for command in &section.commands {
+                render_command(&mut markdown, command, section.shell);
+            }
+        }
+
+        markdown
+    }
+
+    pub fn sections(&self) -> &[CheatSheetSection] {
+        &self.sections
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+}
+
+fn render_command(markdown: &mut String, command: &Command, workspace_shell: Option<Shell>) {
+    markdown.push_str(&format!("\n### {}\n", heading(command.name())));
+
+    if let Some(description) = command.description() {
+        let description = description
+            .trim()
+            .lines()
+            .map(escape_line)
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        markdown.push_str(&format!("\n{}\n", description));
+    }
+
+    // A fence longer than any backtick run of the program keeps the block
+    // intact.
+    let fence = "`".repeat(MIN_FENCE_LENGTH.max(longest_backtick_run(command.program()) + 1));
+    let language = command
+        .shell()
+        .or(workspace_shell)
+        .map(shell_language)
+        .unwrap_or(UNKNOWN_SHELL_LANGUAGE);
+
+    markdown.push_str(&format!(
+        "\n{}{}\n{}\n{}\n",
+        fence,
+        language,
+        command.program(),
+        fence
+    ));
+
+    let last_run = command
+        .last_execute_time()
+        .map(|time| time.format(LAST_RUN_DATE_FORMAT).to_string())
+        .unwrap_or_else(|| "never".to_string());
+
+    markdown.push_str(&format!("\nLast run: {}\n", last_run));
+}
+
+/// Backslash escapes inline formatting everywhere and block markers at the
+/// start of the line. Leading whitespace is dropped, it would indent the
+/// line into a code block.
+fn escape_line(line: &str) -> String {
+    let line = line.trim_start();
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let mut escaped = String::with_capacity(line.len());
+
+    for (index, c) in line.chars().enumerate() {
+        let is_special = match c {
+            '-' | '+' | '=' => index == 0,
+            '.' | ')' => digits > 0 && index == digits,
+            _ => MARKDOWN_CHARACTERS.contains(c),
+        };
+
+        if is_special {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Headings end at the line break, so the text is collapsed to one line.
+fn heading(text: &str) -> String {
+    escape_line(&text.split_whitespace().collect::<Vec<&str>>().join(" "))
+}
+
+/// A delimiter longer than any backtick run of the text keeps the span
+/// intact, padding keeps backticks at its edges apart from the delimiter.
+fn inline_code(text: &str) -> String {
+    let delimiter = "`".repeat(longest_backtick_run(text) + 1);
+
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{} {} {}", delimiter, text, delimiter)
+    } else {
+        format!("{}{}{}", delimiter, text, delimiter)
+    }
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default()
+}
+
+fn shell_language(shell: Shell) -> &'static str {
+    match shell {
+        Shell::Bash => "bash",
+        Shell::Cmd => "batch",
+        Shell::Fish => "fish",
+        Shell::Pwsh => "powershell",
+        Shell::Sh => "sh",
+        Shell::Zsh => "zsh",
+    }
+}
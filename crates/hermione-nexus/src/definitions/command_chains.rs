@@ -0,0 +1,115 @@
+use crate::{
+    definitions::{CommandId, WorkspaceId},
+    Error, Result,
+};
+use eyre::eyre;
+use std::fmt::{self, Debug, Display, Formatter};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct CommandChain {
+    id: CommandChainId,
+    name: CommandChainName,
+    steps: Vec<CommandId>,
+    workspace_id: WorkspaceId,
+}
+
+pub struct CommandChainParameters {
+    pub id: Uuid,
+    pub name: String,
+    pub steps: Vec<CommandId>,
+    pub workspace_id: WorkspaceId,
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct CommandChainId(Uuid);
+
+#[derive(Clone)]
+struct CommandChainName {
+    value: String,
+}
+
+impl CommandChain {
+    pub fn id(&self) -> CommandChainId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name.value
+    }
+
+    pub fn new(parameters: CommandChainParameters) -> Result<Self> {
+        let CommandChainParameters {
+            id,
+            name,
+            steps,
+            workspace_id,
+        } = parameters;
+
+        if steps.is_empty() {
+            return Err(Error::invalid_argument(eyre!(
+                "Command chain must have at least one step"
+            )));
+        }
+
+        Ok(Self {
+            id: CommandChainId::new(id)?,
+            name: CommandChainName { value: name },
+            steps,
+            workspace_id,
+        })
+    }
+
+    pub fn steps(&self) -> &[CommandId] {
+        &self.steps
+    }
+
+    pub fn workspace_id(&self) -> WorkspaceId {
+        self.workspace_id
+    }
+}
+
+impl CommandChainId {
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        self.0.as_bytes()
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+
+    pub fn into_bytes(self) -> [u8; 16] {
+        self.0.into_bytes()
+    }
+
+    pub fn new(id: Uuid) -> Result<Self> {
+        if id.is_nil() {
+            return Err(Error::invalid_argument(eyre!(
+                "Command chain ID cannot be nil"
+            )));
+        }
+
+        Ok(Self(id))
+    }
+
+    pub fn parse_str(value: &str) -> Result<Self> {
+        let id = Uuid::parse_str(value).map_err(|err| {
+            let err = eyre::Error::new(err).wrap_err("Invalid command chain ID representation");
+            Error::invalid_argument(err)
+        })?;
+
+        Self::new(id)
+    }
+}
+
+impl Debug for CommandChainId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "command_chain:{}", self.0)
+    }
+}
+
+impl Display for CommandChainId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
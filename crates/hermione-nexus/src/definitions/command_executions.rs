@@ -0,0 +1,63 @@
+use crate::definitions::{CommandId, WorkspaceId};
+use chrono::{DateTime, Utc};
+
+/// Commands run detached in a new terminal window, so an execution records
+/// its start only and not its outcome.
+#[derive(Clone)]
+pub struct CommandExecution {
+    command_id: Option<CommandId>,
+    location: Option<String>,
+    program: String,
+    start_time: DateTime<Utc>,
+    workspace_id: Option<WorkspaceId>,
+}
+
+pub struct CommandExecutionParameters {
+    pub command_id: Option<CommandId>,
+    pub location: Option<String>,
+    pub program: String,
+    pub start_time: DateTime<Utc>,
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+impl CommandExecution {
+    /// Missing once the command is deleted, the execution itself stays in
+    /// the history.
+    pub fn command_id(&self) -> Option<CommandId> {
+        self.command_id
+    }
+
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    pub fn new(parameters: CommandExecutionParameters) -> Self {
+        let CommandExecutionParameters {
+            command_id,
+            location,
+            program,
+            start_time,
+            workspace_id,
+        } = parameters;
+
+        Self {
+            command_id,
+            location,
+            program,
+            start_time,
+            workspace_id,
+        }
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    pub fn start_time(&self) -> &DateTime<Utc> {
+        &self.start_time
+    }
+
+    pub fn workspace_id(&self) -> Option<WorkspaceId> {
+        self.workspace_id
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{definitions::CommandId, Error, Result};
+use chrono::{DateTime, Utc};
+use eyre::eyre;
+use std::fmt::{self, Debug, Display, Formatter};
+use uuid::Uuid;
+
+/// Name and program a command had before one of its edits.
+#[derive(Clone)]
+pub struct CommandRevision {
+    id: CommandRevisionId,
+    command_id: CommandId,
+    created_at: DateTime<Utc>,
+    name: String,
+    program: String,
+}
+
+pub struct CommandRevisionParameters {
+    pub id: Uuid,
+    pub command_id: CommandId,
+    pub created_at: DateTime<Utc>,
+    pub name: String,
+    pub program: String,
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct CommandRevisionId(Uuid);
+
+/// Word level difference between two versions of a command.
+pub struct CommandRevisionDiff {
+    name: Vec<DiffChange>,
+    program: Vec<DiffChange>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DiffChange {
+    Added(String),
+    Removed(String),
+    Unchanged(String),
+}
+
+impl CommandRevision {
+    pub fn command_id(&self) -> CommandId {
+        self.command_id
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn id(&self) -> CommandRevisionId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn new(parameters: CommandRevisionParameters) -> Result<Self> {
+        let CommandRevisionParameters {
+            id,
+            command_id,
+            created_at,
+            name,
+            program,
+        } = parameters;
+
+        Ok(Self {
+            id: CommandRevisionId::new(id)?,
+            command_id,
+            created_at,
+            name,
+            program,
+        })
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+}
+
+impl CommandRevisionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.name
+            .iter()
+            .chain(&self.program)
+            .all(|change| matches!(change, DiffChange::Unchanged(_)))
+    }
+
+    pub fn name(&self) -> &[DiffChange] {
+        &self.name
+    }
+
+    pub(crate) fn new(
+        old_name: &str,
+        old_program: &str,
+        new_name: &str,
+        new_program: &str,
+    ) -> Self {
+        Self {
+            name: diff_words(old_name, new_name),
+            program: diff_words(old_program, new_program),
+        }
+    }
+
+    pub fn program(&self) -> &[DiffChange] {
+        &self.program
+    }
+}
+
+impl DiffChange {
+    pub fn text(&self) -> &str {
+        match self {
+            DiffChange::Added(text) | DiffChange::Removed(text) | DiffChange::Unchanged(text) => {
+                text
+            }
+        }
+    }
+}
+
+impl CommandRevisionId {
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        self.0.as_bytes()
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+
+    pub fn into_bytes(self) -> [u8; 16] {
+        self.0.into_bytes()
+    }
+
+    pub fn new(id: Uuid) -> Result<Self> {
+        if id.is_nil() {
+            return Err(Error::invalid_argument(eyre!(
+                "Command revision ID cannot be nil"
+            )));
+        }
+
+        Ok(Self(id))
+    }
+
+    pub fn parse_str(value: &str) -> Result<Self> {
+        let id = Uuid::parse_str(value).map_err(|err| {
+            let err = eyre::Error::new(err).wrap_err("Invalid command revision ID representation");
+            Error::invalid_argument(err)
+        })?;
+
+        Self::new(id)
+    }
+}
+
+impl Debug for CommandRevisionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "command_revision:{}", self.0)
+    }
+}
+
+impl Display for CommandRevisionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Diffs whitespace separated words using their longest common subsequence.
+/// Neighbouring words of the same kind are joined into a single change, so
+/// a tweaked flag in a long one-liner shows up as one removal and one
+/// addition.
+fn diff_words(old: &str, new: &str) -> Vec<DiffChange> {
+    let old: Vec<&str> = old.split_whitespace().collect();
+    let new: Vec<&str> = new.split_whitespace().collect();
+
+    let mut changes: Vec<DiffChange> = Vec::new();
+
+    diff_slices(&old, &new, &mut changes);
+
+    changes
+}
+
+/// Hirschberg's divide and conquer variant of the longest common subsequence
+/// diff. It only keeps two rows of subsequence lengths at a time, so memory
+/// stays linear even for programs with tens of thousands of words.
+fn diff_slices(old: &[&str], new: &[&str], changes: &mut Vec<DiffChange>) {
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    for word in &old[..prefix] {
+        push_change(changes, DiffChange::Unchanged(word.to_string()));
+    }
+
+    let (old, new) = (&old[prefix..], &new[prefix..]);
+
+    let suffix = old
+        .iter()
+        .rev()
+        .zip(new.iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let (middle_old, middle_new) = (&old[..old.len() - suffix], &new[..new.len() - suffix]);
+
+    if middle_old.is_empty() || middle_new.is_empty() {
+        for word in middle_old {
+            push_change(changes, DiffChange::Removed(word.to_string()));
+        }
+
+        for word in middle_new {
+            push_change(changes, DiffChange::Added(word.to_string()));
+        }
+    } else if let [word] = middle_old {
+        match middle_new.iter().position(|new| new == word) {
+            Some(position) => {
+                for word in &middle_new[..position] {
+                    push_change(changes, DiffChange::Added(word.to_string()));
+                }
+
+                push_change(changes, DiffChange::Unchanged(word.to_string()));
+
+                for word in &middle_new[position + 1..] {
+                    push_change(changes, DiffChange::Added(word.to_string()));
+                }
+            }
+            None => {
+                push_change(changes, DiffChange::Removed(word.to_string()));
+
+                for word in middle_new {
+                    push_change(changes, DiffChange::Added(word.to_string()));
+                }
+            }
+        }
+    } else {
+        let middle = middle_old.len() / 2;
+        let (old_head, old_tail) = middle_old.split_at(middle);
+
+        let forward = subsequence_lengths(old_head.iter(), middle_new.iter());
+        let backward = subsequence_lengths(old_tail.iter().rev(), middle_new.iter().rev());
+
+        let split = (0..=middle_new.len())
+            .max_by_key(|&index| {
+                (
+                    forward[index] + backward[middle_new.len() - index],
+                    std::cmp::Reverse(index),
+                )
+            })
+            .unwrap_or_default();
+
+        diff_slices(old_head, &middle_new[..split], changes);
+        diff_slices(old_tail, &middle_new[split..], changes);
+    }
+
+    for word in &old[old.len() - suffix..] {
+        push_change(changes, DiffChange::Unchanged(word.to_string()));
+    }
+}
+
+/// Lengths of the longest common subsequence of `old` and every prefix of
+/// `new`.
+fn subsequence_lengths<'a>(
+    old: impl Iterator<Item = &'a &'a str>,
+    new: impl Iterator<Item = &'a &'a str> + Clone,
+) -> Vec<usize> {
+    let mut previous = vec![0; new.clone().count() + 1];
+    let mut current = previous.clone();
+
+    for old_word in old {
+        for (index, new_word) in new.clone().enumerate() {
+            current[index + 1] = if old_word == new_word {
+                previous[index] + 1
+            } else {
+                current[index].max(previous[index + 1])
+            };
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous
+}
+
+fn push_change(changes: &mut Vec<DiffChange>, change: DiffChange) {
+    let last = changes.last_mut();
+
+    match (last, change) {
+        (Some(DiffChange::Added(text)), DiffChange::Added(word))
+        | (Some(DiffChange::Removed(text)), DiffChange::Removed(word))
+        | (Some(DiffChange::Unchanged(text)), DiffChange::Unchanged(word)) => {
+            text.push(' ');
+            text.push_str(&word);
+        }
+        (_, change) => changes.push(change),
+    }
+}
@@ -1,29 +1,76 @@
-use crate::{definitions::WorkspaceId, Error, Result};
+use super::{tags, templates::ProgramTemplate, validation};
+use crate::{
+    definitions::{Shell, UndoRecord, WorkspaceId},
+    Error, Result,
+};
 use chrono::{DateTime, Utc};
 use eyre::eyre;
-use std::fmt::{self, Debug, Display, Formatter};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Display, Formatter},
+};
 use uuid::Uuid;
 
+const COMMAND_ENTITY: &str = "Command";
+const PROJECT_TASKS_MANAGER: &str = "project_tasks";
+const REPOSITORY_MANAGER: &str = "repository";
+
 #[derive(Clone)]
 pub struct Command {
     id: CommandId,
+    created_at: Option<DateTime<Utc>>,
+    description: Option<CommandDescription>,
     last_execute_time: Option<DateTime<Utc>>,
+    managed_by: Option<CommandManager>,
     name: CommandName,
+    pinned: bool,
     program: CommandProgram,
-    workspace_id: WorkspaceId,
+    shell: Option<Shell>,
+    tags: Vec<String>,
+    updated_at: Option<DateTime<Utc>>,
+    workspace_id: Option<WorkspaceId>,
 }
 
 pub struct CommandParameters {
     pub id: Uuid,
+    pub created_at: Option<DateTime<Utc>>,
+    pub description: Option<String>,
     pub last_execute_time: Option<DateTime<Utc>>,
+    pub managed_by: Option<CommandManager>,
     pub name: String,
+    pub pinned: bool,
     pub program: String,
-    pub workspace_id: WorkspaceId,
+    pub shell: Option<Shell>,
+    pub tags: Vec<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub workspace_id: Option<WorkspaceId>,
 }
 
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
 pub struct CommandId(Uuid);
 
+/// Synchronization that created a command and keeps it up to date. Only
+/// the synchronizations create such commands, so unlike tags the marker
+/// cannot be set by hand.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommandManager {
+    ProjectTasks,
+    Repository,
+}
+
+/// Changes made by a synchronization, updated and removed commands are
+/// given as they were before it.
+pub struct CommandsSync {
+    pub created: Vec<CommandId>,
+    pub updated: Vec<Command>,
+    pub removed: Vec<Command>,
+}
+
+#[derive(Clone)]
+struct CommandDescription {
+    value: String,
+}
+
 #[derive(Clone)]
 struct CommandProgram {
     value: String,
@@ -35,6 +82,15 @@ struct CommandName {
 }
 
 impl Command {
+    /// Unknown for commands that come from outside of the storage, such as backups.
+    pub fn created_at(&self) -> Option<&DateTime<Utc>> {
+        self.created_at.as_ref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_ref().map(|d| d.value.as_str())
+    }
+
     pub fn id(&self) -> CommandId {
         self.id
     }
@@ -43,49 +99,228 @@ impl Command {
         self.last_execute_time.as_ref()
     }
 
-    pub fn name(&self) -> &str {
-        &self.name.value
+    pub fn is_global(&self) -> bool {
+        self.workspace_id.is_none()
     }
 
-    pub fn new(parameters: CommandParameters) -> Result<Self> {
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// Declared in the repository command file of its workspace, such
+    /// commands are not edited locally.
+    pub fn is_repository_managed(&self) -> bool {
+        self.managed_by == Some(CommandManager::Repository)
+    }
+
+    pub fn managed_by(&self) -> Option<CommandManager> {
+        self.managed_by
+    }
+
+    /// Builds a command from stored fields without checking its name and
+    /// program, so records saved before the current validation rules still
+    /// load.
+    pub fn load(parameters: CommandParameters) -> Result<Self> {
         let CommandParameters {
             id,
+            created_at,
+            description,
             last_execute_time,
+            managed_by,
             program,
             name,
+            pinned,
+            shell,
+            tags,
+            updated_at,
             workspace_id,
         } = parameters;
 
-        Ok(Self {
+        let mut command = Self {
             id: CommandId::new(id)?,
+            created_at,
+            description: None,
             last_execute_time,
+            managed_by,
             program: CommandProgram { value: program },
             name: CommandName { value: name },
+            pinned,
+            shell,
+            tags: tags::normalize_tags(tags),
+            updated_at,
             workspace_id,
-        })
+        };
+
+        command.set_description(description);
+
+        Ok(command)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name.value
+    }
+
+    pub fn new(parameters: CommandParameters) -> Result<Self> {
+        validate_command(&parameters.name, &parameters.program)?;
+
+        Self::load(parameters)
+    }
+
+    pub fn pin(&mut self) {
+        self.pinned = true;
+    }
+
+    pub fn placeholders(&self) -> Vec<&str> {
+        ProgramTemplate::parse(self.program()).placeholders()
     }
 
     pub fn program(&self) -> &str {
         &self.program.value
     }
 
+    /// Keeps `{{secret:name}}` references in place, so the rendered program
+    /// can be recorded in the execution history.
+    pub fn render_program(&self, placeholder_values: &HashMap<String, String>) -> Result<String> {
+        ProgramTemplate::parse(self.program()).render(placeholder_values)
+    }
+
+    pub fn render_program_with_secrets(
+        &self,
+        placeholder_values: &HashMap<String, String>,
+        secret_values: &HashMap<String, String>,
+    ) -> Result<String> {
+        ProgramTemplate::parse(self.program())
+            .render_with_secrets(placeholder_values, secret_values)
+    }
+
+    /// Names of the secrets the program references, in order of appearance.
+    pub fn secrets(&self) -> Vec<&str> {
+        ProgramTemplate::parse(self.program()).secrets()
+    }
+
+    pub fn set_description(&mut self, description: Option<String>) {
+        let description = description.unwrap_or_default();
+
+        if description.trim().is_empty() {
+            self.description = None;
+        } else {
+            self.description = Some(CommandDescription { value: description });
+        }
+    }
+
     pub fn set_execute_time(&mut self, time: DateTime<Utc>) {
         self.last_execute_time = Some(time);
     }
 
-    pub fn set_program(&mut self, program: String) {
-        self.program = CommandProgram { value: program };
+    pub fn set_program(&mut self, program: String) -> Result<()> {
+        self.program = CommandProgram::new(program)?;
+
+        Ok(())
+    }
+
+    pub fn set_name(&mut self, name: String) -> Result<()> {
+        self.name = CommandName::new(name)?;
+
+        Ok(())
+    }
+
+    pub fn set_shell(&mut self, shell: Option<Shell>) {
+        self.shell = shell;
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags = tags::normalize_tags(tags);
+    }
+
+    pub fn set_update_time(&mut self, time: DateTime<Utc>) {
+        self.updated_at = Some(time);
+    }
+
+    pub fn set_workspace_id(&mut self, workspace_id: Option<WorkspaceId>) {
+        self.workspace_id = workspace_id;
     }
 
-    pub fn set_name(&mut self, name: String) {
-        self.name = CommandName { value: name };
+    pub fn shell(&self) -> Option<Shell> {
+        self.shell
     }
 
-    pub fn workspace_id(&self) -> WorkspaceId {
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn unpin(&mut self) {
+        self.pinned = false;
+    }
+
+    pub fn updated_at(&self) -> Option<&DateTime<Utc>> {
+        self.updated_at.as_ref()
+    }
+
+    pub fn workspace_id(&self) -> Option<WorkspaceId> {
         self.workspace_id
     }
 }
 
+/// Checks command fields before they are handed to storage.
+pub(crate) fn validate_command(name: &str, program: &str) -> Result<()> {
+    validation::validate_name(COMMAND_ENTITY, name)?;
+    validation::validate_program(COMMAND_ENTITY, program)
+}
+
+impl CommandManager {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommandManager::ProjectTasks => PROJECT_TASKS_MANAGER,
+            CommandManager::Repository => REPOSITORY_MANAGER,
+        }
+    }
+
+    pub fn parse_str(value: &str) -> Result<Self> {
+        match value {
+            PROJECT_TASKS_MANAGER => Ok(CommandManager::ProjectTasks),
+            REPOSITORY_MANAGER => Ok(CommandManager::Repository),
+            _ => Err(Error::invalid_argument(eyre!(
+                "Unsupported command manager: {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl CommandsSync {
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+
+    pub(crate) fn undo_record(&self) -> UndoRecord {
+        UndoRecord::CommandsImported {
+            created: self.created.clone(),
+            previous: self
+                .updated
+                .iter()
+                .chain(self.removed.iter())
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl CommandName {
+    fn new(value: String) -> Result<Self> {
+        validation::validate_name(COMMAND_ENTITY, &value)?;
+
+        Ok(Self { value })
+    }
+}
+
+impl CommandProgram {
+    fn new(value: String) -> Result<Self> {
+        validation::validate_program(COMMAND_ENTITY, &value)?;
+
+        Ok(Self { value })
+    }
+}
+
 impl CommandId {
     pub fn as_bytes(&self) -> &[u8; 16] {
         self.0.as_bytes()
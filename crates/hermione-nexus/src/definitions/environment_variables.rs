@@ -0,0 +1,49 @@
+use crate::{Error, Result};
+use eyre::eyre;
+
+#[derive(Clone)]
+pub struct EnvironmentVariable {
+    name: String,
+    value: String,
+}
+
+pub struct EnvironmentVariableParameters {
+    pub name: String,
+    pub value: String,
+}
+
+impl EnvironmentVariable {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn new(parameters: EnvironmentVariableParameters) -> Result<Self> {
+        let EnvironmentVariableParameters { name, value } = parameters;
+
+        let name = name.trim().to_string();
+
+        if !is_environment_variable_name(&name) {
+            return Err(Error::invalid_argument(eyre!(
+                "Invalid environment variable name: {}",
+                name
+            )));
+        }
+
+        Ok(Self { name, value })
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+fn is_environment_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    let Some(first) = chars.next() else {
+        return false;
+    };
+
+    (first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -1,7 +1,41 @@
 mod backup;
+mod bundles;
+mod cheat_sheets;
+mod command_chains;
+mod command_executions;
+mod command_revisions;
 mod commands;
+mod environment_variables;
+mod project_tasks;
+mod repository_commands;
+mod secrets;
+mod shell_history;
+mod shells;
+mod tags;
+mod templates;
+mod trash;
+mod undo;
+mod validation;
 mod workspaces;
 
 pub use backup::*;
+pub use bundles::*;
+pub use cheat_sheets::*;
+pub use command_chains::*;
+pub use command_executions::*;
+pub use command_revisions::*;
 pub use commands::*;
+pub use environment_variables::*;
+pub use project_tasks::*;
+pub use repository_commands::*;
+pub use secrets::*;
+pub use shell_history::*;
+pub use shells::*;
+pub use trash::*;
+pub use undo::*;
+pub use validation::{LOCATION_FIELD, NAME_FIELD, PROGRAM_FIELD};
 pub use workspaces::*;
+
+pub(crate) use commands::validate_command;
+pub(crate) use tags::normalize_tags;
+pub(crate) use workspaces::validate_workspace;
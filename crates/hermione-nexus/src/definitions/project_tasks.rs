@@ -0,0 +1,82 @@
+/// Tag carried by commands created from project tasks. It only labels them,
+/// the synchronization goes by `CommandManager::ProjectTasks`.
+pub const PROJECT_TASK_TAG: &str = "task";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProjectTaskSource {
+    /// `[alias]` table of `.cargo/config.toml`.
+    CargoAlias,
+
+    /// Recipes of a `justfile`.
+    Justfile,
+
+    /// Explicit targets of a `Makefile`.
+    Makefile,
+
+    /// `scripts` of `package.json`.
+    NpmScript,
+
+    /// Tasks of `.vscode/tasks.json`.
+    VsCodeTask,
+}
+
+/// Task defined by one of the well-known project files at a workspace
+/// location.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectTask {
+    name: String,
+    program: String,
+    source: ProjectTaskSource,
+}
+
+pub struct ProjectTaskParameters {
+    pub name: String,
+    pub program: String,
+    pub source: ProjectTaskSource,
+}
+
+impl ProjectTask {
+    /// Name of the command the task is imported as, prefixed with the tool
+    /// so equally named tasks of different files do not clash.
+    pub fn command_name(&self) -> String {
+        format!("{} {}", self.source.as_str(), self.name)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn new(parameters: ProjectTaskParameters) -> Self {
+        let ProjectTaskParameters {
+            name,
+            program,
+            source,
+        } = parameters;
+
+        Self {
+            name: name.trim().to_string(),
+            program: program.trim().to_string(),
+            source,
+        }
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    pub fn source(&self) -> ProjectTaskSource {
+        self.source
+    }
+}
+
+impl ProjectTaskSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectTaskSource::CargoAlias => "cargo",
+            ProjectTaskSource::Justfile => "just",
+            ProjectTaskSource::Makefile => "make",
+            ProjectTaskSource::NpmScript => "npm",
+            ProjectTaskSource::VsCodeTask => "vscode",
+        }
+    }
+}
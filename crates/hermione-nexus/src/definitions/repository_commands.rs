@@ -0,0 +1,76 @@
+use super::tags;
+use crate::definitions::Shell;
+
+/// File a repository declares its shared commands in, looked up at the
+/// workspace location.
+pub const REPOSITORY_COMMANDS_FILE: &str = ".hermione.toml";
+
+/// Tag carried by commands declared in the repository command file. It only
+/// labels them, the synchronization goes by `CommandManager::Repository`.
+pub const REPOSITORY_COMMAND_TAG: &str = "repo";
+
+/// Command declared in the repository command file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RepositoryCommand {
+    description: Option<String>,
+    name: String,
+    program: String,
+    shell: Option<Shell>,
+    tags: Vec<String>,
+}
+
+pub struct RepositoryCommandParameters {
+    pub description: Option<String>,
+    pub name: String,
+    pub program: String,
+    pub shell: Option<Shell>,
+    pub tags: Vec<String>,
+}
+
+impl RepositoryCommand {
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn new(parameters: RepositoryCommandParameters) -> Self {
+        let RepositoryCommandParameters {
+            description,
+            name,
+            program,
+            shell,
+            tags,
+        } = parameters;
+
+        Self {
+            description: description
+                .map(|description| description.trim().to_string())
+                .filter(|description| !description.is_empty()),
+            name: name.trim().to_string(),
+            program: program.trim().to_string(),
+            shell,
+            tags: tags::normalize_tags(
+                tags.into_iter()
+                    .chain(std::iter::once(REPOSITORY_COMMAND_TAG.to_string()))
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    pub fn shell(&self) -> Option<Shell> {
+        self.shell
+    }
+
+    /// Declared tags together with the repository command tag, normalized
+    /// the way command tags are.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+}
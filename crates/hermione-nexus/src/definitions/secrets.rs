@@ -0,0 +1,57 @@
+use super::templates;
+use crate::{Error, Result};
+use eyre::eyre;
+use std::fmt::{self, Debug, Formatter};
+
+#[derive(Clone)]
+pub struct Secret {
+    name: String,
+    value: String,
+}
+
+pub struct SecretParameters {
+    pub name: String,
+    pub value: String,
+}
+
+impl Secret {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Secret names follow the placeholder naming rules, so every stored
+    /// secret can be referenced as `{{secret:name}}` from a program.
+    pub fn new(parameters: SecretParameters) -> Result<Self> {
+        let SecretParameters { name, value } = parameters;
+
+        let name = name.trim().to_string();
+
+        if !templates::is_placeholder_name(&name) {
+            return Err(Error::invalid_argument(eyre!(
+                "Invalid secret name: {}",
+                name
+            )));
+        }
+
+        if value.is_empty() {
+            return Err(Error::invalid_argument(eyre!(
+                "Secret {} cannot be empty",
+                name
+            )));
+        }
+
+        Ok(Self { name, value })
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Secret")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
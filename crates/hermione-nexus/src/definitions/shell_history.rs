@@ -0,0 +1,175 @@
+const BASH_TIMESTAMP_PREFIX: char = '#';
+const FISH_COMMAND_PREFIX: &str = "- cmd: ";
+const POWERSHELL_LINE_CONTINUATION: char = '`';
+const ZSH_EXTENDED_PREFIX: &str = ": ";
+const ZSH_LINE_CONTINUATION: char = '\\';
+const ZSH_META: u8 = 0x83;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShellHistoryFormat {
+    /// One command per line, optionally preceded by `#<timestamp>` lines.
+    Bash,
+
+    /// `- cmd: ...` entries of the fish history file.
+    Fish,
+
+    /// PSReadLine `ConsoleHost_history.txt`, lines ending with a backtick
+    /// continue on the next one.
+    PowerShell,
+
+    /// Plain or extended (`: <start>:<duration>;command`) zsh history.
+    Zsh,
+}
+
+/// Program found in shell history together with how many times it was run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryCommand {
+    count: u32,
+    program: String,
+}
+
+impl HistoryCommand {
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub(crate) fn new(program: String, count: u32) -> Self {
+        Self { count, program }
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+}
+
+impl ShellHistoryFormat {
+    /// Splits raw history file content into programs in the order they
+    /// were run. Blank entries are dropped.
+    pub(crate) fn parse(&self, content: &[u8]) -> Vec<String> {
+        let programs = match self {
+            ShellHistoryFormat::Bash => parse_bash(&String::from_utf8_lossy(content)),
+            ShellHistoryFormat::Fish => parse_fish(&String::from_utf8_lossy(content)),
+            ShellHistoryFormat::PowerShell => parse_powershell(&String::from_utf8_lossy(content)),
+            ShellHistoryFormat::Zsh => parse_zsh(&String::from_utf8_lossy(&unmetafy(content))),
+        };
+
+        programs
+            .into_iter()
+            .map(|program| program.trim().to_string())
+            .filter(|program| !program.is_empty())
+            .collect()
+    }
+}
+
+fn parse_bash(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter(|line| !is_bash_timestamp(line))
+        .map(ToString::to_string)
+        .collect()
+}
+
+fn parse_fish(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| line.strip_prefix(FISH_COMMAND_PREFIX))
+        .map(unescape_fish)
+        .collect()
+}
+
+fn parse_powershell(content: &str) -> Vec<String> {
+    join_continued_lines(content, POWERSHELL_LINE_CONTINUATION)
+}
+
+fn parse_zsh(content: &str) -> Vec<String> {
+    join_continued_lines(content, ZSH_LINE_CONTINUATION)
+        .into_iter()
+        .map(|entry| match entry.strip_prefix(ZSH_EXTENDED_PREFIX) {
+            Some(extended) => extended
+                .split_once(';')
+                .map(|(_timestamps, program)| program.to_string())
+                .unwrap_or(entry),
+            None => entry,
+        })
+        .collect()
+}
+
+fn is_bash_timestamp(line: &str) -> bool {
+    line.strip_prefix(BASH_TIMESTAMP_PREFIX)
+        .is_some_and(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Lines ending with `continuation` are joined with the next one, keeping
+/// the line break so multi-line programs stay intact.
+fn join_continued_lines(content: &str, continuation: char) -> Vec<String> {
+    let mut entries = Vec::new();
+    let mut current: Option<String> = None;
+
+    for line in content.lines() {
+        let (text, continued) = match line.strip_suffix(continuation) {
+            Some(text) => (text, true),
+            None => (line, false),
+        };
+
+        let entry = match current.take() {
+            Some(mut entry) => {
+                entry.push('\n');
+                entry.push_str(text);
+                entry
+            }
+            None => text.to_string(),
+        };
+
+        if continued {
+            current = Some(entry);
+        } else {
+            entries.push(entry);
+        }
+    }
+
+    entries.extend(current);
+    entries
+}
+
+fn unescape_fish(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('\\') => result.push('\\'),
+            Some(other) => {
+                result.push('\\');
+                result.push(other);
+            }
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+/// zsh stores bytes of non-ASCII characters behind a meta marker with the
+/// sixth bit flipped.
+fn unmetafy(content: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(content.len());
+    let mut bytes = content.iter();
+
+    while let Some(&byte) = bytes.next() {
+        if byte == ZSH_META {
+            if let Some(&next) = bytes.next() {
+                result.push(next ^ 0x20);
+            }
+        } else {
+            result.push(byte);
+        }
+    }
+
+    result
+}
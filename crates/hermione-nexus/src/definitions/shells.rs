@@ -0,0 +1,52 @@
+use crate::{Error, Result};
+use eyre::eyre;
+
+const BASH: &str = "bash";
+const CMD: &str = "cmd";
+const FISH: &str = "fish";
+const PWSH: &str = "pwsh";
+const SH: &str = "sh";
+const ZSH: &str = "zsh";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Shell {
+    Bash,
+    Cmd,
+    Fish,
+    Pwsh,
+    Sh,
+    Zsh,
+}
+
+impl Shell {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Shell::Bash => BASH,
+            Shell::Cmd => CMD,
+            Shell::Fish => FISH,
+            Shell::Pwsh => PWSH,
+            Shell::Sh => SH,
+            Shell::Zsh => ZSH,
+        }
+    }
+
+    pub fn parse_str(value: &str) -> Result<Self> {
+        let shell = match value.trim().to_lowercase().as_str() {
+            BASH => Shell::Bash,
+            CMD => Shell::Cmd,
+            FISH => Shell::Fish,
+            PWSH => Shell::Pwsh,
+            SH => Shell::Sh,
+            ZSH => Shell::Zsh,
+            _ => {
+                return Err(Error::invalid_argument(eyre!(
+                    "Unsupported shell: {}. Expected one of: {}",
+                    value,
+                    [BASH, CMD, FISH, PWSH, SH, ZSH].join(", ")
+                )))
+            }
+        };
+
+        Ok(shell)
+    }
+}
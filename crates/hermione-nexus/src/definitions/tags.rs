@@ -0,0 +1,13 @@
+pub(crate) fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut tags: Vec<String> = tags
+        .iter()
+        .flat_map(|tag| tag.split(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|tag| !tag.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    tags.sort();
+    tags.dedup();
+
+    tags
+}
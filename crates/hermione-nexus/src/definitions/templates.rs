@@ -0,0 +1,142 @@
+use crate::{Error, Result};
+use eyre::eyre;
+use std::collections::HashMap;
+
+const PLACEHOLDER_END: &str = "}}";
+const PLACEHOLDER_START: &str = "{{";
+const SECRET_PREFIX: &str = "secret:";
+
+pub(crate) struct ProgramTemplate<'a> {
+    segments: Vec<Segment<'a>>,
+}
+
+enum Segment<'a> {
+    Placeholder(&'a str),
+    Secret(&'a str),
+    Text(&'a str),
+}
+
+impl<'a> ProgramTemplate<'a> {
+    pub(crate) fn parse(program: &'a str) -> Self {
+        let mut segments = Vec::new();
+        let mut rest = program;
+
+        while let Some(start) = rest.find(PLACEHOLDER_START) {
+            let after_start = &rest[start + PLACEHOLDER_START.len()..];
+
+            let Some(end) = after_start.find(PLACEHOLDER_END) else {
+                break;
+            };
+
+            let name = after_start[..end].trim();
+            let secret = name.strip_prefix(SECRET_PREFIX).map(str::trim);
+
+            if let Some(secret) = secret.filter(|secret| is_placeholder_name(secret)) {
+                segments.push(Segment::Text(&rest[..start]));
+                segments.push(Segment::Secret(secret));
+            } else if is_placeholder_name(name) {
+                segments.push(Segment::Text(&rest[..start]));
+                segments.push(Segment::Placeholder(name));
+            } else {
+                segments.push(Segment::Text(
+                    &rest[..start + PLACEHOLDER_START.len() + end + PLACEHOLDER_END.len()],
+                ));
+            }
+
+            rest = &after_start[end + PLACEHOLDER_END.len()..];
+        }
+
+        segments.push(Segment::Text(rest));
+
+        Self { segments }
+    }
+
+    pub(crate) fn placeholders(&self) -> Vec<&'a str> {
+        let mut placeholders: Vec<&str> = Vec::new();
+
+        for segment in &self.segments {
+            if let Segment::Placeholder(name) = segment {
+                if !placeholders.contains(name) {
+                    placeholders.push(name);
+                }
+            }
+        }
+
+        placeholders
+    }
+
+    /// Renders placeholders only. Secret references are written back as they
+    /// are, so the result is safe to store, log and show.
+    pub(crate) fn render(&self, values: &HashMap<String, String>) -> Result<String> {
+        self.render_segments(values, None)
+    }
+
+    /// Renders placeholders and replaces secret references with their values.
+    /// The result must only ever be handed over to the shell.
+    pub(crate) fn render_with_secrets(
+        &self,
+        values: &HashMap<String, String>,
+        secrets: &HashMap<String, String>,
+    ) -> Result<String> {
+        self.render_segments(values, Some(secrets))
+    }
+
+    pub(crate) fn secrets(&self) -> Vec<&'a str> {
+        let mut secrets: Vec<&str> = Vec::new();
+
+        for segment in &self.segments {
+            if let Segment::Secret(name) = segment {
+                if !secrets.contains(name) {
+                    secrets.push(name);
+                }
+            }
+        }
+
+        secrets
+    }
+
+    fn render_segments(
+        &self,
+        values: &HashMap<String, String>,
+        secrets: Option<&HashMap<String, String>>,
+    ) -> Result<String> {
+        let mut program = String::new();
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Placeholder(name) => {
+                    let value = values.get(*name).ok_or_else(|| {
+                        Error::invalid_argument(eyre!("Missing value for placeholder: {}", name))
+                    })?;
+
+                    program.push_str(value);
+                }
+                Segment::Secret(name) => match secrets {
+                    Some(secrets) => {
+                        let value = secrets.get(*name).ok_or_else(|| {
+                            Error::invalid_argument(eyre!("Missing value for secret: {}", name))
+                        })?;
+
+                        program.push_str(value);
+                    }
+                    None => {
+                        program.push_str(PLACEHOLDER_START);
+                        program.push_str(SECRET_PREFIX);
+                        program.push_str(name);
+                        program.push_str(PLACEHOLDER_END);
+                    }
+                },
+                Segment::Text(text) => program.push_str(text),
+            }
+        }
+
+        Ok(program)
+    }
+}
+
+pub(crate) fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
@@ -0,0 +1,58 @@
+use crate::definitions::{Command, CommandId, Workspace, WorkspaceId};
+use chrono::{DateTime, Utc};
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Clone)]
+pub struct TrashItem {
+    deleted_at: DateTime<Utc>,
+    entry: TrashEntry,
+}
+
+#[derive(Clone)]
+pub enum TrashEntry {
+    Command(Command),
+    Workspace(Workspace),
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub enum TrashItemId {
+    Command(CommandId),
+    Workspace(WorkspaceId),
+}
+
+pub struct TrashItemParameters {
+    pub deleted_at: DateTime<Utc>,
+    pub entry: TrashEntry,
+}
+
+impl TrashItem {
+    pub fn deleted_at(&self) -> &DateTime<Utc> {
+        &self.deleted_at
+    }
+
+    pub fn entry(&self) -> &TrashEntry {
+        &self.entry
+    }
+
+    pub fn id(&self) -> TrashItemId {
+        match &self.entry {
+            TrashEntry::Command(command) => TrashItemId::Command(command.id()),
+            TrashEntry::Workspace(workspace) => TrashItemId::Workspace(workspace.id()),
+        }
+    }
+
+    pub fn new(parameters: TrashItemParameters) -> Self {
+        let TrashItemParameters { deleted_at, entry } = parameters;
+
+        Self { deleted_at, entry }
+    }
+}
+
+impl Display for TrashItemId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TrashItemId::Command(id) => write!(f, "command {}", id),
+            TrashItemId::Workspace(id) => write!(f, "workspace {}", id),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::definitions::{Command, CommandId, TrashItemId, Workspace, WorkspaceId};
+
+/// What a mutation changed, enough to bring storage back to the state
+/// before it.
+#[derive(Clone)]
+pub enum UndoRecord {
+    /// Moving back keeps history, executions dropped by the original move are
+    /// not recovered.
+    CommandMoved {
+        previous: Command,
+    },
+    CommandUpdated {
+        previous: Command,
+    },
+    CommandsImported {
+        created: Vec<CommandId>,
+        previous: Vec<Command>,
+    },
+    MovedToTrash(TrashItemId),
+    WorkspaceUpdated {
+        previous: Workspace,
+    },
+    WorkspacesImported {
+        created: Vec<WorkspaceId>,
+        previous: Vec<Workspace>,
+    },
+}
@@ -0,0 +1,72 @@
+use crate::{Error, Result};
+use eyre::eyre;
+
+pub const LOCATION_FIELD: &str = "location";
+pub const NAME_FIELD: &str = "name";
+pub const PROGRAM_FIELD: &str = "program";
+
+const MAX_LOCATION_LENGTH: usize = 4096;
+const MAX_NAME_LENGTH: usize = 255;
+const MAX_PROGRAM_LENGTH: usize = 65_536;
+
+/// Locations may be empty, which means the current directory.
+pub(crate) fn validate_location(entity: &str, value: &str) -> Result<()> {
+    forbid_characters(entity, LOCATION_FIELD, value, char::is_control)?;
+    limit_length(entity, LOCATION_FIELD, value, MAX_LOCATION_LENGTH)
+}
+
+pub(crate) fn validate_name(entity: &str, value: &str) -> Result<()> {
+    require_text(entity, NAME_FIELD, value)?;
+    forbid_characters(entity, NAME_FIELD, value, char::is_control)?;
+    limit_length(entity, NAME_FIELD, value, MAX_NAME_LENGTH)
+}
+
+/// Programs may span several lines, so only NUL bytes are rejected.
+pub(crate) fn validate_program(entity: &str, value: &str) -> Result<()> {
+    require_text(entity, PROGRAM_FIELD, value)?;
+    forbid_characters(entity, PROGRAM_FIELD, value, |c| c == '\0')?;
+    limit_length(entity, PROGRAM_FIELD, value, MAX_PROGRAM_LENGTH)
+}
+
+fn forbid_characters(
+    entity: &str,
+    field: &'static str,
+    value: &str,
+    forbidden: impl Fn(char) -> bool,
+) -> Result<()> {
+    if let Some(c) = value.chars().find(|c| forbidden(*c)) {
+        return Err(Error::invalid_field(
+            field,
+            eyre!("{} {} cannot contain {:?}", entity, field, c),
+        ));
+    }
+
+    Ok(())
+}
+
+fn limit_length(entity: &str, field: &'static str, value: &str, max_length: usize) -> Result<()> {
+    if value.chars().count() > max_length {
+        return Err(Error::invalid_field(
+            field,
+            eyre!(
+                "{} {} cannot be longer than {} characters",
+                entity,
+                field,
+                max_length
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn require_text(entity: &str, field: &'static str, value: &str) -> Result<()> {
+    if value.trim().is_empty() {
+        return Err(Error::invalid_field(
+            field,
+            eyre!("{} {} cannot be empty", entity, field),
+        ));
+    }
+
+    Ok(())
+}
@@ -1,22 +1,37 @@
-use crate::{Error, Result};
+use super::{tags, validation};
+use crate::{definitions::Shell, Error, Result};
 use chrono::{DateTime, Utc};
 use eyre::eyre;
 use std::fmt::{self, Debug, Display, Formatter};
 use uuid::Uuid;
 
+const WORKSPACE_ENTITY: &str = "Workspace";
+
 #[derive(Clone)]
 pub struct Workspace {
     id: WorkspaceId,
+    created_at: Option<DateTime<Utc>>,
     last_access_time: Option<DateTime<Utc>>,
     location: Option<WorkspaceLocation>,
     name: WorkspaceName,
+    parent_id: Option<WorkspaceId>,
+    pinned: bool,
+    shell: Option<Shell>,
+    tags: Vec<String>,
+    updated_at: Option<DateTime<Utc>>,
 }
 
 pub struct WorkspaceParameters {
     pub id: Uuid,
+    pub created_at: Option<DateTime<Utc>>,
     pub last_access_time: Option<DateTime<Utc>>,
     pub location: Option<String>,
     pub name: String,
+    pub parent_id: Option<WorkspaceId>,
+    pub pinned: bool,
+    pub shell: Option<Shell>,
+    pub tags: Vec<String>,
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
@@ -33,10 +48,19 @@ struct WorkspaceName {
 }
 
 impl Workspace {
+    /// Unknown for workspaces that come from outside of the storage, such as backups.
+    pub fn created_at(&self) -> Option<&DateTime<Utc>> {
+        self.created_at.as_ref()
+    }
+
     pub fn id(&self) -> WorkspaceId {
         self.id
     }
 
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
     pub fn last_access_time(&self) -> Option<&DateTime<Utc>> {
         self.last_access_time.as_ref()
     }
@@ -45,46 +69,141 @@ impl Workspace {
         self.location.as_ref().map(|l| l.value.as_str())
     }
 
-    pub fn name(&self) -> &str {
-        &self.name.value
-    }
-
-    pub fn new(parameters: WorkspaceParameters) -> Result<Self> {
+    /// Builds a workspace from stored fields without checking its name and
+    /// location, so records saved before the current validation rules still
+    /// load.
+    pub fn load(parameters: WorkspaceParameters) -> Result<Self> {
         let WorkspaceParameters {
             id,
+            created_at,
             last_access_time,
             location,
             name,
+            parent_id,
+            pinned,
+            shell,
+            tags,
+            updated_at,
         } = parameters;
 
-        let mut workspace = Self {
+        Ok(Self {
             id: WorkspaceId::new(id)?,
+            created_at,
             last_access_time,
-            location: None,
+            location: location
+                .filter(|location| !location.is_empty())
+                .map(|value| WorkspaceLocation { value }),
             name: WorkspaceName { value: name },
-        };
+            parent_id,
+            pinned,
+            shell,
+            tags: tags::normalize_tags(tags),
+            updated_at,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name.value
+    }
+
+    pub fn new(parameters: WorkspaceParameters) -> Result<Self> {
+        validate_workspace(&parameters.name, parameters.location.as_deref())?;
 
-        workspace.set_location(location);
+        if parameters
+            .parent_id
+            .is_some_and(|parent_id| parent_id.as_uuid() == parameters.id)
+        {
+            return Err(Error::invalid_argument(eyre!(
+                "Workspace {} cannot be its own parent",
+                parameters.id
+            )));
+        }
 
-        Ok(workspace)
+        Self::load(parameters)
+    }
+
+    pub fn parent_id(&self) -> Option<WorkspaceId> {
+        self.parent_id
+    }
+
+    pub fn pin(&mut self) {
+        self.pinned = true;
     }
 
     pub fn set_access_time(&mut self, time: DateTime<Utc>) {
         self.last_access_time = Some(time);
     }
 
-    pub fn set_location(&mut self, location: Option<String>) {
+    pub fn set_location(&mut self, location: Option<String>) -> Result<()> {
         let location = location.unwrap_or_default();
 
         if location.is_empty() {
             self.location = None;
         } else {
-            self.location = Some(WorkspaceLocation { value: location });
+            self.location = Some(WorkspaceLocation::new(location)?);
         }
+
+        Ok(())
+    }
+
+    pub fn set_name(&mut self, name: String) -> Result<()> {
+        self.name = WorkspaceName::new(name)?;
+
+        Ok(())
+    }
+
+    pub fn set_parent_id(&mut self, parent_id: Option<WorkspaceId>) {
+        self.parent_id = parent_id;
+    }
+
+    pub fn set_shell(&mut self, shell: Option<Shell>) {
+        self.shell = shell;
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags = tags::normalize_tags(tags);
+    }
+
+    pub fn set_update_time(&mut self, time: DateTime<Utc>) {
+        self.updated_at = Some(time);
+    }
+
+    pub fn shell(&self) -> Option<Shell> {
+        self.shell
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn unpin(&mut self) {
+        self.pinned = false;
     }
 
-    pub fn set_name(&mut self, name: String) {
-        self.name = WorkspaceName { value: name };
+    pub fn updated_at(&self) -> Option<&DateTime<Utc>> {
+        self.updated_at.as_ref()
+    }
+}
+
+/// Checks workspace fields before they are handed to storage.
+pub(crate) fn validate_workspace(name: &str, location: Option<&str>) -> Result<()> {
+    validation::validate_name(WORKSPACE_ENTITY, name)?;
+    validation::validate_location(WORKSPACE_ENTITY, location.unwrap_or_default())
+}
+
+impl WorkspaceLocation {
+    fn new(value: String) -> Result<Self> {
+        validation::validate_location(WORKSPACE_ENTITY, &value)?;
+
+        Ok(Self { value })
+    }
+}
+
+impl WorkspaceName {
+    fn new(value: String) -> Result<Self> {
+        validation::validate_name(WORKSPACE_ENTITY, &value)?;
+
+        Ok(Self { value })
     }
 }
 
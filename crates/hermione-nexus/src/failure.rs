@@ -9,7 +9,7 @@ pub struct Error {
 
 #[derive(Debug)]
 enum ErrorKind {
-    InvalidArgument,
+    InvalidArgument { field: Option<&'static str> },
     NotFound,
     Service(ServiceKind),
 }
@@ -29,9 +29,24 @@ impl Error {
         }
     }
 
+    /// Name of the argument field that failed validation, if known.
+    pub fn field(&self) -> Option<&'static str> {
+        match self.kind {
+            ErrorKind::InvalidArgument { field } => field,
+            ErrorKind::NotFound | ErrorKind::Service(_) => None,
+        }
+    }
+
     pub fn invalid_argument(source: Report) -> Self {
         Self {
-            kind: ErrorKind::InvalidArgument,
+            kind: ErrorKind::InvalidArgument { field: None },
+            source,
+        }
+    }
+
+    pub fn invalid_field(field: &'static str, source: Report) -> Self {
+        Self {
+            kind: ErrorKind::InvalidArgument { field: Some(field) },
             source,
         }
     }
@@ -41,7 +56,7 @@ impl Error {
     }
 
     pub fn is_invalid_argument(&self) -> bool {
-        matches!(self.kind, ErrorKind::InvalidArgument)
+        matches!(self.kind, ErrorKind::InvalidArgument { .. })
     }
 
     pub fn is_not_found(&self) -> bool {
@@ -2,8 +2,8 @@ use crate::{
     definitions::{BackupCredentials, BackupProviderKind, Command},
     operations::GetBackupCredentialsOperation,
     services::{
-        BackupCommands, BackupService, BackupServiceBuilder, FilterCommandsParameters,
-        FindBackupCredentials, ListCommands, StorageService,
+        BackupCommands, BackupService, BackupServiceBuilder, CommandScope,
+        FilterCommandsParameters, FindBackupCredentials, ListCommands, SortOrder, StorageService,
     },
     Result,
 };
@@ -78,10 +78,15 @@ where
 
     fn list_commands(&self, page_number: u32) -> Result<Vec<Command>> {
         let parameters = FilterCommandsParameters {
+            created_after: None,
+            description_contains: None,
             program_contains: None,
             page_number,
             page_size: BACKUP_BATCH_SIZE,
-            workspace_id: None,
+            scope: CommandScope::All,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
         };
 
         self.commands.list_commands(parameters)
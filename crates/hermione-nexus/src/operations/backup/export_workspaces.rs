@@ -3,7 +3,7 @@ use crate::{
     operations::GetBackupCredentialsOperation,
     services::{
         BackupService, BackupServiceBuilder, BackupWorkspaces, FilterWorkspacesParameters,
-        FindBackupCredentials, ListWorkspaces, StorageService,
+        FindBackupCredentials, ListWorkspaces, SortOrder, StorageService, WorkspaceScope,
     },
     Result,
 };
@@ -78,9 +78,14 @@ where
 
     fn list_workspaces(&self, page_number: u32) -> Result<Vec<Workspace>> {
         let parameters = FilterWorkspacesParameters {
+            created_after: None,
             name_contains: None,
             page_number,
             page_size: BACKUP_BATCH_SIZE,
+            scope: WorkspaceScope::All,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
         };
 
         self.workspaces.list_workspaces(parameters)
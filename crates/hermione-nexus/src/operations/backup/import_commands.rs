@@ -1,9 +1,9 @@
 use crate::{
-    definitions::{BackupCredentials, BackupProviderKind},
+    definitions::{BackupCredentials, BackupProviderKind, Command, UndoRecord},
     operations::GetBackupCredentialsOperation,
     services::{
         BackupCopies, BackupCopyParameters, BackupService, BackupServiceBuilder,
-        FindBackupCredentials, GetCommandsBackupCopy, StorageService, UpsertCommands,
+        FindBackupCredentials, FindCommand, GetCommandsBackupCopy, StorageService, UpsertCommands,
     },
     Result,
 };
@@ -46,7 +46,7 @@ where
         let credentials = self.get_backup_credentials(backup_provider_kind)?;
         let backup_provider = self.build_backup_provider(credentials)?;
 
-        self.import_commands(backup_provider)?;
+        self.import_commands(backup_provider, |_commands| Ok(()))?;
 
         Ok(())
     }
@@ -61,7 +61,10 @@ where
         .execute(backup_provider_kind)
     }
 
-    fn import_commands(&self, backup_provider: BP) -> Result<()> {
+    fn import_commands<F>(&self, backup_provider: BP, mut before_upsert: F) -> Result<()>
+    where
+        F: FnMut(&[Command]) -> Result<()>,
+    {
         let mut page_token = None;
 
         loop {
@@ -74,6 +77,8 @@ where
                 next_page_token,
             } = backup;
 
+            before_upsert(&collection)?;
+
             self.upsert_commands_provider.upsert_commands(collection)?;
 
             if next_page_token.is_none() {
@@ -101,3 +106,37 @@ where
         }
     }
 }
+
+impl<BCP, UP, BPB, BP> ImportCommandsOperation<'_, BCP, UP, BPB, BP>
+where
+    BCP: FindBackupCredentials,
+    UP: FindCommand + UpsertCommands,
+    BPB: BackupServiceBuilder<BP>,
+    BP: GetCommandsBackupCopy,
+{
+    pub fn execute_with_undo(
+        &self,
+        backup_provider_kind: BackupProviderKind,
+    ) -> Result<UndoRecord> {
+        tracing::info!(operation = "Import commands");
+
+        let credentials = self.get_backup_credentials(backup_provider_kind)?;
+        let backup_provider = self.build_backup_provider(credentials)?;
+
+        let mut created = Vec::new();
+        let mut previous = Vec::new();
+
+        self.import_commands(backup_provider, |commands| {
+            for command in commands {
+                match self.upsert_commands_provider.find_command(command.id())? {
+                    Some(existing) => previous.push(existing),
+                    None => created.push(command.id()),
+                }
+            }
+
+            Ok(())
+        })?;
+
+        Ok(UndoRecord::CommandsImported { created, previous })
+    }
+}
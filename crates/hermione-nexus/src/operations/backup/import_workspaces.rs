@@ -1,9 +1,10 @@
 use crate::{
-    definitions::{BackupCredentials, BackupProviderKind},
+    definitions::{BackupCredentials, BackupProviderKind, UndoRecord, Workspace},
     operations::GetBackupCredentialsOperation,
     services::{
         BackupCopies, BackupCopyParameters, BackupService, BackupServiceBuilder,
-        FindBackupCredentials, GetWorkspacesBackupCopy, StorageService, UpsertWorkspaces,
+        FindBackupCredentials, FindWorkspace, GetWorkspacesBackupCopy, StorageService,
+        UpsertWorkspaces,
     },
     Result,
 };
@@ -46,7 +47,7 @@ where
         let credentials = self.get_backup_credentials(backup_provider_kind)?;
         let backup_provider = self.build_backup_provider(credentials)?;
 
-        self.import_workspaces(backup_provider)?;
+        self.import_workspaces(backup_provider, |_workspaces| Ok(()))?;
 
         Ok(())
     }
@@ -61,7 +62,10 @@ where
         .execute(backup_provider_kind)
     }
 
-    fn import_workspaces(&self, backup_provider: BP) -> Result<()> {
+    fn import_workspaces<F>(&self, backup_provider: BP, mut before_upsert: F) -> Result<()>
+    where
+        F: FnMut(&[Workspace]) -> Result<()>,
+    {
         let mut page_token = None;
 
         loop {
@@ -74,6 +78,8 @@ where
                 next_page_token,
             } = backup;
 
+            before_upsert(&collection)?;
+
             self.upsert_workspaces_provider
                 .upsert_workspaces(collection)?;
 
@@ -102,3 +108,40 @@ where
         }
     }
 }
+
+impl<BCP, UP, BPB, BP> ImportWorkspacesOperation<'_, BCP, UP, BPB, BP>
+where
+    BCP: FindBackupCredentials,
+    UP: FindWorkspace + UpsertWorkspaces,
+    BPB: BackupServiceBuilder<BP>,
+    BP: GetWorkspacesBackupCopy,
+{
+    pub fn execute_with_undo(
+        &self,
+        backup_provider_kind: BackupProviderKind,
+    ) -> Result<UndoRecord> {
+        tracing::info!(operation = "Import workspaces");
+
+        let credentials = self.get_backup_credentials(backup_provider_kind)?;
+        let backup_provider = self.build_backup_provider(credentials)?;
+
+        let mut created = Vec::new();
+        let mut previous = Vec::new();
+
+        self.import_workspaces(backup_provider, |workspaces| {
+            for workspace in workspaces {
+                match self
+                    .upsert_workspaces_provider
+                    .find_workspace(workspace.id())?
+                {
+                    Some(existing) => previous.push(existing),
+                    None => created.push(workspace.id()),
+                }
+            }
+
+            Ok(())
+        })?;
+
+        Ok(UndoRecord::WorkspacesImported { created, previous })
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    definitions::{Bundle, BundleParameters, Workspace, WorkspaceId, BUNDLE_SCHEMA_VERSION},
+    operations::{list_all_commands, list_all_workspaces},
+    services::{
+        CommandScope, ListCommands, ListWorkspaces, StorageService, SystemService, WorkspaceScope,
+        WriteBundle,
+    },
+    Error, Result,
+};
+use eyre::eyre;
+use std::collections::HashSet;
+
+pub struct ExportBundleOperation<'a, LC, LW, WB>
+where
+    LC: StorageService,
+    LW: StorageService,
+    WB: SystemService,
+{
+    pub list_commands_provider: &'a LC,
+    pub list_workspaces_provider: &'a LW,
+    pub write_bundle_provider: &'a WB,
+}
+
+pub struct ExportBundleParameters<'a> {
+    pub path: &'a str,
+    pub scope: BundleScope,
+}
+
+pub enum BundleScope {
+    /// Every workspace and command, global commands included.
+    Library,
+
+    /// The given workspaces together with their descendants and commands.
+    Workspaces(Vec<WorkspaceId>),
+}
+
+impl<LC, LW, WB> ExportBundleOperation<'_, LC, LW, WB>
+where
+    LC: ListCommands,
+    LW: ListWorkspaces,
+    WB: WriteBundle,
+{
+    /// Workspaces whose parent is not part of the bundle become roots, so
+    /// the bundle does not depend on the library it was taken from.
+    pub fn execute(&self, parameters: ExportBundleParameters) -> Result<Bundle> {
+        tracing::info!(operation = "Export bundle");
+
+        let ExportBundleParameters { path, scope } = parameters;
+
+        let mut workspaces =
+            list_all_workspaces(self.list_workspaces_provider, WorkspaceScope::All)?;
+        let mut commands = list_all_commands(self.list_commands_provider, CommandScope::All)?;
+
+        if let BundleScope::Workspaces(ids) = scope {
+            let selected = select_descendants(&workspaces, ids)?;
+
+            workspaces.retain(|workspace| selected.contains(&workspace.id()));
+            commands.retain(|command| {
+                command
+                    .workspace_id()
+                    .is_some_and(|workspace_id| selected.contains(&workspace_id))
+            });
+
+            for workspace in &mut workspaces {
+                if workspace
+                    .parent_id()
+                    .is_some_and(|parent_id| !selected.contains(&parent_id))
+                {
+                    workspace.set_parent_id(None);
+                }
+            }
+        }
+
+        let bundle = Bundle::new(BundleParameters {
+            commands,
+            schema_version: BUNDLE_SCHEMA_VERSION,
+            workspaces,
+        })?;
+
+        self.write_bundle_provider.write_bundle(path, &bundle)?;
+
+        Ok(bundle)
+    }
+}
+
+fn select_descendants(
+    workspaces: &[Workspace],
+    ids: Vec<WorkspaceId>,
+) -> Result<HashSet<WorkspaceId>> {
+    let known = workspaces
+        .iter()
+        .map(Workspace::id)
+        .collect::<HashSet<WorkspaceId>>();
+
+    if let Some(id) = ids.iter().find(|id| !known.contains(id)) {
+        return Err(Error::not_found(eyre!(
+            "Could not find workspace with ID: {}",
+            id
+        )));
+    }
+
+    let mut selected = ids.into_iter().collect::<HashSet<WorkspaceId>>();
+
+    loop {
+        let children = workspaces
+            .iter()
+            .filter(|workspace| !selected.contains(&workspace.id()))
+            .filter(|workspace| {
+                workspace
+                    .parent_id()
+                    .is_some_and(|parent_id| selected.contains(&parent_id))
+            })
+            .map(Workspace::id)
+            .collect::<Vec<WorkspaceId>>();
+
+        if children.is_empty() {
+            break;
+        }
+
+        selected.extend(children);
+    }
+
+    Ok(selected)
+}
@@ -0,0 +1,338 @@
+use crate::{
+    definitions::{self, BundleConflictStrategy, Command, TrashItemId, Workspace, WorkspaceId},
+    services::{
+        CreateCommand, CreateWorkspace, FindCommand, FindTrashItem, FindWorkspace,
+        NewCommandParameters, NewWorkspaceParameters, ReadBundle, RestoreFromTrash, StorageService,
+        SystemService, UpsertCommands, UpsertWorkspaces,
+    },
+    Error, Result,
+};
+use eyre::eyre;
+use std::collections::{HashMap, HashSet};
+
+pub struct ImportBundleOperation<'a, CC, CW, FC, FW, RB, TP, UC, UW>
+where
+    CC: StorageService,
+    CW: StorageService,
+    FC: StorageService,
+    FW: StorageService,
+    RB: SystemService,
+    TP: StorageService,
+    UC: StorageService,
+    UW: StorageService,
+{
+    pub create_command_provider: &'a CC,
+    pub create_workspace_provider: &'a CW,
+    pub find_command_provider: &'a FC,
+    pub find_workspace_provider: &'a FW,
+    pub read_bundle_provider: &'a RB,
+    pub trash_provider: &'a TP,
+    pub upsert_commands_provider: &'a UC,
+    pub upsert_workspaces_provider: &'a UW,
+}
+
+pub struct ImportBundleParameters<'a> {
+    pub path: &'a str,
+    pub strategy: BundleConflictStrategy,
+}
+
+/// Number of bundle items stored and of conflicting ones left as they were.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct BundleImport {
+    pub imported_commands: usize,
+    pub imported_workspaces: usize,
+    pub kept_commands: usize,
+    pub kept_workspaces: usize,
+}
+
+impl<CC, CW, FC, FW, RB, TP, UC, UW> ImportBundleOperation<'_, CC, CW, FC, FW, RB, TP, UC, UW>
+where
+    CC: CreateCommand,
+    CW: CreateWorkspace,
+    FC: FindCommand,
+    FW: FindWorkspace,
+    RB: ReadBundle,
+    TP: FindTrashItem + RestoreFromTrash,
+    UC: UpsertCommands,
+    UW: UpsertWorkspaces,
+{
+    /// The whole bundle is checked before anything is stored, an invalid
+    /// item fails the import without changes. Parents are imported before
+    /// their children. References to duplicated workspaces follow the new
+    /// IDs, references to workspaces that are neither bundled nor known
+    /// locally are dropped.
+    ///
+    /// Items in the trash conflict like any local item, commands of a
+    /// trashed workspace go with it. Overwriting an item brings it back
+    /// from the trash first, a workspace together with the commands trashed
+    /// with it, and keeps its local times. Repository-managed commands are
+    /// never overwritten.
+    pub fn execute(&self, parameters: ImportBundleParameters) -> Result<BundleImport> {
+        tracing::info!(operation = "Import bundle");
+
+        let ImportBundleParameters { path, strategy } = parameters;
+
+        let (workspaces, commands) = self.read_bundle_provider.read_bundle(path)?.into_parts();
+
+        validate_items(&workspaces, &commands)?;
+
+        let workspaces = parents_first(workspaces)?;
+
+        let mut import = BundleImport::default();
+        let mut local_ids = HashMap::new();
+
+        for workspace in workspaces {
+            self.import_workspace(workspace, strategy, &mut local_ids, &mut import)?;
+        }
+
+        for command in commands {
+            self.import_command(command, strategy, &local_ids, &mut import)?;
+        }
+
+        Ok(import)
+    }
+
+    fn import_command(
+        &self,
+        mut command: Command,
+        strategy: BundleConflictStrategy,
+        local_ids: &HashMap<WorkspaceId, WorkspaceId>,
+        import: &mut BundleImport,
+    ) -> Result<()> {
+        let workspace_id = match command.workspace_id() {
+            Some(id) => self.resolve_workspace_id(id, local_ids)?,
+            None => None,
+        };
+
+        let id = command.id();
+        let existing = self.find_command_provider.find_command(id)?;
+
+        let trashed = existing.is_none() && self.is_command_trashed(&command)?;
+
+        // Repository-managed commands only change through their repository
+        // command file, overwriting keeps them as they are
+        let strategy = match strategy {
+            BundleConflictStrategy::Overwrite
+                if existing
+                    .as_ref()
+                    .is_some_and(Command::is_repository_managed) =>
+            {
+                BundleConflictStrategy::KeepLocal
+            }
+            strategy => strategy,
+        };
+
+        match (existing.is_some() || trashed, strategy) {
+            (true, BundleConflictStrategy::KeepLocal) => {
+                import.kept_commands += 1;
+
+                return Ok(());
+            }
+            (true, BundleConflictStrategy::Duplicate) => {
+                self.create_command_provider
+                    .create_command(NewCommandParameters {
+                        description: command.description().map(ToString::to_string),
+                        name: command.name().to_string(),
+                        program: command.program().to_string(),
+                        shell: command.shell(),
+                        tags: command.tags().to_vec(),
+                        workspace_id,
+                    })?;
+            }
+            _ => {
+                let existing = if trashed {
+                    self.trash_provider
+                        .restore_from_trash(TrashItemId::Command(id))?;
+
+                    self.find_command_provider.find_command(id)?
+                } else {
+                    existing
+                };
+
+                if let Some(time) = existing.and_then(|local| local.last_execute_time().copied()) {
+                    command.set_execute_time(time);
+                }
+
+                command.set_workspace_id(workspace_id);
+
+                self.upsert_commands_provider
+                    .upsert_commands(vec![command])?;
+            }
+        }
+
+        import.imported_commands += 1;
+
+        Ok(())
+    }
+
+    fn import_workspace(
+        &self,
+        mut workspace: Workspace,
+        strategy: BundleConflictStrategy,
+        local_ids: &mut HashMap<WorkspaceId, WorkspaceId>,
+        import: &mut BundleImport,
+    ) -> Result<()> {
+        let id = workspace.id();
+
+        let parent_id = match workspace.parent_id() {
+            Some(parent_id) => self.resolve_workspace_id(parent_id, local_ids)?,
+            None => None,
+        };
+
+        let existing = self.find_workspace_provider.find_workspace(id)?;
+
+        let trashed = existing.is_none()
+            && self
+                .trash_provider
+                .find_trash_item(TrashItemId::Workspace(id))?
+                .is_some();
+
+        match (existing.is_some() || trashed, strategy) {
+            (true, BundleConflictStrategy::KeepLocal) => {
+                local_ids.insert(id, id);
+                import.kept_workspaces += 1;
+
+                return Ok(());
+            }
+            (true, BundleConflictStrategy::Duplicate) => {
+                let duplicate =
+                    self.create_workspace_provider
+                        .create_workspace(NewWorkspaceParameters {
+                            name: workspace.name().to_string(),
+                            location: workspace.location().map(ToString::to_string),
+                            parent_id,
+                            shell: workspace.shell(),
+                            tags: workspace.tags().to_vec(),
+                        })?;
+
+                local_ids.insert(id, duplicate.id());
+            }
+            _ => {
+                let existing = if trashed {
+                    self.trash_provider
+                        .restore_from_trash(TrashItemId::Workspace(id))?;
+
+                    self.find_workspace_provider.find_workspace(id)?
+                } else {
+                    existing
+                };
+
+                if let Some(time) = existing.and_then(|local| local.last_access_time().copied()) {
+                    workspace.set_access_time(time);
+                }
+
+                workspace.set_parent_id(parent_id);
+
+                self.upsert_workspaces_provider
+                    .upsert_workspaces(vec![workspace])?;
+
+                local_ids.insert(id, id);
+            }
+        }
+
+        import.imported_workspaces += 1;
+
+        Ok(())
+    }
+
+    /// Commands trashed together with their workspace are only listed
+    /// under the workspace, so they are looked up through it.
+    fn is_command_trashed(&self, command: &Command) -> Result<bool> {
+        if self
+            .trash_provider
+            .find_trash_item(TrashItemId::Command(command.id()))?
+            .is_some()
+        {
+            return Ok(true);
+        }
+
+        let Some(workspace_id) = command.workspace_id() else {
+            return Ok(false);
+        };
+
+        Ok(self
+            .trash_provider
+            .find_trash_item(TrashItemId::Workspace(workspace_id))?
+            .is_some())
+    }
+
+    fn resolve_workspace_id(
+        &self,
+        id: WorkspaceId,
+        local_ids: &HashMap<WorkspaceId, WorkspaceId>,
+    ) -> Result<Option<WorkspaceId>> {
+        if let Some(local_id) = local_ids.get(&id) {
+            return Ok(Some(*local_id));
+        }
+
+        Ok(self
+            .find_workspace_provider
+            .find_workspace(id)?
+            .map(|workspace| workspace.id()))
+    }
+}
+
+/// Orders workspaces so every parent comes before its children. Bundled
+/// workspaces that are their own ancestors make the bundle invalid.
+fn parents_first(workspaces: Vec<Workspace>) -> Result<Vec<Workspace>> {
+    let bundled = workspaces
+        .iter()
+        .map(Workspace::id)
+        .collect::<HashSet<WorkspaceId>>();
+
+    let mut ordered = Vec::with_capacity(workspaces.len());
+    let mut placed = HashSet::new();
+    let mut pending = workspaces;
+
+    while !pending.is_empty() {
+        let (ready, rest): (Vec<Workspace>, Vec<Workspace>) =
+            pending.into_iter().partition(|workspace| {
+                !workspace.parent_id().is_some_and(|parent_id| {
+                    bundled.contains(&parent_id) && !placed.contains(&parent_id)
+                })
+            });
+
+        if ready.is_empty() {
+            return Err(Error::invalid_argument(eyre!(
+                "Bundled workspace {} is its own ancestor",
+                rest[0].id()
+            )));
+        }
+
+        placed.extend(ready.iter().map(Workspace::id));
+        ordered.extend(ready);
+        pending = rest;
+    }
+
+    Ok(ordered)
+}
+
+fn validate_items(workspaces: &[Workspace], commands: &[Command]) -> Result<()> {
+    let mut workspace_ids = HashSet::new();
+
+    for workspace in workspaces {
+        if !workspace_ids.insert(workspace.id()) {
+            return Err(Error::invalid_argument(eyre!(
+                "Bundle contains workspace {} more than once",
+                workspace.id()
+            )));
+        }
+
+        definitions::validate_workspace(workspace.name(), workspace.location())?;
+    }
+
+    let mut command_ids = HashSet::new();
+
+    for command in commands {
+        if !command_ids.insert(command.id()) {
+            return Err(Error::invalid_argument(eyre!(
+                "Bundle contains command {} more than once",
+                command.id()
+            )));
+        }
+
+        definitions::validate_command(command.name(), command.program())?;
+    }
+
+    Ok(())
+}
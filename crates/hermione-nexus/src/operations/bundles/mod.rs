@@ -0,0 +1,5 @@
+mod export_bundle;
+mod import_bundle;
+
+pub use export_bundle::*;
+pub use import_bundle::*;
@@ -0,0 +1,65 @@
+use crate::{
+    definitions::{Command, CommandId, WorkspaceId},
+    operations::{GetCommandOperation, GetWorkspaceOperation},
+    services::{CopyCommand, FindCommand, FindWorkspace, NewCommandCopyParameters, StorageService},
+    Result,
+};
+
+pub struct CopyCommandOperation<'a, CCP, FCP, FWP>
+where
+    CCP: StorageService,
+    FCP: StorageService,
+    FWP: StorageService,
+{
+    pub copy_command_provider: &'a CCP,
+    pub find_command_provider: &'a FCP,
+    pub find_workspace_provider: &'a FWP,
+}
+
+pub struct CopyCommandParameters {
+    pub id: CommandId,
+
+    /// Copies the execute count and time of the original command to the
+    /// copy, otherwise the copy starts as if it was never run. Executions
+    /// of the original are never copied.
+    pub keep_history: bool,
+
+    /// Target workspace, `None` creates a global copy.
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+impl<CCP, FCP, FWP> CopyCommandOperation<'_, CCP, FCP, FWP>
+where
+    CCP: CopyCommand,
+    FCP: FindCommand,
+    FWP: FindWorkspace,
+{
+    pub fn execute(&self, parameters: CopyCommandParameters) -> Result<Command> {
+        tracing::info!(operation = "Copy command");
+
+        let CopyCommandParameters {
+            id,
+            keep_history,
+            workspace_id,
+        } = parameters;
+
+        let command = GetCommandOperation {
+            provider: self.find_command_provider,
+        }
+        .execute(id)?;
+
+        if let Some(workspace_id) = workspace_id {
+            GetWorkspaceOperation {
+                provider: self.find_workspace_provider,
+            }
+            .execute(workspace_id)?;
+        }
+
+        self.copy_command_provider
+            .copy_command(NewCommandCopyParameters {
+                id: command.id(),
+                keep_history,
+                workspace_id,
+            })
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    definitions::{Command, WorkspaceId},
+    definitions::{self, Command, Shell, WorkspaceId},
     services::{CreateCommand, NewCommandParameters, StorageService},
     Result,
 };
@@ -12,9 +12,12 @@ where
 }
 
 pub struct CreateCommandParameters {
+    pub description: Option<String>,
     pub name: String,
     pub program: String,
-    pub workspace_id: WorkspaceId,
+    pub shell: Option<Shell>,
+    pub tags: Vec<String>,
+    pub workspace_id: Option<WorkspaceId>,
 }
 
 impl<CC> CreateCommandOperation<'_, CC>
@@ -25,14 +28,22 @@ where
         tracing::info!(operation = "Create command");
 
         let CreateCommandParameters {
+            description,
             name,
             program,
+            shell,
+            tags,
             workspace_id,
         } = parameters;
 
+        definitions::validate_command(&name, &program)?;
+
         self.storage_provider.create_command(NewCommandParameters {
+            description,
             name,
             program,
+            shell,
+            tags: definitions::normalize_tags(tags),
             workspace_id,
         })
     }
@@ -0,0 +1,58 @@
+use super::GetCommandOperation;
+use crate::{
+    definitions::{CommandChain, CommandId, WorkspaceId},
+    services::{CreateCommandChain, FindCommand, NewCommandChainParameters, StorageService},
+    Error, Result,
+};
+use eyre::eyre;
+
+pub struct CreateCommandChainOperation<'a, FCP, CCP>
+where
+    FCP: StorageService,
+    CCP: StorageService,
+{
+    pub find_command_provider: &'a FCP,
+    pub create_command_chain_provider: &'a CCP,
+}
+
+pub struct CreateCommandChainParameters {
+    pub name: String,
+    pub steps: Vec<CommandId>,
+    pub workspace_id: WorkspaceId,
+}
+
+impl<FCP, CCP> CreateCommandChainOperation<'_, FCP, CCP>
+where
+    FCP: FindCommand,
+    CCP: CreateCommandChain,
+{
+    pub fn execute(&self, parameters: CreateCommandChainParameters) -> Result<CommandChain> {
+        tracing::info!(operation = "Create command chain");
+
+        let CreateCommandChainParameters {
+            name,
+            steps,
+            workspace_id,
+        } = parameters;
+
+        if steps.is_empty() {
+            return Err(Error::invalid_argument(eyre!(
+                "Command chain must have at least one step"
+            )));
+        }
+
+        for id in &steps {
+            GetCommandOperation {
+                provider: self.find_command_provider,
+            }
+            .execute(*id)?;
+        }
+
+        self.create_command_chain_provider
+            .create_command_chain(NewCommandChainParameters {
+                name,
+                steps,
+                workspace_id,
+            })
+    }
+}
@@ -1,4 +1,4 @@
-use super::GetCommandOperation;
+use super::{update_command, GetCommandOperation};
 use crate::{
     definitions::{Command, CommandId},
     services::{DeleteCommand, FindCommand, StorageService},
@@ -22,7 +22,10 @@ where
     pub fn execute(&self, id: CommandId) -> Result<()> {
         tracing::info!(operation = "Delete command");
 
-        self.get_command(id)?;
+        let command = self.get_command(id)?;
+
+        update_command::ensure_not_repository_managed(&command)?;
+
         self.delete_provider.delete_command(id)?;
 
         Ok(())
@@ -0,0 +1,32 @@
+use super::GetCommandChainOperation;
+use crate::{
+    definitions::CommandChainId,
+    services::{DeleteCommandChain, FindCommandChain, StorageService},
+    Result,
+};
+
+pub struct DeleteCommandChainOperation<'a, FCP, DCP>
+where
+    FCP: StorageService,
+    DCP: StorageService,
+{
+    pub find_provider: &'a FCP,
+    pub delete_provider: &'a DCP,
+}
+
+impl<FCP, DCP> DeleteCommandChainOperation<'_, FCP, DCP>
+where
+    FCP: FindCommandChain,
+    DCP: DeleteCommandChain,
+{
+    pub fn execute(&self, id: CommandChainId) -> Result<()> {
+        tracing::info!(operation = "Delete command chain");
+
+        GetCommandChainOperation {
+            provider: self.find_provider,
+        }
+        .execute(id)?;
+
+        self.delete_provider.delete_command_chain(id)
+    }
+}
@@ -0,0 +1,79 @@
+use super::{GetCommandOperation, GetCommandRevisionOperation};
+use crate::{
+    definitions::{CommandRevision, CommandRevisionDiff, CommandRevisionId},
+    services::{FindCommand, FindCommandRevision, StorageService},
+    Error, Result,
+};
+use eyre::eyre;
+
+pub struct DiffCommandRevisionsOperation<'a, FC, FR>
+where
+    FC: StorageService,
+    FR: StorageService,
+{
+    pub find_command_provider: &'a FC,
+    pub find_revision_provider: &'a FR,
+}
+
+pub struct DiffCommandRevisionsParameters {
+    pub from: CommandRevisionId,
+
+    /// Revision to compare with. The current version of the command is used
+    /// when it is not given.
+    pub to: Option<CommandRevisionId>,
+}
+
+impl<FC, FR> DiffCommandRevisionsOperation<'_, FC, FR>
+where
+    FC: FindCommand,
+    FR: FindCommandRevision,
+{
+    pub fn execute(
+        &self,
+        parameters: DiffCommandRevisionsParameters,
+    ) -> Result<CommandRevisionDiff> {
+        tracing::info!(operation = "Diff command revisions");
+
+        let DiffCommandRevisionsParameters { from, to } = parameters;
+
+        let from = self.get_revision(from)?;
+
+        let Some(to) = to else {
+            let command = GetCommandOperation {
+                provider: self.find_command_provider,
+            }
+            .execute(from.command_id())?;
+
+            return Ok(CommandRevisionDiff::new(
+                from.name(),
+                from.program(),
+                command.name(),
+                command.program(),
+            ));
+        };
+
+        let to = self.get_revision(to)?;
+
+        if from.command_id() != to.command_id() {
+            return Err(Error::invalid_argument(eyre!(
+                "Revisions {} and {} belong to different commands",
+                from.id(),
+                to.id()
+            )));
+        }
+
+        Ok(CommandRevisionDiff::new(
+            from.name(),
+            from.program(),
+            to.name(),
+            to.program(),
+        ))
+    }
+
+    fn get_revision(&self, id: CommandRevisionId) -> Result<CommandRevision> {
+        GetCommandRevisionOperation {
+            provider: self.find_revision_provider,
+        }
+        .execute(id)
+    }
+}
@@ -1,51 +1,150 @@
 use crate::{
-    definitions::{Command, CommandId, Workspace, WorkspaceId},
-    operations::{GetCommandOperation, GetWorkspaceOperation},
+    definitions::{Command, CommandId, EnvironmentVariable, Shell, Workspace, WorkspaceId},
+    operations::{
+        GetCommandOperation, GetWorkspaceOperation, ListWorkspaceAncestorsOperation,
+        ListWorkspaceEnvironmentVariablesOperation,
+    },
     services::{
-        FindCommand, FindWorkspace, InvokeCommand, InvokeCommandParameters, StorageService,
-        SystemService, TrackCommandExecuteTime, TrackWorkspaceAccessTime,
+        FindCommand, FindSecret, FindWorkspace, InvokeCommand, InvokeCommandParameters,
+        ListWorkspaceEnvironmentVariables, NewCommandExecutionParameters, SecretStore,
+        StorageService, SystemService, TrackCommandExecuteTime, TrackCommandExecution,
+        TrackWorkspaceAccessTime,
     },
-    Result,
+    Error, Result,
 };
+use eyre::eyre;
+use std::collections::HashMap;
 
-pub struct ExecuteCommandOperation<'a, FCP, FWP, SP, TCP, TWP>
+pub struct ExecuteCommandOperation<'a, FCP, FSP, FWP, LEP, SP, TCP, TWP>
 where
     FCP: StorageService,
+    FSP: SecretStore,
     FWP: StorageService,
+    LEP: StorageService,
     SP: SystemService,
     TCP: StorageService,
     TWP: StorageService,
 {
     pub find_command_provider: &'a FCP,
+    pub find_secret_provider: &'a FSP,
     pub find_workspace_provider: &'a FWP,
+    pub list_environment_variables_provider: &'a LEP,
     pub system_provider: &'a SP,
     pub track_command_provider: &'a TCP,
     pub track_workspace_provider: &'a TWP,
 }
 
-impl<FCP, FWP, SP, TCP, TWP> ExecuteCommandOperation<'_, FCP, FWP, SP, TCP, TWP>
+pub struct ExecuteCommandParameters {
+    pub command_id: CommandId,
+    pub placeholder_values: HashMap<String, String>,
+
+    /// Workspace a global or inherited command runs in. A command that
+    /// belongs to an ancestor of the given workspace runs in the given one,
+    /// other workspace commands always run in their own workspace, and a
+    /// global command without a workspace runs in the current directory.
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+pub(crate) struct PreparedCommand {
+    id: CommandId,
+    pub environment_variables: Vec<EnvironmentVariable>,
+    program: String,
+
+    /// Program with secret references replaced by their values.
+    pub resolved_program: String,
+    pub shell: Option<Shell>,
+    workspace: Option<Workspace>,
+}
+
+impl<FCP, FSP, FWP, LEP, SP, TCP, TWP> ExecuteCommandOperation<'_, FCP, FSP, FWP, LEP, SP, TCP, TWP>
 where
     FCP: FindCommand,
+    FSP: FindSecret,
     FWP: FindWorkspace,
+    LEP: ListWorkspaceEnvironmentVariables,
     SP: InvokeCommand,
-    TCP: TrackCommandExecuteTime,
+    TCP: TrackCommandExecuteTime + TrackCommandExecution,
     TWP: TrackWorkspaceAccessTime,
 {
-    pub fn execute(&self, id: CommandId) -> Result<()> {
+    /// Secret references are resolved right before the program is handed
+    /// over to the shell. The execution history keeps the references only.
+    pub fn execute(&self, parameters: ExecuteCommandParameters) -> Result<()> {
         tracing::info!(operation = "Execute command");
 
-        let command = self.get_command(id)?;
-        let workspace = self.get_workspace(command.workspace_id())?;
+        let prepared_command = self.prepare(parameters)?;
 
         self.system_provider
             .invoke_command(InvokeCommandParameters {
-                command: command.program(),
-                location: workspace.location(),
+                command: &prepared_command.resolved_program,
+                environment_variables: &prepared_command.environment_variables,
+                location: prepared_command.location(),
+                shell: prepared_command.shell,
+            })?;
+
+        self.track(&prepared_command)
+    }
+
+    /// Resolves everything the command needs to run without running it, so
+    /// a chain can check all of its steps before the first one starts.
+    pub(crate) fn prepare(&self, parameters: ExecuteCommandParameters) -> Result<PreparedCommand> {
+        let ExecuteCommandParameters {
+            command_id: id,
+            placeholder_values,
+            workspace_id,
+        } = parameters;
+
+        let command = self.get_command(id)?;
+        let program = command.render_program(&placeholder_values)?;
+        let secret_values = self.resolve_secrets(&command)?;
+        let resolved_program =
+            command.render_program_with_secrets(&placeholder_values, &secret_values)?;
+
+        let workspace = self
+            .resolve_workspace_id(&command, workspace_id)?
+            .map(|id| self.get_workspace(id))
+            .transpose()?;
+
+        let environment_variables = match &workspace {
+            Some(workspace) => self.list_environment_variables(workspace.id())?,
+            None => Vec::new(),
+        };
+
+        let shell = command
+            .shell()
+            .or(workspace.as_ref().and_then(Workspace::shell));
+
+        Ok(PreparedCommand {
+            id,
+            environment_variables,
+            program,
+            resolved_program,
+            shell,
+            workspace,
+        })
+    }
+
+    pub(crate) fn track(&self, prepared_command: &PreparedCommand) -> Result<()> {
+        let PreparedCommand {
+            id,
+            program,
+            workspace,
+            ..
+        } = prepared_command;
+
+        self.track_command_provider
+            .track_command_execute_time(*id)?;
+        self.track_command_provider
+            .track_command_execution(NewCommandExecutionParameters {
+                command_id: *id,
+                location: prepared_command.location(),
+                program,
+                workspace_id: workspace.as_ref().map(Workspace::id),
             })?;
 
-        self.track_command_provider.track_command_execute_time(id)?;
-        self.track_workspace_provider
-            .track_workspace_access_time(command.workspace_id())?;
+        if let Some(workspace) = workspace {
+            self.track_workspace_provider
+                .track_workspace_access_time(workspace.id())?;
+        }
 
         Ok(())
     }
@@ -63,4 +162,58 @@ where
         }
         .execute(id)
     }
+
+    fn resolve_secrets(&self, command: &Command) -> Result<HashMap<String, String>> {
+        let mut secret_values = HashMap::new();
+
+        for name in command.secrets() {
+            let secret = self
+                .find_secret_provider
+                .find_secret(name)?
+                .ok_or(eyre!("Could not find secret with name: {}", name))
+                .map_err(Error::not_found)?;
+
+            secret_values.insert(name.to_string(), secret.value().to_string());
+        }
+
+        Ok(secret_values)
+    }
+
+    fn resolve_workspace_id(
+        &self,
+        command: &Command,
+        workspace_id: Option<WorkspaceId>,
+    ) -> Result<Option<WorkspaceId>> {
+        let Some(owner_id) = command.workspace_id() else {
+            return Ok(workspace_id);
+        };
+
+        let Some(workspace_id) = workspace_id.filter(|id| *id != owner_id) else {
+            return Ok(Some(owner_id));
+        };
+
+        let ancestors = ListWorkspaceAncestorsOperation {
+            provider: self.find_workspace_provider,
+        }
+        .execute(workspace_id)?;
+
+        if ancestors.iter().any(|ancestor| ancestor.id() == owner_id) {
+            return Ok(Some(workspace_id));
+        }
+
+        Ok(Some(owner_id))
+    }
+
+    fn list_environment_variables(&self, id: WorkspaceId) -> Result<Vec<EnvironmentVariable>> {
+        ListWorkspaceEnvironmentVariablesOperation {
+            provider: self.list_environment_variables_provider,
+        }
+        .execute(id)
+    }
+}
+
+impl PreparedCommand {
+    pub fn location(&self) -> Option<&str> {
+        self.workspace.as_ref().and_then(Workspace::location)
+    }
 }
@@ -0,0 +1,126 @@
+use super::{ExecuteCommandOperation, ExecuteCommandParameters, GetCommandChainOperation};
+use crate::{
+    definitions::CommandChainId,
+    operations::GetWorkspaceOperation,
+    services::{
+        FindCommand, FindCommandChain, FindSecret, FindWorkspace, InvokeCommand,
+        InvokeCommandChain, InvokeCommandChainParameters, InvokeCommandChainStep,
+        ListWorkspaceEnvironmentVariables, SecretStore, StorageService, SystemService,
+        TrackCommandExecuteTime, TrackCommandExecution, TrackWorkspaceAccessTime,
+    },
+    Result,
+};
+use std::collections::HashMap;
+
+pub struct ExecuteCommandChainOperation<'a, FCCP, FCP, FSP, FWP, LEP, SP, TCP, TWP>
+where
+    FCCP: StorageService,
+    FCP: StorageService,
+    FSP: SecretStore,
+    FWP: StorageService,
+    LEP: StorageService,
+    SP: SystemService,
+    TCP: StorageService,
+    TWP: StorageService,
+{
+    pub find_command_chain_provider: &'a FCCP,
+    pub find_command_provider: &'a FCP,
+    pub find_secret_provider: &'a FSP,
+    pub find_workspace_provider: &'a FWP,
+    pub list_environment_variables_provider: &'a LEP,
+    pub system_provider: &'a SP,
+    pub track_command_provider: &'a TCP,
+    pub track_workspace_provider: &'a TWP,
+}
+
+pub struct ExecuteCommandChainParameters {
+    pub command_chain_id: CommandChainId,
+
+    /// Values shared by the placeholders of every step.
+    pub placeholder_values: HashMap<String, String>,
+}
+
+impl<FCCP, FCP, FSP, FWP, LEP, SP, TCP, TWP>
+    ExecuteCommandChainOperation<'_, FCCP, FCP, FSP, FWP, LEP, SP, TCP, TWP>
+where
+    FCCP: FindCommandChain,
+    FCP: FindCommand,
+    FSP: FindSecret,
+    FWP: FindWorkspace,
+    LEP: ListWorkspaceEnvironmentVariables,
+    SP: InvokeCommand + InvokeCommandChain,
+    TCP: TrackCommandExecuteTime + TrackCommandExecution,
+    TWP: TrackWorkspaceAccessTime,
+{
+    /// Builds a single script out of the chain steps, so a step starts only
+    /// after the previous one has succeeded. Every step runs in its own
+    /// shell and in the location of its own workspace, global steps run in
+    /// the workspace of the chain. All steps are resolved before the script
+    /// starts, a missing step or placeholder value runs nothing.
+    ///
+    /// Only the first step is known to start, the rest depend on outcomes
+    /// the script does not report back. So only the first step goes into
+    /// the execution history.
+    pub fn execute(&self, parameters: ExecuteCommandChainParameters) -> Result<()> {
+        tracing::info!(operation = "Execute command chain");
+
+        let ExecuteCommandChainParameters {
+            command_chain_id,
+            placeholder_values,
+        } = parameters;
+
+        let command_chain = GetCommandChainOperation {
+            provider: self.find_command_chain_provider,
+        }
+        .execute(command_chain_id)?;
+
+        let workspace = GetWorkspaceOperation {
+            provider: self.find_workspace_provider,
+        }
+        .execute(command_chain.workspace_id())?;
+
+        let execute_command_operation = ExecuteCommandOperation {
+            find_command_provider: self.find_command_provider,
+            find_secret_provider: self.find_secret_provider,
+            find_workspace_provider: self.find_workspace_provider,
+            list_environment_variables_provider: self.list_environment_variables_provider,
+            system_provider: self.system_provider,
+            track_command_provider: self.track_command_provider,
+            track_workspace_provider: self.track_workspace_provider,
+        };
+
+        let prepared_commands = command_chain
+            .steps()
+            .iter()
+            .map(|command_id| {
+                execute_command_operation.prepare(ExecuteCommandParameters {
+                    command_id: *command_id,
+                    placeholder_values: placeholder_values.clone(),
+                    workspace_id: Some(command_chain.workspace_id()),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let steps: Vec<InvokeCommandChainStep> = prepared_commands
+            .iter()
+            .map(|prepared_command| InvokeCommandChainStep {
+                command: &prepared_command.resolved_program,
+                environment_variables: &prepared_command.environment_variables,
+                location: prepared_command.location(),
+                shell: prepared_command.shell,
+            })
+            .collect();
+
+        self.system_provider
+            .invoke_command_chain(InvokeCommandChainParameters {
+                steps: &steps,
+                shell: workspace.shell(),
+            })?;
+
+        if let Some(prepared_command) = prepared_commands.first() {
+            execute_command_operation.track(prepared_command)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,19 +1,22 @@
 use crate::{
     definitions::WorkspaceId,
-    operations::GetWorkspaceOperation,
+    operations::{GetWorkspaceOperation, ListWorkspaceEnvironmentVariablesOperation},
     services::{
-        FindWorkspace, InvokeCommand, InvokeCommandParameters, StorageService, SystemService,
+        FindWorkspace, InvokeCommand, InvokeCommandParameters, ListWorkspaceEnvironmentVariables,
+        StorageService, SystemService,
     },
     Result,
 };
 
-pub struct ExecuteProgramOperation<'a, FW, S>
+pub struct ExecuteProgramOperation<'a, FW, LE, S>
 where
     FW: StorageService,
+    LE: StorageService,
     S: SystemService,
 {
     pub system: &'a S,
     pub find_workspace: &'a FW,
+    pub list_environment_variables: &'a LE,
 }
 
 pub struct ExecuteProgramParameters<'a> {
@@ -21,9 +24,10 @@ pub struct ExecuteProgramParameters<'a> {
     pub workspace_id: Option<WorkspaceId>,
 }
 
-impl<FW, S> ExecuteProgramOperation<'_, FW, S>
+impl<FW, LE, S> ExecuteProgramOperation<'_, FW, LE, S>
 where
     FW: FindWorkspace,
+    LE: ListWorkspaceEnvironmentVariables,
     S: InvokeCommand,
 {
     pub fn execute(&self, parameters: ExecuteProgramParameters) -> Result<()> {
@@ -38,14 +42,23 @@ where
             }
             .execute(id)?;
 
+            let environment_variables = ListWorkspaceEnvironmentVariablesOperation {
+                provider: self.list_environment_variables,
+            }
+            .execute(id)?;
+
             self.system.invoke_command(InvokeCommandParameters {
                 command,
+                environment_variables: &environment_variables,
                 location: workspace.location(),
+                shell: workspace.shell(),
             })?;
         } else {
             self.system.invoke_command(InvokeCommandParameters {
                 command,
+                environment_variables: &[],
                 location: None,
+                shell: None,
             })?;
         };
 
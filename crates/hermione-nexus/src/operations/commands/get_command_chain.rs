@@ -0,0 +1,27 @@
+use crate::{
+    definitions::{CommandChain, CommandChainId},
+    services::{FindCommandChain, StorageService},
+    Error, Result,
+};
+use eyre::eyre;
+
+pub struct GetCommandChainOperation<'a, SP>
+where
+    SP: StorageService,
+{
+    pub provider: &'a SP,
+}
+
+impl<F> GetCommandChainOperation<'_, F>
+where
+    F: FindCommandChain,
+{
+    pub fn execute(&self, id: CommandChainId) -> Result<CommandChain> {
+        tracing::info!(operation = "Get command chain");
+
+        self.provider
+            .find_command_chain(id)?
+            .ok_or(eyre!("Could not find command chain with ID: {}", id))
+            .map_err(Error::not_found)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{
+    definitions::{CommandRevision, CommandRevisionId},
+    services::{FindCommandRevision, StorageService},
+    Error, Result,
+};
+use eyre::eyre;
+
+pub struct GetCommandRevisionOperation<'a, SP>
+where
+    SP: StorageService,
+{
+    pub provider: &'a SP,
+}
+
+impl<F> GetCommandRevisionOperation<'_, F>
+where
+    F: FindCommandRevision,
+{
+    pub fn execute(&self, id: CommandRevisionId) -> Result<CommandRevision> {
+        tracing::info!(operation = "Get command revision");
+
+        self.provider
+            .find_command_revision(id)?
+            .ok_or(eyre!("Could not find command revision with ID: {}", id))
+            .map_err(Error::not_found)
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{
+    definitions::{CommandChain, WorkspaceId},
+    services::{FilterCommandChainsParameters, ListCommandChains, StorageService},
+    Result,
+};
+use std::num::NonZeroU32;
+
+const DEFAULT_PAGE_NUMBER: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(1) };
+const DEFAULT_PAGE_SIZE: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(100) };
+
+pub struct ListCommandChainsOperation<'a, SP>
+where
+    SP: StorageService,
+{
+    pub provider: &'a SP,
+}
+
+pub struct ListCommandChainsParameters {
+    pub page_number: Option<NonZeroU32>,
+    pub page_size: Option<NonZeroU32>,
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+impl<L> ListCommandChainsOperation<'_, L>
+where
+    L: ListCommandChains,
+{
+    pub fn execute(&self, parameters: ListCommandChainsParameters) -> Result<Vec<CommandChain>> {
+        tracing::info!(operation = "List command chains");
+
+        let ListCommandChainsParameters {
+            page_number,
+            page_size,
+            workspace_id,
+        } = parameters;
+
+        let page_number = page_number.unwrap_or(DEFAULT_PAGE_NUMBER).get() - 1;
+        let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE).get();
+
+        self.provider
+            .list_command_chains(FilterCommandChainsParameters {
+                page_number,
+                page_size,
+                workspace_id,
+            })
+    }
+}
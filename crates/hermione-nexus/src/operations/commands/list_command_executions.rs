@@ -0,0 +1,53 @@
+use crate::{
+    definitions::{CommandExecution, CommandId, WorkspaceId},
+    services::{FilterCommandExecutionsParameters, ListCommandExecutions, StorageService},
+    Result,
+};
+use std::num::NonZeroU32;
+
+const DEFAULT_PAGE_NUMBER: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(1) };
+const DEFAULT_PAGE_SIZE: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(100) };
+
+pub struct ListCommandExecutionsOperation<'a, SP>
+where
+    SP: StorageService,
+{
+    pub provider: &'a SP,
+}
+
+pub struct ListCommandExecutionsParameters {
+    pub command_id: Option<CommandId>,
+    pub page_number: Option<NonZeroU32>,
+    pub page_size: Option<NonZeroU32>,
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+impl<L> ListCommandExecutionsOperation<'_, L>
+where
+    L: ListCommandExecutions,
+{
+    pub fn execute(
+        &self,
+        parameters: ListCommandExecutionsParameters,
+    ) -> Result<Vec<CommandExecution>> {
+        tracing::info!(operation = "List command executions");
+
+        let ListCommandExecutionsParameters {
+            command_id,
+            page_number,
+            page_size,
+            workspace_id,
+        } = parameters;
+
+        let page_number = page_number.unwrap_or(DEFAULT_PAGE_NUMBER).get() - 1;
+        let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE).get();
+
+        self.provider
+            .list_command_executions(FilterCommandExecutionsParameters {
+                command_id,
+                page_number,
+                page_size,
+                workspace_id,
+            })
+    }
+}
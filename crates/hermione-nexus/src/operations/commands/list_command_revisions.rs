@@ -0,0 +1,51 @@
+use crate::{
+    definitions::{CommandId, CommandRevision},
+    services::{FilterCommandRevisionsParameters, ListCommandRevisions, StorageService},
+    Result,
+};
+use std::num::NonZeroU32;
+
+const DEFAULT_PAGE_NUMBER: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(1) };
+const DEFAULT_PAGE_SIZE: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(100) };
+
+pub struct ListCommandRevisionsOperation<'a, SP>
+where
+    SP: StorageService,
+{
+    pub provider: &'a SP,
+}
+
+pub struct ListCommandRevisionsParameters {
+    pub command_id: CommandId,
+    pub page_number: Option<NonZeroU32>,
+    pub page_size: Option<NonZeroU32>,
+}
+
+impl<L> ListCommandRevisionsOperation<'_, L>
+where
+    L: ListCommandRevisions,
+{
+    /// Lists revisions of a command, newest first.
+    pub fn execute(
+        &self,
+        parameters: ListCommandRevisionsParameters,
+    ) -> Result<Vec<CommandRevision>> {
+        tracing::info!(operation = "List command revisions");
+
+        let ListCommandRevisionsParameters {
+            command_id,
+            page_number,
+            page_size,
+        } = parameters;
+
+        let page_number = page_number.unwrap_or(DEFAULT_PAGE_NUMBER).get() - 1;
+        let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE).get();
+
+        self.provider
+            .list_command_revisions(FilterCommandRevisionsParameters {
+                command_id,
+                page_number,
+                page_size,
+            })
+    }
+}
@@ -1,12 +1,16 @@
 use crate::{
-    definitions::{Command, WorkspaceId},
-    services::{FilterCommandsParameters, ListCommands, StorageService},
+    definitions::Command,
+    services::{
+        CommandScope, FilterCommandsParameters, ListCommands, SortOrder, StorageService, TagsFilter,
+    },
     Result,
 };
+use chrono::{DateTime, Utc};
 use std::num::NonZeroU32;
 
 const DEFAULT_PAGE_NUMBER: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(1) };
 const DEFAULT_PAGE_SIZE: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(100) };
+const COMMANDS_BATCH_SIZE: u32 = 100;
 
 pub struct ListCommandsOperation<'a, SP>
 where
@@ -16,10 +20,18 @@ where
 }
 
 pub struct ListCommandsParameters<'a> {
+    /// Only commands created after the given time.
+    pub created_after: Option<DateTime<Utc>>,
+    pub description_contains: Option<&'a str>,
     pub page_size: Option<NonZeroU32>,
     pub page_number: Option<NonZeroU32>,
     pub program_contains: Option<&'a str>,
-    pub workspace_id: Option<WorkspaceId>,
+    pub scope: CommandScope,
+    pub sort: SortOrder,
+    pub tags: Option<TagsFilter>,
+
+    /// Only commands modified after the given time.
+    pub updated_after: Option<DateTime<Utc>>,
 }
 
 impl<L> ListCommandsOperation<'_, L>
@@ -30,20 +42,62 @@ where
         tracing::info!(operation = "List commands");
 
         let ListCommandsParameters {
+            created_after,
+            description_contains,
             page_size,
             page_number,
             program_contains,
-            workspace_id,
+            scope,
+            sort,
+            tags,
+            updated_after,
         } = parameters;
 
         let page_number = page_number.unwrap_or(DEFAULT_PAGE_NUMBER).get() - 1;
         let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE).get();
 
         self.provider.list_commands(FilterCommandsParameters {
+            created_after,
+            description_contains,
             program_contains,
             page_number,
             page_size,
-            workspace_id,
+            scope,
+            sort,
+            tags: tags.and_then(TagsFilter::normalize),
+            updated_after,
         })
     }
 }
+
+/// Goes through every page of the scope, oldest commands first.
+pub(crate) fn list_all_commands<LC>(provider: &LC, scope: CommandScope) -> Result<Vec<Command>>
+where
+    LC: ListCommands,
+{
+    let mut commands = Vec::new();
+    let mut page_number = 0;
+
+    loop {
+        let page = provider.list_commands(FilterCommandsParameters {
+            created_after: None,
+            description_contains: None,
+            program_contains: None,
+            page_number,
+            page_size: COMMANDS_BATCH_SIZE,
+            scope,
+            sort: SortOrder::Created,
+            tags: None,
+            updated_after: None,
+        })?;
+
+        if page.is_empty() {
+            break;
+        }
+
+        commands.extend(page);
+        page_number += 1;
+    }
+
+    Ok(commands)
+}
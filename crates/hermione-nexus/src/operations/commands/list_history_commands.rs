@@ -0,0 +1,82 @@
+use crate::{
+    definitions::{HistoryCommand, ShellHistoryFormat},
+    services::{ReadFile, SystemService},
+    Result,
+};
+use std::{collections::HashMap, num::NonZeroU32};
+
+const DEFAULT_LIMIT: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(200) };
+
+pub struct ListHistoryCommandsOperation<'a, RF>
+where
+    RF: SystemService,
+{
+    pub read_file_provider: &'a RF,
+}
+
+pub struct ListHistoryCommandsParameters<'a> {
+    pub sources: Vec<ShellHistorySource<'a>>,
+    pub limit: Option<NonZeroU32>,
+}
+
+pub struct ShellHistorySource<'a> {
+    pub format: ShellHistoryFormat,
+    pub path: &'a str,
+}
+
+struct Occurrences {
+    count: u32,
+    last_position: usize,
+}
+
+impl<RF> ListHistoryCommandsOperation<'_, RF>
+where
+    RF: ReadFile,
+{
+    /// Deduplicates programs of all given history files, missing files are
+    /// skipped. The most frequently run programs come first, ties go to the
+    /// most recently run one.
+    pub fn execute(
+        &self,
+        parameters: ListHistoryCommandsParameters,
+    ) -> Result<Vec<HistoryCommand>> {
+        tracing::info!(operation = "List history commands");
+
+        let ListHistoryCommandsParameters { sources, limit } = parameters;
+
+        let mut occurrences: HashMap<String, Occurrences> = HashMap::new();
+        let mut position = 0;
+
+        for ShellHistorySource { format, path } in sources {
+            let Some(content) = self.read_file_provider.read_file(path)? else {
+                continue;
+            };
+
+            for program in format.parse(&content) {
+                position += 1;
+
+                let entry = occurrences.entry(program).or_insert(Occurrences {
+                    count: 0,
+                    last_position: 0,
+                });
+
+                entry.count += 1;
+                entry.last_position = position;
+            }
+        }
+
+        let mut occurrences = occurrences.into_iter().collect::<Vec<_>>();
+
+        occurrences.sort_by(|(_, a), (_, b)| {
+            b.count
+                .cmp(&a.count)
+                .then(b.last_position.cmp(&a.last_position))
+        });
+
+        Ok(occurrences
+            .into_iter()
+            .take(limit.unwrap_or(DEFAULT_LIMIT).get() as usize)
+            .map(|(program, occurrences)| HistoryCommand::new(program, occurrences.count))
+            .collect())
+    }
+}
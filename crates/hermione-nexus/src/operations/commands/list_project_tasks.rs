@@ -0,0 +1,48 @@
+use crate::{
+    definitions::{ProjectTask, Workspace, WorkspaceId},
+    operations::GetWorkspaceOperation,
+    services::{FindWorkspace, ListProjectTasks, StorageService, SystemService},
+    Error, Result,
+};
+use eyre::eyre;
+
+pub struct ListProjectTasksOperation<'a, FW, LPT>
+where
+    FW: StorageService,
+    LPT: SystemService,
+{
+    pub find_workspace_provider: &'a FW,
+    pub list_project_tasks_provider: &'a LPT,
+}
+
+impl<FW, LPT> ListProjectTasksOperation<'_, FW, LPT>
+where
+    FW: FindWorkspace,
+    LPT: ListProjectTasks,
+{
+    /// Tasks found at the workspace location, the workspace must have one.
+    pub fn execute(&self, workspace_id: WorkspaceId) -> Result<Vec<ProjectTask>> {
+        tracing::info!(operation = "List project tasks");
+
+        let workspace = GetWorkspaceOperation {
+            provider: self.find_workspace_provider,
+        }
+        .execute(workspace_id)?;
+
+        list_workspace_tasks(self.list_project_tasks_provider, &workspace)
+    }
+}
+
+pub(crate) fn list_workspace_tasks<LPT>(
+    provider: &LPT,
+    workspace: &Workspace,
+) -> Result<Vec<ProjectTask>>
+where
+    LPT: ListProjectTasks,
+{
+    let location = workspace.location().ok_or_else(|| {
+        Error::invalid_argument(eyre!("Workspace {} has no location", workspace.name()))
+    })?;
+
+    provider.list_project_tasks(location)
+}
@@ -1,19 +1,55 @@
+mod copy_command;
 mod copy_command_to_clipboard;
 mod create_command;
+mod create_command_chain;
 mod delete_command;
+mod delete_command_chain;
 mod delete_commands;
+mod diff_command_revisions;
 mod execute_command;
+mod execute_command_chain;
 mod execute_program;
 mod get_command;
+mod get_command_chain;
+mod get_command_revision;
+mod list_command_chains;
+mod list_command_executions;
+mod list_command_revisions;
 mod list_commands;
+mod list_history_commands;
+mod list_project_tasks;
+mod move_command;
+mod pin_command;
+mod restore_command_revision;
+mod sync_project_tasks;
+mod sync_repository_commands;
+mod unpin_command;
 mod update_command;
 
+pub use copy_command::*;
 pub use copy_command_to_clipboard::*;
 pub use create_command::*;
+pub use create_command_chain::*;
 pub use delete_command::*;
+pub use delete_command_chain::*;
 pub use delete_commands::*;
+pub use diff_command_revisions::*;
 pub use execute_command::*;
+pub use execute_command_chain::*;
 pub use execute_program::*;
 pub use get_command::*;
+pub use get_command_chain::*;
+pub use get_command_revision::*;
+pub use list_command_chains::*;
+pub use list_command_executions::*;
+pub use list_command_revisions::*;
 pub use list_commands::*;
+pub use list_history_commands::*;
+pub use list_project_tasks::*;
+pub use move_command::*;
+pub use pin_command::*;
+pub use restore_command_revision::*;
+pub use sync_project_tasks::*;
+pub use sync_repository_commands::*;
+pub use unpin_command::*;
 pub use update_command::*;
@@ -0,0 +1,86 @@
+use super::update_command;
+use crate::{
+    definitions::{Command, CommandId, UndoRecord, WorkspaceId},
+    operations::{GetCommandOperation, GetWorkspaceOperation},
+    services::{
+        EditCommandWorkspaceParameters, FindCommand, FindWorkspace, MoveCommand, StorageService,
+    },
+    Result,
+};
+
+pub struct MoveCommandOperation<'a, FCP, FWP, MCP>
+where
+    FCP: StorageService,
+    FWP: StorageService,
+    MCP: StorageService,
+{
+    pub find_command_provider: &'a FCP,
+    pub find_workspace_provider: &'a FWP,
+    pub move_command_provider: &'a MCP,
+}
+
+pub struct MoveCommandParameters {
+    pub id: CommandId,
+
+    /// Keeps execute time and executions of the command, otherwise the
+    /// command starts in the target workspace as if it was never run and its
+    /// executions stay in the history without a reference to it.
+    pub keep_history: bool,
+
+    /// Target workspace, `None` makes the command global.
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+impl<FCP, FWP, MCP> MoveCommandOperation<'_, FCP, FWP, MCP>
+where
+    FCP: FindCommand,
+    FWP: FindWorkspace,
+    MCP: MoveCommand,
+{
+    pub fn execute(&self, parameters: MoveCommandParameters) -> Result<Command> {
+        self.execute_with_undo(parameters)
+            .map(|(command, _undo_record)| command)
+    }
+
+    pub fn execute_with_undo(
+        &self,
+        parameters: MoveCommandParameters,
+    ) -> Result<(Command, UndoRecord)> {
+        tracing::info!(operation = "Move command");
+
+        let MoveCommandParameters {
+            id,
+            keep_history,
+            workspace_id,
+        } = parameters;
+
+        let previous = self.get_command(id)?;
+
+        update_command::ensure_not_repository_managed(&previous)?;
+
+        if let Some(workspace_id) = workspace_id {
+            GetWorkspaceOperation {
+                provider: self.find_workspace_provider,
+            }
+            .execute(workspace_id)?;
+        }
+
+        self.move_command_provider
+            .move_command(EditCommandWorkspaceParameters {
+                id: previous.id(),
+                keep_history,
+                workspace_id,
+            })?;
+
+        let command = self.get_command(id)?;
+
+        Ok((command, UndoRecord::CommandMoved { previous }))
+    }
+
+    fn get_command(&self, id: CommandId) -> Result<Command> {
+        GetCommandOperation {
+            provider: self.find_command_provider,
+        }
+        .execute(id)
+    }
+}
@@ -0,0 +1,66 @@
+use super::{GetCommandOperation, GetCommandRevisionOperation};
+use crate::{
+    definitions::{Command, CommandRevisionId, UndoRecord},
+    operations::{UpdateCommandOperation, UpdateCommandParameters},
+    services::{
+        CreateCommandRevision, FindCommand, FindCommandRevision, StorageService, UpdateCommand,
+    },
+    Result,
+};
+
+pub struct RestoreCommandRevisionOperation<'a, CR, FC, FR, UC>
+where
+    CR: StorageService,
+    FC: StorageService,
+    FR: StorageService,
+    UC: StorageService,
+{
+    pub create_revision_provider: &'a CR,
+    pub find_command_provider: &'a FC,
+    pub find_revision_provider: &'a FR,
+    pub update_command_provider: &'a UC,
+}
+
+impl<CR, FC, FR, UC> RestoreCommandRevisionOperation<'_, CR, FC, FR, UC>
+where
+    CR: CreateCommandRevision,
+    FC: FindCommand,
+    FR: FindCommandRevision,
+    UC: UpdateCommand,
+{
+    pub fn execute(&self, id: CommandRevisionId) -> Result<Command> {
+        self.execute_with_undo(id)
+            .map(|(command, _undo_record)| command)
+    }
+
+    /// Brings back the name and program of the revision. The replaced
+    /// version becomes a revision itself, so restoring can be repeated in
+    /// either direction.
+    pub fn execute_with_undo(&self, id: CommandRevisionId) -> Result<(Command, UndoRecord)> {
+        tracing::info!(operation = "Restore command revision");
+
+        let revision = GetCommandRevisionOperation {
+            provider: self.find_revision_provider,
+        }
+        .execute(id)?;
+
+        let command = GetCommandOperation {
+            provider: self.find_command_provider,
+        }
+        .execute(revision.command_id())?;
+
+        UpdateCommandOperation {
+            create_revision_provider: self.create_revision_provider,
+            find_command_provider: self.find_command_provider,
+            update_command_provider: self.update_command_provider,
+        }
+        .execute_with_undo(UpdateCommandParameters {
+            id: command.id(),
+            description: command.description().map(ToString::to_string),
+            program: revision.program().to_string(),
+            name: revision.name().to_string(),
+            shell: command.shell(),
+            tags: command.tags().to_vec(),
+        })
+    }
+}
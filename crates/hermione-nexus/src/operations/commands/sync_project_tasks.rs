@@ -0,0 +1,152 @@
+use super::{list_commands, list_project_tasks};
+use crate::{
+    definitions::{
+        Command, CommandManager, CommandsSync, UndoRecord, WorkspaceId, PROJECT_TASK_TAG,
+    },
+    operations::GetWorkspaceOperation,
+    services::{
+        CommandScope, FindWorkspace, ListCommands, ListProjectTasks, NewCommandParameters,
+        StorageService, SyncCommands, SyncCommandsParameters, SystemService,
+    },
+    Result,
+};
+use std::collections::HashMap;
+
+pub struct SyncProjectTasksOperation<'a, FW, LC, LPT, SC>
+where
+    FW: StorageService,
+    LC: StorageService,
+    LPT: SystemService,
+    SC: StorageService,
+{
+    pub find_workspace_provider: &'a FW,
+    pub list_commands_provider: &'a LC,
+    pub list_project_tasks_provider: &'a LPT,
+    pub sync_commands_provider: &'a SC,
+}
+
+pub struct SyncProjectTasksParameters {
+    pub workspace_id: WorkspaceId,
+
+    /// Command names of tasks to import in addition to the already
+    /// imported ones, see `ProjectTask::command_name`.
+    pub include: Vec<String>,
+}
+
+impl<FW, LC, LPT, SC> SyncProjectTasksOperation<'_, FW, LC, LPT, SC>
+where
+    FW: FindWorkspace,
+    LC: ListCommands,
+    LPT: ListProjectTasks,
+    SC: SyncCommands,
+{
+    pub fn execute(&self, parameters: SyncProjectTasksParameters) -> Result<CommandsSync> {
+        self.execute_with_undo(parameters)
+            .map(|(sync, _undo_record)| sync)
+    }
+
+    /// Creates commands for the included tasks, updates programs of the
+    /// imported ones when their definition changed and removes those whose
+    /// task is gone. Only commands created by the synchronization are
+    /// touched, all changes are applied at once.
+    pub fn execute_with_undo(
+        &self,
+        parameters: SyncProjectTasksParameters,
+    ) -> Result<(CommandsSync, UndoRecord)> {
+        tracing::info!(operation = "Sync project tasks");
+
+        let SyncProjectTasksParameters {
+            workspace_id,
+            include,
+        } = parameters;
+
+        let workspace = GetWorkspaceOperation {
+            provider: self.find_workspace_provider,
+        }
+        .execute(workspace_id)?;
+
+        let mut imported = list_commands::list_all_commands(
+            self.list_commands_provider,
+            CommandScope::Workspace(workspace_id),
+        )?
+        .into_iter()
+        .filter(|command| command.managed_by() == Some(CommandManager::ProjectTasks))
+        .map(|command| (command.name().to_string(), command))
+        .collect::<HashMap<String, Command>>();
+
+        let mut sync = CommandsSync {
+            created: vec![],
+            updated: vec![],
+            removed: vec![],
+        };
+
+        // Workspaces without imported tasks are left alone, their task
+        // files are not even read.
+        if imported.is_empty() && include.is_empty() {
+            return Ok((
+                sync,
+                UndoRecord::CommandsImported {
+                    created: vec![],
+                    previous: vec![],
+                },
+            ));
+        }
+
+        let tasks =
+            list_project_tasks::list_workspace_tasks(self.list_project_tasks_provider, &workspace)?;
+
+        let mut created = Vec::new();
+        let mut changed = Vec::new();
+
+        for task in tasks {
+            let name = task.command_name();
+
+            if let Some(command) = imported.remove(&name) {
+                if command.program() != task.program() {
+                    let mut update = command.clone();
+                    update.set_program(task.program().to_string())?;
+
+                    changed.push(update);
+                    sync.updated.push(command);
+                }
+
+                continue;
+            }
+
+            if !include.contains(&name) {
+                continue;
+            }
+
+            created.push(NewCommandParameters {
+                description: None,
+                name,
+                program: task.program().to_string(),
+                shell: None,
+                tags: vec![PROJECT_TASK_TAG.to_string()],
+                workspace_id: Some(workspace_id),
+            });
+        }
+
+        sync.removed = imported.into_values().collect();
+        sync.removed
+            .sort_by(|left, right| left.name().cmp(right.name()));
+
+        if !created.is_empty() || !sync.is_empty() {
+            sync.created = self
+                .sync_commands_provider
+                .sync_commands(SyncCommandsParameters {
+                    created,
+                    managed_by: CommandManager::ProjectTasks,
+                    removed: sync.removed.iter().map(Command::id).collect(),
+                    updated: changed,
+                })?
+                .iter()
+                .map(Command::id)
+                .collect();
+        }
+
+        let undo_record = sync.undo_record();
+
+        Ok((sync, undo_record))
+    }
+}
@@ -0,0 +1,130 @@
+use super::list_commands;
+use crate::{
+    definitions::{Command, CommandManager, CommandsSync, RepositoryCommand, WorkspaceId},
+    operations::GetWorkspaceOperation,
+    services::{
+        CommandScope, FindWorkspace, ListCommands, ListRepositoryCommands, NewCommandParameters,
+        StorageService, SyncCommands, SyncCommandsParameters, SystemService,
+    },
+    Result,
+};
+use std::collections::HashMap;
+
+pub struct SyncRepositoryCommandsOperation<'a, FW, LC, LRC, SC>
+where
+    FW: StorageService,
+    LC: StorageService,
+    LRC: SystemService,
+    SC: StorageService,
+{
+    pub find_workspace_provider: &'a FW,
+    pub list_commands_provider: &'a LC,
+    pub list_repository_commands_provider: &'a LRC,
+    pub sync_commands_provider: &'a SC,
+}
+
+impl<FW, LC, LRC, SC> SyncRepositoryCommandsOperation<'_, FW, LC, LRC, SC>
+where
+    FW: FindWorkspace,
+    LC: ListCommands,
+    LRC: ListRepositoryCommands,
+    SC: SyncCommands,
+{
+    /// Makes the repository-managed commands of the workspace match the
+    /// repository command file, commands are matched by name. Without a
+    /// location there is nothing to read and nothing changes, without a
+    /// file every repository-managed command is removed. Only commands
+    /// created by the synchronization are touched, all changes are applied
+    /// at once. The file stays the source of truth, so the sync is not
+    /// undoable.
+    pub fn execute(&self, workspace_id: WorkspaceId) -> Result<CommandsSync> {
+        tracing::info!(operation = "Sync repository commands");
+
+        let workspace = GetWorkspaceOperation {
+            provider: self.find_workspace_provider,
+        }
+        .execute(workspace_id)?;
+
+        let mut sync = CommandsSync {
+            created: vec![],
+            updated: vec![],
+            removed: vec![],
+        };
+
+        let Some(location) = workspace.location() else {
+            return Ok(sync);
+        };
+
+        let declared = self
+            .list_repository_commands_provider
+            .list_repository_commands(location)?
+            .unwrap_or_default();
+
+        let mut managed = list_commands::list_all_commands(
+            self.list_commands_provider,
+            CommandScope::Workspace(workspace_id),
+        )?
+        .into_iter()
+        .filter(|command| command.is_repository_managed())
+        .map(|command| (command.name().to_string(), command))
+        .collect::<HashMap<String, Command>>();
+
+        let mut created = Vec::new();
+        let mut changed = Vec::new();
+
+        for repository_command in declared {
+            if let Some(command) = managed.remove(repository_command.name()) {
+                if !is_up_to_date(&command, &repository_command) {
+                    let mut update = command.clone();
+
+                    update
+                        .set_description(repository_command.description().map(ToString::to_string));
+                    update.set_program(repository_command.program().to_string())?;
+                    update.set_shell(repository_command.shell());
+                    update.set_tags(repository_command.tags().to_vec());
+
+                    changed.push(update);
+                    sync.updated.push(command);
+                }
+
+                continue;
+            }
+
+            created.push(NewCommandParameters {
+                description: repository_command.description().map(ToString::to_string),
+                name: repository_command.name().to_string(),
+                program: repository_command.program().to_string(),
+                shell: repository_command.shell(),
+                tags: repository_command.tags().to_vec(),
+                workspace_id: Some(workspace_id),
+            });
+        }
+
+        sync.removed = managed.into_values().collect();
+        sync.removed
+            .sort_by(|left, right| left.name().cmp(right.name()));
+
+        if !created.is_empty() || !sync.is_empty() {
+            sync.created = self
+                .sync_commands_provider
+                .sync_commands(SyncCommandsParameters {
+                    created,
+                    managed_by: CommandManager::Repository,
+                    removed: sync.removed.iter().map(Command::id).collect(),
+                    updated: changed,
+                })?
+                .iter()
+                .map(Command::id)
+                .collect();
+        }
+
+        Ok(sync)
+    }
+}
+
+fn is_up_to_date(command: &Command, repository_command: &RepositoryCommand) -> bool {
+    command.description() == repository_command.description()
+        && command.program() == repository_command.program()
+        && command.shell() == repository_command.shell()
+        && command.tags() == repository_command.tags()
+}
@@ -0,0 +1,40 @@
+use crate::{
+    definitions::{Command, CommandId},
+    operations::GetCommandOperation,
+    services::{EditCommandPinnedParameters, FindCommand, StorageService, UpdateCommandPinned},
+    Result,
+};
+
+pub struct UnpinCommandOperation<'a, F, U>
+where
+    F: StorageService,
+    U: StorageService,
+{
+    pub find_command_provider: &'a F,
+    pub update_command_provider: &'a U,
+}
+
+impl<F, U> UnpinCommandOperation<'_, F, U>
+where
+    F: FindCommand,
+    U: UpdateCommandPinned,
+{
+    pub fn execute(&self, id: CommandId) -> Result<Command> {
+        tracing::info!(operation = "Unpin command");
+
+        let mut command = GetCommandOperation {
+            provider: self.find_command_provider,
+        }
+        .execute(id)?;
+
+        command.unpin();
+
+        self.update_command_provider
+            .update_command_pinned(EditCommandPinnedParameters {
+                id: command.id(),
+                pinned: command.is_pinned(),
+            })?;
+
+        Ok(command)
+    }
+}
@@ -1,48 +1,97 @@
 use crate::{
-    definitions::{Command, CommandId},
+    definitions::{Command, CommandId, Shell, UndoRecord, REPOSITORY_COMMANDS_FILE},
     operations::GetCommandOperation,
-    services::{EditCommandParameters, FindCommand, StorageService, UpdateCommand},
-    Result,
+    services::{
+        CreateCommandRevision, EditCommandParameters, FindCommand, NewCommandRevisionParameters,
+        StorageService, UpdateCommand,
+    },
+    Error, Result,
 };
+use eyre::eyre;
 
-pub struct UpdateCommandOperation<'a, FW, UW>
+pub struct UpdateCommandOperation<'a, CR, FW, UW>
 where
+    CR: StorageService,
     FW: StorageService,
     UW: StorageService,
 {
+    pub create_revision_provider: &'a CR,
     pub find_command_provider: &'a FW,
     pub update_command_provider: &'a UW,
 }
 
 pub struct UpdateCommandParameters {
     pub id: CommandId,
+    pub description: Option<String>,
     pub program: String,
     pub name: String,
+    pub shell: Option<Shell>,
+    pub tags: Vec<String>,
 }
 
-impl<FW, UW> UpdateCommandOperation<'_, FW, UW>
+impl<CR, FW, UW> UpdateCommandOperation<'_, CR, FW, UW>
 where
+    CR: CreateCommandRevision,
     FW: FindCommand,
     UW: UpdateCommand,
 {
     pub fn execute(&self, parameters: UpdateCommandParameters) -> Result<Command> {
+        self.execute_with_undo(parameters)
+            .map(|(command, _undo_record)| command)
+    }
+
+    /// Keeps the previous name and program as a revision whenever either of
+    /// them changes. The revision is written first, so a failed update never
+    /// loses the previous version. Repository-managed commands are edited in
+    /// their repository command file instead.
+    pub fn execute_with_undo(
+        &self,
+        parameters: UpdateCommandParameters,
+    ) -> Result<(Command, UndoRecord)> {
         tracing::info!(operation = "Update command");
 
-        let UpdateCommandParameters { id, program, name } = parameters;
+        let UpdateCommandParameters {
+            id,
+            description,
+            program,
+            name,
+            shell,
+            tags,
+        } = parameters;
+
+        let previous = self.get_command(id)?;
+
+        ensure_not_repository_managed(&previous)?;
+
+        let mut command = previous.clone();
 
-        let mut command = self.get_command(id)?;
+        command.set_description(description);
+        command.set_program(program)?;
+        command.set_name(name)?;
+        command.set_shell(shell);
+        command.set_tags(tags);
 
-        command.set_program(program);
-        command.set_name(name);
+        if previous.name() != command.name() || previous.program() != command.program() {
+            self.create_revision_provider.create_command_revision(
+                NewCommandRevisionParameters {
+                    command_id: id,
+                    name: previous.name(),
+                    program: previous.program(),
+                },
+            )?;
+        }
 
         self.update_command_provider
             .update_command(EditCommandParameters {
                 id: command.id(),
+                description: command.description(),
                 name: command.name(),
                 program: command.program(),
+                shell: command.shell(),
+                tags: command.tags(),
             })?;
 
-        Ok(command)
+        Ok((command, UndoRecord::CommandUpdated { previous }))
     }
 
     fn get_command(&self, id: CommandId) -> Result<Command> {
@@ -52,3 +101,18 @@ where
         .execute(id)
     }
 }
+
+/// Definitions of repository-managed commands only change through their
+/// repository command file, operations refuse to edit, move or delete them
+/// otherwise. Pinning and copying leave the definition intact.
+pub(crate) fn ensure_not_repository_managed(command: &Command) -> Result<()> {
+    if command.is_repository_managed() {
+        return Err(Error::invalid_argument(eyre!(
+            "Command {} is managed by the {} file of its repository",
+            command.name(),
+            REPOSITORY_COMMANDS_FILE
+        )));
+    }
+
+    Ok(())
+}
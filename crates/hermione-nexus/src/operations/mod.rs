@@ -1,7 +1,15 @@
 mod backup;
+mod bundles;
 mod commands;
+mod secrets;
+mod trash;
+mod undo;
 mod workspaces;
 
 pub use backup::*;
+pub use bundles::*;
 pub use commands::*;
+pub use secrets::*;
+pub use trash::*;
+pub use undo::*;
 pub use workspaces::*;
@@ -0,0 +1,30 @@
+use crate::{
+    services::{DeleteSecret, FindSecret, SecretStore},
+    Error, Result,
+};
+use eyre::eyre;
+
+pub struct DeleteSecretOperation<'a, SSP>
+where
+    SSP: SecretStore,
+{
+    pub provider: &'a SSP,
+}
+
+impl<SSP> DeleteSecretOperation<'_, SSP>
+where
+    SSP: DeleteSecret + FindSecret,
+{
+    pub fn execute(&self, name: &str) -> Result<()> {
+        tracing::info!(operation = "Delete secret");
+
+        if self.provider.find_secret(name)?.is_none() {
+            return Err(Error::not_found(eyre!(
+                "Could not find secret with name: {}",
+                name
+            )));
+        }
+
+        self.provider.delete_secret(name)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{
+    services::{ListSecretNames, SecretStore},
+    Result,
+};
+
+pub struct ListSecretNamesOperation<'a, SSP>
+where
+    SSP: SecretStore,
+{
+    pub provider: &'a SSP,
+}
+
+impl<SSP> ListSecretNamesOperation<'_, SSP>
+where
+    SSP: ListSecretNames,
+{
+    pub fn execute(&self) -> Result<Vec<String>> {
+        tracing::info!(operation = "List secret names");
+
+        self.provider.list_secret_names()
+    }
+}
@@ -0,0 +1,7 @@
+mod delete_secret;
+mod list_secret_names;
+mod save_secret;
+
+pub use delete_secret::*;
+pub use list_secret_names::*;
+pub use save_secret::*;
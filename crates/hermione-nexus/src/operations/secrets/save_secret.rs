@@ -0,0 +1,35 @@
+use crate::{
+    definitions::{Secret, SecretParameters},
+    services::{SaveSecret, SecretStore},
+    Result,
+};
+
+pub struct SaveSecretOperation<'a, SSP>
+where
+    SSP: SecretStore,
+{
+    pub provider: &'a SSP,
+}
+
+pub struct SaveSecretParameters {
+    pub name: String,
+    pub value: String,
+}
+
+impl<SSP> SaveSecretOperation<'_, SSP>
+where
+    SSP: SaveSecret,
+{
+    /// Creates the secret or replaces the value of an existing one.
+    pub fn execute(&self, parameters: SaveSecretParameters) -> Result<Secret> {
+        tracing::info!(operation = "Save secret");
+
+        let SaveSecretParameters { name, value } = parameters;
+
+        let secret = Secret::new(SecretParameters { name, value })?;
+
+        self.provider.save_secret(&secret)?;
+
+        Ok(secret)
+    }
+}
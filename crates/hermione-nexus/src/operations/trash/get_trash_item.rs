@@ -0,0 +1,28 @@
+use eyre::eyre;
+
+use crate::{
+    definitions::{TrashItem, TrashItemId},
+    services::{FindTrashItem, StorageService},
+    Error, Result,
+};
+
+pub struct GetTrashItemOperation<'a, SP>
+where
+    SP: StorageService,
+{
+    pub provider: &'a SP,
+}
+
+impl<F> GetTrashItemOperation<'_, F>
+where
+    F: FindTrashItem,
+{
+    pub fn execute(&self, id: TrashItemId) -> Result<TrashItem> {
+        tracing::info!(operation = "Get trash item");
+
+        self.provider
+            .find_trash_item(id)?
+            .ok_or(eyre!("Could not find {} in trash", id))
+            .map_err(Error::not_found)
+    }
+}
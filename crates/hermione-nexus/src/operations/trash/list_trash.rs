@@ -0,0 +1,43 @@
+use crate::{
+    definitions::TrashItem,
+    services::{FilterTrashParameters, ListTrash, StorageService},
+    Result,
+};
+use std::num::NonZeroU32;
+
+const DEFAULT_PAGE_NUMBER: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(1) };
+const DEFAULT_PAGE_SIZE: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(100) };
+
+pub struct ListTrashOperation<'a, SP>
+where
+    SP: StorageService,
+{
+    pub provider: &'a SP,
+}
+
+pub struct ListTrashParameters {
+    pub page_number: Option<NonZeroU32>,
+    pub page_size: Option<NonZeroU32>,
+}
+
+impl<L> ListTrashOperation<'_, L>
+where
+    L: ListTrash,
+{
+    pub fn execute(&self, parameters: ListTrashParameters) -> Result<Vec<TrashItem>> {
+        tracing::info!(operation = "List trash");
+
+        let ListTrashParameters {
+            page_number,
+            page_size,
+        } = parameters;
+
+        let page_number = page_number.unwrap_or(DEFAULT_PAGE_NUMBER).get() - 1;
+        let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE).get();
+
+        self.provider.list_trash(FilterTrashParameters {
+            page_number,
+            page_size,
+        })
+    }
+}
@@ -0,0 +1,11 @@
+mod get_trash_item;
+mod list_trash;
+mod move_to_trash;
+mod purge_trash;
+mod restore_from_trash;
+
+pub use get_trash_item::*;
+pub use list_trash::*;
+pub use move_to_trash::*;
+pub use purge_trash::*;
+pub use restore_from_trash::*;
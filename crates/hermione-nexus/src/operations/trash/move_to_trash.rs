@@ -0,0 +1,55 @@
+use crate::{
+    definitions::{TrashItemId, UndoRecord},
+    operations::{ensure_not_repository_managed, GetCommandOperation, GetWorkspaceOperation},
+    services::{FindCommand, FindWorkspace, MoveToTrash, StorageService},
+    Result,
+};
+
+/// Hides a command or a workspace until it is restored or purged from the
+/// trash. A workspace is moved to the trash together with its commands.
+pub struct MoveToTrashOperation<'a, FCP, FWP, MTP>
+where
+    FCP: StorageService,
+    FWP: StorageService,
+    MTP: StorageService,
+{
+    pub find_command_provider: &'a FCP,
+    pub find_workspace_provider: &'a FWP,
+    pub move_to_trash_provider: &'a MTP,
+}
+
+impl<FCP, FWP, MTP> MoveToTrashOperation<'_, FCP, FWP, MTP>
+where
+    FCP: FindCommand,
+    FWP: FindWorkspace,
+    MTP: MoveToTrash,
+{
+    pub fn execute(&self, id: TrashItemId) -> Result<()> {
+        self.execute_with_undo(id).map(|_undo_record| ())
+    }
+
+    pub fn execute_with_undo(&self, id: TrashItemId) -> Result<UndoRecord> {
+        tracing::info!(operation = "Move to trash");
+
+        match id {
+            TrashItemId::Command(id) => {
+                let command = GetCommandOperation {
+                    provider: self.find_command_provider,
+                }
+                .execute(id)?;
+
+                ensure_not_repository_managed(&command)?;
+            }
+            TrashItemId::Workspace(id) => {
+                GetWorkspaceOperation {
+                    provider: self.find_workspace_provider,
+                }
+                .execute(id)?;
+            }
+        }
+
+        self.move_to_trash_provider.move_to_trash(id)?;
+
+        Ok(UndoRecord::MovedToTrash(id))
+    }
+}
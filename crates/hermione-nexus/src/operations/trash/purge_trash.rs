@@ -0,0 +1,33 @@
+use super::GetTrashItemOperation;
+use crate::{
+    services::{FindTrashItem, PurgeTrash, PurgeTrashScope, StorageService},
+    Result,
+};
+
+pub struct PurgeTrashOperation<'a, FTP, PTP>
+where
+    FTP: StorageService,
+    PTP: StorageService,
+{
+    pub find_trash_item_provider: &'a FTP,
+    pub purge_trash_provider: &'a PTP,
+}
+
+impl<FTP, PTP> PurgeTrashOperation<'_, FTP, PTP>
+where
+    FTP: FindTrashItem,
+    PTP: PurgeTrash,
+{
+    pub fn execute(&self, scope: PurgeTrashScope) -> Result<()> {
+        tracing::info!(operation = "Purge trash");
+
+        if let PurgeTrashScope::Item(id) = scope {
+            GetTrashItemOperation {
+                provider: self.find_trash_item_provider,
+            }
+            .execute(id)?;
+        }
+
+        self.purge_trash_provider.purge_trash(scope)
+    }
+}
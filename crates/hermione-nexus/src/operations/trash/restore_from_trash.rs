@@ -0,0 +1,34 @@
+use super::GetTrashItemOperation;
+use crate::{
+    definitions::TrashItemId,
+    services::{FindTrashItem, RestoreFromTrash, StorageService},
+    Result,
+};
+
+/// Restoring a workspace brings back the commands that were moved to the
+/// trash together with it.
+pub struct RestoreFromTrashOperation<'a, FTP, RTP>
+where
+    FTP: StorageService,
+    RTP: StorageService,
+{
+    pub find_trash_item_provider: &'a FTP,
+    pub restore_from_trash_provider: &'a RTP,
+}
+
+impl<FTP, RTP> RestoreFromTrashOperation<'_, FTP, RTP>
+where
+    FTP: FindTrashItem,
+    RTP: RestoreFromTrash,
+{
+    pub fn execute(&self, id: TrashItemId) -> Result<()> {
+        tracing::info!(operation = "Restore from trash");
+
+        GetTrashItemOperation {
+            provider: self.find_trash_item_provider,
+        }
+        .execute(id)?;
+
+        self.restore_from_trash_provider.restore_from_trash(id)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    definitions::{Command, UndoRecord, Workspace},
+    operations::{
+        MoveCommandOperation, MoveCommandParameters, RestoreFromTrashOperation,
+        UpdateCommandOperation, UpdateCommandParameters, UpdateWorkspaceOperation,
+        UpdateWorkspaceParameters,
+    },
+    services::{
+        CreateCommandRevision, DeleteCommand, DeleteWorkspace, DeleteWorkspaceCommands,
+        FindCommand, FindTrashItem, FindWorkspace, MoveCommand, RestoreFromTrash, StorageService,
+        UpdateCommand, UpdateWorkspace, UpsertCommands, UpsertWorkspaces,
+    },
+    Result,
+};
+
+/// Replays the inverse of a recorded mutation.
+pub struct ApplyUndoOperation<'a, CP, TP, WP>
+where
+    CP: StorageService,
+    TP: StorageService,
+    WP: StorageService,
+{
+    pub commands_provider: &'a CP,
+    pub trash_provider: &'a TP,
+    pub workspaces_provider: &'a WP,
+}
+
+impl<CP, TP, WP> ApplyUndoOperation<'_, CP, TP, WP>
+where
+    CP: CreateCommandRevision
+        + DeleteCommand
+        + DeleteWorkspaceCommands
+        + FindCommand
+        + MoveCommand
+        + UpdateCommand
+        + UpsertCommands,
+    TP: FindTrashItem + RestoreFromTrash,
+    WP: DeleteWorkspace + FindWorkspace + UpdateWorkspace + UpsertWorkspaces,
+{
+    pub fn execute(&self, record: UndoRecord) -> Result<()> {
+        tracing::info!(operation = "Apply undo");
+
+        match record {
+            UndoRecord::CommandMoved { previous } => self.move_command_back(previous),
+            UndoRecord::CommandUpdated { previous } => self.restore_command(previous),
+            UndoRecord::CommandsImported { created, previous } => {
+                self.commands_provider.upsert_commands(previous)?;
+
+                for id in created {
+                    self.commands_provider.delete_command(id)?;
+                }
+
+                Ok(())
+            }
+            UndoRecord::MovedToTrash(id) => RestoreFromTrashOperation {
+                find_trash_item_provider: self.trash_provider,
+                restore_from_trash_provider: self.trash_provider,
+            }
+            .execute(id),
+            UndoRecord::WorkspaceUpdated { previous } => self.restore_workspace(previous),
+            UndoRecord::WorkspacesImported { created, previous } => {
+                self.workspaces_provider.upsert_workspaces(previous)?;
+
+                for id in created {
+                    self.commands_provider.delete_workspace_commands(id)?;
+                    self.workspaces_provider.delete_workspace(id)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    fn move_command_back(&self, previous: Command) -> Result<()> {
+        MoveCommandOperation {
+            find_command_provider: self.commands_provider,
+            find_workspace_provider: self.workspaces_provider,
+            move_command_provider: self.commands_provider,
+        }
+        .execute(MoveCommandParameters {
+            id: previous.id(),
+            keep_history: true,
+            workspace_id: previous.workspace_id(),
+        })?;
+
+        Ok(())
+    }
+
+    fn restore_command(&self, previous: Command) -> Result<()> {
+        UpdateCommandOperation {
+            create_revision_provider: self.commands_provider,
+            find_command_provider: self.commands_provider,
+            update_command_provider: self.commands_provider,
+        }
+        .execute(UpdateCommandParameters {
+            id: previous.id(),
+            description: previous.description().map(ToString::to_string),
+            program: previous.program().to_string(),
+            name: previous.name().to_string(),
+            shell: previous.shell(),
+            tags: previous.tags().to_vec(),
+        })?;
+
+        Ok(())
+    }
+
+    fn restore_workspace(&self, previous: Workspace) -> Result<()> {
+        UpdateWorkspaceOperation {
+            find_workspace_provider: self.workspaces_provider,
+            update_workspace_provider: self.workspaces_provider,
+        }
+        .execute(UpdateWorkspaceParameters {
+            id: previous.id(),
+            location: previous.location().map(ToString::to_string),
+            name: previous.name().to_string(),
+            parent_id: previous.parent_id(),
+            shell: previous.shell(),
+            tags: previous.tags().to_vec(),
+        })?;
+
+        Ok(())
+    }
+}
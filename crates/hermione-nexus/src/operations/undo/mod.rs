@@ -0,0 +1,3 @@
+mod apply_undo;
+
+pub use apply_undo::*;
@@ -0,0 +1,50 @@
+use crate::{
+    definitions::{self, Workspace, WorkspaceId},
+    operations::GetWorkspaceOperation,
+    services::{CloneWorkspace, FindWorkspace, NewWorkspaceCloneParameters, StorageService},
+    Result,
+};
+
+/// Creates a workspace with a new name and location and copies everything
+/// else, commands included, from the original one. Copied commands start
+/// without execution history.
+pub struct CloneWorkspaceOperation<'a, CWP, FWP>
+where
+    CWP: StorageService,
+    FWP: StorageService,
+{
+    pub clone_workspace_provider: &'a CWP,
+    pub find_workspace_provider: &'a FWP,
+}
+
+pub struct CloneWorkspaceParameters {
+    pub id: WorkspaceId,
+    pub location: Option<String>,
+    pub name: String,
+}
+
+impl<CWP, FWP> CloneWorkspaceOperation<'_, CWP, FWP>
+where
+    CWP: CloneWorkspace,
+    FWP: FindWorkspace,
+{
+    pub fn execute(&self, parameters: CloneWorkspaceParameters) -> Result<Workspace> {
+        tracing::info!(operation = "Clone workspace");
+
+        let CloneWorkspaceParameters { id, location, name } = parameters;
+
+        definitions::validate_workspace(&name, location.as_deref())?;
+
+        let workspace = GetWorkspaceOperation {
+            provider: self.find_workspace_provider,
+        }
+        .execute(id)?;
+
+        self.clone_workspace_provider
+            .clone_workspace(NewWorkspaceCloneParameters {
+                id: workspace.id(),
+                location,
+                name,
+            })
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
-    definitions::Workspace,
-    services::{CreateWorkspace, NewWorkspaceParameters, StorageService},
+    definitions::{self, Shell, Workspace, WorkspaceId},
+    operations::GetWorkspaceOperation,
+    services::{CreateWorkspace, FindWorkspace, NewWorkspaceParameters, StorageService},
     Result,
 };
 
@@ -14,18 +15,42 @@ where
 pub struct CreateWorkspaceParameters {
     pub name: String,
     pub location: Option<String>,
+    pub parent_id: Option<WorkspaceId>,
+    pub shell: Option<Shell>,
+    pub tags: Vec<String>,
 }
 
 impl<CW> CreateWorkspaceOperation<'_, CW>
 where
-    CW: CreateWorkspace,
+    CW: CreateWorkspace + FindWorkspace,
 {
     pub fn execute(&self, parameters: CreateWorkspaceParameters) -> Result<Workspace> {
         tracing::info!(operation = "Create workspace");
 
-        let CreateWorkspaceParameters { name, location } = parameters;
+        let CreateWorkspaceParameters {
+            name,
+            location,
+            parent_id,
+            shell,
+            tags,
+        } = parameters;
+
+        definitions::validate_workspace(&name, location.as_deref())?;
+
+        if let Some(parent_id) = parent_id {
+            GetWorkspaceOperation {
+                provider: self.storage_provider,
+            }
+            .execute(parent_id)?;
+        }
 
         self.storage_provider
-            .create_workspace(NewWorkspaceParameters { name, location })
+            .create_workspace(NewWorkspaceParameters {
+                name,
+                location,
+                parent_id,
+                shell,
+                tags: definitions::normalize_tags(tags),
+            })
     }
 }
@@ -0,0 +1,155 @@
+use super::list_workspaces;
+use crate::{
+    services::{
+        DirectoryEntry, ListDirectory, ListWorkspaces, NewWorkspaceParameters, StorageService,
+        SystemService, WorkspaceScope,
+    },
+    Result,
+};
+use std::collections::HashSet;
+
+const DEFAULT_MAX_DEPTH: u32 = 3;
+const PROJECT_MARKERS: [&str; 4] = [".git", "Cargo.toml", "go.mod", "package.json"];
+const SKIPPED_DIRECTORIES: [&str; 3] = ["node_modules", "target", "vendor"];
+
+/// Walks a directory tree and proposes a workspace for every git repository
+/// or project root found in it. Nothing is stored, the caller decides which
+/// proposals to create.
+pub struct DiscoverWorkspacesOperation<'a, LD, LW>
+where
+    LD: SystemService,
+    LW: StorageService,
+{
+    pub list_directory_provider: &'a LD,
+    pub list_workspaces_provider: &'a LW,
+}
+
+pub struct DiscoverWorkspacesParameters<'a> {
+    pub root: &'a str,
+
+    /// How many directory levels below the root are scanned.
+    pub max_depth: Option<u32>,
+}
+
+impl<LD, LW> DiscoverWorkspacesOperation<'_, LD, LW>
+where
+    LD: ListDirectory,
+    LW: ListWorkspaces,
+{
+    /// Proposals are sorted by location. A root that is a project itself is
+    /// the only proposal. Project roots are not descended into, hidden,
+    /// dependency and unreadable directories are skipped, and locations
+    /// that already belong to a workspace are left out.
+    pub fn execute(
+        &self,
+        parameters: DiscoverWorkspacesParameters,
+    ) -> Result<Vec<NewWorkspaceParameters>> {
+        tracing::info!(operation = "Discover workspaces");
+
+        let DiscoverWorkspacesParameters { root, max_depth } = parameters;
+
+        let known_locations = self.list_known_locations()?;
+        let entries = self.list_directory_provider.list_directory(root)?;
+        let mut project_roots = Vec::new();
+
+        if is_project_root(&entries) {
+            project_roots.push(DirectoryEntry {
+                name: directory_name(root).to_string(),
+                path: root.to_string(),
+                is_directory: true,
+            });
+        } else {
+            self.scan(
+                entries,
+                max_depth.unwrap_or(DEFAULT_MAX_DEPTH),
+                &mut project_roots,
+            );
+        }
+
+        project_roots.retain(|entry| !known_locations.contains(normalize(&entry.path)));
+        project_roots.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(project_roots
+            .into_iter()
+            .map(|entry| NewWorkspaceParameters {
+                name: entry.name,
+                location: Some(entry.path),
+                parent_id: None,
+                shell: None,
+                tags: vec![],
+            })
+            .collect())
+    }
+
+    fn list_known_locations(&self) -> Result<HashSet<String>> {
+        let workspaces = list_workspaces::list_all_workspaces(
+            self.list_workspaces_provider,
+            WorkspaceScope::All,
+        )?;
+
+        Ok(workspaces
+            .iter()
+            .filter_map(|workspace| workspace.location())
+            .map(|location| normalize(location).to_string())
+            .collect())
+    }
+
+    fn scan(
+        &self,
+        entries: Vec<DirectoryEntry>,
+        depth: u32,
+        project_roots: &mut Vec<DirectoryEntry>,
+    ) {
+        if depth == 0 {
+            return;
+        }
+
+        let directories = entries
+            .into_iter()
+            .filter(|entry| entry.is_directory && !is_skipped(&entry.name));
+
+        for directory in directories {
+            let entries = match self.list_directory_provider.list_directory(&directory.path) {
+                Ok(entries) => entries,
+                Err(err) => {
+                    tracing::warn!(path = directory.path, error = ?err, "Skipped unreadable directory");
+                    continue;
+                }
+            };
+
+            if is_project_root(&entries) {
+                project_roots.push(directory);
+            } else {
+                self.scan(entries, depth - 1, project_roots);
+            }
+        }
+    }
+}
+
+fn directory_name(path: &str) -> &str {
+    let path = normalize(path);
+
+    path.rsplit(['/', '\\'])
+        .find(|name| !name.is_empty())
+        .unwrap_or(path)
+}
+
+fn is_project_root(entries: &[DirectoryEntry]) -> bool {
+    entries
+        .iter()
+        .any(|entry| PROJECT_MARKERS.contains(&entry.name.as_str()))
+}
+
+fn is_skipped(name: &str) -> bool {
+    name.starts_with('.') || SKIPPED_DIRECTORIES.contains(&name)
+}
+
+fn normalize(location: &str) -> &str {
+    let trimmed = location.trim_end_matches(['/', '\\']);
+
+    if trimmed.is_empty() {
+        location
+    } else {
+        trimmed
+    }
+}
@@ -0,0 +1,108 @@
+use super::list_workspaces;
+use crate::{
+    definitions::{CheatSheet, CheatSheetParameters, CheatSheetSection, Workspace, WorkspaceId},
+    operations::list_all_commands,
+    services::{
+        CommandScope, ListCommands, ListWorkspaces, StorageService, SystemService, WorkspaceScope,
+        WriteFile,
+    },
+    Error, Result,
+};
+use eyre::eyre;
+use std::collections::HashSet;
+
+const PATH_SEPARATOR: &str = " / ";
+
+pub struct ExportCheatSheetOperation<'a, LC, LW, WF>
+where
+    LC: StorageService,
+    LW: StorageService,
+    WF: SystemService,
+{
+    pub list_commands_provider: &'a LC,
+    pub list_workspaces_provider: &'a LW,
+    pub write_file_provider: &'a WF,
+}
+
+pub struct ExportCheatSheetParameters<'a> {
+    pub workspace_id: WorkspaceId,
+
+    /// File the Markdown is written to, nothing is written without one.
+    pub path: Option<&'a str>,
+}
+
+impl<LC, LW, WF> ExportCheatSheetOperation<'_, LC, LW, WF>
+where
+    LC: ListCommands,
+    LW: ListWorkspaces,
+    WF: WriteFile,
+{
+    /// Collects the workspace and its descendants depth first, children and
+    /// commands ordered by name.
+    pub fn execute(&self, parameters: ExportCheatSheetParameters) -> Result<CheatSheet> {
+        tracing::info!(operation = "Export cheat sheet");
+
+        let ExportCheatSheetParameters { workspace_id, path } = parameters;
+
+        let workspaces = list_workspaces::list_all_workspaces(
+            self.list_workspaces_provider,
+            WorkspaceScope::All,
+        )?;
+
+        let workspace = workspaces
+            .iter()
+            .find(|workspace| workspace.id() == workspace_id)
+            .ok_or_else(|| {
+                Error::not_found(eyre!("Could not find workspace with ID: {}", workspace_id))
+            })?;
+
+        let mut sections = Vec::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![(workspace, workspace.name().to_string())];
+
+        while let Some((workspace, title)) = pending.pop() {
+            if !visited.insert(workspace.id()) {
+                continue;
+            }
+
+            let mut children = workspaces
+                .iter()
+                .filter(|child| child.parent_id() == Some(workspace.id()))
+                .collect::<Vec<&Workspace>>();
+
+            // Reversed, so the stack hands them out in name order.
+            children.sort_by(|left, right| right.name().cmp(left.name()));
+
+            pending.extend(children.into_iter().map(|child| {
+                let title = format!("{}{}{}", title, PATH_SEPARATOR, child.name());
+
+                (child, title)
+            }));
+
+            let mut commands = list_all_commands(
+                self.list_commands_provider,
+                CommandScope::Workspace(workspace.id()),
+            )?;
+            commands.sort_by(|left, right| left.name().cmp(right.name()));
+
+            sections.push(CheatSheetSection {
+                commands,
+                location: workspace.location().map(ToString::to_string),
+                shell: workspace.shell(),
+                title,
+            });
+        }
+
+        let cheat_sheet = CheatSheet::new(CheatSheetParameters {
+            sections,
+            title: workspace.name().to_string(),
+        });
+
+        if let Some(path) = path {
+            self.write_file_provider
+                .write_file(path, &cheat_sheet.render_markdown())?;
+        }
+
+        Ok(cheat_sheet)
+    }
+}
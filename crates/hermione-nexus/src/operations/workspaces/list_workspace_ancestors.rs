@@ -0,0 +1,49 @@
+use crate::{
+    definitions::{Workspace, WorkspaceId},
+    operations::GetWorkspaceOperation,
+    services::{FindWorkspace, StorageService},
+    Result,
+};
+use std::collections::HashSet;
+
+/// Lists ancestors of a workspace starting from its parent up to the root.
+/// The walk stops at a parent that no longer exists.
+pub struct ListWorkspaceAncestorsOperation<'a, SP>
+where
+    SP: StorageService,
+{
+    pub provider: &'a SP,
+}
+
+impl<F> ListWorkspaceAncestorsOperation<'_, F>
+where
+    F: FindWorkspace,
+{
+    pub fn execute(&self, id: WorkspaceId) -> Result<Vec<Workspace>> {
+        tracing::info!(operation = "List workspace ancestors");
+
+        let workspace = GetWorkspaceOperation {
+            provider: self.provider,
+        }
+        .execute(id)?;
+
+        let mut visited = HashSet::from([id]);
+        let mut ancestors = Vec::new();
+        let mut parent_id = workspace.parent_id();
+
+        while let Some(id) = parent_id {
+            if !visited.insert(id) {
+                break;
+            }
+
+            let Some(parent) = self.provider.find_workspace(id)? else {
+                break;
+            };
+
+            parent_id = parent.parent_id();
+            ancestors.push(parent);
+        }
+
+        Ok(ancestors)
+    }
+}
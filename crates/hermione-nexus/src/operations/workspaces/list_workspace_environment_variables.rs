@@ -0,0 +1,24 @@
+use crate::{
+    definitions::{EnvironmentVariable, WorkspaceId},
+    services::{ListWorkspaceEnvironmentVariables, StorageService},
+    Result,
+};
+
+pub struct ListWorkspaceEnvironmentVariablesOperation<'a, SP>
+where
+    SP: StorageService,
+{
+    pub provider: &'a SP,
+}
+
+impl<L> ListWorkspaceEnvironmentVariablesOperation<'_, L>
+where
+    L: ListWorkspaceEnvironmentVariables,
+{
+    pub fn execute(&self, workspace_id: WorkspaceId) -> Result<Vec<EnvironmentVariable>> {
+        tracing::info!(operation = "List workspace environment variables");
+
+        self.provider
+            .list_workspace_environment_variables(workspace_id)
+    }
+}
@@ -1,13 +1,18 @@
+use chrono::{DateTime, Utc};
 use std::num::NonZeroU32;
 
 use crate::{
     definitions::Workspace,
-    services::{FilterWorkspacesParameters, ListWorkspaces, StorageService},
+    services::{
+        FilterWorkspacesParameters, ListWorkspaces, SortOrder, StorageService, TagsFilter,
+        WorkspaceScope,
+    },
     Result,
 };
 
 const DEFAULT_PAGE_NUMBER: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(1) };
 const DEFAULT_PAGE_SIZE: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(100) };
+const WORKSPACES_BATCH_SIZE: u32 = 100;
 
 pub struct ListWorkspacesOperation<'a, SP>
 where
@@ -17,9 +22,17 @@ where
 }
 
 pub struct ListWorkspacesParameters<'a> {
+    /// Only workspaces created after the given time.
+    pub created_after: Option<DateTime<Utc>>,
     pub name_contains: Option<&'a str>,
     pub page_number: Option<NonZeroU32>,
     pub page_size: Option<NonZeroU32>,
+    pub scope: WorkspaceScope,
+    pub sort: SortOrder,
+    pub tags: Option<TagsFilter>,
+
+    /// Only workspaces modified after the given time.
+    pub updated_after: Option<DateTime<Utc>>,
 }
 
 impl<L> ListWorkspacesOperation<'_, L>
@@ -30,18 +43,62 @@ where
         tracing::info!(operation = "List workspaces");
 
         let ListWorkspacesParameters {
+            created_after,
             name_contains,
             page_number,
             page_size,
+            scope,
+            sort,
+            tags,
+            updated_after,
         } = parameters;
 
         let page_number = page_number.unwrap_or(DEFAULT_PAGE_NUMBER).get() - 1;
         let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE).get();
 
         self.provider.list_workspaces(FilterWorkspacesParameters {
+            created_after,
             name_contains,
             page_number,
             page_size,
+            scope,
+            sort,
+            tags: tags.and_then(TagsFilter::normalize),
+            updated_after,
         })
     }
 }
+
+/// Goes through every page of the scope, newest workspaces first.
+pub(crate) fn list_all_workspaces<LW>(
+    provider: &LW,
+    scope: WorkspaceScope,
+) -> Result<Vec<Workspace>>
+where
+    LW: ListWorkspaces,
+{
+    let mut workspaces = Vec::new();
+    let mut page_number = 0;
+
+    loop {
+        let page = provider.list_workspaces(FilterWorkspacesParameters {
+            created_after: None,
+            name_contains: None,
+            page_number,
+            page_size: WORKSPACES_BATCH_SIZE,
+            scope,
+            sort: SortOrder::Created,
+            tags: None,
+            updated_after: None,
+        })?;
+
+        if page.is_empty() {
+            break;
+        }
+
+        workspaces.extend(page);
+        page_number += 1;
+    }
+
+    Ok(workspaces)
+}
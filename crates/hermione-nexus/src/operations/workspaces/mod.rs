@@ -1,13 +1,29 @@
+mod clone_workspace;
 mod create_workspace;
 mod delete_workspace;
+mod discover_workspaces;
+mod export_cheat_sheet;
 mod get_workspace;
+mod list_workspace_ancestors;
+mod list_workspace_environment_variables;
 mod list_workspaces;
+mod pin_workspace;
+mod unpin_workspace;
 mod update_workspace;
+mod update_workspace_environment_variables;
 mod visit_workspace_location;
 
+pub use clone_workspace::*;
 pub use create_workspace::*;
 pub use delete_workspace::*;
+pub use discover_workspaces::*;
+pub use export_cheat_sheet::*;
 pub use get_workspace::*;
+pub use list_workspace_ancestors::*;
+pub use list_workspace_environment_variables::*;
 pub use list_workspaces::*;
+pub use pin_workspace::*;
+pub use unpin_workspace::*;
 pub use update_workspace::*;
+pub use update_workspace_environment_variables::*;
 pub use visit_workspace_location::*;
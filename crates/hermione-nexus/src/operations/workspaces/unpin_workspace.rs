@@ -0,0 +1,42 @@
+use crate::{
+    definitions::{Workspace, WorkspaceId},
+    operations::GetWorkspaceOperation,
+    services::{
+        EditWorkspacePinnedParameters, FindWorkspace, StorageService, UpdateWorkspacePinned,
+    },
+    Result,
+};
+
+pub struct UnpinWorkspaceOperation<'a, F, U>
+where
+    F: StorageService,
+    U: StorageService,
+{
+    pub find_workspace_provider: &'a F,
+    pub update_workspace_provider: &'a U,
+}
+
+impl<F, U> UnpinWorkspaceOperation<'_, F, U>
+where
+    F: FindWorkspace,
+    U: UpdateWorkspacePinned,
+{
+    pub fn execute(&self, id: WorkspaceId) -> Result<Workspace> {
+        tracing::info!(operation = "Unpin workspace");
+
+        let mut workspace = GetWorkspaceOperation {
+            provider: self.find_workspace_provider,
+        }
+        .execute(id)?;
+
+        workspace.unpin();
+
+        self.update_workspace_provider
+            .update_workspace_pinned(EditWorkspacePinnedParameters {
+                id: workspace.id(),
+                pinned: workspace.is_pinned(),
+            })?;
+
+        Ok(workspace)
+    }
+}
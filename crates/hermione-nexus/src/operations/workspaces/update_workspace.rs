@@ -1,9 +1,10 @@
 use crate::{
-    definitions::{Workspace, WorkspaceId},
-    operations::GetWorkspaceOperation,
+    definitions::{Shell, UndoRecord, Workspace, WorkspaceId},
+    operations::{GetWorkspaceOperation, ListWorkspaceAncestorsOperation},
     services::{EditWorkspaceParameters, FindWorkspace, StorageService, UpdateWorkspace},
-    Result,
+    Error, Result,
 };
+use eyre::eyre;
 
 pub struct UpdateWorkspaceOperation<'a, FW, UW>
 where
@@ -18,6 +19,12 @@ pub struct UpdateWorkspaceParameters {
     pub id: WorkspaceId,
     pub location: Option<String>,
     pub name: String,
+
+    /// Nesting a workspace in itself or in one of its descendants is
+    /// rejected as an invalid argument.
+    pub parent_id: Option<WorkspaceId>,
+    pub shell: Option<Shell>,
+    pub tags: Vec<String>,
 }
 
 impl<FW, UW> UpdateWorkspaceOperation<'_, FW, UW>
@@ -26,23 +33,65 @@ where
     UW: UpdateWorkspace,
 {
     pub fn execute(&self, parameters: UpdateWorkspaceParameters) -> Result<Workspace> {
+        self.execute_with_undo(parameters)
+            .map(|(workspace, _undo_record)| workspace)
+    }
+
+    pub fn execute_with_undo(
+        &self,
+        parameters: UpdateWorkspaceParameters,
+    ) -> Result<(Workspace, UndoRecord)> {
         tracing::info!(operation = "Update workspace");
 
-        let UpdateWorkspaceParameters { id, location, name } = parameters;
+        let UpdateWorkspaceParameters {
+            id,
+            location,
+            name,
+            parent_id,
+            shell,
+            tags,
+        } = parameters;
 
-        let mut workspace = self.get_workspace(id)?;
+        let previous = self.get_workspace(id)?;
 
-        workspace.set_location(location);
-        workspace.set_name(name);
+        if let Some(parent_id) = parent_id {
+            self.verify_parent(id, parent_id)?;
+        }
+        let mut workspace = previous.clone();
+
+        workspace.set_location(location)?;
+        workspace.set_name(name)?;
+        workspace.set_parent_id(parent_id);
+        workspace.set_shell(shell);
+        workspace.set_tags(tags);
 
         self.update_workspace_provider
             .update_workspace(EditWorkspaceParameters {
                 id: workspace.id(),
                 name: workspace.name(),
                 location: workspace.location(),
+                parent_id: workspace.parent_id(),
+                shell: workspace.shell(),
+                tags: workspace.tags(),
             })?;
 
-        Ok(workspace)
+        Ok((workspace, UndoRecord::WorkspaceUpdated { previous }))
+    }
+
+    fn verify_parent(&self, id: WorkspaceId, parent_id: WorkspaceId) -> Result<()> {
+        let ancestors = ListWorkspaceAncestorsOperation {
+            provider: self.find_workspace_provider,
+        }
+        .execute(parent_id)?;
+
+        if parent_id == id || ancestors.iter().any(|ancestor| ancestor.id() == id) {
+            return Err(Error::invalid_argument(eyre!(
+                "Workspace {} cannot be nested in itself or its descendant",
+                id
+            )));
+        }
+
+        Ok(())
     }
 
     fn get_workspace(&self, id: WorkspaceId) -> Result<Workspace> {
@@ -0,0 +1,74 @@
+use crate::{
+    definitions::{EnvironmentVariable, EnvironmentVariableParameters, WorkspaceId},
+    operations::GetWorkspaceOperation,
+    services::{
+        EditWorkspaceEnvironmentVariablesParameters, FindWorkspace, StorageService,
+        UpdateWorkspaceEnvironmentVariables,
+    },
+    Error, Result,
+};
+use eyre::eyre;
+
+pub struct UpdateWorkspaceEnvironmentVariablesOperation<'a, FW, UEV>
+where
+    FW: StorageService,
+    UEV: StorageService,
+{
+    pub find_workspace_provider: &'a FW,
+    pub update_environment_variables_provider: &'a UEV,
+}
+
+pub struct UpdateWorkspaceEnvironmentVariablesParameters {
+    pub environment_variables: Vec<EnvironmentVariableParameters>,
+    pub workspace_id: WorkspaceId,
+}
+
+impl<FW, UEV> UpdateWorkspaceEnvironmentVariablesOperation<'_, FW, UEV>
+where
+    FW: FindWorkspace,
+    UEV: UpdateWorkspaceEnvironmentVariables,
+{
+    pub fn execute(
+        &self,
+        parameters: UpdateWorkspaceEnvironmentVariablesParameters,
+    ) -> Result<Vec<EnvironmentVariable>> {
+        tracing::info!(operation = "Update workspace environment variables");
+
+        let UpdateWorkspaceEnvironmentVariablesParameters {
+            environment_variables,
+            workspace_id,
+        } = parameters;
+
+        let workspace = GetWorkspaceOperation {
+            provider: self.find_workspace_provider,
+        }
+        .execute(workspace_id)?;
+
+        let mut variables: Vec<EnvironmentVariable> = Vec::new();
+
+        for parameters in environment_variables {
+            let variable = EnvironmentVariable::new(parameters)?;
+
+            if variables.iter().any(|v| v.name() == variable.name()) {
+                return Err(Error::invalid_argument(eyre!(
+                    "Duplicate environment variable: {}",
+                    variable.name()
+                )));
+            }
+
+            variables.push(variable);
+        }
+
+        variables.sort_by(|a, b| a.name().cmp(b.name()));
+
+        self.update_environment_variables_provider
+            .update_workspace_environment_variables(
+                EditWorkspaceEnvironmentVariablesParameters {
+                    environment_variables: &variables,
+                    workspace_id: workspace.id(),
+                },
+            )?;
+
+        Ok(variables)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     definitions::WorkspaceId,
-    services::{FindWorkspace, SetLocation, StorageService, SystemService},
+    services::{FindWorkspace, SetLocation, SetLocationParameters, StorageService, SystemService},
     Result,
 };
 
@@ -26,6 +26,9 @@ where
         }
         .execute(id)?;
 
-        self.system_provider.set_location(workspace.location())
+        self.system_provider.set_location(SetLocationParameters {
+            location: workspace.location(),
+            shell: workspace.shell(),
+        })
     }
 }
@@ -1,7 +1,9 @@
 mod backup;
+mod secrets;
 mod storage;
 mod system;
 
 pub use backup::*;
+pub use secrets::*;
 pub use storage::*;
 pub use system::*;
@@ -0,0 +1,21 @@
+use crate::{definitions::Secret, Result};
+
+/// Keeps secret values outside of the regular storage. Implementations are
+/// expected to hold values encrypted at rest.
+pub trait SecretStore {}
+
+pub trait DeleteSecret: SecretStore {
+    fn delete_secret(&self, name: &str) -> Result<()>;
+}
+
+pub trait FindSecret: SecretStore {
+    fn find_secret(&self, name: &str) -> Result<Option<Secret>>;
+}
+
+pub trait ListSecretNames: SecretStore {
+    fn list_secret_names(&self) -> Result<Vec<String>>;
+}
+
+pub trait SaveSecret: SecretStore {
+    fn save_secret(&self, secret: &Secret) -> Result<()>;
+}
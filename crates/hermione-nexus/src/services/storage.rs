@@ -1,16 +1,39 @@
 use crate::{
     definitions::{
-        BackupCredentials, BackupProviderKind, Command, CommandId, Workspace, WorkspaceId,
+        self, BackupCredentials, BackupProviderKind, Command, CommandChain, CommandChainId,
+        CommandExecution, CommandId, CommandManager, CommandRevision, CommandRevisionId,
+        EnvironmentVariable, Shell, TrashItem, TrashItemId, Workspace, WorkspaceId,
     },
     Result,
 };
+use chrono::{DateTime, Utc};
+use std::time::Duration;
 
 pub trait StorageService {}
 
+pub trait CloneWorkspace: StorageService {
+    fn clone_workspace(&self, parameters: NewWorkspaceCloneParameters) -> Result<Workspace>;
+}
+
+pub trait CopyCommand: StorageService {
+    fn copy_command(&self, parameters: NewCommandCopyParameters) -> Result<Command>;
+}
+
 pub trait CreateCommand: StorageService {
     fn create_command(&self, parameters: NewCommandParameters) -> Result<Command>;
 }
 
+pub trait CreateCommandChain: StorageService {
+    fn create_command_chain(&self, parameters: NewCommandChainParameters) -> Result<CommandChain>;
+}
+
+pub trait CreateCommandRevision: StorageService {
+    fn create_command_revision(
+        &self,
+        parameters: NewCommandRevisionParameters,
+    ) -> Result<CommandRevision>;
+}
+
 pub trait CreateWorkspace: StorageService {
     fn create_workspace(&self, parameters: NewWorkspaceParameters) -> Result<Workspace>;
 }
@@ -23,6 +46,10 @@ pub trait DeleteCommand: StorageService {
     fn delete_command(&self, id: CommandId) -> Result<()>;
 }
 
+pub trait DeleteCommandChain: StorageService {
+    fn delete_command_chain(&self, id: CommandChainId) -> Result<()>;
+}
+
 pub trait DeleteWorkspaceCommands: StorageService {
     fn delete_workspace_commands(&self, id: WorkspaceId) -> Result<()>;
 }
@@ -42,6 +69,18 @@ pub trait FindCommand: StorageService {
     fn find_command(&self, id: CommandId) -> Result<Option<Command>>;
 }
 
+pub trait FindCommandChain: StorageService {
+    fn find_command_chain(&self, id: CommandChainId) -> Result<Option<CommandChain>>;
+}
+
+pub trait FindCommandRevision: StorageService {
+    fn find_command_revision(&self, id: CommandRevisionId) -> Result<Option<CommandRevision>>;
+}
+
+pub trait FindTrashItem: StorageService {
+    fn find_trash_item(&self, id: TrashItemId) -> Result<Option<TrashItem>>;
+}
+
 pub trait FindWorkspace: StorageService {
     fn find_workspace(&self, id: WorkspaceId) -> Result<Option<Workspace>>;
 }
@@ -50,18 +89,77 @@ pub trait ListBackupCredentials: StorageService {
     fn list_backup_credentials(&self) -> Result<Vec<BackupCredentials>>;
 }
 
+pub trait ListCommandChains: StorageService {
+    fn list_command_chains(
+        &self,
+        parameters: FilterCommandChainsParameters,
+    ) -> Result<Vec<CommandChain>>;
+}
+
+pub trait ListCommandExecutions: StorageService {
+    fn list_command_executions(
+        &self,
+        parameters: FilterCommandExecutionsParameters,
+    ) -> Result<Vec<CommandExecution>>;
+}
+
+pub trait ListCommandRevisions: StorageService {
+    fn list_command_revisions(
+        &self,
+        parameters: FilterCommandRevisionsParameters,
+    ) -> Result<Vec<CommandRevision>>;
+}
+
 pub trait ListCommands: StorageService {
     fn list_commands(&self, parameters: FilterCommandsParameters) -> Result<Vec<Command>>;
 }
 
+pub trait ListTrash: StorageService {
+    fn list_trash(&self, parameters: FilterTrashParameters) -> Result<Vec<TrashItem>>;
+}
+
+pub trait ListWorkspaceEnvironmentVariables: StorageService {
+    fn list_workspace_environment_variables(
+        &self,
+        workspace_id: WorkspaceId,
+    ) -> Result<Vec<EnvironmentVariable>>;
+}
+
 pub trait ListWorkspaces: StorageService {
     fn list_workspaces(&self, parameters: FilterWorkspacesParameters) -> Result<Vec<Workspace>>;
 }
 
+pub trait MoveCommand: StorageService {
+    fn move_command(&self, parameters: EditCommandWorkspaceParameters) -> Result<()>;
+}
+
+pub trait MoveToTrash: StorageService {
+    fn move_to_trash(&self, id: TrashItemId) -> Result<()>;
+}
+
+pub trait PurgeTrash: StorageService {
+    fn purge_trash(&self, scope: PurgeTrashScope) -> Result<()>;
+}
+
+pub trait RestoreFromTrash: StorageService {
+    fn restore_from_trash(&self, id: TrashItemId) -> Result<()>;
+}
+
 pub trait SaveBackupCredentials: StorageService {
     fn save_backup_credentials(&self, credentials: &BackupCredentials) -> Result<()>;
 }
 
+/// Applies all changes of a synchronization or none of them. Created
+/// commands are marked with the manager of the synchronization and given
+/// back in the order of their parameters.
+pub trait SyncCommands: StorageService {
+    fn sync_commands(&self, parameters: SyncCommandsParameters) -> Result<Vec<Command>>;
+}
+
+pub trait TrackCommandExecution: StorageService {
+    fn track_command_execution(&self, parameters: NewCommandExecutionParameters) -> Result<()>;
+}
+
 pub trait TrackCommandExecuteTime: StorageService {
     fn track_command_execute_time(&self, command_id: CommandId) -> Result<()>;
 }
@@ -74,10 +172,25 @@ pub trait UpdateCommand: StorageService {
     fn update_command(&self, parameters: EditCommandParameters) -> Result<()>;
 }
 
+pub trait UpdateCommandPinned: StorageService {
+    fn update_command_pinned(&self, parameters: EditCommandPinnedParameters) -> Result<()>;
+}
+
 pub trait UpdateWorkspace: StorageService {
     fn update_workspace(&self, workspace: EditWorkspaceParameters) -> Result<()>;
 }
 
+pub trait UpdateWorkspaceEnvironmentVariables: StorageService {
+    fn update_workspace_environment_variables(
+        &self,
+        parameters: EditWorkspaceEnvironmentVariablesParameters,
+    ) -> Result<()>;
+}
+
+pub trait UpdateWorkspacePinned: StorageService {
+    fn update_workspace_pinned(&self, parameters: EditWorkspacePinnedParameters) -> Result<()>;
+}
+
 pub trait UpsertCommands: StorageService {
     fn upsert_commands(&self, commands: Vec<Command>) -> Result<()>;
 }
@@ -88,36 +201,229 @@ pub trait UpsertWorkspaces: StorageService {
 
 pub struct EditCommandParameters<'a> {
     pub id: CommandId,
+    pub description: Option<&'a str>,
     pub name: &'a str,
     pub program: &'a str,
+    pub shell: Option<Shell>,
+    pub tags: &'a [String],
+}
+
+pub struct EditCommandPinnedParameters {
+    pub id: CommandId,
+    pub pinned: bool,
+}
+
+pub struct EditCommandWorkspaceParameters {
+    pub id: CommandId,
+    pub keep_history: bool,
+    pub workspace_id: Option<WorkspaceId>,
 }
 
 pub struct EditWorkspaceParameters<'a> {
     pub id: WorkspaceId,
     pub name: &'a str,
     pub location: Option<&'a str>,
+    pub parent_id: Option<WorkspaceId>,
+    pub shell: Option<Shell>,
+    pub tags: &'a [String],
+}
+
+pub struct EditWorkspaceEnvironmentVariablesParameters<'a> {
+    pub environment_variables: &'a [EnvironmentVariable],
+    pub workspace_id: WorkspaceId,
+}
+
+pub struct EditWorkspacePinnedParameters {
+    pub id: WorkspaceId,
+    pub pinned: bool,
+}
+
+pub struct FilterCommandChainsParameters {
+    pub page_number: u32,
+    pub page_size: u32,
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+pub struct FilterCommandExecutionsParameters {
+    pub command_id: Option<CommandId>,
+    pub page_number: u32,
+    pub page_size: u32,
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+pub struct FilterCommandRevisionsParameters {
+    pub command_id: CommandId,
+    pub page_number: u32,
+    pub page_size: u32,
 }
 
 pub struct FilterCommandsParameters<'a> {
+    pub created_after: Option<DateTime<Utc>>,
+    pub description_contains: Option<&'a str>,
     pub program_contains: Option<&'a str>,
     pub page_number: u32,
     pub page_size: u32,
-    pub workspace_id: Option<WorkspaceId>,
+    pub scope: CommandScope,
+    pub sort: SortOrder,
+    pub tags: Option<TagsFilter>,
+    pub updated_after: Option<DateTime<Utc>>,
+}
+
+pub struct FilterTrashParameters {
+    pub page_number: u32,
+    pub page_size: u32,
 }
 
 pub struct FilterWorkspacesParameters<'a> {
+    pub created_after: Option<DateTime<Utc>>,
     pub name_contains: Option<&'a str>,
     pub page_number: u32,
     pub page_size: u32,
+    pub scope: WorkspaceScope,
+    pub sort: SortOrder,
+    pub tags: Option<TagsFilter>,
+    pub updated_after: Option<DateTime<Utc>>,
+}
+
+pub struct NewCommandChainParameters {
+    pub name: String,
+    pub steps: Vec<CommandId>,
+    pub workspace_id: WorkspaceId,
+}
+
+pub struct NewCommandCopyParameters {
+    pub id: CommandId,
+    pub keep_history: bool,
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+pub struct NewCommandExecutionParameters<'a> {
+    pub command_id: CommandId,
+    pub location: Option<&'a str>,
+    pub program: &'a str,
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+pub struct NewCommandRevisionParameters<'a> {
+    pub command_id: CommandId,
+    pub name: &'a str,
+    pub program: &'a str,
 }
 
 pub struct NewCommandParameters {
+    pub description: Option<String>,
     pub name: String,
     pub program: String,
-    pub workspace_id: WorkspaceId,
+    pub shell: Option<Shell>,
+    pub tags: Vec<String>,
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+pub struct NewWorkspaceCloneParameters {
+    pub id: WorkspaceId,
+    pub location: Option<String>,
+    pub name: String,
 }
 
 pub struct NewWorkspaceParameters {
     pub name: String,
     pub location: Option<String>,
+    pub parent_id: Option<WorkspaceId>,
+    pub shell: Option<Shell>,
+    pub tags: Vec<String>,
+}
+
+pub struct SyncCommandsParameters {
+    pub created: Vec<NewCommandParameters>,
+    pub managed_by: CommandManager,
+    pub removed: Vec<CommandId>,
+    pub updated: Vec<Command>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CommandScope {
+    /// Commands of every workspace together with global commands.
+    #[default]
+    All,
+
+    /// Commands that do not belong to any workspace.
+    Global,
+
+    /// Commands of the given workspace only.
+    Workspace(WorkspaceId),
+
+    /// Commands of the given workspace together with global commands.
+    WorkspaceAndGlobal(WorkspaceId),
+
+    /// Commands of the given workspace and its ancestors together with
+    /// global commands.
+    Inherited(WorkspaceId),
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WorkspaceScope {
+    /// Every workspace regardless of its place in the hierarchy.
+    #[default]
+    All,
+
+    /// Workspaces without a parent, including the ones whose parent is gone.
+    Roots,
+
+    /// Direct children of the given workspace.
+    Children(WorkspaceId),
+}
+
+#[derive(Clone, Copy)]
+pub enum PurgeTrashScope {
+    /// Everything in the trash.
+    All,
+
+    /// A single trash item. Purging a workspace purges its commands too.
+    Item(TrashItemId),
+
+    /// Items that have been in the trash for longer than the given period.
+    OlderThan(Duration),
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortOrder {
+    /// Most recently created items first.
+    Created,
+
+    /// Usage count weighted by how recently the item was used.
+    Frecency,
+
+    /// Most recently used items first.
+    #[default]
+    Recency,
+
+    /// Most recently modified items first.
+    Updated,
+}
+
+#[derive(Clone)]
+pub enum TagsFilter {
+    AllOf(Vec<String>),
+    AnyOf(Vec<String>),
+}
+
+impl TagsFilter {
+    pub fn tags(&self) -> &[String] {
+        match self {
+            TagsFilter::AllOf(tags) | TagsFilter::AnyOf(tags) => tags,
+        }
+    }
+
+    pub(crate) fn normalize(self) -> Option<Self> {
+        let filter = match self {
+            TagsFilter::AllOf(tags) => TagsFilter::AllOf(definitions::normalize_tags(tags)),
+            TagsFilter::AnyOf(tags) => TagsFilter::AnyOf(definitions::normalize_tags(tags)),
+        };
+
+        if filter.tags().is_empty() {
+            return None;
+        }
+
+        Some(filter)
+    }
 }
@@ -1,20 +1,100 @@
-use crate::Result;
+use crate::{
+    definitions::{Bundle, EnvironmentVariable, ProjectTask, RepositoryCommand, Shell},
+    Result,
+};
 
 pub trait SystemService {}
 
+pub struct DirectoryEntry {
+    pub name: String,
+
+    /// Full path of the entry, usable as a workspace location.
+    pub path: String,
+    pub is_directory: bool,
+}
+
 pub struct InvokeCommandParameters<'a> {
     pub command: &'a str,
+    pub environment_variables: &'a [EnvironmentVariable],
     pub location: Option<&'a str>,
+    pub shell: Option<Shell>,
+}
+
+pub struct InvokeCommandChainParameters<'a> {
+    pub steps: &'a [InvokeCommandChainStep<'a>],
+
+    /// Shell that starts the steps one after another.
+    pub shell: Option<Shell>,
+}
+
+pub struct InvokeCommandChainStep<'a> {
+    pub command: &'a str,
+    pub environment_variables: &'a [EnvironmentVariable],
+    pub location: Option<&'a str>,
+    pub shell: Option<Shell>,
+}
+
+pub struct SetLocationParameters<'a> {
+    pub location: Option<&'a str>,
+    pub shell: Option<Shell>,
 }
 
 pub trait InvokeCommand: SystemService {
     fn invoke_command(&self, parameters: InvokeCommandParameters) -> Result<()>;
 }
 
+/// Runs the steps one after another, each in a separate process of its own
+/// shell and in its own location. The first failing step stops the rest.
+pub trait InvokeCommandChain: SystemService {
+    fn invoke_command_chain(&self, parameters: InvokeCommandChainParameters) -> Result<()>;
+}
+
+/// Lists entries of a directory in no particular order. A leading `~`
+/// stands for the user home directory.
+pub trait ListDirectory: SystemService {
+    fn list_directory(&self, path: &str) -> Result<Vec<DirectoryEntry>>;
+}
+
+/// Tasks defined by the well-known project files at the root of the
+/// location, files that do not exist are skipped.
+pub trait ListProjectTasks: SystemService {
+    fn list_project_tasks(&self, location: &str) -> Result<Vec<ProjectTask>>;
+}
+
+/// Reads the repository command file at the location, `None` when the
+/// location has none.
+pub trait ListRepositoryCommands: SystemService {
+    fn list_repository_commands(&self, location: &str) -> Result<Option<Vec<RepositoryCommand>>>;
+}
+
+/// Reads a bundle file written by `WriteBundle`. A leading `~` stands for
+/// the user home directory.
+pub trait ReadBundle: SystemService {
+    fn read_bundle(&self, path: &str) -> Result<Bundle>;
+}
+
+/// Reads raw file content, `None` when the file does not exist. A leading
+/// `~` stands for the user home directory.
+pub trait ReadFile: SystemService {
+    fn read_file(&self, path: &str) -> Result<Option<Vec<u8>>>;
+}
+
 pub trait SetClipboardContent: SystemService {
     fn set_clipboard_content(&self, text: &str) -> Result<()>;
 }
 
 pub trait SetLocation: SystemService {
-    fn set_location(&self, location: Option<&str>) -> Result<()>;
+    fn set_location(&self, parameters: SetLocationParameters) -> Result<()>;
+}
+
+/// Writes the bundle to a file, replacing an existing one. A leading `~`
+/// stands for the user home directory.
+pub trait WriteBundle: SystemService {
+    fn write_bundle(&self, path: &str, bundle: &Bundle) -> Result<()>;
+}
+
+/// Writes text content to a file, replacing an existing one. A leading `~`
+/// stands for the user home directory.
+pub trait WriteFile: SystemService {
+    fn write_file(&self, path: &str, content: &str) -> Result<()>;
 }
@@ -0,0 +1,193 @@
+mod test_case;
+
+use crate::support::{
+    self, CommandFixture, ExpectedCommand, ExpectedTrashState, InMemoryStorage, NotionCommand,
+    WorkspaceFixture,
+};
+use hermione_nexus::{
+    definitions::TrashItemId,
+    operations::{MoveCommandParameters, UpdateCommandParameters},
+};
+use test_case::{Background, BackgroundContext};
+
+fn background_context<'a>() -> BackgroundContext<'a> {
+    BackgroundContext {
+        workspaces: vec![
+            WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            WorkspaceFixture {
+                id: "2b7e1ac6-7b4f-4c63-8f8c-3a4cf2d7a5e1",
+                name: "Hulk",
+                parent_id: None,
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+        ],
+        commands: vec![CommandFixture {
+            id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            description: None,
+            managed_by: None,
+            name: "Ping",
+            pinned: false,
+            program: "ping 1.1.1.1",
+            last_execute_time: None,
+            workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            shell: None,
+            tags: vec!["network"],
+        }],
+        notion_commands: vec![
+            NotionCommand {
+                external_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b".to_string(),
+                name: "Ping".to_string(),
+                program: "ping 8.8.8.8".to_string(),
+                workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa".to_string(),
+            },
+            NotionCommand {
+                external_id: "8c1ea1c3-3d2b-4a4e-9d6f-57b1dc1c5f70".to_string(),
+                name: "Date".to_string(),
+                program: "date".to_string(),
+                workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa".to_string(),
+            },
+        ],
+    }
+}
+
+fn expected_command<'a>() -> ExpectedCommand<'a> {
+    ExpectedCommand {
+        id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        description: None,
+        name: "Ping",
+        pinned: false,
+        program: "ping 1.1.1.1",
+        last_execute_time: None,
+        workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+        tags: vec!["network"],
+    }
+}
+
+fn new_background() -> Background {
+    Background {
+        notion_storage: Default::default(),
+        storage: InMemoryStorage::empty(),
+    }
+}
+
+#[test]
+fn test_apply_undo_operation_restores_updated_command() {
+    let background = new_background();
+
+    test_case::setup(&background, background_context());
+
+    let undo_record = test_case::update_command(
+        &background,
+        UpdateCommandParameters {
+            id: support::parse_command_id("51280bfc-2eea-444a-8df9-a1e7158c2c6b"),
+            description: Some("Typo".to_string()),
+            program: "pnig 1.1.1.1".to_string(),
+            name: "Pnig".to_string(),
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(&background, undo_record);
+
+    assert!(operation_result.is_ok());
+
+    let command = support::get_command(
+        &background.storage,
+        support::parse_command_id("51280bfc-2eea-444a-8df9-a1e7158c2c6b"),
+    );
+
+    support::assert_command(command, expected_command());
+}
+
+#[test]
+fn test_apply_undo_operation_moves_command_back() {
+    let background = new_background();
+
+    test_case::setup(&background, background_context());
+
+    let undo_record = test_case::move_command(
+        &background,
+        MoveCommandParameters {
+            id: support::parse_command_id("51280bfc-2eea-444a-8df9-a1e7158c2c6b"),
+            keep_history: true,
+            workspace_id: Some(support::parse_workspace_id(
+                "2b7e1ac6-7b4f-4c63-8f8c-3a4cf2d7a5e1",
+            )),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(&background, undo_record);
+
+    assert!(operation_result.is_ok());
+
+    let command = support::get_command(
+        &background.storage,
+        support::parse_command_id("51280bfc-2eea-444a-8df9-a1e7158c2c6b"),
+    );
+
+    support::assert_command(command, expected_command());
+}
+
+#[test]
+fn test_apply_undo_operation_restores_trashed_workspace() {
+    let background = new_background();
+
+    test_case::setup(&background, background_context());
+
+    let undo_record = test_case::move_to_trash(
+        &background,
+        TrashItemId::Workspace(support::parse_workspace_id(
+            "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        )),
+    );
+
+    let operation_result = test_case::execute_operation(&background, undo_record);
+
+    assert!(operation_result.is_ok());
+
+    support::assert_trash_state(
+        &background.storage,
+        ExpectedTrashState {
+            command_ids: vec!["51280bfc-2eea-444a-8df9-a1e7158c2c6b"],
+            trashed_command_ids: vec![],
+            trashed_workspace_ids: vec![],
+            workspace_ids: vec![
+                "2b7e1ac6-7b4f-4c63-8f8c-3a4cf2d7a5e1",
+                "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_apply_undo_operation_reverts_commands_import() {
+    let background = new_background();
+
+    test_case::setup(&background, background_context());
+
+    let undo_record = test_case::import_commands(&background);
+
+    assert_eq!(background.storage.list_commands().unwrap().len(), 2);
+
+    let operation_result = test_case::execute_operation(&background, undo_record);
+
+    assert!(operation_result.is_ok());
+
+    let commands = background.storage.list_commands().unwrap();
+
+    support::assert_commands(commands, vec![expected_command()]);
+}
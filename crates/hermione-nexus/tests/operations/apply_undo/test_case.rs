@@ -0,0 +1,123 @@
+use crate::support::{
+    self, CommandFixture, InMemoryStorage, MockNotionBuilder, MockNotionStorage,
+    NotionBackupCredentialsFixture, NotionCommand, WorkspaceFixture,
+};
+use hermione_nexus::{
+    definitions::{BackupProviderKind, TrashItemId, UndoRecord},
+    operations::{
+        ApplyUndoOperation, ImportCommandsOperation, ImportCommandsOperationParameters,
+        MoveCommandOperation, MoveCommandParameters, MoveToTrashOperation, UpdateCommandOperation,
+        UpdateCommandParameters,
+    },
+    Result,
+};
+use std::rc::Rc;
+
+pub struct Background {
+    pub notion_storage: Rc<MockNotionStorage>,
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub workspaces: Vec<WorkspaceFixture<'a>>,
+    pub commands: Vec<CommandFixture<'a>>,
+    pub notion_commands: Vec<NotionCommand>,
+}
+
+pub fn execute_operation(background: &Background, undo_record: UndoRecord) -> Result<()> {
+    let Background { storage, .. } = background;
+
+    ApplyUndoOperation {
+        commands_provider: storage,
+        trash_provider: storage,
+        workspaces_provider: storage,
+    }
+    .execute(undo_record)
+}
+
+pub fn import_commands(background: &Background) -> UndoRecord {
+    let Background {
+        notion_storage,
+        storage,
+    } = background;
+
+    ImportCommandsOperation::new(ImportCommandsOperationParameters {
+        backup_credentials_provider: storage,
+        upsert_commands_provider: storage,
+        backup_provider_builder: &MockNotionBuilder {
+            storage: notion_storage.clone(),
+        },
+    })
+    .execute_with_undo(BackupProviderKind::Notion)
+    .unwrap()
+}
+
+pub fn move_command(background: &Background, parameters: MoveCommandParameters) -> UndoRecord {
+    let Background { storage, .. } = background;
+
+    let (_command, undo_record) = MoveCommandOperation {
+        find_command_provider: storage,
+        find_workspace_provider: storage,
+        move_command_provider: storage,
+    }
+    .execute_with_undo(parameters)
+    .unwrap();
+
+    undo_record
+}
+
+pub fn move_to_trash(background: &Background, id: TrashItemId) -> UndoRecord {
+    let Background { storage, .. } = background;
+
+    MoveToTrashOperation {
+        find_command_provider: storage,
+        find_workspace_provider: storage,
+        move_to_trash_provider: storage,
+    }
+    .execute_with_undo(id)
+    .unwrap()
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let Background {
+        notion_storage,
+        storage,
+    } = background;
+
+    let BackgroundContext {
+        workspaces,
+        commands,
+        notion_commands,
+    } = context;
+
+    support::insert_workspaces(storage, workspaces);
+    support::insert_commands(storage, commands);
+    support::insert_notion_backup_credentials(
+        storage,
+        NotionBackupCredentialsFixture {
+            api_key: "test_api_key",
+            commands_database_id: "test_commands_database_id",
+            workspaces_database_id: "test_workspaces_database_id",
+        },
+    );
+
+    let mut commands = notion_storage.commands.write().unwrap();
+
+    for command in notion_commands {
+        commands.insert(command.external_id.clone(), command);
+    }
+}
+
+pub fn update_command(background: &Background, parameters: UpdateCommandParameters) -> UndoRecord {
+    let Background { storage, .. } = background;
+
+    let (_command, undo_record) = UpdateCommandOperation {
+        create_revision_provider: storage,
+        find_command_provider: storage,
+        update_command_provider: storage,
+    }
+    .execute_with_undo(parameters)
+    .unwrap();
+
+    undo_record
+}
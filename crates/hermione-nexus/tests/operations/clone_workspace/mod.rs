@@ -0,0 +1,114 @@
+mod test_case;
+
+use crate::support::{CommandFixture, InMemoryStorage, WorkspaceFixture};
+use test_case::{Background, BackgroundContext, ExpectedClone, ExpectedOperationResult};
+
+fn background_context<'a>() -> BackgroundContext<'a> {
+    BackgroundContext {
+        workspace: WorkspaceFixture {
+            id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            name: "Ironman",
+            parent_id: None,
+            pinned: true,
+            location: Some("/home/ironman"),
+            last_access_time: Some("2024-11-17 20:00:00"),
+            shell: None,
+            tags: vec!["rust"],
+        },
+        environment_variables: vec![("RUST_LOG", "info")],
+        commands: vec![
+            CommandFixture {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: None,
+                name: "Build",
+                pinned: false,
+                program: "cargo build",
+                last_execute_time: Some("2024-11-17 20:00:00"),
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            },
+            CommandFixture {
+                id: "7cdc1f5c-0d28-4f4b-9c47-39a8b2e1d6a0",
+                description: None,
+                managed_by: None,
+                name: "Test",
+                pinned: false,
+                program: "cargo test",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            },
+            CommandFixture {
+                id: "0c3c6a4e-1d1f-4b0a-bb0e-3c6bd2f7e1a2",
+                description: None,
+                managed_by: None,
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: None,
+                shell: None,
+                tags: vec![],
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_clone_workspace_operation_copies_commands() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        "Warmachine",
+        Some("/home/warmachine"),
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_clone: ExpectedClone {
+                name: "Warmachine",
+                location: Some("/home/warmachine"),
+                tags: vec!["rust"],
+                environment_variables: vec![("RUST_LOG", "info")],
+                programs: vec!["cargo build", "cargo test"],
+            },
+        },
+    );
+
+    test_case::assert_storage_counts(&background, 2, 5);
+}
+
+#[test]
+fn test_clone_workspace_operation_fails_for_missing_workspace() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        "0b1e5a0a-5c43-4bbb-8a0e-7a2b7c9b1f11",
+        "Warmachine",
+        None,
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::NotFound,
+    );
+
+    test_case::assert_storage_counts(&background, 1, 3);
+}
@@ -0,0 +1,138 @@
+use crate::support::{self, CommandFixture, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::{
+    definitions::Workspace,
+    operations::{CloneWorkspaceOperation, CloneWorkspaceParameters},
+    Result,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub workspace: WorkspaceFixture<'a>,
+    pub environment_variables: Vec<(&'a str, &'a str)>,
+    pub commands: Vec<CommandFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    NotFound,
+    Success { expected_clone: ExpectedClone<'a> },
+}
+
+pub struct ExpectedClone<'a> {
+    pub name: &'a str,
+    pub location: Option<&'a str>,
+    pub tags: Vec<&'a str>,
+    pub environment_variables: Vec<(&'a str, &'a str)>,
+    pub programs: Vec<&'a str>,
+}
+
+pub fn assert_operation_result(
+    background: &Background,
+    result: Result<Workspace>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::NotFound => {
+            assert!(result.is_err_and(|err| err.is_not_found()))
+        }
+        ExpectedOperationResult::Success { expected_clone } => {
+            assert!(result.is_ok());
+
+            let clone = result.unwrap();
+            let ExpectedClone {
+                name,
+                location,
+                tags,
+                environment_variables,
+                programs,
+            } = expected_clone;
+
+            assert_eq!(clone.name(), name);
+            assert_eq!(clone.location(), location);
+            assert_eq!(clone.tags(), support::to_strings(tags));
+            assert!(!clone.is_pinned());
+            assert!(clone.last_access_time().is_none());
+
+            let clone_environment_variables: Vec<(String, String)> = background
+                .storage
+                .get_environment_variables(clone.id())
+                .unwrap()
+                .into_iter()
+                .map(|variable| (variable.name().to_string(), variable.value().to_string()))
+                .collect();
+
+            assert_eq!(
+                clone_environment_variables,
+                environment_variables
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect::<Vec<_>>()
+            );
+
+            let mut clone_commands: Vec<_> = background
+                .storage
+                .list_commands()
+                .unwrap()
+                .into_iter()
+                .filter(|command| command.workspace_id() == Some(clone.id()))
+                .collect();
+
+            clone_commands.sort_by(|a, b| a.program().cmp(b.program()));
+
+            assert_eq!(
+                clone_commands
+                    .iter()
+                    .map(|command| command.program())
+                    .collect::<Vec<_>>(),
+                programs
+            );
+            assert!(clone_commands
+                .iter()
+                .all(|command| command.last_execute_time().is_none()));
+        }
+    }
+}
+
+pub fn assert_storage_counts(background: &Background, workspaces: usize, commands: usize) {
+    let Background { storage } = background;
+
+    assert_eq!(storage.list_workspaces().unwrap().len(), workspaces);
+    assert_eq!(storage.list_commands().unwrap().len(), commands);
+}
+
+pub fn execute_operation(
+    background: &Background,
+    workspace_id: &str,
+    name: &str,
+    location: Option<&str>,
+) -> Result<Workspace> {
+    let Background { storage } = background;
+
+    CloneWorkspaceOperation {
+        clone_workspace_provider: storage,
+        find_workspace_provider: storage,
+    }
+    .execute(CloneWorkspaceParameters {
+        id: support::parse_workspace_id(workspace_id),
+        location: location.map(ToString::to_string),
+        name: name.to_string(),
+    })
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let Background { storage } = background;
+
+    let BackgroundContext {
+        workspace,
+        environment_variables,
+        commands,
+    } = context;
+
+    let workspace_id = workspace.id;
+
+    support::insert_workspace(storage, workspace);
+    support::insert_environment_variables(storage, workspace_id, environment_variables);
+    support::insert_commands(storage, commands);
+}
@@ -0,0 +1,202 @@
+mod test_case;
+
+use crate::support::{
+    CommandExecutionFixture, CommandFixture, ExpectedCommand, InMemoryStorage, WorkspaceFixture,
+};
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+fn background_context<'a>() -> BackgroundContext<'a> {
+    BackgroundContext {
+        workspaces: vec![
+            WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            WorkspaceFixture {
+                id: "2b7e1ac6-7b4f-4c63-8f8c-3a4cf2d7a5e1",
+                name: "Hulk",
+                parent_id: None,
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+        ],
+        command: CommandFixture {
+            id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            description: None,
+            managed_by: None,
+            name: "Ping",
+            pinned: false,
+            program: "ping 1.1.1.1",
+            last_execute_time: Some("2024-11-17 20:00:00"),
+            workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            shell: None,
+            tags: vec!["network"],
+        },
+        command_executions: vec![CommandExecutionFixture {
+            command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            location: None,
+            program: "ping 1.1.1.1",
+            start_time: "2024-11-17 20:00:00",
+            workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+        }],
+    }
+}
+
+fn original_command<'a>() -> ExpectedCommand<'a> {
+    ExpectedCommand {
+        id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        description: None,
+        name: "Ping",
+        pinned: false,
+        program: "ping 1.1.1.1",
+        last_execute_time: Some("2024-11-17 20:00:00"),
+        workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+        tags: vec!["network"],
+    }
+}
+
+#[test]
+fn test_copy_command_operation_keeps_history() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        Some("2b7e1ac6-7b4f-4c63-8f8c-3a4cf2d7a5e1"),
+        true,
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_copy: ExpectedCommand {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: Some("2024-11-17 20:00:00"),
+                workspace_id: Some("2b7e1ac6-7b4f-4c63-8f8c-3a4cf2d7a5e1"),
+                tags: vec!["network"],
+            },
+            expected_copy_executions_count: 0,
+        },
+    );
+
+    test_case::assert_storage_commands_count(&background, 2);
+    test_case::assert_storage_contains_command(&background, original_command());
+}
+
+#[test]
+fn test_copy_command_operation_resets_history() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        None,
+        false,
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_copy: ExpectedCommand {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: None,
+                tags: vec!["network"],
+            },
+            expected_copy_executions_count: 0,
+        },
+    );
+
+    test_case::assert_storage_commands_count(&background, 2);
+    test_case::assert_storage_contains_command(&background, original_command());
+}
+
+#[test]
+fn test_copy_command_operation_fails_for_missing_command() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        "0b1e5a0a-5c43-4bbb-8a0e-7a2b7c9b1f11",
+        Some("2b7e1ac6-7b4f-4c63-8f8c-3a4cf2d7a5e1"),
+        true,
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::NotFound,
+    );
+
+    test_case::assert_storage_commands_count(&background, 1);
+}
+
+#[test]
+fn test_copy_command_operation_copies_repository_managed_command_as_regular_command() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    let mut context = background_context();
+    context.command.managed_by = Some("repository");
+
+    test_case::setup(&background, context);
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        Some("2b7e1ac6-7b4f-4c63-8f8c-3a4cf2d7a5e1"),
+        false,
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_copy: ExpectedCommand {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: Some("2b7e1ac6-7b4f-4c63-8f8c-3a4cf2d7a5e1"),
+                tags: vec!["network"],
+            },
+            expected_copy_executions_count: 0,
+        },
+    );
+
+    test_case::assert_storage_commands_count(&background, 2);
+}
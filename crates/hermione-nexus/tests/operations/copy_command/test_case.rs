@@ -0,0 +1,109 @@
+use crate::support::{
+    self, CommandExecutionFixture, CommandFixture, ExpectedCommand, InMemoryStorage,
+    WorkspaceFixture,
+};
+use hermione_nexus::{
+    definitions::Command,
+    operations::{CopyCommandOperation, CopyCommandParameters},
+    Result,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub workspaces: Vec<WorkspaceFixture<'a>>,
+    pub command: CommandFixture<'a>,
+    pub command_executions: Vec<CommandExecutionFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    NotFound,
+    Success {
+        expected_copy: ExpectedCommand<'a>,
+        expected_copy_executions_count: usize,
+    },
+}
+
+pub fn assert_operation_result(
+    background: &Background,
+    result: Result<Command>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::NotFound => {
+            assert!(result.is_err_and(|err| err.is_not_found()))
+        }
+        ExpectedOperationResult::Success {
+            expected_copy,
+            expected_copy_executions_count,
+        } => {
+            assert!(result.is_ok());
+
+            let copy = result.unwrap();
+            let expected_copy = Command::from(expected_copy);
+
+            assert_ne!(copy.id(), expected_copy.id());
+            assert_eq!(copy.description(), expected_copy.description());
+            assert_eq!(copy.name(), expected_copy.name());
+            assert_eq!(copy.is_pinned(), expected_copy.is_pinned());
+            assert_eq!(copy.program(), expected_copy.program());
+            assert_eq!(copy.last_execute_time(), expected_copy.last_execute_time());
+            assert_eq!(copy.tags(), expected_copy.tags());
+            assert_eq!(copy.workspace_id(), expected_copy.workspace_id());
+            assert!(!copy.is_repository_managed());
+
+            let copy_executions_count = support::list_command_executions(&background.storage)
+                .into_iter()
+                .filter(|execution| execution.command_id() == Some(copy.id()))
+                .count();
+
+            assert_eq!(copy_executions_count, expected_copy_executions_count);
+        }
+    }
+}
+
+pub fn assert_storage_contains_command(background: &Background, expected: ExpectedCommand) {
+    let command = support::get_command(&background.storage, support::parse_command_id(expected.id));
+
+    support::assert_command(command, expected);
+}
+
+pub fn assert_storage_commands_count(background: &Background, expected: usize) {
+    assert_eq!(background.storage.list_commands().unwrap().len(), expected);
+}
+
+pub fn execute_operation(
+    background: &Background,
+    command_id: &str,
+    workspace_id: Option<&str>,
+    keep_history: bool,
+) -> Result<Command> {
+    let Background { storage } = background;
+
+    CopyCommandOperation {
+        copy_command_provider: storage,
+        find_command_provider: storage,
+        find_workspace_provider: storage,
+    }
+    .execute(CopyCommandParameters {
+        id: support::parse_command_id(command_id),
+        keep_history,
+        workspace_id: workspace_id.map(support::parse_workspace_id),
+    })
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let Background { storage } = background;
+
+    let BackgroundContext {
+        workspaces,
+        command,
+        command_executions,
+    } = context;
+
+    support::insert_workspaces(storage, workspaces);
+    support::insert_command(storage, command);
+    support::insert_command_executions(storage, command_executions);
+}
@@ -1,7 +1,10 @@
 mod test_case;
 
 use crate::support::{self, ExpectedCommand, InMemoryStorage, WorkspaceFixture};
-use hermione_nexus::operations::CreateCommandParameters;
+use hermione_nexus::{
+    definitions::{NAME_FIELD, PROGRAM_FIELD},
+    operations::CreateCommandParameters,
+};
 use test_case::Background;
 
 #[test]
@@ -17,15 +20,24 @@ fn test_create_command_operation_succeeds() {
             last_access_time: None,
             location: None,
             name: "Ironman",
+            parent_id: None,
+            pinned: false,
+            shell: None,
+            tags: vec![],
         },
     );
 
     let command = test_case::execute_operation(
         &background,
         CreateCommandParameters {
+            description: Some("Checks network connectivity".to_string()),
             name: "Ping".to_string(),
             program: "ping 1.1.1.1".to_string(),
-            workspace_id: support::parse_workspace_id("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            shell: None,
+            tags: vec!["Network".to_string(), "diagnostics".to_string()],
+            workspace_id: Some(support::parse_workspace_id(
+                "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            )),
         },
     )
     .unwrap();
@@ -36,10 +48,13 @@ fn test_create_command_operation_succeeds() {
         command,
         ExpectedCommand {
             id: &id,
+            description: Some("Checks network connectivity"),
             name: "Ping",
+            pinned: false,
             program: "ping 1.1.1.1",
             last_execute_time: None,
-            workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            tags: vec!["diagnostics", "network"],
         },
     );
 
@@ -47,10 +62,79 @@ fn test_create_command_operation_succeeds() {
         &background,
         ExpectedCommand {
             id: &id,
+            description: Some("Checks network connectivity"),
             name: "Ping",
+            pinned: false,
             program: "ping 1.1.1.1",
             last_execute_time: None,
-            workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            tags: vec!["diagnostics", "network"],
         },
     );
 }
+
+#[test]
+fn test_create_command_operation_rejects_empty_name() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        CreateCommandParameters {
+            description: None,
+            name: String::new(),
+            program: "ping 1.1.1.1".to_string(),
+            shell: None,
+            tags: vec![],
+            workspace_id: None,
+        },
+    );
+
+    test_case::assert_invalid_field(operation_result, NAME_FIELD);
+    test_case::assert_storage_is_empty(&background);
+}
+
+#[test]
+fn test_create_command_operation_rejects_too_long_name() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        CreateCommandParameters {
+            description: None,
+            name: "P".repeat(256),
+            program: "ping 1.1.1.1".to_string(),
+            shell: None,
+            tags: vec![],
+            workspace_id: None,
+        },
+    );
+
+    test_case::assert_invalid_field(operation_result, NAME_FIELD);
+    test_case::assert_storage_is_empty(&background);
+}
+
+#[test]
+fn test_create_command_operation_rejects_program_with_nul_byte() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        CreateCommandParameters {
+            description: None,
+            name: "Ping".to_string(),
+            program: "ping \0 1.1.1.1".to_string(),
+            shell: None,
+            tags: vec![],
+            workspace_id: None,
+        },
+    );
+
+    test_case::assert_invalid_field(operation_result, PROGRAM_FIELD);
+    test_case::assert_storage_is_empty(&background);
+}
@@ -13,6 +13,17 @@ pub fn assert_returned_command(command: Command, expected: ExpectedCommand) {
     support::assert_command(command, expected);
 }
 
+pub fn assert_invalid_field(operation_result: Result<Command, Error>, field: &str) {
+    let err = operation_result.err().unwrap();
+
+    assert!(err.is_invalid_argument());
+    assert_eq!(err.field(), Some(field));
+}
+
+pub fn assert_storage_is_empty(background: &Background) {
+    assert!(background.storage.list_commands().unwrap().is_empty());
+}
+
 pub fn assert_storage_contains_command(background: &Background, expected: ExpectedCommand) {
     let command = support::get_command(&background.storage, expected.id());
 
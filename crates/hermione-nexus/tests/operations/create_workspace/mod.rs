@@ -1,6 +1,6 @@
 mod test_case;
 
-use crate::support::{ExpectedWorkspace, InMemoryStorage};
+use crate::support::{self, ExpectedWorkspace, InMemoryStorage};
 use hermione_nexus::operations::CreateWorkspaceParameters;
 use test_case::Background;
 
@@ -15,6 +15,9 @@ fn test_create_workspace_operation_succeeds() {
         CreateWorkspaceParameters {
             name: "Ironman".to_string(),
             location: Some("/home/ironman".to_string()),
+            parent_id: None,
+            shell: None,
+            tags: vec![],
         },
     )
     .unwrap();
@@ -26,8 +29,10 @@ fn test_create_workspace_operation_succeeds() {
         ExpectedWorkspace {
             id: &id,
             name: "Ironman",
+            pinned: false,
             location: Some("/home/ironman"),
             last_access_time: None,
+            tags: vec![],
         },
     );
 
@@ -36,8 +41,32 @@ fn test_create_workspace_operation_succeeds() {
         ExpectedWorkspace {
             id: &id,
             name: "Ironman",
+            pinned: false,
             location: Some("/home/ironman"),
             last_access_time: None,
+            tags: vec![],
         },
     );
 }
+
+#[test]
+fn test_create_workspace_operation_fails_when_parent_is_missing() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    let result = test_case::execute_operation(
+        &background,
+        CreateWorkspaceParameters {
+            name: "Acme".to_string(),
+            location: None,
+            parent_id: Some(support::parse_workspace_id(
+                "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            )),
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    test_case::assert_not_found(result);
+}
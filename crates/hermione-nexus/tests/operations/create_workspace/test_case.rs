@@ -19,6 +19,10 @@ pub fn assert_storage_contains_workspace(background: &Background, expected: Expe
     support::assert_workspace(workspace, expected);
 }
 
+pub fn assert_not_found(result: Result<Workspace>) {
+    assert!(result.is_err_and(|err| err.is_not_found()));
+}
+
 pub fn execute_operation(
     background: &Background,
     parameters: CreateWorkspaceParameters,
@@ -15,15 +15,24 @@ fn test_delete_command_operation_succeeds() {
             workspace: WorkspaceFixture {
                 id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                 name: "Ironman",
+                parent_id: None,
+                pinned: false,
                 last_access_time: None,
                 location: None,
+                shell: None,
+                tags: vec![],
             },
             command: CommandFixture {
                 id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: None,
                 name: "Ping",
+                pinned: false,
                 program: "ping 1.1.1.1",
                 last_execute_time: None,
-                workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
             },
         },
     );
@@ -37,3 +46,45 @@ fn test_delete_command_operation_succeeds() {
         "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
     );
 }
+
+#[test]
+fn test_delete_command_operation_rejects_repository_managed_command() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        ExistingStorageData {
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                last_access_time: None,
+                location: None,
+                shell: None,
+                tags: vec![],
+            },
+            command: CommandFixture {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: Some("repository"),
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            },
+        },
+    );
+
+    let operation_result =
+        test_case::execute_operation(&background, "51280bfc-2eea-444a-8df9-a1e7158c2c6b");
+
+    assert!(operation_result.is_err_and(|err| err.is_invalid_argument()));
+
+    test_case::assert_storage_contains_command(&background, "51280bfc-2eea-444a-8df9-a1e7158c2c6b");
+}
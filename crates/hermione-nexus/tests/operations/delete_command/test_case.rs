@@ -17,6 +17,13 @@ pub fn assert_operation_success(operation_result: Result<()>) {
     }
 }
 
+pub fn assert_storage_contains_command(background: &Background, command_id: &str) {
+    let command =
+        support::maybe_get_command(&background.storage, support::parse_command_id(command_id));
+
+    assert!(command.is_some());
+}
+
 pub fn assert_storage_does_not_contain_command(background: &Background, command_id: &str) {
     let command =
         support::maybe_get_command(&background.storage, support::parse_command_id(command_id));
@@ -0,0 +1,29 @@
+mod test_case;
+
+use crate::support::InMemorySecretStore;
+use test_case::{Background, ExpectedOperationResult};
+
+#[test]
+fn test_delete_secret_operation_succeeds() {
+    let background = Background {
+        secret_store: InMemorySecretStore::default(),
+    };
+
+    test_case::setup(&background, vec![("db_pw", "jarvis")]);
+
+    let operation_result = test_case::execute_operation(&background, "db_pw");
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::Success);
+    test_case::assert_secret_does_not_exist(&background, "db_pw");
+}
+
+#[test]
+fn test_delete_secret_operation_fails_for_missing_secret() {
+    let background = Background {
+        secret_store: InMemorySecretStore::default(),
+    };
+
+    let operation_result = test_case::execute_operation(&background, "db_pw");
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::NotFound);
+}
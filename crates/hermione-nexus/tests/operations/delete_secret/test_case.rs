@@ -0,0 +1,39 @@
+use crate::support::{self, InMemorySecretStore};
+use hermione_nexus::{operations::DeleteSecretOperation, Error};
+
+pub struct Background {
+    pub secret_store: InMemorySecretStore,
+}
+
+pub enum ExpectedOperationResult {
+    NotFound,
+    Success,
+}
+
+pub fn assert_operation_result(result: Result<(), Error>, expected: ExpectedOperationResult) {
+    match expected {
+        ExpectedOperationResult::NotFound => assert!(result.is_err_and(|err| err.is_not_found())),
+        ExpectedOperationResult::Success => assert!(result.is_ok()),
+    }
+}
+
+pub fn assert_secret_does_not_exist(background: &Background, name: &str) {
+    let secret = support::maybe_get_secret(&background.secret_store, name);
+
+    assert!(secret.is_none());
+}
+
+pub fn execute_operation(background: &Background, name: &str) -> Result<(), Error> {
+    let Background { secret_store } = background;
+
+    DeleteSecretOperation {
+        provider: secret_store,
+    }
+    .execute(name)
+}
+
+pub fn setup(background: &Background, secrets: Vec<(&str, &str)>) {
+    let Background { secret_store } = background;
+
+    support::insert_secrets(secret_store, secrets);
+}
@@ -0,0 +1,166 @@
+mod test_case;
+
+use crate::support::{self, CommandFixture, CommandRevisionFixture, InMemoryStorage};
+use hermione_nexus::{definitions::DiffChange, operations::DiffCommandRevisionsParameters};
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+fn background_context<'a>() -> BackgroundContext<'a> {
+    BackgroundContext {
+        command: CommandFixture {
+            id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            description: None,
+            managed_by: None,
+            name: "Ping Cloudflare",
+            pinned: false,
+            program: "ping -c 4 1.1.1.1",
+            last_execute_time: None,
+            workspace_id: None,
+            shell: None,
+            tags: vec![],
+        },
+        command_revisions: vec![
+            CommandRevisionFixture {
+                id: "0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d",
+                command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                created_at: "2024-11-17 20:20:00",
+                name: "Ping",
+                program: "ping 1.1.1.1",
+            },
+            CommandRevisionFixture {
+                id: "b1c2d3e4-f5a6-4b7c-8d9e-0f1a2b3c4d5e",
+                command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                created_at: "2024-11-17 20:40:00",
+                name: "Ping Google",
+                program: "ping 8.8.8.8",
+            },
+            CommandRevisionFixture {
+                id: "6a7b8c9d-0e1f-4a2b-9c3d-4e5f6a7b8c9d",
+                command_id: "a3a4c9d5-7a5f-4f4e-9e3a-0c3f4b3e8e11",
+                created_at: "2024-11-17 20:30:00",
+                name: "Pods",
+                program: "kubectl get pods",
+            },
+        ],
+    }
+}
+
+fn unchanged(text: &str) -> DiffChange {
+    DiffChange::Unchanged(text.to_string())
+}
+
+fn added(text: &str) -> DiffChange {
+    DiffChange::Added(text.to_string())
+}
+
+fn removed(text: &str) -> DiffChange {
+    DiffChange::Removed(text.to_string())
+}
+
+#[test]
+fn test_diff_command_revisions_operation_compares_with_current_command() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        DiffCommandRevisionsParameters {
+            from: support::parse_command_revision_id("0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d"),
+            to: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_name: vec![unchanged("Ping"), added("Cloudflare")],
+            expected_program: vec![unchanged("ping"), added("-c 4"), unchanged("1.1.1.1")],
+        },
+    );
+}
+
+#[test]
+fn test_diff_command_revisions_operation_compares_two_revisions() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        DiffCommandRevisionsParameters {
+            from: support::parse_command_revision_id("0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d"),
+            to: Some(support::parse_command_revision_id(
+                "b1c2d3e4-f5a6-4b7c-8d9e-0f1a2b3c4d5e",
+            )),
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_name: vec![unchanged("Ping"), added("Google")],
+            expected_program: vec![unchanged("ping"), removed("1.1.1.1"), added("8.8.8.8")],
+        },
+    );
+}
+
+#[test]
+fn test_diff_command_revisions_operation_rejects_revisions_of_different_commands() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        DiffCommandRevisionsParameters {
+            from: support::parse_command_revision_id("0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d"),
+            to: Some(support::parse_command_revision_id(
+                "6a7b8c9d-0e1f-4a2b-9c3d-4e5f6a7b8c9d",
+            )),
+        },
+    );
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::InvalidArgument);
+}
+
+#[test]
+fn test_diff_command_revisions_operation_aligns_scattered_changes() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    let mut context = background_context();
+    context.command.program = "git commit --all --message fix --amend";
+    context.command_revisions[0].program = "git commit --message wip --no-verify --amend";
+
+    test_case::setup(&background, context);
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        DiffCommandRevisionsParameters {
+            from: support::parse_command_revision_id("0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d"),
+            to: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_name: vec![unchanged("Ping"), added("Cloudflare")],
+            expected_program: vec![
+                unchanged("git commit"),
+                added("--all"),
+                unchanged("--message"),
+                removed("wip --no-verify"),
+                added("fix"),
+                unchanged("--amend"),
+            ],
+        },
+    );
+}
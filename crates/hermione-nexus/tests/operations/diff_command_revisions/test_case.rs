@@ -0,0 +1,66 @@
+use crate::support::{self, CommandFixture, CommandRevisionFixture, InMemoryStorage};
+use hermione_nexus::{
+    definitions::{CommandRevisionDiff, DiffChange},
+    operations::{DiffCommandRevisionsOperation, DiffCommandRevisionsParameters},
+    Error,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub command: CommandFixture<'a>,
+    pub command_revisions: Vec<CommandRevisionFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult {
+    Success {
+        expected_name: Vec<DiffChange>,
+        expected_program: Vec<DiffChange>,
+    },
+    InvalidArgument,
+}
+
+pub fn assert_operation_result(
+    operation_result: Result<CommandRevisionDiff, Error>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::Success {
+            expected_name,
+            expected_program,
+        } => {
+            let diff = operation_result.unwrap();
+
+            assert_eq!(diff.name(), expected_name);
+            assert_eq!(diff.program(), expected_program);
+        }
+        ExpectedOperationResult::InvalidArgument => {
+            assert!(operation_result.is_err_and(|err| err.is_invalid_argument()))
+        }
+    }
+}
+
+pub fn execute_operation(
+    background: &Background,
+    parameters: DiffCommandRevisionsParameters,
+) -> Result<CommandRevisionDiff, Error> {
+    let Background { storage } = background;
+
+    DiffCommandRevisionsOperation {
+        find_command_provider: storage,
+        find_revision_provider: storage,
+    }
+    .execute(parameters)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext {
+        command,
+        command_revisions,
+    } = context;
+
+    support::insert_command(&background.storage, command);
+    support::insert_command_revisions(&background.storage, command_revisions);
+}
@@ -0,0 +1,163 @@
+mod test_case;
+
+use crate::support::{InMemoryStorage, MockSystem, WorkspaceFixture};
+use hermione_nexus::operations::DiscoverWorkspacesParameters;
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+fn files<'a>() -> Vec<&'a str> {
+    vec![
+        "/home/ironman/src/hermione/.git/HEAD",
+        "/home/ironman/src/hermione/Cargo.toml",
+        "/home/ironman/src/hermione/crates/hermione-nexus/Cargo.toml",
+        "/home/ironman/src/web/package.json",
+        "/home/ironman/src/web/node_modules/react/package.json",
+        "/home/ironman/src/go/services/api/go.mod",
+        "/home/ironman/src/go/deep/nested/project/tool/go.mod",
+        "/home/ironman/src/.cache/project/Cargo.toml",
+        "/home/ironman/src/notes.txt",
+    ]
+}
+
+#[test]
+fn test_discover_workspaces_operation_finds_project_roots() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            files: files(),
+            unreadable_directories: vec![],
+            workspaces: vec![],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        DiscoverWorkspacesParameters {
+            root: "/home/ironman/src",
+            max_depth: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_workspaces: vec![
+                ("api", "/home/ironman/src/go/services/api"),
+                ("hermione", "/home/ironman/src/hermione"),
+                ("web", "/home/ironman/src/web"),
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_discover_workspaces_operation_skips_known_locations() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            files: files(),
+            unreadable_directories: vec![],
+            workspaces: vec![WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Hermione",
+                parent_id: None,
+                pinned: false,
+                last_access_time: None,
+                location: Some("/home/ironman/src/hermione/"),
+                shell: None,
+                tags: vec![],
+            }],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        DiscoverWorkspacesParameters {
+            root: "/home/ironman/src",
+            max_depth: Some(1),
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_workspaces: vec![("web", "/home/ironman/src/web")],
+        },
+    );
+}
+
+#[test]
+fn test_discover_workspaces_operation_skips_unreadable_directories() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            files: files(),
+            unreadable_directories: vec!["/home/ironman/src/go"],
+            workspaces: vec![],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        DiscoverWorkspacesParameters {
+            root: "/home/ironman/src",
+            max_depth: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_workspaces: vec![
+                ("hermione", "/home/ironman/src/hermione"),
+                ("web", "/home/ironman/src/web"),
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_discover_workspaces_operation_proposes_project_root() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            files: files(),
+            unreadable_directories: vec![],
+            workspaces: vec![],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        DiscoverWorkspacesParameters {
+            root: "/home/ironman/src/hermione/",
+            max_depth: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_workspaces: vec![("hermione", "/home/ironman/src/hermione/")],
+        },
+    );
+}
@@ -0,0 +1,80 @@
+use crate::support::{self, InMemoryStorage, MockSystem, WorkspaceFixture};
+use hermione_nexus::{
+    operations::{DiscoverWorkspacesOperation, DiscoverWorkspacesParameters},
+    services::NewWorkspaceParameters,
+    Error,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+    pub system: MockSystem,
+}
+
+pub struct BackgroundContext<'a> {
+    pub files: Vec<&'a str>,
+    pub unreadable_directories: Vec<&'a str>,
+    pub workspaces: Vec<WorkspaceFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    Success {
+        expected_workspaces: Vec<(&'a str, &'a str)>,
+    },
+}
+
+pub fn assert_operation_result(
+    operation_result: Result<Vec<NewWorkspaceParameters>, Error>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::Success {
+            expected_workspaces,
+        } => {
+            let workspaces = operation_result
+                .unwrap()
+                .into_iter()
+                .map(|workspace| (workspace.name, workspace.location.unwrap_or_default()))
+                .collect::<Vec<(String, String)>>();
+
+            let expected_workspaces = expected_workspaces
+                .into_iter()
+                .map(|(name, location)| (name.to_string(), location.to_string()))
+                .collect::<Vec<(String, String)>>();
+
+            assert_eq!(workspaces, expected_workspaces);
+        }
+    }
+}
+
+pub fn execute_operation(
+    background: &Background,
+    parameters: DiscoverWorkspacesParameters,
+) -> Result<Vec<NewWorkspaceParameters>, Error> {
+    let Background { storage, system } = background;
+
+    DiscoverWorkspacesOperation {
+        list_directory_provider: system,
+        list_workspaces_provider: storage,
+    }
+    .execute(parameters)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext {
+        files,
+        unreadable_directories,
+        workspaces,
+    } = context;
+
+    support::insert_files(
+        &background.system,
+        files.into_iter().map(|path| (path, "")).collect(),
+    );
+    background
+        .system
+        .unreadable_directories
+        .write()
+        .unwrap()
+        .extend(unreadable_directories.into_iter().map(ToString::to_string));
+    support::insert_workspaces(&background.storage, workspaces);
+}
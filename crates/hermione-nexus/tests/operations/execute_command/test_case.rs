@@ -1,38 +1,50 @@
 use crate::support::{
-    self, CommandFixture, ExpectedCommand, ExpectedWorkspace, InMemoryStorage, MockSystem,
-    WorkspaceFixture,
+    self, CommandFixture, ExpectedCommand, ExpectedCommandExecution, ExpectedWorkspace,
+    InMemorySecretStore, InMemoryStorage, MockSystem, WorkspaceFixture,
 };
-use hermione_nexus::{operations::ExecuteCommandOperation, Error};
+use hermione_nexus::{
+    operations::{ExecuteCommandOperation, ExecuteCommandParameters},
+    Error,
+};
+use std::collections::HashMap;
 
 pub struct Background {
+    pub secret_store: InMemorySecretStore,
     pub storage: InMemoryStorage,
     pub system: MockSystem,
 }
 
 pub struct BackgroundContext<'a> {
     pub workspace: WorkspaceFixture<'a>,
+    pub environment_variables: Vec<(&'a str, &'a str)>,
     pub command: CommandFixture<'a>,
     pub time_freeze: &'a str,
 }
 
 pub enum ExpectedOperationResult {
+    InvalidArgument,
+    NotFound,
     Success,
 }
 
 pub struct ExpectedStorageState<'a> {
     pub expected_command: ExpectedCommand<'a>,
+    pub expected_command_executions: Vec<ExpectedCommandExecution<'a>>,
     pub expected_workspace: ExpectedWorkspace<'a>,
 }
 
 pub struct ExpectedSystemChanges<'a> {
+    pub environment_variables: Vec<(&'a str, &'a str)>,
     pub last_executed_program: &'a str,
     pub last_visited_location: &'a str,
+    pub shell: Option<&'a str>,
 }
 
 pub fn assert_storage_changes(backgound: &Background, expected: ExpectedStorageState) {
-    let Background { storage, system: _ } = backgound;
+    let Background { storage, .. } = backgound;
     let ExpectedStorageState {
         expected_command,
+        expected_command_executions,
         expected_workspace,
     } = expected;
 
@@ -40,6 +52,10 @@ pub fn assert_storage_changes(backgound: &Background, expected: ExpectedStorageS
     let workspace = support::get_workspace(storage, expected_workspace.id());
 
     support::assert_command(command, expected_command);
+    support::assert_command_executions(
+        support::list_command_executions(storage),
+        expected_command_executions,
+    );
     support::assert_workspace(workspace, expected_workspace);
 }
 
@@ -47,43 +63,77 @@ pub fn assert_system_changes(backgound: &Background, expected: ExpectedSystemCha
     let Background { system, .. } = backgound;
 
     let ExpectedSystemChanges {
+        environment_variables,
         last_executed_program,
         last_visited_location,
+        shell,
     } = expected;
 
+    support::assert_environment_variables(system, environment_variables);
     support::assert_last_executed_program(system, last_executed_program);
     support::assert_file_system_location(system, last_visited_location);
+    support::assert_shell(system, shell);
 }
 
 pub fn assert_operation_result(result: Result<(), Error>, expected: ExpectedOperationResult) {
     match expected {
+        ExpectedOperationResult::InvalidArgument => {
+            assert!(result.is_err_and(|err| err.is_invalid_argument()))
+        }
+        ExpectedOperationResult::NotFound => {
+            assert!(result.is_err_and(|err| err.is_not_found()))
+        }
         ExpectedOperationResult::Success => assert!(result.is_ok()),
     }
 }
 
-pub fn execute_operation(backgound: &Background, command_id: &str) -> Result<(), Error> {
-    let Background { storage, system } = backgound;
+pub fn execute_operation(
+    backgound: &Background,
+    command_id: &str,
+    placeholder_values: Vec<(&str, &str)>,
+    workspace_id: Option<&str>,
+) -> Result<(), Error> {
+    let Background {
+        secret_store,
+        storage,
+        system,
+    } = backgound;
+
+    let placeholder_values: HashMap<String, String> = placeholder_values
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
 
     ExecuteCommandOperation {
         find_command_provider: storage,
+        find_secret_provider: secret_store,
         find_workspace_provider: storage,
+        list_environment_variables_provider: storage,
         system_provider: system,
         track_command_provider: storage,
         track_workspace_provider: storage,
     }
-    .execute(support::parse_command_id(command_id))
+    .execute(ExecuteCommandParameters {
+        command_id: support::parse_command_id(command_id),
+        placeholder_values,
+        workspace_id: workspace_id.map(support::parse_workspace_id),
+    })
 }
 
 pub fn setup(backgound: &Background, context: BackgroundContext) {
-    let Background { storage, system: _ } = backgound;
+    let Background { storage, .. } = backgound;
 
     let BackgroundContext {
         workspace,
+        environment_variables,
         command,
         time_freeze,
     } = context;
 
+    let workspace_id = workspace.id;
+
     support::insert_workspace(storage, workspace);
+    support::insert_environment_variables(storage, workspace_id, environment_variables);
     support::insert_command(storage, command);
     support::freeze_storage_time(storage, support::parse_time(time_freeze));
 }
@@ -0,0 +1,275 @@
+mod test_case;
+
+use crate::support::{
+    CommandChainFixture, CommandFixture, ExpectedCommandExecution, InMemorySecretStore,
+    InMemoryStorage, MockSystem, WorkspaceFixture,
+};
+use test_case::{
+    Background, BackgroundContext, ExpectedOperationResult, ExpectedStorageState,
+    ExpectedSystemState,
+};
+
+fn workspaces() -> Vec<WorkspaceFixture<'static>> {
+    vec![
+        WorkspaceFixture {
+            id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            name: "Ironman",
+            parent_id: None,
+            pinned: false,
+            last_access_time: None,
+            location: Some("/home/ironman"),
+            shell: None,
+            tags: vec![],
+        },
+        WorkspaceFixture {
+            id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
+            name: "Avenger",
+            parent_id: None,
+            pinned: false,
+            last_access_time: None,
+            location: Some("/home/avenger"),
+            shell: None,
+            tags: vec![],
+        },
+    ]
+}
+
+#[test]
+fn test_execute_command_chain_operation_runs_steps_in_their_workspaces_and_shells() {
+    let background = Background {
+        secret_store: InMemorySecretStore::default(),
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            workspaces: workspaces(),
+            commands: vec![
+                CommandFixture {
+                    id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    managed_by: None,
+                    name: "Test",
+                    pinned: false,
+                    program: "cargo test",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
+                },
+                CommandFixture {
+                    id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    managed_by: None,
+                    name: "Tag",
+                    pinned: false,
+                    program: "git tag {{version}}",
+                    last_execute_time: None,
+                    workspace_id: Some("637d207c-7a18-47eb-b0b4-7f27d4ecbf88"),
+                    shell: Some("zsh"),
+                    tags: vec![],
+                },
+            ],
+            command_chain: CommandChainFixture {
+                id: "a1bd8d4c-6a31-4c34-9fb3-05bbd1b4e9e4",
+                name: "Release",
+                steps: vec![
+                    "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    "657acc69-aafe-426d-8496-9859bc40ca62",
+                ],
+                workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            },
+            time_freeze: "2024-11-17 20:20:00",
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        "a1bd8d4c-6a31-4c34-9fb3-05bbd1b4e9e4",
+        vec![("version", "v1.0.0")],
+    );
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::Success);
+
+    test_case::assert_storage_changes(
+        &background,
+        ExpectedStorageState {
+            expected_command_executions: vec![ExpectedCommandExecution {
+                command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                location: Some("/home/ironman"),
+                program: "cargo test",
+                start_time: "2024-11-17 20:20:01",
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            }],
+        },
+    );
+
+    test_case::assert_system_changes(
+        &background,
+        ExpectedSystemState {
+            expected_command_chain: vec![
+                ("cargo test", Some("/home/ironman"), None),
+                ("git tag v1.0.0", Some("/home/avenger"), Some("zsh")),
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_execute_command_chain_operation_runs_nothing_without_placeholder_value() {
+    let background = Background {
+        secret_store: InMemorySecretStore::default(),
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            workspaces: workspaces(),
+            commands: vec![
+                CommandFixture {
+                    id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    managed_by: None,
+                    name: "Test",
+                    pinned: false,
+                    program: "cargo test",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
+                },
+                CommandFixture {
+                    id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    managed_by: None,
+                    name: "Tag",
+                    pinned: false,
+                    program: "git tag {{version}}",
+                    last_execute_time: None,
+                    workspace_id: Some("637d207c-7a18-47eb-b0b4-7f27d4ecbf88"),
+                    shell: None,
+                    tags: vec![],
+                },
+            ],
+            command_chain: CommandChainFixture {
+                id: "a1bd8d4c-6a31-4c34-9fb3-05bbd1b4e9e4",
+                name: "Release",
+                steps: vec![
+                    "657acc69-aafe-426d-8496-9859bc40ca62",
+                    "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                ],
+                workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            },
+            time_freeze: "2024-11-17 20:20:00",
+        },
+    );
+
+    let operation_result =
+        test_case::execute_operation(&background, "a1bd8d4c-6a31-4c34-9fb3-05bbd1b4e9e4", vec![]);
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::InvalidArgument);
+
+    test_case::assert_storage_changes(
+        &background,
+        ExpectedStorageState {
+            expected_command_executions: vec![],
+        },
+    );
+
+    test_case::assert_system_changes(
+        &background,
+        ExpectedSystemState {
+            expected_command_chain: vec![],
+        },
+    );
+}
+
+#[test]
+fn test_execute_command_chain_operation_runs_nothing_when_step_is_missing() {
+    let background = Background {
+        secret_store: InMemorySecretStore::default(),
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            workspaces: workspaces(),
+            commands: vec![CommandFixture {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: None,
+                name: "Test",
+                pinned: false,
+                program: "cargo test",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            }],
+            command_chain: CommandChainFixture {
+                id: "a1bd8d4c-6a31-4c34-9fb3-05bbd1b4e9e4",
+                name: "Release",
+                steps: vec![
+                    "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    "657acc69-aafe-426d-8496-9859bc40ca62",
+                ],
+                workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            },
+            time_freeze: "2024-11-17 20:20:00",
+        },
+    );
+
+    let operation_result =
+        test_case::execute_operation(&background, "a1bd8d4c-6a31-4c34-9fb3-05bbd1b4e9e4", vec![]);
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::NotFound);
+
+    test_case::assert_storage_changes(
+        &background,
+        ExpectedStorageState {
+            expected_command_executions: vec![],
+        },
+    );
+
+    test_case::assert_system_changes(
+        &background,
+        ExpectedSystemState {
+            expected_command_chain: vec![],
+        },
+    );
+}
+
+#[test]
+fn test_execute_command_chain_operation_fails_for_missing_chain() {
+    let background = Background {
+        secret_store: InMemorySecretStore::default(),
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            workspaces: workspaces(),
+            commands: vec![],
+            command_chain: CommandChainFixture {
+                id: "a1bd8d4c-6a31-4c34-9fb3-05bbd1b4e9e4",
+                name: "Release",
+                steps: vec!["51280bfc-2eea-444a-8df9-a1e7158c2c6b"],
+                workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            },
+            time_freeze: "2024-11-17 20:20:00",
+        },
+    );
+
+    let operation_result =
+        test_case::execute_operation(&background, "0b1e5a0a-5c43-4bbb-8a0e-7a2b7c9b1f11", vec![]);
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::NotFound);
+}
@@ -0,0 +1,129 @@
+use crate::support::{
+    self, CommandChainFixture, CommandFixture, ExpectedCommandExecution, InMemorySecretStore,
+    InMemoryStorage, MockSystem, WorkspaceFixture,
+};
+use hermione_nexus::{
+    operations::{ExecuteCommandChainOperation, ExecuteCommandChainParameters},
+    Error,
+};
+use std::collections::HashMap;
+
+pub struct Background {
+    pub secret_store: InMemorySecretStore,
+    pub storage: InMemoryStorage,
+    pub system: MockSystem,
+}
+
+pub struct BackgroundContext<'a> {
+    pub workspaces: Vec<WorkspaceFixture<'a>>,
+    pub commands: Vec<CommandFixture<'a>>,
+    pub command_chain: CommandChainFixture<'a>,
+    pub time_freeze: &'a str,
+}
+
+pub enum ExpectedOperationResult {
+    InvalidArgument,
+    NotFound,
+    Success,
+}
+
+pub struct ExpectedStorageState<'a> {
+    pub expected_command_executions: Vec<ExpectedCommandExecution<'a>>,
+}
+
+pub struct ExpectedSystemState<'a> {
+    pub expected_command_chain: Vec<(&'a str, Option<&'a str>, Option<&'a str>)>,
+}
+
+pub fn assert_operation_result(result: Result<(), Error>, expected: ExpectedOperationResult) {
+    match expected {
+        ExpectedOperationResult::InvalidArgument => {
+            assert!(result.is_err_and(|err| err.is_invalid_argument()))
+        }
+        ExpectedOperationResult::NotFound => {
+            assert!(result.is_err_and(|err| err.is_not_found()))
+        }
+        ExpectedOperationResult::Success => assert!(result.is_ok()),
+    }
+}
+
+pub fn assert_storage_changes(backgound: &Background, expected: ExpectedStorageState) {
+    let Background { storage, .. } = backgound;
+    let ExpectedStorageState {
+        expected_command_executions,
+    } = expected;
+
+    support::assert_command_executions(
+        support::list_command_executions(storage),
+        expected_command_executions,
+    );
+}
+
+pub fn assert_system_changes(backgound: &Background, expected: ExpectedSystemState) {
+    let Background { system, .. } = backgound;
+    let ExpectedSystemState {
+        expected_command_chain,
+    } = expected;
+
+    let command_chain = system.command_chain.read().unwrap();
+
+    assert_eq!(
+        command_chain
+            .iter()
+            .map(|(program, location, shell)| (
+                program.as_str(),
+                location.as_deref(),
+                shell.map(|shell| shell.as_str())
+            ))
+            .collect::<Vec<_>>(),
+        expected_command_chain
+    );
+}
+
+pub fn execute_operation(
+    backgound: &Background,
+    command_chain_id: &str,
+    placeholder_values: Vec<(&str, &str)>,
+) -> Result<(), Error> {
+    let Background {
+        secret_store,
+        storage,
+        system,
+    } = backgound;
+
+    let placeholder_values: HashMap<String, String> = placeholder_values
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+
+    ExecuteCommandChainOperation {
+        find_command_chain_provider: storage,
+        find_command_provider: storage,
+        find_secret_provider: secret_store,
+        find_workspace_provider: storage,
+        list_environment_variables_provider: storage,
+        system_provider: system,
+        track_command_provider: storage,
+        track_workspace_provider: storage,
+    }
+    .execute(ExecuteCommandChainParameters {
+        command_chain_id: support::parse_command_chain_id(command_chain_id),
+        placeholder_values,
+    })
+}
+
+pub fn setup(backgound: &Background, context: BackgroundContext) {
+    let Background { storage, .. } = backgound;
+
+    let BackgroundContext {
+        workspaces,
+        commands,
+        command_chain,
+        time_freeze,
+    } = context;
+
+    support::insert_workspaces(storage, workspaces);
+    support::insert_commands(storage, commands);
+    support::insert_command_chain(storage, command_chain);
+    support::freeze_storage_time(storage, support::parse_time(time_freeze));
+}
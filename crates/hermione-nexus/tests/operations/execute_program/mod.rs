@@ -16,8 +16,12 @@ fn test_execute_program_operation_succeeds() {
         WorkspaceFixture {
             id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
             name: "Ironman",
+            parent_id: None,
+            pinned: false,
             last_access_time: None,
             location: None,
+            shell: None,
+            tags: vec![],
         },
     );
 
@@ -34,6 +34,7 @@ pub fn execute_operation(
     ExecuteProgramOperation {
         system,
         find_workspace: storage,
+        list_environment_variables: storage,
     }
     .execute(parameters)
 }
@@ -0,0 +1,154 @@
+mod test_case;
+
+use crate::support::{self, CommandFixture, InMemoryStorage, MockSystem, WorkspaceFixture};
+use hermione_nexus::operations::{BundleScope, ExportBundleParameters};
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+const PROJECTS_ID: &str = "2f3c1e1a-7b0e-4a43-9d3a-2b9e0f3c1a10";
+const HERMIONE_ID: &str = "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa";
+const DOCS_ID: &str = "6f4f5c0e-2d0c-4c36-8a47-0d8f7b1e4c22";
+const PERSONAL_ID: &str = "c2b7e1d4-5a1f-4f0b-8f6e-3e2d1c0b9a33";
+
+fn workspace<'a>(id: &'a str, name: &'a str, parent_id: Option<&'a str>) -> WorkspaceFixture<'a> {
+    WorkspaceFixture {
+        id,
+        last_access_time: None,
+        location: None,
+        name,
+        parent_id,
+        pinned: false,
+        shell: None,
+        tags: vec![],
+    }
+}
+
+fn command<'a>(id: &'a str, name: &'a str, workspace_id: Option<&'a str>) -> CommandFixture<'a> {
+    CommandFixture {
+        id,
+        description: None,
+        managed_by: None,
+        name,
+        pinned: false,
+        program: "echo",
+        last_execute_time: None,
+        workspace_id,
+        shell: None,
+        tags: vec![],
+    }
+}
+
+fn library<'a>() -> BackgroundContext<'a> {
+    BackgroundContext {
+        commands: vec![
+            command(
+                "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                "Build",
+                Some(HERMIONE_ID),
+            ),
+            command(
+                "4b2e6b9c-3a57-4d2d-9d55-7a3c1a2f4e01",
+                "Serve docs",
+                Some(DOCS_ID),
+            ),
+            command(
+                "0e6e4e9e-1b9a-4c55-9d7a-6a0f7a9e2b12",
+                "Backup photos",
+                Some(PERSONAL_ID),
+            ),
+            command("7d1e2f3a-4b5c-4d6e-8f90-a1b2c3d4e5f6", "Uptime", None),
+        ],
+        workspaces: vec![
+            workspace(PROJECTS_ID, "Projects", None),
+            workspace(HERMIONE_ID, "Hermione", Some(PROJECTS_ID)),
+            workspace(DOCS_ID, "Docs", Some(HERMIONE_ID)),
+            workspace(PERSONAL_ID, "Personal", None),
+        ],
+    }
+}
+
+#[test]
+fn test_export_bundle_operation_exports_library() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, library());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ExportBundleParameters {
+            path: "/home/ironman/library.json",
+            scope: BundleScope::Library,
+        },
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            path: "/home/ironman/library.json",
+            workspaces: vec![
+                ("Docs", Some("Hermione")),
+                ("Hermione", Some("Projects")),
+                ("Personal", None),
+                ("Projects", None),
+            ],
+            commands: vec!["Backup photos", "Build", "Serve docs", "Uptime"],
+        },
+    );
+}
+
+#[test]
+fn test_export_bundle_operation_exports_selected_workspaces_with_descendants() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, library());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ExportBundleParameters {
+            path: "/home/ironman/hermione.json",
+            scope: BundleScope::Workspaces(vec![support::parse_workspace_id(HERMIONE_ID)]),
+        },
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            path: "/home/ironman/hermione.json",
+            workspaces: vec![("Docs", Some("Hermione")), ("Hermione", None)],
+            commands: vec!["Build", "Serve docs"],
+        },
+    );
+}
+
+#[test]
+fn test_export_bundle_operation_fails_for_unknown_workspace() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, library());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ExportBundleParameters {
+            path: "/home/ironman/unknown.json",
+            scope: BundleScope::Workspaces(vec![support::parse_workspace_id(
+                "e0a1b2c3-d4e5-4f60-8a9b-0c1d2e3f4a55",
+            )]),
+        },
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::NotFound,
+    );
+}
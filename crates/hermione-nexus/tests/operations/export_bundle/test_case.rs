@@ -0,0 +1,110 @@
+use crate::support::{self, CommandFixture, InMemoryStorage, MockSystem, WorkspaceFixture};
+use hermione_nexus::{
+    definitions::Bundle,
+    operations::{ExportBundleOperation, ExportBundleParameters},
+    Error,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+    pub system: MockSystem,
+}
+
+pub struct BackgroundContext<'a> {
+    pub commands: Vec<CommandFixture<'a>>,
+    pub workspaces: Vec<WorkspaceFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    NotFound,
+    Success {
+        path: &'a str,
+        workspaces: Vec<(&'a str, Option<&'a str>)>,
+        commands: Vec<&'a str>,
+    },
+}
+
+pub fn assert_operation_result(
+    background: &Background,
+    operation_result: Result<Bundle, Error>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::NotFound => {
+            assert!(operation_result.is_err_and(|err| err.is_not_found()));
+            assert!(background.system.bundles.read().unwrap().is_empty());
+        }
+        ExpectedOperationResult::Success {
+            path,
+            workspaces,
+            commands,
+        } => {
+            let bundle = operation_result.unwrap();
+            let bundles = background.system.bundles.read().unwrap();
+            let written = bundles.get(path).unwrap();
+
+            for bundle in [&bundle, written] {
+                assert_eq!(bundle.schema_version(), 1);
+                assert_eq!(workspace_parents(bundle), workspaces);
+                assert_eq!(command_names(bundle), commands);
+            }
+        }
+    }
+}
+
+pub fn execute_operation(
+    background: &Background,
+    parameters: ExportBundleParameters,
+) -> Result<Bundle, Error> {
+    let Background { storage, system } = background;
+
+    ExportBundleOperation {
+        list_commands_provider: storage,
+        list_workspaces_provider: storage,
+        write_bundle_provider: system,
+    }
+    .execute(parameters)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext {
+        commands,
+        workspaces,
+    } = context;
+
+    support::insert_workspaces(&background.storage, workspaces);
+    support::insert_commands(&background.storage, commands);
+}
+
+fn command_names(bundle: &Bundle) -> Vec<&str> {
+    let mut names = bundle
+        .commands()
+        .iter()
+        .map(|command| command.name())
+        .collect::<Vec<&str>>();
+
+    names.sort();
+    names
+}
+
+fn workspace_parents(bundle: &Bundle) -> Vec<(&str, Option<&str>)> {
+    let mut parents = bundle
+        .workspaces()
+        .iter()
+        .map(|workspace| {
+            let parent = workspace.parent_id().map(|parent_id| {
+                bundle
+                    .workspaces()
+                    .iter()
+                    .find(|parent| parent.id() == parent_id)
+                    .map(|parent| parent.name())
+                    .unwrap()
+            });
+
+            (workspace.name(), parent)
+        })
+        .collect::<Vec<(&str, Option<&str>)>>();
+
+    parents.sort();
+    parents
+}
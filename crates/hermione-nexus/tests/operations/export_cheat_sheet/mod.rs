@@ -0,0 +1,250 @@
+mod test_case;
+
+use crate::support::{self, CommandFixture, InMemoryStorage, MockSystem, WorkspaceFixture};
+use hermione_nexus::operations::ExportCheatSheetParameters;
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+const HERMIONE_ID: &str = "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa";
+const DOCS_ID: &str = "6f4f5c0e-2d0c-4c36-8a47-0d8f7b1e4c22";
+const PERSONAL_ID: &str = "c2b7e1d4-5a1f-4f0b-8f6e-3e2d1c0b9a33";
+
+const HERMIONE_CHEAT_SHEET: &str = r#"# Hermione
+
+## Hermione
+
+Location: `~/src/hermione`
+
+### Build
+
+Builds every crate.
+
+```bash
+cargo build --workspace
+```
+
+Last run: 2024-11-17
+
+### Release notes
+
+````powershell
+git log --format='```%s```'
+````
+
+Last run: never
+
+## Hermione / Docs
+
+_No commands_
+"#;
+
+const ESCAPED_CHEAT_SHEET: &str = r#"# \#1 Personal
+
+## \#1 Personal
+
+Location: `` `~/notes` ``
+
+### \# Backup photos now
+
+\- keeps \*deleted\* files
+1\. runs \<nightly\>
+\#hashtag
+
+```bash
+rsync -a ~/Photos /mnt/backup
+```
+
+Last run: never
+"#;
+
+fn workspace<'a>(
+    id: &'a str,
+    name: &'a str,
+    parent_id: Option<&'a str>,
+    location: Option<&'a str>,
+) -> WorkspaceFixture<'a> {
+    WorkspaceFixture {
+        id,
+        last_access_time: None,
+        location,
+        name,
+        parent_id,
+        pinned: false,
+        shell: Some("bash"),
+        tags: vec![],
+    }
+}
+
+fn library<'a>() -> BackgroundContext<'a> {
+    BackgroundContext {
+        commands: vec![
+            CommandFixture {
+                id: "4b2e6b9c-3a57-4d2d-9d55-7a3c1a2f4e01",
+                description: None,
+                managed_by: None,
+                name: "Release notes",
+                pinned: false,
+                program: "git log --format='```%s```'",
+                last_execute_time: None,
+                workspace_id: Some(HERMIONE_ID),
+                shell: Some("pwsh"),
+                tags: vec![],
+            },
+            CommandFixture {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: Some("Builds every crate."),
+                managed_by: None,
+                name: "Build",
+                pinned: false,
+                program: "cargo build --workspace",
+                last_execute_time: Some("2024-11-17 20:00:00"),
+                workspace_id: Some(HERMIONE_ID),
+                shell: None,
+                tags: vec![],
+            },
+            CommandFixture {
+                id: "0e6e4e9e-1b9a-4c55-9d7a-6a0f7a9e2b12",
+                description: None,
+                managed_by: None,
+                name: "Backup photos",
+                pinned: false,
+                program: "rsync -a ~/Photos /mnt/backup",
+                last_execute_time: None,
+                workspace_id: Some(PERSONAL_ID),
+                shell: None,
+                tags: vec![],
+            },
+        ],
+        workspaces: vec![
+            workspace(HERMIONE_ID, "Hermione", None, Some("~/src/hermione")),
+            workspace(DOCS_ID, "Docs", Some(HERMIONE_ID), None),
+            workspace(PERSONAL_ID, "Personal", None, None),
+        ],
+    }
+}
+
+#[test]
+fn test_export_cheat_sheet_operation_renders_workspace_with_descendants() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, library());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ExportCheatSheetParameters {
+            workspace_id: support::parse_workspace_id(HERMIONE_ID),
+            path: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            markdown: HERMIONE_CHEAT_SHEET,
+            path: None,
+        },
+    );
+}
+
+#[test]
+fn test_export_cheat_sheet_operation_writes_file() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, library());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ExportCheatSheetParameters {
+            workspace_id: support::parse_workspace_id(HERMIONE_ID),
+            path: Some("/home/ironman/src/hermione/COMMANDS.md"),
+        },
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            markdown: HERMIONE_CHEAT_SHEET,
+            path: Some("/home/ironman/src/hermione/COMMANDS.md"),
+        },
+    );
+}
+
+#[test]
+fn test_export_cheat_sheet_operation_fails_for_unknown_workspace() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, library());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ExportCheatSheetParameters {
+            workspace_id: support::parse_workspace_id("e0a1b2c3-d4e5-4f60-8a9b-0c1d2e3f4a55"),
+            path: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::NotFound,
+    );
+}
+
+#[test]
+fn test_export_cheat_sheet_operation_escapes_names_and_descriptions() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            commands: vec![CommandFixture {
+                id: "0e6e4e9e-1b9a-4c55-9d7a-6a0f7a9e2b12",
+                description: Some("- keeps *deleted* files\n1. runs <nightly>\n  #hashtag"),
+                managed_by: None,
+                name: "# Backup photos\nnow",
+                pinned: false,
+                program: "rsync -a ~/Photos /mnt/backup",
+                last_execute_time: None,
+                workspace_id: Some(PERSONAL_ID),
+                shell: None,
+                tags: vec![],
+            }],
+            workspaces: vec![workspace(
+                PERSONAL_ID,
+                "#1 Personal",
+                None,
+                Some("`~/notes`"),
+            )],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ExportCheatSheetParameters {
+            workspace_id: support::parse_workspace_id(PERSONAL_ID),
+            path: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            markdown: ESCAPED_CHEAT_SHEET,
+            path: None,
+        },
+    );
+}
@@ -0,0 +1,70 @@
+use crate::support::{self, CommandFixture, InMemoryStorage, MockSystem, WorkspaceFixture};
+use hermione_nexus::{
+    definitions::CheatSheet,
+    operations::{ExportCheatSheetOperation, ExportCheatSheetParameters},
+    Error,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+    pub system: MockSystem,
+}
+
+pub struct BackgroundContext<'a> {
+    pub commands: Vec<CommandFixture<'a>>,
+    pub workspaces: Vec<WorkspaceFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    NotFound,
+    Success {
+        markdown: &'a str,
+        path: Option<&'a str>,
+    },
+}
+
+pub fn assert_operation_result(
+    background: &Background,
+    operation_result: Result<CheatSheet, Error>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::NotFound => {
+            assert!(operation_result.is_err_and(|err| err.is_not_found()))
+        }
+        ExpectedOperationResult::Success { markdown, path } => {
+            assert_eq!(operation_result.unwrap().render_markdown(), markdown);
+
+            let files = background.system.files.read().unwrap();
+
+            match path {
+                Some(path) => assert_eq!(files.get(path).map(String::as_str), Some(markdown)),
+                None => assert!(files.is_empty()),
+            }
+        }
+    }
+}
+
+pub fn execute_operation(
+    background: &Background,
+    parameters: ExportCheatSheetParameters,
+) -> Result<CheatSheet, Error> {
+    let Background { storage, system } = background;
+
+    ExportCheatSheetOperation {
+        list_commands_provider: storage,
+        list_workspaces_provider: storage,
+        write_file_provider: system,
+    }
+    .execute(parameters)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext {
+        commands,
+        workspaces,
+    } = context;
+
+    support::insert_workspaces(&background.storage, workspaces);
+    support::insert_commands(&background.storage, commands);
+}
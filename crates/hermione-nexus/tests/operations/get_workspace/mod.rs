@@ -14,8 +14,12 @@ fn test_get_workspace_operation_succeeds() {
         WorkspaceFixture {
             id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
             name: "Ironman",
+            parent_id: None,
+            pinned: false,
             location: Some("/home/ironman"),
             last_access_time: Some("2024-11-17 20:00:00"),
+            shell: None,
+            tags: vec![],
         },
     );
 
@@ -28,8 +32,10 @@ fn test_get_workspace_operation_succeeds() {
             expected_workspace: ExpectedWorkspace {
                 id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                 name: "Ironman",
+                pinned: false,
                 location: Some("/home/ironman"),
                 last_access_time: Some("2024-11-17 20:00:00"),
+                tags: vec![],
             },
         },
     );
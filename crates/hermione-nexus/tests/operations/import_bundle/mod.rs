@@ -0,0 +1,517 @@
+mod test_case;
+
+use crate::support::{
+    self, CommandFixture, ExpectedTrashState, InMemoryStorage, MockSystem, WorkspaceFixture,
+};
+use hermione_nexus::{
+    definitions::{BundleConflictStrategy, TrashItemId},
+    operations::{BundleImport, ImportBundleParameters},
+};
+use test_case::{Background, BackgroundContext, ExpectedWorkspace, BUNDLE_PATH};
+
+const PROJECTS_ID: &str = "2f3c1e1a-7b0e-4a43-9d3a-2b9e0f3c1a10";
+const HERMIONE_ID: &str = "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa";
+const BUILD_ID: &str = "51280bfc-2eea-444a-8df9-a1e7158c2c6b";
+const UPTIME_ID: &str = "7d1e2f3a-4b5c-4d6e-8f90-a1b2c3d4e5f6";
+
+fn workspace<'a>(
+    name: &'a str,
+    parent_id: Option<&'a str>,
+    last_access_time: Option<&'a str>,
+) -> WorkspaceFixture<'a> {
+    WorkspaceFixture {
+        id: HERMIONE_ID,
+        last_access_time,
+        location: None,
+        name,
+        parent_id,
+        pinned: false,
+        shell: None,
+        tags: vec![],
+    }
+}
+
+fn projects<'a>() -> WorkspaceFixture<'a> {
+    WorkspaceFixture {
+        id: PROJECTS_ID,
+        last_access_time: None,
+        location: None,
+        name: "Projects",
+        parent_id: None,
+        pinned: false,
+        shell: None,
+        tags: vec![],
+    }
+}
+
+fn command<'a>(id: &'a str, name: &'a str, workspace_id: Option<&'a str>) -> CommandFixture<'a> {
+    CommandFixture {
+        id,
+        description: None,
+        managed_by: None,
+        name,
+        pinned: false,
+        program: "echo",
+        last_execute_time: None,
+        workspace_id,
+        shell: None,
+        tags: vec![],
+    }
+}
+
+/// The bundle holds `Projects > Hermione` with a build command and a global
+/// command, the library holds a local copy of `Hermione` with its own name.
+fn conflicting_library<'a>() -> BackgroundContext<'a> {
+    BackgroundContext {
+        bundle_commands: vec![
+            command(BUILD_ID, "Build", Some(HERMIONE_ID)),
+            command(UPTIME_ID, "Uptime", None),
+        ],
+        bundle_workspaces: vec![workspace("Hermione", Some(PROJECTS_ID), None), projects()],
+        commands: vec![command(BUILD_ID, "Local build", Some(HERMIONE_ID))],
+        workspaces: vec![workspace(
+            "Local Hermione",
+            None,
+            Some("2024-11-17 20:00:00"),
+        )],
+    }
+}
+
+#[test]
+fn test_import_bundle_operation_imports_into_empty_library() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            commands: vec![],
+            workspaces: vec![],
+            ..conflicting_library()
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ImportBundleParameters {
+            path: BUNDLE_PATH,
+            strategy: BundleConflictStrategy::KeepLocal,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        BundleImport {
+            imported_commands: 2,
+            imported_workspaces: 2,
+            kept_commands: 0,
+            kept_workspaces: 0,
+        },
+    );
+
+    test_case::assert_workspaces(
+        &background,
+        vec![
+            ExpectedWorkspace {
+                name: "Hermione",
+                parent: Some("Projects"),
+                last_access_time: None,
+                commands: vec!["Build"],
+            },
+            ExpectedWorkspace {
+                name: "Projects",
+                parent: None,
+                last_access_time: None,
+                commands: vec![],
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_import_bundle_operation_keeps_local_items() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, conflicting_library());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ImportBundleParameters {
+            path: BUNDLE_PATH,
+            strategy: BundleConflictStrategy::KeepLocal,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        BundleImport {
+            imported_commands: 1,
+            imported_workspaces: 1,
+            kept_commands: 1,
+            kept_workspaces: 1,
+        },
+    );
+
+    test_case::assert_workspaces(
+        &background,
+        vec![
+            ExpectedWorkspace {
+                name: "Local Hermione",
+                parent: None,
+                last_access_time: Some("2024-11-17 20:00:00"),
+                commands: vec!["Local build"],
+            },
+            ExpectedWorkspace {
+                name: "Projects",
+                parent: None,
+                last_access_time: None,
+                commands: vec![],
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_import_bundle_operation_overwrites_local_items() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, conflicting_library());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ImportBundleParameters {
+            path: BUNDLE_PATH,
+            strategy: BundleConflictStrategy::Overwrite,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        BundleImport {
+            imported_commands: 2,
+            imported_workspaces: 2,
+            kept_commands: 0,
+            kept_workspaces: 0,
+        },
+    );
+
+    test_case::assert_workspaces(
+        &background,
+        vec![
+            ExpectedWorkspace {
+                name: "Hermione",
+                parent: Some("Projects"),
+                last_access_time: Some("2024-11-17 20:00:00"),
+                commands: vec!["Build"],
+            },
+            ExpectedWorkspace {
+                name: "Projects",
+                parent: None,
+                last_access_time: None,
+                commands: vec![],
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_import_bundle_operation_keeps_repository_managed_commands() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            commands: vec![CommandFixture {
+                managed_by: Some("repository"),
+                ..command(BUILD_ID, "Local build", Some(HERMIONE_ID))
+            }],
+            ..conflicting_library()
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ImportBundleParameters {
+            path: BUNDLE_PATH,
+            strategy: BundleConflictStrategy::Overwrite,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        BundleImport {
+            imported_commands: 1,
+            imported_workspaces: 2,
+            kept_commands: 1,
+            kept_workspaces: 0,
+        },
+    );
+
+    test_case::assert_workspaces(
+        &background,
+        vec![
+            ExpectedWorkspace {
+                name: "Hermione",
+                parent: Some("Projects"),
+                last_access_time: Some("2024-11-17 20:00:00"),
+                commands: vec!["Local build"],
+            },
+            ExpectedWorkspace {
+                name: "Projects",
+                parent: None,
+                last_access_time: None,
+                commands: vec![],
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_import_bundle_operation_duplicates_local_items() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, conflicting_library());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ImportBundleParameters {
+            path: BUNDLE_PATH,
+            strategy: BundleConflictStrategy::Duplicate,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        BundleImport {
+            imported_commands: 2,
+            imported_workspaces: 2,
+            kept_commands: 0,
+            kept_workspaces: 0,
+        },
+    );
+
+    test_case::assert_workspaces(
+        &background,
+        vec![
+            ExpectedWorkspace {
+                name: "Hermione",
+                parent: Some("Projects"),
+                last_access_time: None,
+                commands: vec!["Build"],
+            },
+            ExpectedWorkspace {
+                name: "Local Hermione",
+                parent: None,
+                last_access_time: Some("2024-11-17 20:00:00"),
+                commands: vec!["Local build"],
+            },
+            ExpectedWorkspace {
+                name: "Projects",
+                parent: None,
+                last_access_time: None,
+                commands: vec![],
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_import_bundle_operation_overwrites_trashed_items() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, conflicting_library());
+
+    support::move_to_trash(
+        &background.storage,
+        TrashItemId::Workspace(support::parse_workspace_id(HERMIONE_ID)),
+        "2024-11-18 10:00:00",
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ImportBundleParameters {
+            path: BUNDLE_PATH,
+            strategy: BundleConflictStrategy::Overwrite,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        BundleImport {
+            imported_commands: 2,
+            imported_workspaces: 2,
+            kept_commands: 0,
+            kept_workspaces: 0,
+        },
+    );
+
+    test_case::assert_workspaces(
+        &background,
+        vec![
+            ExpectedWorkspace {
+                name: "Hermione",
+                parent: Some("Projects"),
+                last_access_time: Some("2024-11-17 20:00:00"),
+                commands: vec!["Build"],
+            },
+            ExpectedWorkspace {
+                name: "Projects",
+                parent: None,
+                last_access_time: None,
+                commands: vec![],
+            },
+        ],
+    );
+
+    support::assert_trash_state(
+        &background.storage,
+        ExpectedTrashState {
+            command_ids: vec![BUILD_ID, UPTIME_ID],
+            trashed_command_ids: vec![],
+            trashed_workspace_ids: vec![],
+            workspace_ids: vec![PROJECTS_ID, HERMIONE_ID],
+        },
+    );
+}
+
+#[test]
+fn test_import_bundle_operation_keeps_trashed_items() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(&background, conflicting_library());
+
+    support::move_to_trash(
+        &background.storage,
+        TrashItemId::Workspace(support::parse_workspace_id(HERMIONE_ID)),
+        "2024-11-18 10:00:00",
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ImportBundleParameters {
+            path: BUNDLE_PATH,
+            strategy: BundleConflictStrategy::KeepLocal,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        BundleImport {
+            imported_commands: 1,
+            imported_workspaces: 1,
+            kept_commands: 1,
+            kept_workspaces: 1,
+        },
+    );
+
+    support::assert_trash_state(
+        &background.storage,
+        ExpectedTrashState {
+            command_ids: vec![UPTIME_ID],
+            trashed_command_ids: vec![BUILD_ID],
+            trashed_workspace_ids: vec![HERMIONE_ID],
+            workspace_ids: vec![PROJECTS_ID],
+        },
+    );
+}
+
+#[test]
+fn test_import_bundle_operation_rejects_repeated_ids() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            bundle_commands: vec![
+                command(UPTIME_ID, "Uptime", None),
+                command(UPTIME_ID, "Uptime again", None),
+            ],
+            ..conflicting_library()
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ImportBundleParameters {
+            path: BUNDLE_PATH,
+            strategy: BundleConflictStrategy::Overwrite,
+        },
+    );
+
+    assert!(operation_result.is_err_and(|err| err.is_invalid_argument()));
+
+    test_case::assert_workspaces(
+        &background,
+        vec![ExpectedWorkspace {
+            name: "Local Hermione",
+            parent: None,
+            last_access_time: Some("2024-11-17 20:00:00"),
+            commands: vec!["Local build"],
+        }],
+    );
+}
+
+#[test]
+fn test_import_bundle_operation_rejects_cyclic_parents() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            bundle_workspaces: vec![
+                workspace("Hermione", Some(PROJECTS_ID), None),
+                WorkspaceFixture {
+                    parent_id: Some(HERMIONE_ID),
+                    ..projects()
+                },
+            ],
+            ..conflicting_library()
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ImportBundleParameters {
+            path: BUNDLE_PATH,
+            strategy: BundleConflictStrategy::Overwrite,
+        },
+    );
+
+    assert!(operation_result.is_err_and(|err| err.is_invalid_argument()));
+
+    test_case::assert_workspaces(
+        &background,
+        vec![ExpectedWorkspace {
+            name: "Local Hermione",
+            parent: None,
+            last_access_time: Some("2024-11-17 20:00:00"),
+            commands: vec!["Local build"],
+        }],
+    );
+}
@@ -0,0 +1,134 @@
+use crate::support::{self, CommandFixture, InMemoryStorage, MockSystem, WorkspaceFixture};
+use hermione_nexus::{
+    definitions::{Bundle, BundleParameters, Command, Workspace, BUNDLE_SCHEMA_VERSION},
+    operations::{BundleImport, ImportBundleOperation, ImportBundleParameters},
+    Error,
+};
+
+pub const BUNDLE_PATH: &str = "/home/ironman/library.json";
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+    pub system: MockSystem,
+}
+
+pub struct BackgroundContext<'a> {
+    pub bundle_commands: Vec<CommandFixture<'a>>,
+    pub bundle_workspaces: Vec<WorkspaceFixture<'a>>,
+    pub commands: Vec<CommandFixture<'a>>,
+    pub workspaces: Vec<WorkspaceFixture<'a>>,
+}
+
+pub struct ExpectedWorkspace<'a> {
+    pub name: &'a str,
+    pub parent: Option<&'a str>,
+    pub last_access_time: Option<&'a str>,
+    pub commands: Vec<&'a str>,
+}
+
+pub fn assert_operation_result(
+    operation_result: Result<BundleImport, Error>,
+    expected: BundleImport,
+) {
+    assert_eq!(operation_result.unwrap(), expected);
+}
+
+/// Compares every stored workspace by name, along with its parent name and
+/// the names of its commands.
+pub fn assert_workspaces(background: &Background, expected: Vec<ExpectedWorkspace>) {
+    let workspaces = background.storage.list_workspaces().unwrap();
+    let commands = background.storage.list_commands().unwrap();
+
+    let mut stored = workspaces
+        .iter()
+        .map(|workspace| {
+            let parent = workspace.parent_id().map(|parent_id| {
+                workspaces
+                    .iter()
+                    .find(|parent| parent.id() == parent_id)
+                    .map(|parent| parent.name().to_string())
+                    .unwrap()
+            });
+
+            let mut names = commands
+                .iter()
+                .filter(|command| command.workspace_id() == Some(workspace.id()))
+                .map(|command| command.name().to_string())
+                .collect::<Vec<String>>();
+
+            names.sort();
+
+            (
+                workspace.name().to_string(),
+                parent,
+                workspace.last_access_time().copied(),
+                names,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    stored.sort();
+
+    let expected = expected
+        .into_iter()
+        .map(|workspace| {
+            (
+                workspace.name.to_string(),
+                workspace.parent.map(ToString::to_string),
+                support::maybe_parse_time(workspace.last_access_time),
+                workspace
+                    .commands
+                    .into_iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(stored, expected);
+}
+
+pub fn execute_operation(
+    background: &Background,
+    parameters: ImportBundleParameters,
+) -> Result<BundleImport, Error> {
+    let Background { storage, system } = background;
+
+    ImportBundleOperation {
+        create_command_provider: storage,
+        create_workspace_provider: storage,
+        find_command_provider: storage,
+        find_workspace_provider: storage,
+        read_bundle_provider: system,
+        trash_provider: storage,
+        upsert_commands_provider: storage,
+        upsert_workspaces_provider: storage,
+    }
+    .execute(parameters)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext {
+        bundle_commands,
+        bundle_workspaces,
+        commands,
+        workspaces,
+    } = context;
+
+    let bundle = Bundle::new(BundleParameters {
+        commands: bundle_commands.into_iter().map(Command::from).collect(),
+        schema_version: BUNDLE_SCHEMA_VERSION,
+        workspaces: bundle_workspaces.into_iter().map(Workspace::from).collect(),
+    })
+    .unwrap();
+
+    background
+        .system
+        .bundles
+        .write()
+        .unwrap()
+        .insert(BUNDLE_PATH.to_string(), bundle);
+
+    support::insert_workspaces(&background.storage, workspaces);
+    support::insert_commands(&background.storage, commands);
+}
@@ -0,0 +1,163 @@
+mod test_case;
+
+use crate::support::{self, CommandExecutionFixture, ExpectedCommandExecution, InMemoryStorage};
+use hermione_nexus::operations::ListCommandExecutionsParameters;
+use std::num::NonZeroU32;
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+fn command_executions<'a>() -> Vec<CommandExecutionFixture<'a>> {
+    vec![
+        CommandExecutionFixture {
+            command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            location: Some("/home/ironman"),
+            program: "ping 1.1.1.1",
+            start_time: "2024-11-17 20:20:00",
+            workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+        },
+        CommandExecutionFixture {
+            command_id: "a3a4c9d5-7a5f-4f4e-9e3a-0c3f4b3e8e11",
+            location: Some("/home/ironman"),
+            program: "kubectl get pods",
+            start_time: "2024-11-17 20:30:00",
+            workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+        },
+        CommandExecutionFixture {
+            command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            location: Some("/home/ironman"),
+            program: "ping 1.1.1.1",
+            start_time: "2024-11-17 20:40:00",
+            workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+        },
+        CommandExecutionFixture {
+            command_id: "2d3f46b4-34ad-4fb0-a0c1-7b5c2f1a3b21",
+            location: None,
+            program: "cargo test",
+            start_time: "2024-11-17 20:50:00",
+            workspace_id: Some("cb6b6bb4-4a2e-4b3b-a4a6-8a3c8d9c1d0e"),
+        },
+    ]
+}
+
+#[test]
+fn test_list_command_executions_operation_filters_by_command() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            command_executions: command_executions(),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandExecutionsParameters {
+            command_id: Some(support::parse_command_id(
+                "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            )),
+            page_number: None,
+            page_size: None,
+            workspace_id: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_command_executions: vec![
+                ExpectedCommandExecution {
+                    command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    location: Some("/home/ironman"),
+                    program: "ping 1.1.1.1",
+                    start_time: "2024-11-17 20:40:00",
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                },
+                ExpectedCommandExecution {
+                    command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    location: Some("/home/ironman"),
+                    program: "ping 1.1.1.1",
+                    start_time: "2024-11-17 20:20:00",
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                },
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_list_command_executions_operation_filters_by_workspace() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            command_executions: command_executions(),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandExecutionsParameters {
+            command_id: None,
+            page_number: None,
+            page_size: None,
+            workspace_id: Some(support::parse_workspace_id(
+                "cb6b6bb4-4a2e-4b3b-a4a6-8a3c8d9c1d0e",
+            )),
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_command_executions: vec![ExpectedCommandExecution {
+                command_id: "2d3f46b4-34ad-4fb0-a0c1-7b5c2f1a3b21",
+                location: None,
+                program: "cargo test",
+                start_time: "2024-11-17 20:50:00",
+                workspace_id: Some("cb6b6bb4-4a2e-4b3b-a4a6-8a3c8d9c1d0e"),
+            }],
+        },
+    );
+}
+
+#[test]
+fn test_list_command_executions_operation_paginates() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            command_executions: command_executions(),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandExecutionsParameters {
+            command_id: None,
+            page_number: NonZeroU32::new(2),
+            page_size: NonZeroU32::new(3),
+            workspace_id: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_command_executions: vec![ExpectedCommandExecution {
+                command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                location: Some("/home/ironman"),
+                program: "ping 1.1.1.1",
+                start_time: "2024-11-17 20:20:00",
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            }],
+        },
+    );
+}
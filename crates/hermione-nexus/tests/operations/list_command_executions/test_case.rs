@@ -0,0 +1,51 @@
+use crate::support::{self, CommandExecutionFixture, ExpectedCommandExecution, InMemoryStorage};
+use hermione_nexus::{
+    definitions::CommandExecution,
+    operations::{ListCommandExecutionsOperation, ListCommandExecutionsParameters},
+    Error,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub command_executions: Vec<CommandExecutionFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    Success {
+        expected_command_executions: Vec<ExpectedCommandExecution<'a>>,
+    },
+}
+
+pub fn assert_operation_result(
+    result: Result<Vec<CommandExecution>, Error>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::Success {
+            expected_command_executions,
+        } => {
+            assert!(result.is_ok());
+            support::assert_command_executions(result.unwrap(), expected_command_executions)
+        }
+    }
+}
+
+pub fn execute_operation(
+    backgound: &Background,
+    parameters: ListCommandExecutionsParameters,
+) -> Result<Vec<CommandExecution>, Error> {
+    let Background { storage } = backgound;
+
+    ListCommandExecutionsOperation { provider: storage }.execute(parameters)
+}
+
+pub fn setup(backgound: &Background, context: BackgroundContext) {
+    let Background { storage } = backgound;
+
+    let BackgroundContext { command_executions } = context;
+
+    support::insert_command_executions(storage, command_executions);
+}
@@ -0,0 +1,95 @@
+mod test_case;
+
+use crate::support::{self, CommandRevisionFixture, InMemoryStorage};
+use hermione_nexus::operations::ListCommandRevisionsParameters;
+use std::num::NonZeroU32;
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+fn command_revisions<'a>() -> Vec<CommandRevisionFixture<'a>> {
+    vec![
+        CommandRevisionFixture {
+            id: "0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d",
+            command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            created_at: "2024-11-17 20:20:00",
+            name: "Ping",
+            program: "ping 1.1.1.1",
+        },
+        CommandRevisionFixture {
+            id: "6a7b8c9d-0e1f-4a2b-9c3d-4e5f6a7b8c9d",
+            command_id: "a3a4c9d5-7a5f-4f4e-9e3a-0c3f4b3e8e11",
+            created_at: "2024-11-17 20:30:00",
+            name: "Pods",
+            program: "kubectl get pods",
+        },
+        CommandRevisionFixture {
+            id: "b1c2d3e4-f5a6-4b7c-8d9e-0f1a2b3c4d5e",
+            command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            created_at: "2024-11-17 20:40:00",
+            name: "Ping Cloudflare",
+            program: "ping -c 4 1.1.1.1",
+        },
+    ]
+}
+
+#[test]
+fn test_list_command_revisions_operation_returns_newest_first() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            command_revisions: command_revisions(),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandRevisionsParameters {
+            command_id: support::parse_command_id("51280bfc-2eea-444a-8df9-a1e7158c2c6b"),
+            page_number: None,
+            page_size: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_revision_ids: vec![
+                "b1c2d3e4-f5a6-4b7c-8d9e-0f1a2b3c4d5e",
+                "0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d",
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_list_command_revisions_operation_paginates() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            command_revisions: command_revisions(),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandRevisionsParameters {
+            command_id: support::parse_command_id("51280bfc-2eea-444a-8df9-a1e7158c2c6b"),
+            page_number: NonZeroU32::new(2),
+            page_size: NonZeroU32::new(1),
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_revision_ids: vec!["0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d"],
+        },
+    );
+}
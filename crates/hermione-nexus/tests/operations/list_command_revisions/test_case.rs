@@ -0,0 +1,57 @@
+use crate::support::{self, CommandRevisionFixture, InMemoryStorage};
+use hermione_nexus::{
+    definitions::CommandRevision,
+    operations::{ListCommandRevisionsOperation, ListCommandRevisionsParameters},
+    Error,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub command_revisions: Vec<CommandRevisionFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    Success { expected_revision_ids: Vec<&'a str> },
+}
+
+pub fn assert_operation_result(
+    operation_result: Result<Vec<CommandRevision>, Error>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::Success {
+            expected_revision_ids,
+        } => {
+            let revision_ids = operation_result
+                .unwrap()
+                .iter()
+                .map(|revision| revision.id())
+                .collect::<Vec<_>>();
+
+            let expected_revision_ids = expected_revision_ids
+                .into_iter()
+                .map(support::parse_command_revision_id)
+                .collect::<Vec<_>>();
+
+            assert_eq!(revision_ids, expected_revision_ids);
+        }
+    }
+}
+
+pub fn execute_operation(
+    background: &Background,
+    parameters: ListCommandRevisionsParameters,
+) -> Result<Vec<CommandRevision>, Error> {
+    let Background { storage } = background;
+
+    ListCommandRevisionsOperation { provider: storage }.execute(parameters)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext { command_revisions } = context;
+
+    support::insert_command_revisions(&background.storage, command_revisions);
+}
@@ -1,7 +1,10 @@
 mod test_case;
 
-use crate::support::{CommandFixture, ExpectedCommand, InMemoryStorage, WorkspaceFixture};
-use hermione_nexus::operations::ListCommandsParameters;
+use crate::support::{self, CommandFixture, ExpectedCommand, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::{
+    operations::ListCommandsParameters,
+    services::{CommandScope, SortOrder, TagsFilter},
+};
 use std::num::NonZeroU32;
 use test_case::{Background, BackgroundContext, ExpectedOperationResult};
 
@@ -14,26 +17,41 @@ fn test_list_commands_operation_filteres_by_program() {
     test_case::setup(
         &background,
         BackgroundContext {
+            execute_counts: vec![],
             workspace: WorkspaceFixture {
                 id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                 name: "Ironman",
+                parent_id: None,
+                pinned: false,
                 location: Some("/home/ironman"),
                 last_access_time: None,
+                shell: None,
+                tags: vec![],
             },
             commands: vec![
                 CommandFixture {
                     id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    managed_by: None,
                     name: "Ping",
+                    pinned: false,
                     program: "ping 1.1.1.1",
                     last_execute_time: None,
-                    workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
                 },
                 CommandFixture {
                     id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    managed_by: None,
                     name: "Get directory items",
+                    pinned: false,
                     program: "GetChild-Item .",
                     last_execute_time: None,
-                    workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
                 },
             ],
         },
@@ -42,10 +60,15 @@ fn test_list_commands_operation_filteres_by_program() {
     let operation_result = test_case::execute_operation(
         &background,
         ListCommandsParameters {
+            created_after: None,
+            description_contains: None,
             program_contains: Some("Item"),
             page_number: None,
             page_size: None,
-            workspace_id: None,
+            scope: CommandScope::All,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
         },
     );
 
@@ -54,10 +77,13 @@ fn test_list_commands_operation_filteres_by_program() {
         ExpectedOperationResult::Success {
             expected_commands: vec![ExpectedCommand {
                 id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
                 name: "Get directory items",
+                pinned: false,
                 program: "GetChild-Item .",
                 last_execute_time: None,
-                workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                tags: vec![],
             }],
         },
     );
@@ -72,40 +98,65 @@ fn test_list_commands_operation_paginates() {
     test_case::setup(
         &background,
         BackgroundContext {
+            execute_counts: vec![],
             workspace: WorkspaceFixture {
                 id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                 name: "Ironman",
+                parent_id: None,
+                pinned: false,
                 location: Some("/home/ironman"),
                 last_access_time: None,
+                shell: None,
+                tags: vec![],
             },
             commands: vec![
                 CommandFixture {
                     id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    managed_by: None,
                     name: "Ping",
+                    pinned: false,
                     program: "ping 1.1.1.1",
                     last_execute_time: None,
-                    workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
                 },
                 CommandFixture {
                     id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    managed_by: None,
                     name: "Get directory items",
+                    pinned: false,
                     program: "getchild-item .",
                     last_execute_time: None,
-                    workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
                 },
                 CommandFixture {
                     id: "1d0c6b79-2ea9-4291-85bf-84b9412c3a52",
+                    description: None,
+                    managed_by: None,
                     name: "Generate new UUID",
+                    pinned: false,
                     program: "new-guid",
                     last_execute_time: Some("2024-11-17 11:00:00"),
-                    workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
                 },
                 CommandFixture {
                     id: "12fe0231-2850-4f9b-b11c-844147f50b3d",
+                    description: None,
+                    managed_by: None,
                     name: "Lint Rust codebase",
+                    pinned: false,
                     program: "becon",
                     last_execute_time: None,
-                    workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
                 },
             ],
         },
@@ -114,10 +165,420 @@ fn test_list_commands_operation_paginates() {
     let operation_result = test_case::execute_operation(
         &background,
         ListCommandsParameters {
+            created_after: None,
+            description_contains: None,
             program_contains: None,
             page_number: NonZeroU32::new(2),
             page_size: NonZeroU32::new(2),
-            workspace_id: None,
+            scope: CommandScope::All,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![
+                ExpectedCommand {
+                    id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    name: "Get directory items",
+                    pinned: false,
+                    program: "getchild-item .",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    tags: vec![],
+                },
+                ExpectedCommand {
+                    id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    name: "Ping",
+                    pinned: false,
+                    program: "ping 1.1.1.1",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    tags: vec![],
+                },
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_list_commands_operation_filters_by_all_of_tags() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            execute_counts: vec![],
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: Some("/home/ironman"),
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            commands: vec![
+                CommandFixture {
+                    id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    managed_by: None,
+                    name: "Push changes",
+                    pinned: false,
+                    program: "git push",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec!["git"],
+                },
+                CommandFixture {
+                    id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    managed_by: None,
+                    name: "Deploy tag",
+                    pinned: false,
+                    program: "git push --tags",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec!["deploy", "git"],
+                },
+                CommandFixture {
+                    id: "1d0c6b79-2ea9-4291-85bf-84b9412c3a52",
+                    description: None,
+                    managed_by: None,
+                    name: "Migrate database",
+                    pinned: false,
+                    program: "sqlx migrate run",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec!["db", "deploy"],
+                },
+            ],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandsParameters {
+            created_after: None,
+            description_contains: None,
+            program_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: CommandScope::All,
+            sort: SortOrder::Recency,
+            tags: Some(TagsFilter::AllOf(vec![
+                "Git".to_string(),
+                "deploy".to_string(),
+            ])),
+            updated_after: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![ExpectedCommand {
+                id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                description: None,
+                name: "Deploy tag",
+                pinned: false,
+                program: "git push --tags",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                tags: vec!["deploy", "git"],
+            }],
+        },
+    );
+}
+
+#[test]
+fn test_list_commands_operation_filters_by_any_of_tags() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            execute_counts: vec![],
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: Some("/home/ironman"),
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            commands: vec![
+                CommandFixture {
+                    id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    managed_by: None,
+                    name: "Push changes",
+                    pinned: false,
+                    program: "git push",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec!["git"],
+                },
+                CommandFixture {
+                    id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    managed_by: None,
+                    name: "Deploy tag",
+                    pinned: false,
+                    program: "git push --tags",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec!["deploy", "git"],
+                },
+                CommandFixture {
+                    id: "1d0c6b79-2ea9-4291-85bf-84b9412c3a52",
+                    description: None,
+                    managed_by: None,
+                    name: "Migrate database",
+                    pinned: false,
+                    program: "sqlx migrate run",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec!["db", "deploy"],
+                },
+            ],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandsParameters {
+            created_after: None,
+            description_contains: None,
+            program_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: CommandScope::All,
+            sort: SortOrder::Recency,
+            tags: Some(TagsFilter::AnyOf(vec!["db".to_string(), "git".to_string()])),
+            updated_after: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![
+                ExpectedCommand {
+                    id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    name: "Push changes",
+                    pinned: false,
+                    program: "git push",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    tags: vec!["git"],
+                },
+                ExpectedCommand {
+                    id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    name: "Deploy tag",
+                    pinned: false,
+                    program: "git push --tags",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    tags: vec!["deploy", "git"],
+                },
+                ExpectedCommand {
+                    id: "1d0c6b79-2ea9-4291-85bf-84b9412c3a52",
+                    description: None,
+                    name: "Migrate database",
+                    pinned: false,
+                    program: "sqlx migrate run",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    tags: vec!["db", "deploy"],
+                },
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_list_commands_operation_filters_by_description() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            execute_counts: vec![],
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: Some("/home/ironman"),
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            commands: vec![
+                CommandFixture {
+                    id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: Some("Checks network connectivity"),
+                    managed_by: None,
+                    name: "Ping",
+                    pinned: false,
+                    program: "ping 1.1.1.1",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
+                },
+                CommandFixture {
+                    id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    managed_by: None,
+                    name: "Get directory items",
+                    pinned: false,
+                    program: "GetChild-Item .",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
+                },
+            ],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandsParameters {
+            created_after: None,
+            description_contains: Some("Network"),
+            program_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: CommandScope::All,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![ExpectedCommand {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: Some("Checks network connectivity"),
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                tags: vec![],
+            }],
+        },
+    );
+}
+
+#[test]
+fn test_list_commands_operation_sorts_by_frecency() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            execute_counts: vec![
+                ("51280bfc-2eea-444a-8df9-a1e7158c2c6b", 1),
+                ("657acc69-aafe-426d-8496-9859bc40ca62", 10),
+            ],
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: Some("/home/ironman"),
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            commands: vec![
+                CommandFixture {
+                    id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    managed_by: None,
+                    name: "Ping",
+                    pinned: false,
+                    program: "ping 1.1.1.1",
+                    last_execute_time: Some("2024-11-17 11:30:00"),
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
+                },
+                CommandFixture {
+                    id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    managed_by: None,
+                    name: "Get directory items",
+                    pinned: false,
+                    program: "getchild-item .",
+                    last_execute_time: Some("2024-11-15 12:00:00"),
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
+                },
+                CommandFixture {
+                    id: "1d0c6b79-2ea9-4291-85bf-84b9412c3a52",
+                    description: None,
+                    managed_by: None,
+                    name: "Generate new UUID",
+                    pinned: false,
+                    program: "new-guid",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
+                },
+            ],
+        },
+    );
+
+    support::freeze_storage_time(
+        &background.storage,
+        support::parse_time("2024-11-17 11:59:59"),
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandsParameters {
+            created_after: None,
+            description_contains: None,
+            program_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: CommandScope::All,
+            sort: SortOrder::Frecency,
+            tags: None,
+            updated_after: None,
         },
     );
 
@@ -127,19 +588,289 @@ fn test_list_commands_operation_paginates() {
             expected_commands: vec![
                 ExpectedCommand {
                     id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
                     name: "Get directory items",
+                    pinned: false,
                     program: "getchild-item .",
+                    last_execute_time: Some("2024-11-15 12:00:00"),
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    tags: vec![],
+                },
+                ExpectedCommand {
+                    id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    name: "Ping",
+                    pinned: false,
+                    program: "ping 1.1.1.1",
+                    last_execute_time: Some("2024-11-17 11:30:00"),
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    tags: vec![],
+                },
+                ExpectedCommand {
+                    id: "1d0c6b79-2ea9-4291-85bf-84b9412c3a52",
+                    description: None,
+                    name: "Generate new UUID",
+                    pinned: false,
+                    program: "new-guid",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    tags: vec![],
+                },
+            ],
+        },
+    );
+}
+
+fn scoped_commands() -> Vec<CommandFixture<'static>> {
+    vec![
+        CommandFixture {
+            id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            description: None,
+            managed_by: None,
+            name: "Ping",
+            pinned: false,
+            program: "ping 1.1.1.1",
+            last_execute_time: None,
+            workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            shell: None,
+            tags: vec![],
+        },
+        CommandFixture {
+            id: "657acc69-aafe-426d-8496-9859bc40ca62",
+            description: None,
+            managed_by: None,
+            name: "Prune",
+            pinned: false,
+            program: "docker system prune",
+            last_execute_time: None,
+            workspace_id: None,
+            shell: None,
+            tags: vec![],
+        },
+        CommandFixture {
+            id: "d3e09c4a-3bb0-4c30-a4df-8b9d2a7b7d5c",
+            description: None,
+            managed_by: None,
+            name: "Test",
+            pinned: false,
+            program: "cargo test",
+            last_execute_time: None,
+            workspace_id: Some("637d207c-7a18-47eb-b0b4-7f27d4ecbf88"),
+            shell: None,
+            tags: vec![],
+        },
+    ]
+}
+
+#[test]
+fn test_list_commands_operation_lists_global_commands() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            execute_counts: vec![],
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: Some("/home/ironman"),
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            commands: scoped_commands(),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandsParameters {
+            created_after: None,
+            description_contains: None,
+            program_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: CommandScope::Global,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![ExpectedCommand {
+                id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                description: None,
+                name: "Prune",
+                pinned: false,
+                program: "docker system prune",
+                last_execute_time: None,
+                workspace_id: None,
+                tags: vec![],
+            }],
+        },
+    );
+}
+
+#[test]
+fn test_list_commands_operation_lists_workspace_commands_next_to_global_ones() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            execute_counts: vec![],
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: Some("/home/ironman"),
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            commands: scoped_commands(),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandsParameters {
+            created_after: None,
+            description_contains: None,
+            program_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: CommandScope::WorkspaceAndGlobal(support::parse_workspace_id(
+                "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            )),
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![
+                ExpectedCommand {
+                    id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    name: "Prune",
+                    pinned: false,
+                    program: "docker system prune",
                     last_execute_time: None,
-                    workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                    workspace_id: None,
+                    tags: vec![],
                 },
                 ExpectedCommand {
                     id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    name: "Ping",
+                    pinned: false,
+                    program: "ping 1.1.1.1",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    tags: vec![],
+                },
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_list_commands_operation_filters_by_update_time() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            execute_counts: vec![],
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: Some("/home/ironman"),
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            commands: vec![
+                CommandFixture {
+                    id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    description: None,
+                    managed_by: None,
                     name: "Ping",
+                    pinned: false,
                     program: "ping 1.1.1.1",
                     last_execute_time: None,
-                    workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
+                },
+                CommandFixture {
+                    id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                    description: None,
+                    managed_by: None,
+                    name: "Get directory items",
+                    pinned: false,
+                    program: "Get-ChildItem .",
+                    last_execute_time: None,
+                    workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                    shell: None,
+                    tags: vec![],
                 },
             ],
         },
     );
+
+    test_case::pin_command_at(
+        &background,
+        "657acc69-aafe-426d-8496-9859bc40ca62",
+        support::parse_time("2024-11-17 20:00:00"),
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListCommandsParameters {
+            created_after: None,
+            description_contains: None,
+            program_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: CommandScope::All,
+            sort: SortOrder::Updated,
+            tags: None,
+            updated_after: Some(support::parse_time("2024-11-17 20:00:00")),
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![ExpectedCommand {
+                id: "657acc69-aafe-426d-8496-9859bc40ca62",
+                description: None,
+                name: "Get directory items",
+                pinned: true,
+                program: "Get-ChildItem .",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                tags: vec![],
+            }],
+        },
+    );
 }
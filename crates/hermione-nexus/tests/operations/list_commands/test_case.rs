@@ -1,7 +1,8 @@
 use crate::support::{self, CommandFixture, ExpectedCommand, InMemoryStorage, WorkspaceFixture};
+use chrono::{DateTime, Utc};
 use hermione_nexus::{
     definitions::Command,
-    operations::{ListCommandsOperation, ListCommandsParameters},
+    operations::{ListCommandsOperation, ListCommandsParameters, PinCommandOperation},
     Error,
 };
 
@@ -10,6 +11,7 @@ pub struct Background {
 }
 
 pub struct BackgroundContext<'a> {
+    pub execute_counts: Vec<(&'a str, u32)>,
     pub workspace: WorkspaceFixture<'a>,
     pub commands: Vec<CommandFixture<'a>>,
 }
@@ -41,14 +43,29 @@ pub fn execute_operation(
     ListCommandsOperation { provider: storage }.execute(parameters)
 }
 
+pub fn pin_command_at(background: &Background, id: &str, time: DateTime<Utc>) {
+    let Background { storage } = background;
+
+    support::freeze_storage_time(storage, time);
+
+    PinCommandOperation {
+        find_command_provider: storage,
+        update_command_provider: storage,
+    }
+    .execute(support::parse_command_id(id))
+    .unwrap();
+}
+
 pub fn setup(backgound: &Background, context: BackgroundContext) {
     let Background { storage } = backgound;
 
     let BackgroundContext {
+        execute_counts,
         workspace,
         commands,
     } = context;
 
     support::insert_workspace(storage, workspace);
     support::insert_commands(storage, commands);
+    support::insert_execute_counts(storage, execute_counts);
 }
@@ -0,0 +1,162 @@
+mod test_case;
+
+use crate::support::MockSystem;
+use hermione_nexus::{
+    definitions::ShellHistoryFormat,
+    operations::{ListHistoryCommandsParameters, ShellHistorySource},
+};
+use std::num::NonZeroU32;
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+#[test]
+fn test_list_history_commands_operation_parses_bash_history() {
+    let background = Background {
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            files: vec![(
+                "/home/ironman/.bash_history",
+                "#1731870000\nls -la\n#1731870010\ncargo test\nls -la\n\n",
+            )],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListHistoryCommandsParameters {
+            sources: vec![ShellHistorySource {
+                format: ShellHistoryFormat::Bash,
+                path: "/home/ironman/.bash_history",
+            }],
+            limit: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![("ls -la", 2), ("cargo test", 1)],
+        },
+    );
+}
+
+#[test]
+fn test_list_history_commands_operation_parses_zsh_extended_history() {
+    let background = Background {
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            files: vec![(
+                "/home/ironman/.zsh_history",
+                ": 1731870000:0;git status\n: 1731870010:2;docker run \\\n  --rm alpine\n: 1731870020:0;git status\n",
+            )],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListHistoryCommandsParameters {
+            sources: vec![ShellHistorySource {
+                format: ShellHistoryFormat::Zsh,
+                path: "/home/ironman/.zsh_history",
+            }],
+            limit: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![("git status", 2), ("docker run \n  --rm alpine", 1)],
+        },
+    );
+}
+
+#[test]
+fn test_list_history_commands_operation_parses_fish_history() {
+    let background = Background {
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            files: vec![(
+                "/home/ironman/.local/share/fish/fish_history",
+                "- cmd: kubectl get pods\n  when: 1731870000\n- cmd: echo one\\ntwo\n  when: 1731870010\n  paths:\n    - two\n",
+            )],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListHistoryCommandsParameters {
+            sources: vec![ShellHistorySource {
+                format: ShellHistoryFormat::Fish,
+                path: "/home/ironman/.local/share/fish/fish_history",
+            }],
+            limit: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![("echo one\ntwo", 1), ("kubectl get pods", 1)],
+        },
+    );
+}
+
+#[test]
+fn test_list_history_commands_operation_merges_sources_and_skips_missing_files() {
+    let background = Background {
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            files: vec![
+                (
+                    "/home/ironman/ConsoleHost_history.txt",
+                    "Get-ChildItem\nWrite-Host `\n  hello\nGet-ChildItem\n",
+                ),
+                ("/home/ironman/.bash_history", "Get-ChildItem\npwd\n"),
+            ],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListHistoryCommandsParameters {
+            sources: vec![
+                ShellHistorySource {
+                    format: ShellHistoryFormat::PowerShell,
+                    path: "/home/ironman/ConsoleHost_history.txt",
+                },
+                ShellHistorySource {
+                    format: ShellHistoryFormat::Zsh,
+                    path: "/home/ironman/.zsh_history",
+                },
+                ShellHistorySource {
+                    format: ShellHistoryFormat::Bash,
+                    path: "/home/ironman/.bash_history",
+                },
+            ],
+            limit: NonZeroU32::new(2),
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_commands: vec![("Get-ChildItem", 3), ("pwd", 1)],
+        },
+    );
+}
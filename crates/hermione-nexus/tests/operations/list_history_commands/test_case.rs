@@ -0,0 +1,60 @@
+use crate::support::{self, MockSystem};
+use hermione_nexus::{
+    definitions::HistoryCommand,
+    operations::{ListHistoryCommandsOperation, ListHistoryCommandsParameters},
+    Error,
+};
+
+pub struct Background {
+    pub system: MockSystem,
+}
+
+pub struct BackgroundContext<'a> {
+    pub files: Vec<(&'a str, &'a str)>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    Success {
+        expected_commands: Vec<(&'a str, u32)>,
+    },
+}
+
+pub fn assert_operation_result(
+    operation_result: Result<Vec<HistoryCommand>, Error>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::Success { expected_commands } => {
+            let commands = operation_result
+                .unwrap()
+                .iter()
+                .map(|command| (command.program().to_string(), command.count()))
+                .collect::<Vec<(String, u32)>>();
+
+            let expected_commands = expected_commands
+                .into_iter()
+                .map(|(program, count)| (program.to_string(), count))
+                .collect::<Vec<(String, u32)>>();
+
+            assert_eq!(commands, expected_commands);
+        }
+    }
+}
+
+pub fn execute_operation(
+    background: &Background,
+    parameters: ListHistoryCommandsParameters,
+) -> Result<Vec<HistoryCommand>, Error> {
+    let Background { system } = background;
+
+    ListHistoryCommandsOperation {
+        read_file_provider: system,
+    }
+    .execute(parameters)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext { files } = context;
+
+    support::insert_files(&background.system, files);
+}
@@ -0,0 +1,86 @@
+mod test_case;
+
+use crate::support::{self, CommandFixture, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::definitions::{TrashEntry, TrashItemId};
+use test_case::{Background, BackgroundContext};
+
+fn background_context<'a>() -> BackgroundContext<'a> {
+    BackgroundContext {
+        workspace: WorkspaceFixture {
+            id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            name: "Ironman",
+            parent_id: None,
+            pinned: false,
+            location: None,
+            last_access_time: None,
+            shell: None,
+            tags: vec![],
+        },
+        commands: vec![
+            CommandFixture {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: None,
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            },
+            CommandFixture {
+                id: "8c1ea1c3-3d2b-4a4e-9d6f-57b1dc1c5f70",
+                description: None,
+                managed_by: None,
+                name: "Date",
+                pinned: false,
+                program: "date",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_list_trash_operation_hides_commands_of_trashed_workspaces() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    support::move_to_trash(
+        &background.storage,
+        TrashItemId::Command(support::parse_command_id(
+            "8c1ea1c3-3d2b-4a4e-9d6f-57b1dc1c5f70",
+        )),
+        "2024-11-17 20:00:00",
+    );
+    support::move_to_trash(
+        &background.storage,
+        TrashItemId::Workspace(support::parse_workspace_id(
+            "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        )),
+        "2024-11-18 20:00:00",
+    );
+
+    let trash = test_case::execute_operation(&background).unwrap();
+
+    let entries: Vec<String> = trash
+        .iter()
+        .map(|item| match item.entry() {
+            TrashEntry::Command(command) => command.program().to_string(),
+            TrashEntry::Workspace(workspace) => workspace.name().to_string(),
+        })
+        .collect();
+
+    assert_eq!(entries, vec!["Ironman"]);
+    assert_eq!(
+        trash[0].deleted_at(),
+        &support::parse_time("2024-11-18 20:00:01")
+    );
+}
@@ -0,0 +1,35 @@
+use crate::support::{self, CommandFixture, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::{
+    definitions::TrashItem,
+    operations::{ListTrashOperation, ListTrashParameters},
+    Result,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub workspace: WorkspaceFixture<'a>,
+    pub commands: Vec<CommandFixture<'a>>,
+}
+
+pub fn execute_operation(background: &Background) -> Result<Vec<TrashItem>> {
+    let Background { storage } = background;
+
+    ListTrashOperation { provider: storage }.execute(ListTrashParameters {
+        page_number: None,
+        page_size: None,
+    })
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let Background { storage } = background;
+    let BackgroundContext {
+        workspace,
+        commands,
+    } = context;
+
+    support::insert_workspace(storage, workspace);
+    support::insert_commands(storage, commands);
+}
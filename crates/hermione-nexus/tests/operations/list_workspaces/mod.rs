@@ -2,8 +2,11 @@ mod test_case;
 
 use std::num::NonZeroU32;
 
-use crate::support::{ExpectedWorkspace, InMemoryStorage, WorkspaceFixture};
-use hermione_nexus::operations::ListWorkspacesParameters;
+use crate::support::{self, ExpectedWorkspace, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::{
+    operations::ListWorkspacesParameters,
+    services::{SortOrder, TagsFilter, WorkspaceScope},
+};
 use test_case::{Background, ExpectedOperationResult};
 
 #[test]
@@ -18,14 +21,22 @@ fn test_list_workspace_operation_returns_workspace_filtered_by_name() {
             WorkspaceFixture {
                 id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                 name: "Ironman",
+                parent_id: None,
+                pinned: false,
                 location: Some("/home/ironman"),
                 last_access_time: None,
+                shell: None,
+                tags: vec![],
             },
             WorkspaceFixture {
                 id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
                 name: "Avenger",
+                parent_id: None,
+                pinned: false,
                 location: None,
                 last_access_time: None,
+                shell: None,
+                tags: vec![],
             },
         ],
     );
@@ -33,9 +44,14 @@ fn test_list_workspace_operation_returns_workspace_filtered_by_name() {
     let operation_result = test_case::execute_operation(
         &background,
         ListWorkspacesParameters {
+            created_after: None,
             name_contains: Some("man"),
             page_number: None,
             page_size: None,
+            scope: WorkspaceScope::All,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
         },
     );
 
@@ -45,8 +61,10 @@ fn test_list_workspace_operation_returns_workspace_filtered_by_name() {
             expected_workspaces: vec![ExpectedWorkspace {
                 id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                 name: "Ironman",
+                pinned: false,
                 location: Some("/home/ironman"),
                 last_access_time: None,
+                tags: vec![],
             }],
         },
     );
@@ -64,26 +82,42 @@ fn test_list_workspace_operation_paginates_workspaces() {
             WorkspaceFixture {
                 id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                 name: "Ironman",
+                parent_id: None,
+                pinned: false,
                 location: Some("/home/ironman"),
                 last_access_time: None,
+                shell: None,
+                tags: vec![],
             },
             WorkspaceFixture {
                 id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
                 name: "Avenger",
+                parent_id: None,
+                pinned: false,
                 location: None,
                 last_access_time: None,
+                shell: None,
+                tags: vec![],
             },
             WorkspaceFixture {
                 id: "19e0f51f-efaa-4b22-a35d-17c37f350823",
                 location: Some("/home/batman"),
                 name: "Batman",
+                parent_id: None,
+                pinned: false,
                 last_access_time: Some("2024-11-17 20:00:00"),
+                shell: None,
+                tags: vec![],
             },
             WorkspaceFixture {
                 id: "d9469304-ec44-4c84-8612-7ba3c27b9e29",
                 location: Some("/home/vision"),
                 name: "Vision",
+                parent_id: None,
+                pinned: false,
                 last_access_time: None,
+                shell: None,
+                tags: vec![],
             },
         ],
     );
@@ -91,9 +125,14 @@ fn test_list_workspace_operation_paginates_workspaces() {
     let operation_result = test_case::execute_operation(
         &background,
         ListWorkspacesParameters {
+            created_after: None,
             name_contains: None,
             page_number: NonZeroU32::new(2),
             page_size: NonZeroU32::new(2),
+            scope: WorkspaceScope::All,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
         },
     );
 
@@ -104,14 +143,18 @@ fn test_list_workspace_operation_paginates_workspaces() {
                 ExpectedWorkspace {
                     id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                     name: "Ironman",
+                    pinned: false,
                     location: Some("/home/ironman"),
                     last_access_time: None,
+                    tags: vec![],
                 },
                 ExpectedWorkspace {
                     id: "d9469304-ec44-4c84-8612-7ba3c27b9e29",
                     location: Some("/home/vision"),
                     name: "Vision",
+                    pinned: false,
                     last_access_time: None,
+                    tags: vec![],
                 },
             ],
         },
@@ -130,20 +173,32 @@ fn test_list_workspace_operation_sorts_workspaces_by_last_access_time_and_name()
             WorkspaceFixture {
                 id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                 name: "Ironman",
+                parent_id: None,
+                pinned: false,
                 location: Some("/home/ironman"),
                 last_access_time: None,
+                shell: None,
+                tags: vec![],
             },
             WorkspaceFixture {
                 id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
                 name: "Avenger",
+                parent_id: None,
+                pinned: false,
                 location: None,
                 last_access_time: None,
+                shell: None,
+                tags: vec![],
             },
             WorkspaceFixture {
                 id: "19e0f51f-efaa-4b22-a35d-17c37f350823",
                 location: Some("/home/batman"),
                 name: "Batman",
+                parent_id: None,
+                pinned: false,
                 last_access_time: Some("2024-11-17 20:00:00"),
+                shell: None,
+                tags: vec![],
             },
         ],
     );
@@ -151,9 +206,14 @@ fn test_list_workspace_operation_sorts_workspaces_by_last_access_time_and_name()
     let operation_result = test_case::execute_operation(
         &background,
         ListWorkspacesParameters {
+            created_after: None,
             name_contains: None,
             page_number: NonZeroU32::new(1),
             page_size: NonZeroU32::new(10),
+            scope: WorkspaceScope::All,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
         },
     );
 
@@ -165,21 +225,253 @@ fn test_list_workspace_operation_sorts_workspaces_by_last_access_time_and_name()
                     id: "19e0f51f-efaa-4b22-a35d-17c37f350823",
                     location: Some("/home/batman"),
                     name: "Batman",
+                    pinned: false,
                     last_access_time: Some("2024-11-17 20:00:00"),
+                    tags: vec![],
                 },
                 ExpectedWorkspace {
                     id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
                     name: "Avenger",
+                    pinned: false,
                     location: None,
                     last_access_time: None,
+                    tags: vec![],
                 },
                 ExpectedWorkspace {
                     id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                     name: "Ironman",
+                    pinned: false,
                     location: Some("/home/ironman"),
                     last_access_time: None,
+                    tags: vec![],
                 },
             ],
         },
     );
 }
+
+#[test]
+fn test_list_workspace_operation_puts_pinned_workspaces_first() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        vec![
+            WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: None,
+                last_access_time: Some("2024-11-17 20:00:00"),
+                shell: None,
+                tags: vec![],
+            },
+            WorkspaceFixture {
+                id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
+                name: "Avenger",
+                parent_id: None,
+                pinned: true,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+        ],
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListWorkspacesParameters {
+            created_after: None,
+            name_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: WorkspaceScope::All,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_workspaces: vec![
+                ExpectedWorkspace {
+                    id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
+                    name: "Avenger",
+                    pinned: true,
+                    location: None,
+                    last_access_time: None,
+                    tags: vec![],
+                },
+                ExpectedWorkspace {
+                    id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                    name: "Ironman",
+                    pinned: false,
+                    location: None,
+                    last_access_time: Some("2024-11-17 20:00:00"),
+                    tags: vec![],
+                },
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_list_workspace_operation_filters_workspaces_by_tags() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        vec![
+            WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: Some("/home/ironman"),
+                last_access_time: None,
+                shell: None,
+                tags: vec!["marvel", "suit"],
+            },
+            WorkspaceFixture {
+                id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
+                name: "Avenger",
+                parent_id: None,
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec!["marvel"],
+            },
+        ],
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListWorkspacesParameters {
+            created_after: None,
+            name_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: WorkspaceScope::All,
+            sort: SortOrder::Recency,
+            tags: Some(TagsFilter::AllOf(vec![
+                "marvel".to_string(),
+                "suit".to_string(),
+            ])),
+            updated_after: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_workspaces: vec![ExpectedWorkspace {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                pinned: false,
+                location: Some("/home/ironman"),
+                last_access_time: None,
+                tags: vec!["marvel", "suit"],
+            }],
+        },
+    );
+}
+
+#[test]
+fn test_list_workspace_operation_returns_children_of_workspace() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        vec![
+            WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Clients",
+                parent_id: None,
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            WorkspaceFixture {
+                id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
+                name: "Acme",
+                parent_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+        ],
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListWorkspacesParameters {
+            created_after: None,
+            name_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: WorkspaceScope::Children(support::parse_workspace_id(
+                "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            )),
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_workspaces: vec![ExpectedWorkspace {
+                id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
+                name: "Acme",
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                tags: vec![],
+            }],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        ListWorkspacesParameters {
+            created_after: None,
+            name_contains: None,
+            page_number: None,
+            page_size: None,
+            scope: WorkspaceScope::Roots,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
+        },
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_workspaces: vec![ExpectedWorkspace {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Clients",
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                tags: vec![],
+            }],
+        },
+    );
+}
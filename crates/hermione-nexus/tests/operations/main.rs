@@ -1,22 +1,49 @@
 #[macro_use]
 pub mod support;
 
+mod apply_undo;
+mod clone_workspace;
+mod copy_command;
 mod copy_command_to_clipboard;
 mod create_command;
 mod create_workspace;
 mod delete_backup_credentials;
 mod delete_command;
 mod delete_commands;
+mod delete_secret;
 mod delete_workspace;
+mod diff_command_revisions;
+mod discover_workspaces;
 mod execute_command;
+mod execute_command_chain;
 mod execute_program;
+mod export_bundle;
+mod export_cheat_sheet;
 mod get_backup_credentials;
 mod get_command;
 mod get_workspace;
+mod import_bundle;
 mod list_backup_credentials;
+mod list_command_executions;
+mod list_command_revisions;
 mod list_commands;
+mod list_history_commands;
+mod list_trash;
 mod list_workspaces;
+mod move_command;
+mod move_to_trash;
+mod pin_command;
+mod pin_workspace;
+mod purge_trash;
+mod restore_command_revision;
+mod restore_from_trash;
 mod save_backup_credentials;
+mod save_secret;
+mod sync_project_tasks;
+mod sync_repository_commands;
+mod unpin_command;
+mod unpin_workspace;
 mod update_command;
 mod update_workspace;
+mod update_workspace_environment_variables;
 mod visit_workspace_location;
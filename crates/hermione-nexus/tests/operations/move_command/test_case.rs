@@ -0,0 +1,108 @@
+use crate::support::{
+    self, CommandExecutionFixture, CommandFixture, ExpectedCommand, ExpectedCommandExecution,
+    InMemoryStorage, WorkspaceFixture,
+};
+use hermione_nexus::{
+    definitions::Command,
+    operations::{MoveCommandOperation, MoveCommandParameters},
+    Result,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub workspaces: Vec<WorkspaceFixture<'a>>,
+    pub command: CommandFixture<'a>,
+    pub command_executions: Vec<CommandExecutionFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    InvalidArgument,
+    NotFound,
+    Success {
+        expected_command: ExpectedCommand<'a>,
+    },
+}
+
+pub struct ExpectedStorageState<'a> {
+    pub expected_command: ExpectedCommand<'a>,
+
+    /// Executions that still refer to the command.
+    pub expected_command_executions: Vec<ExpectedCommandExecution<'a>>,
+    pub expected_executions_count: usize,
+}
+
+pub fn assert_operation_result(result: Result<Command>, expected: ExpectedOperationResult) {
+    match expected {
+        ExpectedOperationResult::InvalidArgument => {
+            assert!(result.is_err_and(|err| err.is_invalid_argument()))
+        }
+        ExpectedOperationResult::NotFound => {
+            assert!(result.is_err_and(|err| err.is_not_found()))
+        }
+        ExpectedOperationResult::Success { expected_command } => {
+            assert!(result.is_ok());
+            support::assert_command(result.unwrap(), expected_command);
+        }
+    }
+}
+
+pub fn assert_storage_state(background: &Background, expected: ExpectedStorageState) {
+    let Background { storage } = background;
+
+    let ExpectedStorageState {
+        expected_command,
+        expected_command_executions,
+        expected_executions_count,
+    } = expected;
+
+    let command = support::get_command(storage, support::parse_command_id(expected_command.id));
+    let executions = support::list_command_executions(storage);
+
+    assert_eq!(executions.len(), expected_executions_count);
+
+    support::assert_command_executions(
+        executions
+            .into_iter()
+            .filter(|execution| execution.command_id() == Some(command.id()))
+            .collect(),
+        expected_command_executions,
+    );
+    support::assert_command(command, expected_command);
+}
+
+pub fn execute_operation(
+    background: &Background,
+    command_id: &str,
+    workspace_id: Option<&str>,
+    keep_history: bool,
+) -> Result<Command> {
+    let Background { storage } = background;
+
+    MoveCommandOperation {
+        find_command_provider: storage,
+        find_workspace_provider: storage,
+        move_command_provider: storage,
+    }
+    .execute(MoveCommandParameters {
+        id: support::parse_command_id(command_id),
+        keep_history,
+        workspace_id: workspace_id.map(support::parse_workspace_id),
+    })
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let Background { storage } = background;
+
+    let BackgroundContext {
+        workspaces,
+        command,
+        command_executions,
+    } = context;
+
+    support::insert_workspaces(storage, workspaces);
+    support::insert_command(storage, command);
+    support::insert_command_executions(storage, command_executions);
+}
@@ -0,0 +1,51 @@
+use crate::support::{self, CommandFixture, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::{definitions::TrashItemId, operations::MoveToTrashOperation, Result};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub workspace: WorkspaceFixture<'a>,
+    pub commands: Vec<CommandFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult {
+    InvalidArgument,
+    NotFound,
+    Success,
+}
+
+pub fn assert_operation_result(result: Result<()>, expected: ExpectedOperationResult) {
+    match expected {
+        ExpectedOperationResult::InvalidArgument => {
+            assert!(result.is_err_and(|err| err.is_invalid_argument()))
+        }
+        ExpectedOperationResult::NotFound => {
+            assert!(result.is_err_and(|err| err.is_not_found()))
+        }
+        ExpectedOperationResult::Success => assert!(result.is_ok()),
+    }
+}
+
+pub fn execute_operation(background: &Background, id: TrashItemId) -> Result<()> {
+    let Background { storage } = background;
+
+    MoveToTrashOperation {
+        find_command_provider: storage,
+        find_workspace_provider: storage,
+        move_to_trash_provider: storage,
+    }
+    .execute(id)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let Background { storage } = background;
+    let BackgroundContext {
+        workspace,
+        commands,
+    } = context;
+
+    support::insert_workspace(storage, workspace);
+    support::insert_commands(storage, commands);
+}
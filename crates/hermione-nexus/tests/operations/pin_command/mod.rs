@@ -0,0 +1,124 @@
+mod test_case;
+
+use crate::support::{CommandFixture, ExpectedCommand, InMemoryStorage, WorkspaceFixture};
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+#[test]
+fn test_pin_command_operation_succeeds() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            command: CommandFixture {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: None,
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            },
+        },
+    );
+
+    let operation_result =
+        test_case::execute_operation(&background, "51280bfc-2eea-444a-8df9-a1e7158c2c6b");
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_command: ExpectedCommand {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                name: "Ping",
+                pinned: true,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                tags: vec![],
+            },
+        },
+    );
+
+    test_case::assert_storage_contains_command(
+        &background,
+        "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        true,
+    );
+}
+
+#[test]
+fn test_pin_command_operation_pins_repository_managed_command() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+            command: CommandFixture {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: Some("repository"),
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            },
+        },
+    );
+
+    let operation_result =
+        test_case::execute_operation(&background, "51280bfc-2eea-444a-8df9-a1e7158c2c6b");
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_command: ExpectedCommand {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                name: "Ping",
+                pinned: true,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                tags: vec![],
+            },
+        },
+    );
+
+    test_case::assert_storage_contains_command(
+        &background,
+        "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        true,
+    );
+}
@@ -0,0 +1,49 @@
+use crate::support::{self, CommandFixture, ExpectedCommand, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::{definitions::Command, operations::PinCommandOperation, Result};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub workspace: WorkspaceFixture<'a>,
+    pub command: CommandFixture<'a>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    Success {
+        expected_command: ExpectedCommand<'a>,
+    },
+}
+
+pub fn assert_operation_result(result: Result<Command>, expected: ExpectedOperationResult) {
+    match expected {
+        ExpectedOperationResult::Success { expected_command } => {
+            assert!(result.is_ok());
+            support::assert_command(result.unwrap(), expected_command);
+        }
+    }
+}
+
+pub fn assert_storage_contains_command(background: &Background, command_id: &str, pinned: bool) {
+    let command = support::get_command(&background.storage, support::parse_command_id(command_id));
+
+    assert_eq!(command.is_pinned(), pinned);
+}
+
+pub fn execute_operation(background: &Background, command_id: &str) -> Result<Command> {
+    let Background { storage } = background;
+
+    PinCommandOperation {
+        find_command_provider: storage,
+        update_command_provider: storage,
+    }
+    .execute(support::parse_command_id(command_id))
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext { workspace, command } = context;
+
+    support::insert_workspace(&background.storage, workspace);
+    support::insert_command(&background.storage, command);
+}
@@ -0,0 +1,142 @@
+mod test_case;
+
+use crate::support::{self, CommandFixture, ExpectedTrashState, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::{definitions::TrashItemId, services::PurgeTrashScope};
+use std::time::Duration;
+use test_case::{Background, BackgroundContext};
+
+fn background_context<'a>() -> BackgroundContext<'a> {
+    BackgroundContext {
+        workspace: WorkspaceFixture {
+            id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            name: "Ironman",
+            parent_id: None,
+            pinned: false,
+            location: None,
+            last_access_time: None,
+            shell: None,
+            tags: vec![],
+        },
+        commands: vec![
+            CommandFixture {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: None,
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            },
+            CommandFixture {
+                id: "8c1ea1c3-3d2b-4a4e-9d6f-57b1dc1c5f70",
+                description: None,
+                managed_by: None,
+                name: "Date",
+                pinned: false,
+                program: "date",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_purge_trash_operation_purges_items_older_than_retention() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    support::move_to_trash(
+        &background.storage,
+        TrashItemId::Command(support::parse_command_id(
+            "8c1ea1c3-3d2b-4a4e-9d6f-57b1dc1c5f70",
+        )),
+        "2024-11-01 20:00:00",
+    );
+    support::move_to_trash(
+        &background.storage,
+        TrashItemId::Command(support::parse_command_id(
+            "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        )),
+        "2024-11-17 20:00:00",
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        PurgeTrashScope::OlderThan(Duration::from_secs(7 * 24 * 60 * 60)),
+    );
+
+    assert!(operation_result.is_ok());
+
+    support::assert_trash_state(
+        &background.storage,
+        ExpectedTrashState {
+            command_ids: vec![],
+            trashed_command_ids: vec!["51280bfc-2eea-444a-8df9-a1e7158c2c6b"],
+            trashed_workspace_ids: vec![],
+            workspace_ids: vec!["9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"],
+        },
+    );
+}
+
+#[test]
+fn test_purge_trash_operation_purges_workspace_with_its_commands() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    support::move_to_trash(
+        &background.storage,
+        TrashItemId::Workspace(support::parse_workspace_id(
+            "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        )),
+        "2024-11-17 20:00:00",
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        PurgeTrashScope::Item(TrashItemId::Workspace(support::parse_workspace_id(
+            "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        ))),
+    );
+
+    assert!(operation_result.is_ok());
+
+    support::assert_trash_state(
+        &background.storage,
+        ExpectedTrashState {
+            command_ids: vec![],
+            trashed_command_ids: vec![],
+            trashed_workspace_ids: vec![],
+            workspace_ids: vec![],
+        },
+    );
+}
+
+#[test]
+fn test_purge_trash_operation_fails_for_missing_item() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        PurgeTrashScope::Item(TrashItemId::Command(support::parse_command_id(
+            "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        ))),
+    );
+
+    assert!(operation_result.is_err_and(|err| err.is_not_found()));
+}
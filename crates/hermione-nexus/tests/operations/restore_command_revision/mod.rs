@@ -0,0 +1,105 @@
+mod test_case;
+
+use crate::support::{
+    self, CommandFixture, CommandRevisionFixture, ExpectedCommand, InMemoryStorage,
+};
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+fn background_context<'a>() -> BackgroundContext<'a> {
+    BackgroundContext {
+        command: CommandFixture {
+            id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            description: Some("Checks connectivity"),
+            managed_by: None,
+            name: "Ping Cloudflare",
+            pinned: false,
+            program: "ping -c 4 1.1.1.1",
+            last_execute_time: None,
+            workspace_id: None,
+            shell: None,
+            tags: vec!["network"],
+        },
+        command_revisions: vec![CommandRevisionFixture {
+            id: "0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d",
+            command_id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            created_at: "2024-11-17 20:20:00",
+            name: "Ping",
+            program: "ping 1.1.1.1",
+        }],
+    }
+}
+
+#[test]
+fn test_restore_command_revision_operation_succeeds() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        support::parse_command_revision_id("0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d"),
+    );
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_command: ExpectedCommand {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: Some("Checks connectivity"),
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: None,
+                tags: vec!["network"],
+            },
+        },
+    );
+
+    test_case::assert_command_revisions(
+        &background,
+        vec![
+            ("Ping", "ping 1.1.1.1"),
+            ("Ping Cloudflare", "ping -c 4 1.1.1.1"),
+        ],
+    );
+}
+
+#[test]
+fn test_restore_command_revision_operation_fails_for_unknown_revision() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(&background, background_context());
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        support::parse_command_revision_id("b1c2d3e4-f5a6-4b7c-8d9e-0f1a2b3c4d5e"),
+    );
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::NotFound);
+}
+
+#[test]
+fn test_restore_command_revision_operation_rejects_repository_managed_command() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    let mut context = background_context();
+    context.command.managed_by = Some("repository");
+
+    test_case::setup(&background, context);
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        support::parse_command_revision_id("0f1b9a3e-6d2c-4a5b-8c7d-9e0f1a2b3c4d"),
+    );
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::InvalidArgument);
+
+    test_case::assert_command_revisions(&background, vec![("Ping", "ping 1.1.1.1")]);
+}
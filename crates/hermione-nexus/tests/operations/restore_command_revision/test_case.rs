@@ -0,0 +1,78 @@
+use crate::support::{
+    self, CommandFixture, CommandRevisionFixture, ExpectedCommand, InMemoryStorage,
+};
+use hermione_nexus::{
+    definitions::{Command, CommandRevisionId},
+    operations::RestoreCommandRevisionOperation,
+    Error,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub command: CommandFixture<'a>,
+    pub command_revisions: Vec<CommandRevisionFixture<'a>>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    Success {
+        expected_command: ExpectedCommand<'a>,
+    },
+    InvalidArgument,
+    NotFound,
+}
+
+pub fn assert_command_revisions(background: &Background, expected: Vec<(&str, &str)>) {
+    let revisions = support::list_command_revisions(&background.storage)
+        .iter()
+        .map(|revision| (revision.name().to_string(), revision.program().to_string()))
+        .collect::<Vec<(String, String)>>();
+
+    let expected = expected
+        .into_iter()
+        .map(|(name, program)| (name.to_string(), program.to_string()))
+        .collect::<Vec<(String, String)>>();
+
+    assert_eq!(revisions, expected);
+}
+
+pub fn assert_operation_result(
+    operation_result: Result<Command, Error>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::Success { expected_command } => {
+            support::assert_command(operation_result.unwrap(), expected_command);
+        }
+        ExpectedOperationResult::InvalidArgument => {
+            assert!(operation_result.is_err_and(|err| err.is_invalid_argument()))
+        }
+        ExpectedOperationResult::NotFound => {
+            assert!(operation_result.is_err_and(|err| err.is_not_found()))
+        }
+    }
+}
+
+pub fn execute_operation(background: &Background, id: CommandRevisionId) -> Result<Command, Error> {
+    let Background { storage } = background;
+
+    RestoreCommandRevisionOperation {
+        create_revision_provider: storage,
+        find_command_provider: storage,
+        find_revision_provider: storage,
+        update_command_provider: storage,
+    }
+    .execute(id)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext {
+        command,
+        command_revisions,
+    } = context;
+
+    support::insert_command(&background.storage, command);
+    support::insert_command_revisions(&background.storage, command_revisions);
+}
@@ -0,0 +1,32 @@
+use crate::support::{self, CommandFixture, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::{definitions::TrashItemId, operations::RestoreFromTrashOperation, Result};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub struct BackgroundContext<'a> {
+    pub workspace: WorkspaceFixture<'a>,
+    pub commands: Vec<CommandFixture<'a>>,
+}
+
+pub fn execute_operation(background: &Background, id: TrashItemId) -> Result<()> {
+    let Background { storage } = background;
+
+    RestoreFromTrashOperation {
+        find_trash_item_provider: storage,
+        restore_from_trash_provider: storage,
+    }
+    .execute(id)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let Background { storage } = background;
+    let BackgroundContext {
+        workspace,
+        commands,
+    } = context;
+
+    support::insert_workspace(storage, workspace);
+    support::insert_commands(storage, commands);
+}
@@ -0,0 +1,42 @@
+mod test_case;
+
+use crate::support::InMemorySecretStore;
+use test_case::{Background, ExpectedOperationResult};
+
+#[test]
+fn test_save_secret_operation_succeeds() {
+    let background = Background {
+        secret_store: InMemorySecretStore::default(),
+    };
+
+    let operation_result = test_case::execute_operation(&background, " db_pw ", "jarvis");
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::Success);
+    test_case::assert_stored_secret(&background, "db_pw", Some("jarvis"));
+}
+
+#[test]
+fn test_save_secret_operation_replaces_existing_value() {
+    let background = Background {
+        secret_store: InMemorySecretStore::default(),
+    };
+
+    test_case::setup(&background, vec![("db_pw", "jarvis")]);
+
+    let operation_result = test_case::execute_operation(&background, "db_pw", "friday");
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::Success);
+    test_case::assert_stored_secret(&background, "db_pw", Some("friday"));
+}
+
+#[test]
+fn test_save_secret_operation_fails_for_invalid_name() {
+    let background = Background {
+        secret_store: InMemorySecretStore::default(),
+    };
+
+    let operation_result = test_case::execute_operation(&background, "db pw", "jarvis");
+
+    test_case::assert_operation_result(operation_result, ExpectedOperationResult::InvalidArgument);
+    test_case::assert_stored_secret(&background, "db pw", None);
+}
@@ -0,0 +1,52 @@
+use crate::support::{self, InMemorySecretStore};
+use hermione_nexus::{
+    definitions::Secret,
+    operations::{SaveSecretOperation, SaveSecretParameters},
+    Error,
+};
+
+pub struct Background {
+    pub secret_store: InMemorySecretStore,
+}
+
+pub enum ExpectedOperationResult {
+    InvalidArgument,
+    Success,
+}
+
+pub fn assert_operation_result(result: Result<Secret, Error>, expected: ExpectedOperationResult) {
+    match expected {
+        ExpectedOperationResult::InvalidArgument => {
+            assert!(result.is_err_and(|err| err.is_invalid_argument()))
+        }
+        ExpectedOperationResult::Success => assert!(result.is_ok()),
+    }
+}
+
+pub fn assert_stored_secret(background: &Background, name: &str, expected_value: Option<&str>) {
+    let secret = support::maybe_get_secret(&background.secret_store, name);
+
+    assert_eq!(secret.as_ref().map(Secret::value), expected_value);
+}
+
+pub fn execute_operation(
+    background: &Background,
+    name: &str,
+    value: &str,
+) -> Result<Secret, Error> {
+    let Background { secret_store } = background;
+
+    SaveSecretOperation {
+        provider: secret_store,
+    }
+    .execute(SaveSecretParameters {
+        name: name.to_string(),
+        value: value.to_string(),
+    })
+}
+
+pub fn setup(background: &Background, secrets: Vec<(&str, &str)>) {
+    let Background { secret_store } = background;
+
+    support::insert_secrets(secret_store, secrets);
+}
@@ -316,7 +316,10 @@ impl From<Command> for NotionCommand {
             external_id: value.id().to_string(),
             name: value.name().to_string(),
             program: value.program().to_string(),
-            workspace_id: value.workspace_id().to_string(),
+            workspace_id: value
+                .workspace_id()
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
         }
     }
 }
@@ -342,20 +345,33 @@ impl TryFrom<NotionCommand> for Command {
             Error::backup(eyre!("Invalid Notion command ID: {}", value.external_id))
         })?;
 
-        let workspace_id = value.workspace_id.parse::<Uuid>().map_err(|_err| {
-            Error::backup(eyre!(
-                "Invalid Notion command {} workspace ID: {}",
-                id,
-                value.workspace_id,
-            ))
-        })?;
-
-        Command::new(CommandParameters {
+        let workspace_id = if value.workspace_id.is_empty() {
+            None
+        } else {
+            let workspace_id = value.workspace_id.parse::<Uuid>().map_err(|_err| {
+                Error::backup(eyre!(
+                    "Invalid Notion command {} workspace ID: {}",
+                    id,
+                    value.workspace_id,
+                ))
+            })?;
+
+            Some(WorkspaceId::new(workspace_id)?)
+        };
+
+        Command::load(CommandParameters {
             id,
+            created_at: None,
+            description: None,
             last_execute_time: None,
+            managed_by: None,
             name: value.name,
+            pinned: false,
             program: value.program,
-            workspace_id: WorkspaceId::new(workspace_id)?,
+            shell: None,
+            tags: Vec::new(),
+            workspace_id,
+            updated_at: None,
         })
     }
 }
@@ -371,11 +387,17 @@ impl TryFrom<NotionWorkspace> for Workspace {
             ))
         })?;
 
-        Workspace::new(WorkspaceParameters {
+        Workspace::load(WorkspaceParameters {
             id,
+            created_at: None,
             name: value.name,
+            parent_id: None,
+            pinned: false,
             location: Some(value.location),
             last_access_time: None,
+            shell: None,
+            tags: Vec::new(),
+            updated_at: None,
         })
     }
 }
@@ -1,37 +1,83 @@
 mod backup;
+mod secrets;
 mod storage;
 mod system;
 
 pub use backup::*;
+pub use secrets::InMemorySecretStore;
 pub use storage::InMemoryStorage;
 use storage::NOTION_CREDENTIALS_KEY;
 pub use system::MockSystem;
 
 use chrono::{DateTime, NaiveDateTime, Utc};
 use hermione_nexus::definitions::{
-    BackupCredentials, Command, CommandId, CommandParameters, NotionBackupCredentialsParameters,
-    Workspace, WorkspaceId, WorkspaceParameters,
+    BackupCredentials, Command, CommandChain, CommandChainId, CommandChainParameters,
+    CommandExecution, CommandExecutionParameters, CommandId, CommandManager, CommandParameters,
+    CommandRevision, CommandRevisionId, CommandRevisionParameters, EnvironmentVariable,
+    EnvironmentVariableParameters, NotionBackupCredentialsParameters, Secret, SecretParameters,
+    Shell, TrashItemId, Workspace, WorkspaceId, WorkspaceParameters,
 };
+use hermione_nexus::services::{FindSecret, MoveToTrash, SaveSecret};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+pub struct CommandChainFixture<'a> {
+    pub id: &'a str,
+    pub name: &'a str,
+    pub steps: Vec<&'a str>,
+    pub workspace_id: &'a str,
+}
+
+pub struct CommandExecutionFixture<'a> {
+    pub command_id: &'a str,
+    pub location: Option<&'a str>,
+    pub program: &'a str,
+    pub start_time: &'a str,
+    pub workspace_id: Option<&'a str>,
+}
+
+pub struct CommandRevisionFixture<'a> {
+    pub id: &'a str,
+    pub command_id: &'a str,
+    pub created_at: &'a str,
+    pub name: &'a str,
+    pub program: &'a str,
+}
+
 pub struct CommandFixture<'a> {
     pub id: &'a str,
+    pub description: Option<&'a str>,
+    pub managed_by: Option<&'a str>,
     pub name: &'a str,
+    pub pinned: bool,
     pub program: &'a str,
     pub last_execute_time: Option<&'a str>,
-    pub workspace_id: &'a str,
+    pub workspace_id: Option<&'a str>,
+    pub shell: Option<&'a str>,
+    pub tags: Vec<&'a str>,
 }
 
 pub enum ExpectedBackupCredentials<'a> {
     Notion(ExpectedNotionBackupCredentials<'a>),
 }
 
+pub struct ExpectedCommandExecution<'a> {
+    pub command_id: &'a str,
+    pub location: Option<&'a str>,
+    pub program: &'a str,
+    pub start_time: &'a str,
+    pub workspace_id: Option<&'a str>,
+}
+
 pub struct ExpectedCommand<'a> {
     pub id: &'a str,
+    pub description: Option<&'a str>,
     pub name: &'a str,
+    pub pinned: bool,
     pub program: &'a str,
     pub last_execute_time: Option<&'a str>,
-    pub workspace_id: &'a str,
+    pub workspace_id: Option<&'a str>,
+    pub tags: Vec<&'a str>,
 }
 
 pub struct ExpectedNotionBackupCredentials<'a> {
@@ -40,11 +86,20 @@ pub struct ExpectedNotionBackupCredentials<'a> {
     pub workspaces_database_id: &'a str,
 }
 
+pub struct ExpectedTrashState<'a> {
+    pub command_ids: Vec<&'a str>,
+    pub trashed_command_ids: Vec<&'a str>,
+    pub trashed_workspace_ids: Vec<&'a str>,
+    pub workspace_ids: Vec<&'a str>,
+}
+
 pub struct ExpectedWorkspace<'a> {
     pub id: &'a str,
     pub last_access_time: Option<&'a str>,
     pub location: Option<&'a str>,
     pub name: &'a str,
+    pub pinned: bool,
+    pub tags: Vec<&'a str>,
 }
 
 impl ExpectedCommand<'_> {
@@ -70,6 +125,10 @@ pub struct WorkspaceFixture<'a> {
     pub last_access_time: Option<&'a str>,
     pub location: Option<&'a str>,
     pub name: &'a str,
+    pub parent_id: Option<&'a str>,
+    pub pinned: bool,
+    pub shell: Option<&'a str>,
+    pub tags: Vec<&'a str>,
 }
 
 pub fn assert_backup_credentials(
@@ -105,9 +164,35 @@ pub fn assert_command(command: Command, expected: ExpectedCommand) {
     let expected = Command::from(expected);
 
     assert_eq!(command.id(), expected.id());
+    assert_eq!(command.description(), expected.description());
     assert_eq!(command.name(), expected.name());
+    assert_eq!(command.is_pinned(), expected.is_pinned());
     assert_eq!(command.program(), expected.program());
     assert_eq!(command.last_execute_time(), expected.last_execute_time(),);
+    assert_eq!(command.tags(), expected.tags());
+    assert_eq!(command.workspace_id(), expected.workspace_id());
+}
+
+pub fn assert_command_execution(execution: CommandExecution, expected: ExpectedCommandExecution) {
+    let expected = CommandExecution::from(expected);
+
+    assert_eq!(execution.command_id(), expected.command_id());
+    assert_eq!(execution.location(), expected.location());
+    assert_eq!(execution.program(), expected.program());
+    assert_eq!(execution.start_time(), expected.start_time());
+    assert_eq!(execution.workspace_id(), expected.workspace_id());
+}
+
+pub fn assert_command_executions(
+    executions: Vec<CommandExecution>,
+    expected_executions: Vec<ExpectedCommandExecution>,
+) {
+    assert_eq!(executions.len(), expected_executions.len());
+
+    expected_executions
+        .into_iter()
+        .zip(executions)
+        .for_each(|(expected, execution)| assert_command_execution(execution, expected));
 }
 
 pub fn assert_commands(commands: Vec<Command>, expected_commands: Vec<ExpectedCommand>) {
@@ -119,6 +204,23 @@ pub fn assert_commands(commands: Vec<Command>, expected_commands: Vec<ExpectedCo
         .for_each(|(expected, command)| assert_command(command, expected));
 }
 
+pub fn assert_environment_variables(system: &MockSystem, expected: Vec<(&str, &str)>) {
+    let environment_variables = system.environment_variables.read().unwrap();
+
+    let expected: Vec<(String, String)> = expected
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+
+    assert_eq!(*environment_variables, expected);
+}
+
+pub fn assert_shell(system: &MockSystem, expected: Option<&str>) {
+    let shell = system.shell.read().unwrap();
+
+    assert_eq!(*shell, expected.map(parse_shell));
+}
+
 pub fn assert_file_system_location(system: &MockSystem, expected: &str) {
     let location = system.location.read().unwrap();
 
@@ -160,13 +262,38 @@ pub fn assert_stored_notion_backup_credentials(
     assert_notion_backup_credentials(credentials, expected);
 }
 
+pub fn assert_trash_state(storage: &InMemoryStorage, expected: ExpectedTrashState) {
+    let ExpectedTrashState {
+        command_ids,
+        trashed_command_ids,
+        trashed_workspace_ids,
+        workspace_ids,
+    } = expected;
+
+    assert_eq!(sorted_keys(&storage.commands.read().unwrap()), command_ids);
+    assert_eq!(
+        sorted_keys(&storage.trashed_commands.read().unwrap()),
+        trashed_command_ids
+    );
+    assert_eq!(
+        sorted_keys(&storage.workspaces.read().unwrap()),
+        workspace_ids
+    );
+    assert_eq!(
+        sorted_keys(&storage.trashed_workspaces.read().unwrap()),
+        trashed_workspace_ids
+    );
+}
+
 pub fn assert_workspace(workspace: Workspace, expected: ExpectedWorkspace) {
     let expected = Workspace::from(expected);
 
     assert_eq!(workspace.id(), expected.id());
     assert_eq!(workspace.name(), expected.name());
+    assert_eq!(workspace.is_pinned(), expected.is_pinned());
     assert_eq!(workspace.location(), expected.location());
     assert_eq!(workspace.last_access_time(), expected.last_access_time(),);
+    assert_eq!(workspace.tags(), expected.tags());
 }
 
 pub fn assert_workspaces(workspaces: Vec<Workspace>, expected_workspaces: Vec<ExpectedWorkspace>) {
@@ -200,12 +327,57 @@ pub fn insert_command(storage: &InMemoryStorage, command: CommandFixture) {
         .insert(command.id(), command);
 }
 
+pub fn insert_command_chain(storage: &InMemoryStorage, command_chain: CommandChainFixture) {
+    let command_chain = CommandChain::from(command_chain);
+
+    storage
+        .command_chains
+        .write()
+        .unwrap()
+        .insert(command_chain.id(), command_chain);
+}
+
+pub fn insert_command_executions(
+    storage: &InMemoryStorage,
+    executions: Vec<CommandExecutionFixture>,
+) {
+    storage
+        .command_executions
+        .write()
+        .unwrap()
+        .extend(executions.into_iter().map(CommandExecution::from));
+}
+
+pub fn insert_command_revisions(storage: &InMemoryStorage, revisions: Vec<CommandRevisionFixture>) {
+    storage
+        .command_revisions
+        .write()
+        .unwrap()
+        .extend(revisions.into_iter().map(CommandRevision::from));
+}
+
 pub fn insert_commands(storage: &InMemoryStorage, commands: Vec<CommandFixture>) {
     commands
         .into_iter()
         .for_each(|command| insert_command(storage, command));
 }
 
+pub fn insert_execute_counts(storage: &InMemoryStorage, execute_counts: Vec<(&str, u32)>) {
+    storage.execute_counts.write().unwrap().extend(
+        execute_counts
+            .into_iter()
+            .map(|(id, count)| (parse_command_id(id), count)),
+    );
+}
+
+pub fn insert_files(system: &MockSystem, files: Vec<(&str, &str)>) {
+    system.files.write().unwrap().extend(
+        files
+            .into_iter()
+            .map(|(path, content)| (path.to_string(), content.to_string())),
+    );
+}
+
 pub fn insert_notion_backup_credentials(
     storage: &InMemoryStorage,
     credentials: NotionBackupCredentialsFixture,
@@ -217,6 +389,39 @@ pub fn insert_notion_backup_credentials(
         .insert(NOTION_CREDENTIALS_KEY.to_string(), credentials.into());
 }
 
+pub fn insert_environment_variables(
+    storage: &InMemoryStorage,
+    workspace_id: &str,
+    environment_variables: Vec<(&str, &str)>,
+) {
+    let environment_variables = environment_variables
+        .into_iter()
+        .map(|(name, value)| {
+            EnvironmentVariable::new(EnvironmentVariableParameters {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+            .unwrap()
+        })
+        .collect();
+
+    storage
+        .insert_environment_variables(parse_workspace_id(workspace_id), environment_variables)
+        .unwrap();
+}
+
+pub fn insert_secrets(secret_store: &InMemorySecretStore, secrets: Vec<(&str, &str)>) {
+    for (name, value) in secrets {
+        let secret = Secret::new(SecretParameters {
+            name: name.to_string(),
+            value: value.to_string(),
+        })
+        .unwrap();
+
+        secret_store.save_secret(&secret).unwrap();
+    }
+}
+
 pub fn insert_workspace(storage: &InMemoryStorage, existing: WorkspaceFixture) {
     let workspace = Workspace::from(existing);
 
@@ -233,6 +438,14 @@ pub fn insert_workspaces(storage: &InMemoryStorage, workspaces: Vec<WorkspaceFix
         .for_each(|workspace| insert_workspace(storage, workspace));
 }
 
+pub fn list_command_executions(storage: &InMemoryStorage) -> Vec<CommandExecution> {
+    storage.command_executions.read().unwrap().clone()
+}
+
+pub fn list_command_revisions(storage: &InMemoryStorage) -> Vec<CommandRevision> {
+    storage.command_revisions.read().unwrap().clone()
+}
+
 pub fn maybe_get_command(storage: &InMemoryStorage, id: CommandId) -> Option<Command> {
     storage.commands.read().unwrap().get(&id).cloned()
 }
@@ -246,6 +459,10 @@ pub fn maybe_get_notion_backup_credentials(storage: &InMemoryStorage) -> Option<
         .cloned()
 }
 
+pub fn maybe_get_secret(secret_store: &InMemorySecretStore, name: &str) -> Option<Secret> {
+    secret_store.find_secret(name).unwrap()
+}
+
 pub fn maybe_get_workspace(storage: &InMemoryStorage, id: WorkspaceId) -> Option<Workspace> {
     storage.workspaces.read().unwrap().get(&id).cloned()
 }
@@ -254,10 +471,31 @@ pub fn maybe_parse_time(value: Option<&str>) -> Option<DateTime<Utc>> {
     value.map(parse_time)
 }
 
+pub fn move_to_trash(storage: &InMemoryStorage, id: TrashItemId, time: &str) {
+    freeze_storage_time(storage, parse_time(time));
+    storage.move_to_trash(id).unwrap();
+}
+
+pub fn parse_command_chain_id(value: &str) -> CommandChainId {
+    CommandChainId::parse_str(value).unwrap()
+}
+
+pub fn parse_command_revision_id(value: &str) -> CommandRevisionId {
+    CommandRevisionId::parse_str(value).unwrap()
+}
+
 pub fn parse_command_id(value: &str) -> CommandId {
     CommandId::parse_str(value).unwrap()
 }
 
+pub fn parse_command_manager(value: &str) -> CommandManager {
+    CommandManager::parse_str(value).unwrap()
+}
+
+pub fn parse_shell(value: &str) -> Shell {
+    Shell::parse_str(value).unwrap()
+}
+
 pub fn parse_time(value: &str) -> DateTime<Utc> {
     NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
         .unwrap()
@@ -272,22 +510,57 @@ pub fn parse_workspace_id(value: &str) -> WorkspaceId {
     WorkspaceId::parse_str(value).unwrap()
 }
 
+pub fn to_strings(values: Vec<&str>) -> Vec<String> {
+    values.into_iter().map(ToString::to_string).collect()
+}
+
+impl From<CommandChainFixture<'_>> for CommandChain {
+    fn from(value: CommandChainFixture) -> Self {
+        let CommandChainFixture {
+            id,
+            name,
+            steps,
+            workspace_id,
+        } = value;
+
+        CommandChain::new(CommandChainParameters {
+            id: parse_uuid(id),
+            name: name.to_string(),
+            steps: steps.into_iter().map(parse_command_id).collect(),
+            workspace_id: parse_workspace_id(workspace_id),
+        })
+        .unwrap()
+    }
+}
+
 impl From<CommandFixture<'_>> for Command {
     fn from(value: CommandFixture) -> Self {
         let CommandFixture {
             id,
+            description,
+            managed_by,
             name,
+            pinned,
             program,
             workspace_id,
             last_execute_time,
+            shell,
+            tags,
         } = value;
 
-        Command::new(CommandParameters {
+        Command::load(CommandParameters {
             id: parse_uuid(id),
+            created_at: None,
+            description: description.map(ToString::to_string),
+            managed_by: managed_by.map(parse_command_manager),
             name: name.to_string(),
+            pinned,
             program: program.to_string(),
-            workspace_id: parse_workspace_id(workspace_id),
+            workspace_id: workspace_id.map(parse_workspace_id),
             last_execute_time: maybe_parse_time(last_execute_time),
+            shell: shell.map(parse_shell),
+            tags: to_strings(tags),
+            updated_at: None,
         })
         .unwrap()
     }
@@ -314,15 +587,25 @@ impl From<WorkspaceFixture<'_>> for Workspace {
         let WorkspaceFixture {
             id,
             name,
+            parent_id,
+            pinned,
             location,
             last_access_time,
+            shell,
+            tags,
         } = value;
 
-        Workspace::new(WorkspaceParameters {
+        Workspace::load(WorkspaceParameters {
             id: parse_uuid(id),
+            created_at: None,
             name: name.to_string(),
+            parent_id: parent_id.map(parse_workspace_id),
+            pinned,
             location: location.map(ToString::to_string),
             last_access_time: maybe_parse_time(last_access_time),
+            shell: shell.map(parse_shell),
+            tags: to_strings(tags),
+            updated_at: None,
         })
         .unwrap()
     }
@@ -333,37 +616,122 @@ impl From<ExpectedWorkspace<'_>> for Workspace {
         let ExpectedWorkspace {
             id,
             name,
+            pinned,
             location,
             last_access_time,
+            tags,
         } = value;
 
-        Workspace::new(WorkspaceParameters {
+        Workspace::load(WorkspaceParameters {
             id: parse_uuid(id),
+            created_at: None,
             name: name.to_string(),
+            pinned,
             location: location.map(ToString::to_string),
             last_access_time: maybe_parse_time(last_access_time),
+            parent_id: None,
+            shell: None,
+            tags: to_strings(tags),
+            updated_at: None,
         })
         .unwrap()
     }
 }
 
+impl From<CommandExecutionFixture<'_>> for CommandExecution {
+    fn from(value: CommandExecutionFixture) -> Self {
+        let CommandExecutionFixture {
+            command_id,
+            location,
+            program,
+            start_time,
+            workspace_id,
+        } = value;
+
+        CommandExecution::new(CommandExecutionParameters {
+            command_id: Some(parse_command_id(command_id)),
+            location: location.map(ToString::to_string),
+            program: program.to_string(),
+            start_time: parse_time(start_time),
+            workspace_id: workspace_id.map(parse_workspace_id),
+        })
+    }
+}
+
+impl From<CommandRevisionFixture<'_>> for CommandRevision {
+    fn from(value: CommandRevisionFixture) -> Self {
+        let CommandRevisionFixture {
+            id,
+            command_id,
+            created_at,
+            name,
+            program,
+        } = value;
+
+        CommandRevision::new(CommandRevisionParameters {
+            id: parse_uuid(id),
+            command_id: parse_command_id(command_id),
+            created_at: parse_time(created_at),
+            name: name.to_string(),
+            program: program.to_string(),
+        })
+        .unwrap()
+    }
+}
+
+impl From<ExpectedCommandExecution<'_>> for CommandExecution {
+    fn from(value: ExpectedCommandExecution) -> Self {
+        let ExpectedCommandExecution {
+            command_id,
+            location,
+            program,
+            start_time,
+            workspace_id,
+        } = value;
+
+        CommandExecution::new(CommandExecutionParameters {
+            command_id: Some(parse_command_id(command_id)),
+            location: location.map(ToString::to_string),
+            program: program.to_string(),
+            start_time: parse_time(start_time),
+            workspace_id: workspace_id.map(parse_workspace_id),
+        })
+    }
+}
+
 impl From<ExpectedCommand<'_>> for Command {
     fn from(value: ExpectedCommand) -> Self {
         let ExpectedCommand {
             id,
+            description,
             name,
+            pinned,
             program,
             last_execute_time,
             workspace_id,
+            tags,
         } = value;
 
-        Command::new(CommandParameters {
+        Command::load(CommandParameters {
             id: parse_uuid(id),
+            created_at: None,
+            description: description.map(ToString::to_string),
+            managed_by: None,
             name: name.to_string(),
+            pinned,
             program: program.to_string(),
             last_execute_time: maybe_parse_time(last_execute_time),
-            workspace_id: parse_workspace_id(workspace_id),
+            workspace_id: workspace_id.map(parse_workspace_id),
+            shell: None,
+            tags: to_strings(tags),
+            updated_at: None,
         })
         .unwrap()
     }
 }
+
+fn sorted_keys<K: ToString, V>(map: &HashMap<K, V>) -> Vec<String> {
+    let mut keys: Vec<String> = map.keys().map(ToString::to_string).collect();
+    keys.sort();
+    keys
+}
@@ -0,0 +1,69 @@
+use eyre::eyre;
+use hermione_nexus::{
+    definitions::Secret,
+    services::{DeleteSecret, FindSecret, ListSecretNames, SaveSecret, SecretStore},
+    Error, Result,
+};
+use std::{collections::HashMap, sync::RwLock};
+
+#[derive(Default)]
+pub struct InMemorySecretStore {
+    pub secrets: RwLock<HashMap<String, Secret>>,
+}
+
+impl SecretStore for InMemorySecretStore {}
+
+impl DeleteSecret for InMemorySecretStore {
+    fn delete_secret(&self, name: &str) -> Result<()> {
+        let mut secrets = self.secrets.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Secrets blocked for writing, can't proceed with secret deletion"
+            ))
+        })?;
+
+        secrets.remove(name);
+
+        Ok(())
+    }
+}
+
+impl FindSecret for InMemorySecretStore {
+    fn find_secret(&self, name: &str) -> Result<Option<Secret>> {
+        let secrets = self.secrets.read().map_err(|_err| {
+            Error::storage(eyre!(
+                "Secrets blocked for reading, can't proceed with secret search"
+            ))
+        })?;
+
+        Ok(secrets.get(name).cloned())
+    }
+}
+
+impl ListSecretNames for InMemorySecretStore {
+    fn list_secret_names(&self) -> Result<Vec<String>> {
+        let secrets = self.secrets.read().map_err(|_err| {
+            Error::storage(eyre!(
+                "Secrets blocked for reading, can't proceed with secrets listing"
+            ))
+        })?;
+
+        let mut names: Vec<String> = secrets.keys().cloned().collect();
+        names.sort();
+
+        Ok(names)
+    }
+}
+
+impl SaveSecret for InMemorySecretStore {
+    fn save_secret(&self, secret: &Secret) -> Result<()> {
+        let mut secrets = self.secrets.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Secrets blocked for writing, can't proceed with secret saving"
+            ))
+        })?;
+
+        secrets.insert(secret.name().to_string(), secret.clone());
+
+        Ok(())
+    }
+}
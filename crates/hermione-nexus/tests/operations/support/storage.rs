@@ -2,16 +2,31 @@ use chrono::{DateTime, TimeDelta, Utc};
 use eyre::eyre;
 use hermione_nexus::{
     definitions::{
-        BackupCredentials, BackupProviderKind, Command, CommandId, CommandParameters, Workspace,
-        WorkspaceId, WorkspaceParameters,
+        BackupCredentials, BackupProviderKind, Command, CommandChain, CommandChainId,
+        CommandChainParameters, CommandExecution, CommandExecutionParameters, CommandId,
+        CommandManager, CommandParameters, CommandRevision, CommandRevisionId,
+        CommandRevisionParameters, EnvironmentVariable, TrashEntry, TrashItem, TrashItemId,
+        TrashItemParameters, Workspace, WorkspaceId, WorkspaceParameters,
     },
     services::{
-        CreateCommand, CreateWorkspace, DeleteBackupCredentials, DeleteCommand, DeleteWorkspace,
-        DeleteWorkspaceCommands, EditCommandParameters, EditWorkspaceParameters,
-        FilterCommandsParameters, FilterWorkspacesParameters, FindBackupCredentials, FindCommand,
-        FindWorkspace, ListBackupCredentials, ListCommands, ListWorkspaces, NewCommandParameters,
-        NewWorkspaceParameters, SaveBackupCredentials, StorageService, TrackCommandExecuteTime,
-        TrackWorkspaceAccessTime, UpdateCommand, UpdateWorkspace, UpsertCommands, UpsertWorkspaces,
+        CloneWorkspace, CommandScope, CopyCommand, CreateCommand, CreateCommandChain,
+        CreateCommandRevision, CreateWorkspace, DeleteBackupCredentials, DeleteCommand,
+        DeleteWorkspace, DeleteWorkspaceCommands, EditCommandParameters,
+        EditCommandPinnedParameters, EditCommandWorkspaceParameters,
+        EditWorkspaceEnvironmentVariablesParameters, EditWorkspaceParameters,
+        EditWorkspacePinnedParameters, FilterCommandExecutionsParameters,
+        FilterCommandRevisionsParameters, FilterCommandsParameters, FilterTrashParameters,
+        FilterWorkspacesParameters, FindBackupCredentials, FindCommand, FindCommandChain,
+        FindCommandRevision, FindTrashItem, FindWorkspace, ListBackupCredentials,
+        ListCommandExecutions, ListCommandRevisions, ListCommands, ListTrash,
+        ListWorkspaceEnvironmentVariables, ListWorkspaces, MoveCommand, MoveToTrash,
+        NewCommandChainParameters, NewCommandCopyParameters, NewCommandExecutionParameters,
+        NewCommandParameters, NewCommandRevisionParameters, NewWorkspaceCloneParameters,
+        NewWorkspaceParameters, PurgeTrash, PurgeTrashScope, RestoreFromTrash,
+        SaveBackupCredentials, SortOrder, StorageService, SyncCommands, SyncCommandsParameters,
+        TagsFilter, TrackCommandExecuteTime, TrackCommandExecution, TrackWorkspaceAccessTime,
+        UpdateCommand, UpdateCommandPinned, UpdateWorkspace, UpdateWorkspaceEnvironmentVariables,
+        UpdateWorkspacePinned, UpsertCommands, UpsertWorkspaces, WorkspaceScope,
     },
     Error, Result,
 };
@@ -22,8 +37,16 @@ pub const NOTION_CREDENTIALS_KEY: &str = "notion";
 
 #[derive(Default)]
 pub struct InMemoryStorage {
+    pub access_counts: RwLock<HashMap<WorkspaceId, u32>>,
     pub backup_credentials: RwLock<HashMap<String, BackupCredentials>>,
+    pub command_chains: RwLock<HashMap<CommandChainId, CommandChain>>,
+    pub command_executions: RwLock<Vec<CommandExecution>>,
+    pub command_revisions: RwLock<Vec<CommandRevision>>,
     pub commands: RwLock<HashMap<CommandId, Command>>,
+    pub environment_variables: RwLock<HashMap<WorkspaceId, Vec<EnvironmentVariable>>>,
+    pub execute_counts: RwLock<HashMap<CommandId, u32>>,
+    pub trashed_commands: RwLock<HashMap<CommandId, (Command, DateTime<Utc>)>>,
+    pub trashed_workspaces: RwLock<HashMap<WorkspaceId, (Workspace, DateTime<Utc>)>>,
     pub workspaces: RwLock<HashMap<WorkspaceId, Workspace>>,
     pub now: RwLock<Option<DateTime<Utc>>>,
 }
@@ -82,6 +105,26 @@ impl InMemoryStorage {
         Ok(command)
     }
 
+    pub fn get_environment_variables(
+        &self,
+        workspace_id: WorkspaceId,
+    ) -> Result<Vec<EnvironmentVariable>> {
+        let environment_variables = self
+            .environment_variables
+            .read()
+            .map_err(|_err| {
+                Error::storage(eyre!(
+                    "Environment variables blocked for reading, can't get workspace {} environment variables",
+                    workspace_id
+                ))
+            })?
+            .get(&workspace_id)
+            .cloned()
+            .unwrap_or_default();
+
+        Ok(environment_variables)
+    }
+
     pub fn get_workspace(&self, id: WorkspaceId) -> Result<Option<Workspace>> {
         let workspace = self
             .workspaces
@@ -128,6 +171,22 @@ impl InMemoryStorage {
         Ok(())
     }
 
+    pub fn insert_environment_variables(
+        &self,
+        workspace_id: WorkspaceId,
+        environment_variables: Vec<EnvironmentVariable>,
+    ) -> Result<()> {
+        let mut collection = self.environment_variables.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Environment variables blocked for writing, can't proceed with environment variables insert"
+            ))
+        })?;
+
+        collection.insert(workspace_id, environment_variables);
+
+        Ok(())
+    }
+
     pub fn insert_workspace(&self, workspace: &Workspace) -> Result<()> {
         let mut workspaces = self.workspaces.write().map_err(|_err| {
             Error::storage(eyre!(
@@ -174,7 +233,7 @@ impl InMemoryStorage {
             ))
         })?;
 
-        commands.retain(|_id, command| command.workspace_id() != workspace_id);
+        commands.retain(|_id, command| command.workspace_id() != Some(workspace_id));
 
         Ok(())
     }
@@ -192,6 +251,19 @@ impl InMemoryStorage {
         Ok(())
     }
 
+    fn push_command_execution(&self, execution: CommandExecution) -> Result<()> {
+        let mut executions = self.command_executions.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Command executions blocked for writing, can't track execution of {}",
+                execution.program()
+            ))
+        })?;
+
+        executions.push(execution);
+
+        Ok(())
+    }
+
     fn set_command_execute_time(&self, id: CommandId) -> Result<()> {
         let command = self.get_command(id)?;
 
@@ -204,6 +276,18 @@ impl InMemoryStorage {
 
         self.insert_command(command)?;
 
+        *self
+            .execute_counts
+            .write()
+            .map_err(|_err| {
+                Error::storage(eyre!(
+                    "Execute counts blocked for writing, can't track command {} execution",
+                    id
+                ))
+            })?
+            .entry(id)
+            .or_default() += 1;
+
         Ok(())
     }
 
@@ -219,9 +303,37 @@ impl InMemoryStorage {
 
         self.insert_workspace(&workspace)?;
 
+        *self
+            .access_counts
+            .write()
+            .map_err(|_err| {
+                Error::storage(eyre!(
+                    "Access counts blocked for writing, can't track workspace {} access",
+                    id
+                ))
+            })?
+            .entry(id)
+            .or_default() += 1;
+
         Ok(())
     }
 
+    fn list_ancestor_ids(&self, id: WorkspaceId) -> Result<Vec<WorkspaceId>> {
+        let mut ancestor_ids = Vec::new();
+        let mut parent_id = self.get_workspace(id)?.and_then(|w| w.parent_id());
+
+        while let Some(id) = parent_id.filter(|id| !ancestor_ids.contains(id)) {
+            let Some(parent) = self.get_workspace(id)? else {
+                break;
+            };
+
+            ancestor_ids.push(id);
+            parent_id = parent.parent_id();
+        }
+
+        Ok(ancestor_ids)
+    }
+
     pub fn list_workspaces(&self) -> Result<Vec<Workspace>> {
         let workspaces = self.workspaces.read().map_err(|_err| {
             Error::storage(eyre!(
@@ -235,37 +347,184 @@ impl InMemoryStorage {
 
 impl StorageService for InMemoryStorage {}
 
+impl CloneWorkspace for InMemoryStorage {
+    fn clone_workspace(&self, parameters: NewWorkspaceCloneParameters) -> Result<Workspace> {
+        let NewWorkspaceCloneParameters { id, location, name } = parameters;
+
+        let Some(workspace) = self.get_workspace(id)? else {
+            return Err(Error::storage(eyre!("Workspace {} not found", id)));
+        };
+
+        let now = now(self).map_err(Error::storage)?;
+
+        let clone = Workspace::load(WorkspaceParameters {
+            id: Uuid::new_v4(),
+            created_at: Some(now),
+            last_access_time: None,
+            location,
+            name,
+            parent_id: workspace.parent_id(),
+            pinned: false,
+            shell: workspace.shell(),
+            tags: workspace.tags().to_vec(),
+            updated_at: Some(now),
+        })?;
+
+        self.insert_workspace(&clone)?;
+        self.insert_environment_variables(clone.id(), self.get_environment_variables(id)?)?;
+
+        let command_ids: Vec<CommandId> = self
+            .list_commands()?
+            .into_iter()
+            .filter(|command| command.workspace_id() == Some(id))
+            .map(|command| command.id())
+            .collect();
+
+        for command_id in command_ids {
+            self.copy_command(NewCommandCopyParameters {
+                id: command_id,
+                keep_history: false,
+                workspace_id: Some(clone.id()),
+            })?;
+        }
+
+        Ok(clone)
+    }
+}
+
+impl CopyCommand for InMemoryStorage {
+    fn copy_command(&self, parameters: NewCommandCopyParameters) -> Result<Command> {
+        let NewCommandCopyParameters {
+            id,
+            keep_history,
+            workspace_id,
+        } = parameters;
+
+        let Some(command) = self.get_command(id)? else {
+            return Err(Error::storage(eyre!("Command {} not found", id)));
+        };
+
+        let now = now(self).map_err(Error::storage)?;
+
+        let copy = Command::load(CommandParameters {
+            id: Uuid::new_v4(),
+            created_at: Some(now),
+            description: command.description().map(ToString::to_string),
+            last_execute_time: command
+                .last_execute_time()
+                .filter(|_| keep_history)
+                .cloned(),
+            managed_by: None,
+            name: command.name().to_string(),
+            pinned: command.is_pinned(),
+            program: command.program().to_string(),
+            shell: command.shell(),
+            tags: command.tags().to_vec(),
+            workspace_id,
+            updated_at: Some(now),
+        })?;
+
+        self.insert_command(copy.clone())?;
+
+        Ok(copy)
+    }
+}
+
 impl CreateCommand for InMemoryStorage {
     fn create_command(&self, parameters: NewCommandParameters) -> Result<Command> {
-        let NewCommandParameters {
+        let command = new_command(self, parameters, None)?;
+
+        self.insert_command(command.clone())?;
+
+        Ok(command)
+    }
+}
+
+impl CreateCommandChain for InMemoryStorage {
+    fn create_command_chain(&self, parameters: NewCommandChainParameters) -> Result<CommandChain> {
+        let NewCommandChainParameters {
             name,
-            program,
+            steps,
             workspace_id,
         } = parameters;
 
-        let command = Command::new(CommandParameters {
+        let command_chain = CommandChain::new(CommandChainParameters {
             id: Uuid::new_v4(),
-            last_execute_time: None,
             name,
-            program,
+            steps,
             workspace_id,
         })?;
 
-        self.insert_command(command.clone())?;
+        self.command_chains
+            .write()
+            .map_err(|_err| {
+                Error::storage(eyre!(
+                    "Command chains blocked for writing, can't create command chain"
+                ))
+            })?
+            .insert(command_chain.id(), command_chain.clone());
 
-        Ok(command)
+        Ok(command_chain)
+    }
+}
+
+impl CreateCommandRevision for InMemoryStorage {
+    fn create_command_revision(
+        &self,
+        parameters: NewCommandRevisionParameters,
+    ) -> Result<CommandRevision> {
+        let NewCommandRevisionParameters {
+            command_id,
+            name,
+            program,
+        } = parameters;
+
+        let created_at = now(self).map_err(Error::storage)?;
+
+        let revision = CommandRevision::new(CommandRevisionParameters {
+            id: Uuid::new_v4(),
+            command_id,
+            created_at,
+            name: name.to_string(),
+            program: program.to_string(),
+        })?;
+
+        let mut revisions = self.command_revisions.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Command revisions blocked for writing, can't save command {} revision",
+                command_id
+            ))
+        })?;
+
+        revisions.push(revision.clone());
+
+        Ok(revision)
     }
 }
 
 impl CreateWorkspace for InMemoryStorage {
     fn create_workspace(&self, parameters: NewWorkspaceParameters) -> Result<Workspace> {
-        let NewWorkspaceParameters { name, location } = parameters;
+        let NewWorkspaceParameters {
+            name,
+            location,
+            parent_id,
+            shell,
+            tags,
+        } = parameters;
+
+        let now = now(self).map_err(Error::storage)?;
 
         let workspace = Workspace::new(WorkspaceParameters {
             id: Uuid::new_v4(),
+            created_at: Some(now),
             last_access_time: None,
             location,
             name,
+            parent_id,
+            pinned: false,
+            shell,
+            tags,
+            updated_at: Some(now),
         })?;
 
         self.insert_workspace(&workspace)?;
@@ -333,6 +592,51 @@ impl FindCommand for InMemoryStorage {
     }
 }
 
+impl FindCommandChain for InMemoryStorage {
+    fn find_command_chain(&self, id: CommandChainId) -> Result<Option<CommandChain>> {
+        let command_chain = self
+            .command_chains
+            .read()
+            .map_err(|_err| {
+                Error::storage(eyre!(
+                    "Command chains blocked for reading, can't get command chain {}",
+                    id
+                ))
+            })?
+            .get(&id)
+            .cloned();
+
+        Ok(command_chain)
+    }
+}
+
+impl FindCommandRevision for InMemoryStorage {
+    fn find_command_revision(&self, id: CommandRevisionId) -> Result<Option<CommandRevision>> {
+        let revisions = self.command_revisions.read().map_err(|_err| {
+            Error::storage(eyre!(
+                "Command revisions blocked for reading, can't find revision {}",
+                id
+            ))
+        })?;
+
+        Ok(revisions
+            .iter()
+            .find(|revision| revision.id() == id)
+            .cloned())
+    }
+}
+
+impl FindTrashItem for InMemoryStorage {
+    fn find_trash_item(&self, id: TrashItemId) -> Result<Option<TrashItem>> {
+        let trash = self.list_trash(FilterTrashParameters {
+            page_number: 0,
+            page_size: u32::MAX,
+        })?;
+
+        Ok(trash.into_iter().find(|item| item.id() == id))
+    }
+}
+
 impl FindWorkspace for InMemoryStorage {
     fn find_workspace(&self, id: WorkspaceId) -> Result<Option<Workspace>> {
         let workspaces = self.get_workspace(id)?;
@@ -349,15 +653,111 @@ impl ListBackupCredentials for InMemoryStorage {
     }
 }
 
+impl ListCommandExecutions for InMemoryStorage {
+    fn list_command_executions(
+        &self,
+        parameters: FilterCommandExecutionsParameters,
+    ) -> Result<Vec<CommandExecution>> {
+        let FilterCommandExecutionsParameters {
+            command_id,
+            page_number,
+            page_size,
+            workspace_id,
+        } = parameters;
+
+        let executions = self.command_executions.read().map_err(|_err| {
+            Error::storage(eyre!(
+                "Command executions blocked for reading, can't proceed with command executions listing"
+            ))
+        })?;
+
+        let mut executions = executions
+            .iter()
+            .filter(|execution| {
+                let from_command = if let Some(command_id) = command_id {
+                    execution.command_id() == Some(command_id)
+                } else {
+                    true
+                };
+
+                let from_workspace = if let Some(workspace_id) = workspace_id {
+                    execution.workspace_id() == Some(workspace_id)
+                } else {
+                    true
+                };
+
+                from_command && from_workspace
+            })
+            .cloned()
+            .collect::<Vec<CommandExecution>>();
+
+        executions.reverse();
+        executions.sort_by(|a, b| a.start_time().cmp(b.start_time()).reverse());
+
+        Ok(executions
+            .into_iter()
+            .skip(page_number as usize * page_size as usize)
+            .take(page_size as usize)
+            .collect())
+    }
+}
+
+impl ListCommandRevisions for InMemoryStorage {
+    fn list_command_revisions(
+        &self,
+        parameters: FilterCommandRevisionsParameters,
+    ) -> Result<Vec<CommandRevision>> {
+        let FilterCommandRevisionsParameters {
+            command_id,
+            page_number,
+            page_size,
+        } = parameters;
+
+        let revisions = self.command_revisions.read().map_err(|_err| {
+            Error::storage(eyre!(
+                "Command revisions blocked for reading, can't proceed with command revisions listing"
+            ))
+        })?;
+
+        let mut revisions = revisions
+            .iter()
+            .filter(|revision| revision.command_id() == command_id)
+            .cloned()
+            .collect::<Vec<CommandRevision>>();
+
+        revisions.reverse();
+        revisions.sort_by(|a, b| a.created_at().cmp(b.created_at()).reverse());
+
+        Ok(revisions
+            .into_iter()
+            .skip(page_number as usize * page_size as usize)
+            .take(page_size as usize)
+            .collect())
+    }
+}
+
 impl ListCommands for InMemoryStorage {
     fn list_commands(&self, parameters: FilterCommandsParameters) -> Result<Vec<Command>> {
         let FilterCommandsParameters {
+            created_after,
+            description_contains,
             program_contains,
             page_number,
             page_size,
-            workspace_id,
+            scope,
+            sort,
+            tags,
+            updated_after,
         } = parameters;
 
+        let ancestor_ids = match scope {
+            CommandScope::Inherited(workspace_id) => self.list_ancestor_ids(workspace_id)?,
+            CommandScope::All
+            | CommandScope::Global
+            | CommandScope::Workspace(_)
+            | CommandScope::WorkspaceAndGlobal(_) => Vec::new(),
+        };
+
         let mut commands = self
             .list_commands()?
             .into_iter()
@@ -371,19 +771,85 @@ impl ListCommands for InMemoryStorage {
                     true
                 };
 
-                let from_workspace = if let Some(workspace_id) = workspace_id {
-                    command.workspace_id() == workspace_id
+                let contains_description = if let Some(description_contains) = description_contains
+                {
+                    command.description().is_some_and(|description| {
+                        description
+                            .to_lowercase()
+                            .contains(&description_contains.to_lowercase())
+                    })
                 } else {
                     true
                 };
 
-                contains_program && from_workspace
+                let from_workspace = match scope {
+                    CommandScope::All => true,
+                    CommandScope::Global => command.is_global(),
+                    CommandScope::Inherited(workspace_id) => {
+                        command.is_global()
+                            || command.workspace_id() == Some(workspace_id)
+                            || command
+                                .workspace_id()
+                                .is_some_and(|id| ancestor_ids.contains(&id))
+                    }
+                    CommandScope::Workspace(workspace_id) => {
+                        command.workspace_id() == Some(workspace_id)
+                    }
+                    CommandScope::WorkspaceAndGlobal(workspace_id) => {
+                        command.is_global() || command.workspace_id() == Some(workspace_id)
+                    }
+                };
+
+                let has_tags = if let Some(tags) = &tags {
+                    matches_tags(command.tags(), tags)
+                } else {
+                    true
+                };
+
+                let in_time_range = is_after(command.created_at(), created_after)
+                    && is_after(command.updated_at(), updated_after);
+
+                contains_program
+                    && contains_description
+                    && from_workspace
+                    && has_tags
+                    && in_time_range
             })
             .collect::<Vec<Command>>();
 
         commands.sort_by(|a, b| a.program().cmp(b.program()));
         commands.sort_by(|a, b| a.last_execute_time().cmp(&b.last_execute_time()).reverse());
 
+        if let SortOrder::Frecency = sort {
+            let now = now(self).map_err(Error::storage)?;
+            let execute_counts = self.execute_counts.read().map_err(|_err| {
+                Error::storage(eyre!(
+                    "Execute counts blocked for reading, can't sort commands by frecency"
+                ))
+            })?;
+
+            let score = |command: &Command| {
+                frecency(
+                    execute_counts
+                        .get(&command.id())
+                        .copied()
+                        .unwrap_or_default(),
+                    command.last_execute_time(),
+                    now,
+                )
+            };
+
+            commands.sort_by(|a, b| score(b).total_cmp(&score(a)));
+        }
+
+        match sort {
+            SortOrder::Created => commands.sort_by(|a, b| b.created_at().cmp(&a.created_at())),
+            SortOrder::Updated => commands.sort_by(|a, b| b.updated_at().cmp(&a.updated_at())),
+            SortOrder::Frecency | SortOrder::Recency => {}
+        }
+
+        commands.sort_by_key(|item| !item.is_pinned());
+
         Ok(commands
             .into_iter()
             .skip(page_number as usize * page_size as usize)
@@ -392,29 +858,145 @@ impl ListCommands for InMemoryStorage {
     }
 }
 
+impl ListTrash for InMemoryStorage {
+    fn list_trash(&self, parameters: FilterTrashParameters) -> Result<Vec<TrashItem>> {
+        let FilterTrashParameters {
+            page_number,
+            page_size,
+        } = parameters;
+
+        let trashed_commands = self.trashed_commands.read().map_err(|_err| {
+            Error::storage(eyre!(
+                "Trashed commands blocked for reading, can't proceed with trash listing"
+            ))
+        })?;
+
+        let trashed_workspaces = self.trashed_workspaces.read().map_err(|_err| {
+            Error::storage(eyre!(
+                "Trashed workspaces blocked for reading, can't proceed with trash listing"
+            ))
+        })?;
+
+        let commands = trashed_commands
+            .values()
+            .filter(|(command, _deleted_at)| {
+                !command
+                    .workspace_id()
+                    .is_some_and(|workspace_id| trashed_workspaces.contains_key(&workspace_id))
+            })
+            .map(|(command, deleted_at)| {
+                TrashItem::new(TrashItemParameters {
+                    deleted_at: *deleted_at,
+                    entry: TrashEntry::Command(command.clone()),
+                })
+            });
+
+        let workspaces = trashed_workspaces.values().map(|(workspace, deleted_at)| {
+            TrashItem::new(TrashItemParameters {
+                deleted_at: *deleted_at,
+                entry: TrashEntry::Workspace(workspace.clone()),
+            })
+        });
+
+        let mut trash: Vec<TrashItem> = commands.chain(workspaces).collect();
+        trash.sort_by(|a, b| b.deleted_at().cmp(a.deleted_at()));
+
+        Ok(trash
+            .into_iter()
+            .skip(page_number as usize * page_size as usize)
+            .take(page_size as usize)
+            .collect())
+    }
+}
+
+impl ListWorkspaceEnvironmentVariables for InMemoryStorage {
+    fn list_workspace_environment_variables(
+        &self,
+        workspace_id: WorkspaceId,
+    ) -> Result<Vec<EnvironmentVariable>> {
+        self.get_environment_variables(workspace_id)
+    }
+}
+
 impl ListWorkspaces for InMemoryStorage {
     fn list_workspaces(&self, parameters: FilterWorkspacesParameters) -> Result<Vec<Workspace>> {
         let FilterWorkspacesParameters {
+            created_after,
             name_contains,
             page_number,
             page_size,
+            scope,
+            sort,
+            tags,
+            updated_after,
         } = parameters;
 
-        let mut workspaces = self
-            .list_workspaces()?
+        let all_workspaces = self.list_workspaces()?;
+        let workspace_ids: Vec<WorkspaceId> = all_workspaces.iter().map(Workspace::id).collect();
+
+        let mut workspaces = all_workspaces
             .into_iter()
             .filter(|workspace| {
-                if let Some(name_contains) = name_contains {
+                let in_scope = match scope {
+                    WorkspaceScope::All => true,
+                    WorkspaceScope::Roots => !workspace
+                        .parent_id()
+                        .is_some_and(|parent_id| workspace_ids.contains(&parent_id)),
+                    WorkspaceScope::Children(parent_id) => workspace.parent_id() == Some(parent_id),
+                };
+
+                let contains_name = if let Some(name_contains) = name_contains {
                     workspace.name().contains(name_contains)
                 } else {
                     true
-                }
+                };
+
+                let has_tags = if let Some(tags) = &tags {
+                    matches_tags(workspace.tags(), tags)
+                } else {
+                    true
+                };
+
+                let in_time_range = is_after(workspace.created_at(), created_after)
+                    && is_after(workspace.updated_at(), updated_after);
+
+                in_scope && contains_name && has_tags && in_time_range
             })
             .collect::<Vec<Workspace>>();
 
         workspaces.sort_by(|a, b| a.name().cmp(b.name()));
         workspaces.sort_by(|a, b| a.last_access_time().cmp(&b.last_access_time()).reverse());
 
+        if let SortOrder::Frecency = sort {
+            let now = now(self).map_err(Error::storage)?;
+            let access_counts = self.access_counts.read().map_err(|_err| {
+                Error::storage(eyre!(
+                    "Access counts blocked for reading, can't sort workspaces by frecency"
+                ))
+            })?;
+
+            let score = |workspace: &Workspace| {
+                frecency(
+                    access_counts
+                        .get(&workspace.id())
+                        .copied()
+                        .unwrap_or_default(),
+                    workspace.last_access_time(),
+                    now,
+                )
+            };
+
+            workspaces.sort_by(|a, b| score(b).total_cmp(&score(a)));
+        }
+
+        match sort {
+            SortOrder::Created => workspaces.sort_by(|a, b| b.created_at().cmp(&a.created_at())),
+            SortOrder::Updated => workspaces.sort_by(|a, b| b.updated_at().cmp(&a.updated_at())),
+            SortOrder::Frecency | SortOrder::Recency => {}
+        }
+
+        workspaces.sort_by_key(|item| !item.is_pinned());
+
         Ok(workspaces
             .into_iter()
             .skip(page_number as usize * page_size as usize)
@@ -423,6 +1005,227 @@ impl ListWorkspaces for InMemoryStorage {
     }
 }
 
+impl MoveCommand for InMemoryStorage {
+    fn move_command(&self, parameters: EditCommandWorkspaceParameters) -> Result<()> {
+        let EditCommandWorkspaceParameters {
+            id,
+            keep_history,
+            workspace_id,
+        } = parameters;
+
+        let Some(command) = self.get_command(id)? else {
+            return Ok(());
+        };
+
+        if !keep_history {
+            let mut executions = self.command_executions.write().map_err(|_err| {
+                Error::storage(eyre!(
+                    "Command executions blocked for writing, can't detach command {} executions",
+                    id
+                ))
+            })?;
+
+            for execution in executions
+                .iter_mut()
+                .filter(|execution| execution.command_id() == Some(id))
+            {
+                *execution = CommandExecution::new(CommandExecutionParameters {
+                    command_id: None,
+                    location: execution.location().map(ToString::to_string),
+                    program: execution.program().to_string(),
+                    start_time: *execution.start_time(),
+                    workspace_id: execution.workspace_id(),
+                });
+            }
+        }
+
+        let command = Command::load(CommandParameters {
+            id: command.id().as_uuid(),
+            created_at: command.created_at().cloned(),
+            description: command.description().map(ToString::to_string),
+            last_execute_time: command
+                .last_execute_time()
+                .filter(|_| keep_history)
+                .cloned(),
+            managed_by: command.managed_by(),
+            name: command.name().to_string(),
+            pinned: command.is_pinned(),
+            program: command.program().to_string(),
+            shell: command.shell(),
+            tags: command.tags().to_vec(),
+            workspace_id,
+            updated_at: Some(now(self).map_err(Error::storage)?),
+        })?;
+
+        self.insert_command(command)?;
+
+        Ok(())
+    }
+}
+
+impl MoveToTrash for InMemoryStorage {
+    fn move_to_trash(&self, id: TrashItemId) -> Result<()> {
+        let deleted_at = now(self).map_err(Error::storage)?;
+
+        let mut commands = self.commands.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Commands blocked for writing, can't move {} to trash",
+                id
+            ))
+        })?;
+
+        let mut trashed_commands = self.trashed_commands.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Trashed commands blocked for writing, can't move {} to trash",
+                id
+            ))
+        })?;
+
+        match id {
+            TrashItemId::Command(id) => {
+                if let Some(command) = commands.remove(&id) {
+                    trashed_commands.insert(id, (command, deleted_at));
+                }
+            }
+            TrashItemId::Workspace(id) => {
+                let mut workspaces = self.workspaces.write().map_err(|_err| {
+                    Error::storage(eyre!(
+                        "Workspaces blocked for writing, can't move workspace {} to trash",
+                        id
+                    ))
+                })?;
+
+                let Some(workspace) = workspaces.remove(&id) else {
+                    return Ok(());
+                };
+
+                let command_ids: Vec<CommandId> = commands
+                    .values()
+                    .filter(|command| command.workspace_id() == Some(id))
+                    .map(|command| command.id())
+                    .collect();
+
+                for command_id in command_ids {
+                    if let Some(command) = commands.remove(&command_id) {
+                        trashed_commands.insert(command_id, (command, deleted_at));
+                    }
+                }
+
+                self.trashed_workspaces
+                    .write()
+                    .map_err(|_err| {
+                        Error::storage(eyre!(
+                            "Trashed workspaces blocked for writing, can't move workspace {} to trash",
+                            id
+                        ))
+                    })?
+                    .insert(id, (workspace, deleted_at));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PurgeTrash for InMemoryStorage {
+    fn purge_trash(&self, scope: PurgeTrashScope) -> Result<()> {
+        let mut trashed_commands = self.trashed_commands.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Trashed commands blocked for writing, can't purge trash"
+            ))
+        })?;
+
+        let mut trashed_workspaces = self.trashed_workspaces.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Trashed workspaces blocked for writing, can't purge trash"
+            ))
+        })?;
+
+        match scope {
+            PurgeTrashScope::All => {
+                trashed_commands.clear();
+                trashed_workspaces.clear();
+            }
+            PurgeTrashScope::Item(TrashItemId::Command(id)) => {
+                trashed_commands.remove(&id);
+            }
+            PurgeTrashScope::Item(TrashItemId::Workspace(id)) => {
+                trashed_workspaces.remove(&id);
+                trashed_commands
+                    .retain(|_id, (command, _deleted_at)| command.workspace_id() != Some(id));
+            }
+            PurgeTrashScope::OlderThan(age) => {
+                let age = TimeDelta::from_std(age).map_err(|err| Error::storage(eyre!(err)))?;
+                let cutoff = now(self).map_err(Error::storage)? - age;
+
+                trashed_commands.retain(|_id, (_command, deleted_at)| *deleted_at >= cutoff);
+                trashed_workspaces.retain(|_id, (_workspace, deleted_at)| *deleted_at >= cutoff);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RestoreFromTrash for InMemoryStorage {
+    fn restore_from_trash(&self, id: TrashItemId) -> Result<()> {
+        let mut commands = self.commands.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Commands blocked for writing, can't restore {} from trash",
+                id
+            ))
+        })?;
+
+        let mut trashed_commands = self.trashed_commands.write().map_err(|_err| {
+            Error::storage(eyre!(
+                "Trashed commands blocked for writing, can't restore {} from trash",
+                id
+            ))
+        })?;
+
+        match id {
+            TrashItemId::Command(id) => {
+                if let Some((command, _deleted_at)) = trashed_commands.remove(&id) {
+                    commands.insert(id, command);
+                }
+            }
+            TrashItemId::Workspace(id) => {
+                let Some((workspace, deleted_at)) = self
+                    .trashed_workspaces
+                    .write()
+                    .map_err(|_err| {
+                        Error::storage(eyre!(
+                            "Trashed workspaces blocked for writing, can't restore workspace {} from trash",
+                            id
+                        ))
+                    })?
+                    .remove(&id)
+                else {
+                    return Ok(());
+                };
+
+                let command_ids: Vec<CommandId> = trashed_commands
+                    .iter()
+                    .filter(|(_id, (command, command_deleted_at))| {
+                        command.workspace_id() == Some(id) && *command_deleted_at == deleted_at
+                    })
+                    .map(|(command_id, _)| *command_id)
+                    .collect();
+
+                for command_id in command_ids {
+                    if let Some((command, _deleted_at)) = trashed_commands.remove(&command_id) {
+                        commands.insert(command_id, command);
+                    }
+                }
+
+                self.insert_workspace(&workspace)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl SaveBackupCredentials for InMemoryStorage {
     fn save_backup_credentials(&self, credentials: &BackupCredentials) -> Result<()> {
         self.insert_backup_credentials(credentials.clone())?;
@@ -431,6 +1234,53 @@ impl SaveBackupCredentials for InMemoryStorage {
     }
 }
 
+impl SyncCommands for InMemoryStorage {
+    fn sync_commands(&self, parameters: SyncCommandsParameters) -> Result<Vec<Command>> {
+        let SyncCommandsParameters {
+            created,
+            managed_by,
+            removed,
+            updated,
+        } = parameters;
+
+        let created = created
+            .into_iter()
+            .map(|parameters| new_command(self, parameters, Some(managed_by)))
+            .collect::<Result<Vec<Command>>>()?;
+
+        for command in created.iter().cloned().chain(updated) {
+            self.insert_command(command)?;
+        }
+
+        for id in removed {
+            self.remove_command(id)?;
+        }
+
+        Ok(created)
+    }
+}
+
+impl TrackCommandExecution for InMemoryStorage {
+    fn track_command_execution(&self, parameters: NewCommandExecutionParameters) -> Result<()> {
+        let NewCommandExecutionParameters {
+            command_id,
+            location,
+            program,
+            workspace_id,
+        } = parameters;
+
+        let start_time = now(self).map_err(Error::storage)?;
+
+        self.push_command_execution(CommandExecution::new(CommandExecutionParameters {
+            command_id: Some(command_id),
+            location: location.map(ToString::to_string),
+            program: program.to_string(),
+            start_time,
+            workspace_id,
+        }))
+    }
+}
+
 impl TrackCommandExecuteTime for InMemoryStorage {
     fn track_command_execute_time(&self, id: CommandId) -> Result<()> {
         self.set_command_execute_time(id)?;
@@ -449,14 +1299,47 @@ impl TrackWorkspaceAccessTime for InMemoryStorage {
 
 impl UpdateCommand for InMemoryStorage {
     fn update_command(&self, parameters: EditCommandParameters) -> Result<()> {
-        let EditCommandParameters { id, name, program } = parameters;
+        let EditCommandParameters {
+            id,
+            description,
+            name,
+            program,
+            shell,
+            tags,
+        } = parameters;
+
+        let Some(mut command) = self.get_command(id)? else {
+            return Ok(());
+        };
+
+        command.set_description(description.map(ToString::to_string));
+        command.set_name(name.to_string())?;
+        command.set_program(program.to_string())?;
+        command.set_shell(shell);
+        command.set_tags(tags.to_vec());
+        command.set_update_time(now(self).map_err(Error::storage)?);
+
+        self.insert_command(command)?;
+
+        Ok(())
+    }
+}
+
+impl UpdateCommandPinned for InMemoryStorage {
+    fn update_command_pinned(&self, parameters: EditCommandPinnedParameters) -> Result<()> {
+        let EditCommandPinnedParameters { id, pinned } = parameters;
 
         let Some(mut command) = self.get_command(id)? else {
             return Ok(());
         };
 
-        command.set_name(name.to_string());
-        command.set_program(program.to_string());
+        if pinned {
+            command.pin();
+        } else {
+            command.unpin();
+        }
+
+        command.set_update_time(now(self).map_err(Error::storage)?);
 
         self.insert_command(command)?;
 
@@ -476,14 +1359,47 @@ impl UpsertCommands for InMemoryStorage {
 
 impl UpdateWorkspace for InMemoryStorage {
     fn update_workspace(&self, parameters: EditWorkspaceParameters) -> Result<()> {
-        let EditWorkspaceParameters { id, location, name } = parameters;
+        let EditWorkspaceParameters {
+            id,
+            location,
+            name,
+            parent_id,
+            shell,
+            tags,
+        } = parameters;
+
+        let Some(mut workspace) = self.get_workspace(id)? else {
+            return Ok(());
+        };
+
+        workspace.set_location(location.map(ToString::to_string))?;
+        workspace.set_name(name.to_string())?;
+        workspace.set_parent_id(parent_id);
+        workspace.set_shell(shell);
+        workspace.set_tags(tags.to_vec());
+        workspace.set_update_time(now(self).map_err(Error::storage)?);
+
+        self.insert_workspace(&workspace)?;
+
+        Ok(())
+    }
+}
+
+impl UpdateWorkspacePinned for InMemoryStorage {
+    fn update_workspace_pinned(&self, parameters: EditWorkspacePinnedParameters) -> Result<()> {
+        let EditWorkspacePinnedParameters { id, pinned } = parameters;
 
         let Some(mut workspace) = self.get_workspace(id)? else {
             return Ok(());
         };
 
-        workspace.set_location(location.map(ToString::to_string));
-        workspace.set_name(name.to_string());
+        if pinned {
+            workspace.pin();
+        } else {
+            workspace.unpin();
+        }
+
+        workspace.set_update_time(now(self).map_err(Error::storage)?);
 
         self.insert_workspace(&workspace)?;
 
@@ -491,6 +1407,20 @@ impl UpdateWorkspace for InMemoryStorage {
     }
 }
 
+impl UpdateWorkspaceEnvironmentVariables for InMemoryStorage {
+    fn update_workspace_environment_variables(
+        &self,
+        parameters: EditWorkspaceEnvironmentVariablesParameters,
+    ) -> Result<()> {
+        let EditWorkspaceEnvironmentVariablesParameters {
+            environment_variables,
+            workspace_id,
+        } = parameters;
+
+        self.insert_environment_variables(workspace_id, environment_variables.to_vec())
+    }
+}
+
 impl UpsertWorkspaces for InMemoryStorage {
     fn upsert_workspaces(&self, workspaces: Vec<Workspace>) -> Result<()> {
         for workspace in workspaces {
@@ -501,6 +1431,38 @@ impl UpsertWorkspaces for InMemoryStorage {
     }
 }
 
+fn new_command(
+    storage: &InMemoryStorage,
+    parameters: NewCommandParameters,
+    managed_by: Option<CommandManager>,
+) -> Result<Command> {
+    let NewCommandParameters {
+        description,
+        name,
+        program,
+        shell,
+        tags,
+        workspace_id,
+    } = parameters;
+
+    let now = now(storage).map_err(Error::storage)?;
+
+    Command::new(CommandParameters {
+        id: Uuid::new_v4(),
+        created_at: Some(now),
+        description,
+        last_execute_time: None,
+        managed_by,
+        name,
+        pinned: false,
+        program,
+        shell,
+        tags,
+        workspace_id,
+        updated_at: Some(now),
+    })
+}
+
 fn now(storage: &InMemoryStorage) -> eyre::Result<DateTime<Utc>> {
     let time = storage
         .now
@@ -511,3 +1473,37 @@ fn now(storage: &InMemoryStorage) -> eyre::Result<DateTime<Utc>> {
 
     Ok(time)
 }
+
+fn frecency(count: u32, last_use_time: Option<&DateTime<Utc>>, now: DateTime<Utc>) -> f64 {
+    let Some(last_use_time) = last_use_time else {
+        return 0.0;
+    };
+
+    let age = now - *last_use_time;
+
+    let weight = if age < TimeDelta::hours(1) {
+        4.0
+    } else if age < TimeDelta::days(1) {
+        2.0
+    } else if age < TimeDelta::weeks(1) {
+        0.5
+    } else {
+        0.25
+    };
+
+    f64::from(count) * weight
+}
+
+fn is_after(time: Option<&DateTime<Utc>>, after: Option<DateTime<Utc>>) -> bool {
+    match after {
+        Some(after) => time.is_some_and(|time| *time > after),
+        None => true,
+    }
+}
+
+fn matches_tags(tags: &[String], filter: &TagsFilter) -> bool {
+    match filter {
+        TagsFilter::AllOf(expected) => expected.iter().all(|tag| tags.contains(tag)),
+        TagsFilter::AnyOf(expected) => expected.iter().any(|tag| tags.contains(tag)),
+    }
+}
@@ -1,32 +1,110 @@
 use eyre::Report;
 use hermione_nexus::{
+    definitions::{Bundle, EnvironmentVariable, ProjectTask, RepositoryCommand, Shell},
     services::{
-        InvokeCommand, InvokeCommandParameters, SetClipboardContent, SetLocation, SystemService,
+        DirectoryEntry, InvokeCommand, InvokeCommandChain, InvokeCommandChainParameters,
+        InvokeCommandChainStep, InvokeCommandParameters, ListDirectory, ListProjectTasks,
+        ListRepositoryCommands, ReadBundle, ReadFile, SetClipboardContent, SetLocation,
+        SetLocationParameters, SystemService, WriteBundle, WriteFile,
     },
     Error,
 };
-use std::sync::{PoisonError, RwLock};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{PoisonError, RwLock},
+};
+
+/// Program, location and shell of an invoked command chain step.
+pub type CommandChainStep = (String, Option<String>, Option<Shell>);
 
 #[derive(Default)]
 pub struct MockSystem {
+    /// Bundles by file path.
+    pub bundles: RwLock<HashMap<String, Bundle>>,
+
+    /// Programs, locations and shells of the last invoked command chain steps.
+    pub command_chain: RwLock<Vec<CommandChainStep>>,
+    pub environment_variables: RwLock<Vec<(String, String)>>,
     pub program: RwLock<Option<String>>,
     pub location: RwLock<Option<String>>,
     pub clipboard: RwLock<Option<String>>,
+
+    /// File contents by path, directories are implied by the paths.
+    pub files: RwLock<HashMap<String, String>>,
+
+    /// Project tasks by workspace location.
+    pub project_tasks: RwLock<HashMap<String, Vec<ProjectTask>>>,
+
+    /// Repository command files by workspace location.
+    pub repository_commands: RwLock<HashMap<String, Vec<RepositoryCommand>>>,
+    pub shell: RwLock<Option<Shell>>,
+
+    /// Directories whose listing fails as if access was denied.
+    pub unreadable_directories: RwLock<Vec<String>>,
 }
 
 impl SystemService for MockSystem {}
 
 impl InvokeCommand for MockSystem {
     fn invoke_command(&self, parameters: InvokeCommandParameters) -> Result<(), Error> {
-        let InvokeCommandParameters { command, location } = parameters;
+        let InvokeCommandParameters {
+            command,
+            environment_variables,
+            location,
+            shell,
+        } = parameters;
 
+        set_environment_variables(self, environment_variables).map_err(system_error)?;
         set_location(self, location).map_err(system_error)?;
+        set_shell(self, shell).map_err(system_error)?;
         set_program(self, command).map_err(system_error)?;
 
         Ok(())
     }
 }
 
+impl InvokeCommandChain for MockSystem {
+    fn invoke_command_chain(&self, parameters: InvokeCommandChainParameters) -> Result<(), Error> {
+        let InvokeCommandChainParameters { steps, shell } = parameters;
+
+        set_command_chain(self, steps).map_err(system_error)?;
+        set_shell(self, shell).map_err(system_error)
+    }
+}
+
+impl ListDirectory for MockSystem {
+    fn list_directory(&self, path: &str) -> Result<Vec<DirectoryEntry>, Error> {
+        list_directory(self, path).map_err(system_error)
+    }
+}
+
+impl ListProjectTasks for MockSystem {
+    fn list_project_tasks(&self, location: &str) -> Result<Vec<ProjectTask>, Error> {
+        list_project_tasks(self, location).map_err(system_error)
+    }
+}
+
+impl ListRepositoryCommands for MockSystem {
+    fn list_repository_commands(
+        &self,
+        location: &str,
+    ) -> Result<Option<Vec<RepositoryCommand>>, Error> {
+        list_repository_commands(self, location).map_err(system_error)
+    }
+}
+
+impl ReadBundle for MockSystem {
+    fn read_bundle(&self, path: &str) -> Result<Bundle, Error> {
+        read_bundle(self, path).map_err(system_error)
+    }
+}
+
+impl ReadFile for MockSystem {
+    fn read_file(&self, path: &str) -> Result<Option<Vec<u8>>, Error> {
+        read_file(self, path).map_err(system_error)
+    }
+}
+
 impl SetClipboardContent for MockSystem {
     fn set_clipboard_content(&self, text: &str) -> Result<(), Error> {
         set_clipboard(self, text).map_err(system_error)
@@ -34,9 +112,99 @@ impl SetClipboardContent for MockSystem {
 }
 
 impl SetLocation for MockSystem {
-    fn set_location(&self, location: Option<&str>) -> Result<(), Error> {
-        set_location(self, location).map_err(system_error)
+    fn set_location(&self, parameters: SetLocationParameters) -> Result<(), Error> {
+        let SetLocationParameters { location, shell } = parameters;
+
+        set_location(self, location).map_err(system_error)?;
+        set_shell(self, shell).map_err(system_error)
+    }
+}
+
+impl WriteBundle for MockSystem {
+    fn write_bundle(&self, path: &str, bundle: &Bundle) -> Result<(), Error> {
+        write_bundle(self, path, bundle).map_err(system_error)
+    }
+}
+
+impl WriteFile for MockSystem {
+    fn write_file(&self, path: &str, content: &str) -> Result<(), Error> {
+        write_file(self, path, content).map_err(system_error)
+    }
+}
+
+fn list_directory(system: &MockSystem, path: &str) -> Result<Vec<DirectoryEntry>, Report> {
+    let unreadable_directories = system
+        .unreadable_directories
+        .read()
+        .map_err(report_from_poison)?;
+
+    if unreadable_directories
+        .iter()
+        .any(|directory| directory == path)
+    {
+        return Err(Report::msg(format!("Permission denied: {}", path)));
+    }
+
+    let files = system.files.read().map_err(report_from_poison)?;
+    let prefix = format!("{}/", path.trim_end_matches('/'));
+    let mut children = BTreeMap::new();
+
+    for file_path in files.keys() {
+        let Some(relative_path) = file_path.strip_prefix(&prefix) else {
+            continue;
+        };
+
+        match relative_path.split_once('/') {
+            Some((name, _)) => children.insert(name.to_string(), true),
+            None => children.insert(relative_path.to_string(), false),
+        };
+    }
+
+    if children.is_empty() {
+        return Err(Report::msg(format!("Directory {} does not exist", path)));
     }
+
+    Ok(children
+        .into_iter()
+        .map(|(name, is_directory)| DirectoryEntry {
+            path: format!("{}{}", prefix, name),
+            name,
+            is_directory,
+        })
+        .collect())
+}
+
+fn list_project_tasks(system: &MockSystem, location: &str) -> Result<Vec<ProjectTask>, Report> {
+    let project_tasks = system.project_tasks.read().map_err(report_from_poison)?;
+
+    Ok(project_tasks.get(location).cloned().unwrap_or_default())
+}
+
+fn list_repository_commands(
+    system: &MockSystem,
+    location: &str,
+) -> Result<Option<Vec<RepositoryCommand>>, Report> {
+    let repository_commands = system
+        .repository_commands
+        .read()
+        .map_err(report_from_poison)?;
+
+    Ok(repository_commands.get(location).cloned())
+}
+
+fn read_bundle(system: &MockSystem, path: &str) -> Result<Bundle, Report> {
+    let bundles = system.bundles.read().map_err(report_from_poison)?;
+
+    bundles
+        .get(path)
+        .cloned()
+        .ok_or_else(|| Report::msg(format!("Bundle {} does not exist", path)))
+}
+
+fn read_file(system: &MockSystem, path: &str) -> Result<Option<Vec<u8>>, Report> {
+    let files = system.files.read().map_err(report_from_poison)?;
+
+    Ok(files.get(path).map(|content| content.as_bytes().to_vec()))
 }
 
 fn set_clipboard(system: &MockSystem, text: &str) -> Result<(), Report> {
@@ -47,6 +215,40 @@ fn set_clipboard(system: &MockSystem, text: &str) -> Result<(), Report> {
     Ok(())
 }
 
+fn set_command_chain(system: &MockSystem, steps: &[InvokeCommandChainStep]) -> Result<(), Report> {
+    let mut command_chain = system.command_chain.write().map_err(report_from_poison)?;
+
+    *command_chain = steps
+        .iter()
+        .map(|step| {
+            (
+                step.command.to_string(),
+                step.location.map(ToString::to_string),
+                step.shell,
+            )
+        })
+        .collect();
+
+    Ok(())
+}
+
+fn set_environment_variables(
+    system: &MockSystem,
+    environment_variables: &[EnvironmentVariable],
+) -> Result<(), Report> {
+    let mut system_environment_variables = system
+        .environment_variables
+        .write()
+        .map_err(report_from_poison)?;
+
+    *system_environment_variables = environment_variables
+        .iter()
+        .map(|variable| (variable.name().to_string(), variable.value().to_string()))
+        .collect();
+
+    Ok(())
+}
+
 fn set_location(system: &MockSystem, location: Option<&str>) -> Result<(), Report> {
     let mut system_location = system.location.write().map_err(report_from_poison)?;
 
@@ -63,6 +265,30 @@ fn set_program(system: &MockSystem, program: &str) -> Result<(), Report> {
     Ok(())
 }
 
+fn set_shell(system: &MockSystem, shell: Option<Shell>) -> Result<(), Report> {
+    let mut system_shell = system.shell.write().map_err(report_from_poison)?;
+
+    *system_shell = shell;
+
+    Ok(())
+}
+
+fn write_bundle(system: &MockSystem, path: &str, bundle: &Bundle) -> Result<(), Report> {
+    let mut bundles = system.bundles.write().map_err(report_from_poison)?;
+
+    bundles.insert(path.to_string(), bundle.clone());
+
+    Ok(())
+}
+
+fn write_file(system: &MockSystem, path: &str, content: &str) -> Result<(), Report> {
+    let mut files = system.files.write().map_err(report_from_poison)?;
+
+    files.insert(path.to_string(), content.to_string());
+
+    Ok(())
+}
+
 fn report_from_poison<T>(err: PoisonError<T>) -> Report {
     Report::msg(err.to_string())
 }
@@ -0,0 +1,198 @@
+mod test_case;
+
+use crate::support::{self, CommandFixture, InMemoryStorage, MockSystem, WorkspaceFixture};
+use hermione_nexus::{definitions::ProjectTaskSource, operations::SyncProjectTasksParameters};
+use test_case::{Background, BackgroundContext, ExpectedOperationResult};
+
+const WORKSPACE_ID: &str = "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa";
+
+fn workspace<'a>(location: Option<&'a str>) -> WorkspaceFixture<'a> {
+    WorkspaceFixture {
+        id: WORKSPACE_ID,
+        last_access_time: None,
+        location,
+        name: "Hermione",
+        parent_id: None,
+        pinned: false,
+        shell: None,
+        tags: vec![],
+    }
+}
+
+fn command<'a>(
+    id: &'a str,
+    name: &'a str,
+    program: &'a str,
+    managed_by: Option<&'a str>,
+    tags: Vec<&'a str>,
+) -> CommandFixture<'a> {
+    CommandFixture {
+        id,
+        description: None,
+        managed_by,
+        name,
+        pinned: false,
+        program,
+        last_execute_time: None,
+        workspace_id: Some(WORKSPACE_ID),
+        shell: None,
+        tags,
+    }
+}
+
+#[test]
+fn test_sync_project_tasks_operation_imports_included_tasks() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            commands: vec![],
+            project_tasks: vec![
+                (ProjectTaskSource::Makefile, "build", "make build"),
+                (ProjectTaskSource::NpmScript, "test", "npm run test"),
+            ],
+            workspace: workspace(Some("/home/ironman/src/hermione")),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        SyncProjectTasksParameters {
+            workspace_id: support::parse_workspace_id(WORKSPACE_ID),
+            include: vec!["make build".to_string()],
+        },
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            created: vec![("make build", "make build")],
+            updated: vec![],
+            removed: vec![],
+        },
+    );
+}
+
+#[test]
+fn test_sync_project_tasks_operation_refreshes_imported_tasks() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            commands: vec![
+                command(
+                    "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                    "vscode Serve docs",
+                    "mkdocs serve",
+                    Some("project_tasks"),
+                    vec!["task"],
+                ),
+                command(
+                    "4b2e6b9c-3a57-4d2d-9d55-7a3c1a2f4e01",
+                    "make lint",
+                    "make lint",
+                    Some("project_tasks"),
+                    vec!["task"],
+                ),
+                command(
+                    "0e6e4e9e-1b9a-4c55-9d7a-6a0f7a9e2b12",
+                    "make clean",
+                    "make clean",
+                    None,
+                    vec![],
+                ),
+                command(
+                    "7d1c2f4a-8e3b-4a6d-b5c9-2f0e1d3a4b56",
+                    "make docs",
+                    "make docs",
+                    None,
+                    vec!["task"],
+                ),
+            ],
+            project_tasks: vec![
+                (ProjectTaskSource::Makefile, "build", "make build"),
+                (
+                    ProjectTaskSource::VsCodeTask,
+                    "Serve docs",
+                    "mkdocs serve --strict",
+                ),
+            ],
+            workspace: workspace(Some("/home/ironman/src/hermione")),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        SyncProjectTasksParameters {
+            workspace_id: support::parse_workspace_id(WORKSPACE_ID),
+            include: vec![],
+        },
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::Success {
+            created: vec![],
+            updated: vec!["vscode Serve docs"],
+            removed: vec!["make lint"],
+        },
+    );
+
+    test_case::assert_command(
+        &background,
+        "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        Some("mkdocs serve --strict"),
+    );
+    test_case::assert_command(&background, "4b2e6b9c-3a57-4d2d-9d55-7a3c1a2f4e01", None);
+    test_case::assert_command(
+        &background,
+        "0e6e4e9e-1b9a-4c55-9d7a-6a0f7a9e2b12",
+        Some("make clean"),
+    );
+    test_case::assert_command(
+        &background,
+        "7d1c2f4a-8e3b-4a6d-b5c9-2f0e1d3a4b56",
+        Some("make docs"),
+    );
+}
+
+#[test]
+fn test_sync_project_tasks_operation_requires_workspace_location() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            commands: vec![],
+            project_tasks: vec![],
+            workspace: workspace(None),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        SyncProjectTasksParameters {
+            workspace_id: support::parse_workspace_id(WORKSPACE_ID),
+            include: vec!["make build".to_string()],
+        },
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedOperationResult::InvalidArgument,
+    );
+}
@@ -0,0 +1,123 @@
+use crate::support::{self, CommandFixture, InMemoryStorage, MockSystem, WorkspaceFixture};
+use hermione_nexus::{
+    definitions::{
+        CommandManager, CommandsSync, ProjectTask, ProjectTaskParameters, ProjectTaskSource,
+    },
+    operations::{SyncProjectTasksOperation, SyncProjectTasksParameters},
+    Error,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+    pub system: MockSystem,
+}
+
+pub struct BackgroundContext<'a> {
+    pub commands: Vec<CommandFixture<'a>>,
+    pub project_tasks: Vec<(ProjectTaskSource, &'a str, &'a str)>,
+    pub workspace: WorkspaceFixture<'a>,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    InvalidArgument,
+    Success {
+        created: Vec<(&'a str, &'a str)>,
+        updated: Vec<&'a str>,
+        removed: Vec<&'a str>,
+    },
+}
+
+pub fn assert_operation_result(
+    background: &Background,
+    operation_result: Result<CommandsSync, Error>,
+    expected: ExpectedOperationResult,
+) {
+    match expected {
+        ExpectedOperationResult::InvalidArgument => {
+            assert!(operation_result.is_err_and(|err| err.is_invalid_argument()))
+        }
+        ExpectedOperationResult::Success {
+            created,
+            updated,
+            removed,
+        } => {
+            let sync = operation_result.unwrap();
+
+            let created_commands = sync
+                .created
+                .into_iter()
+                .map(|id| support::get_command(&background.storage, id))
+                .map(|command| {
+                    assert_eq!(command.managed_by(), Some(CommandManager::ProjectTasks));
+                    assert_eq!(command.tags(), ["task"]);
+
+                    (command.name().to_string(), command.program().to_string())
+                })
+                .collect::<Vec<(String, String)>>();
+
+            let created = created
+                .into_iter()
+                .map(|(name, program)| (name.to_string(), program.to_string()))
+                .collect::<Vec<(String, String)>>();
+
+            assert_eq!(created_commands, created);
+            assert_eq!(command_names(&sync.updated), updated);
+            assert_eq!(command_names(&sync.removed), removed);
+        }
+    }
+}
+
+pub fn assert_command(background: &Background, id: &str, expected_program: Option<&str>) {
+    let command = support::maybe_get_command(&background.storage, support::parse_command_id(id));
+
+    assert_eq!(
+        command.as_ref().map(|command| command.program()),
+        expected_program
+    );
+}
+
+fn command_names(commands: &[hermione_nexus::definitions::Command]) -> Vec<&str> {
+    commands.iter().map(|command| command.name()).collect()
+}
+
+pub fn execute_operation(
+    background: &Background,
+    parameters: SyncProjectTasksParameters,
+) -> Result<CommandsSync, Error> {
+    let Background { storage, system } = background;
+
+    SyncProjectTasksOperation {
+        find_workspace_provider: storage,
+        list_commands_provider: storage,
+        list_project_tasks_provider: system,
+        sync_commands_provider: storage,
+    }
+    .execute(parameters)
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext {
+        commands,
+        project_tasks,
+        workspace,
+    } = context;
+
+    if let Some(location) = workspace.location {
+        background.system.project_tasks.write().unwrap().insert(
+            location.to_string(),
+            project_tasks
+                .into_iter()
+                .map(|(source, name, program)| {
+                    ProjectTask::new(ProjectTaskParameters {
+                        name: name.to_string(),
+                        program: program.to_string(),
+                        source,
+                    })
+                })
+                .collect(),
+        );
+    }
+
+    support::insert_workspace(&background.storage, workspace);
+    support::insert_commands(&background.storage, commands);
+}
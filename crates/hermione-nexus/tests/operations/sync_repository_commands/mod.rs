@@ -0,0 +1,187 @@
+mod test_case;
+
+use crate::support::{CommandFixture, InMemoryStorage, MockSystem, WorkspaceFixture};
+use test_case::{Background, BackgroundContext, ExpectedSync};
+
+const WORKSPACE_ID: &str = "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa";
+
+fn workspace<'a>(location: Option<&'a str>) -> WorkspaceFixture<'a> {
+    WorkspaceFixture {
+        id: WORKSPACE_ID,
+        last_access_time: None,
+        location,
+        name: "Hermione",
+        parent_id: None,
+        pinned: false,
+        shell: None,
+        tags: vec![],
+    }
+}
+
+fn command<'a>(
+    id: &'a str,
+    name: &'a str,
+    program: &'a str,
+    managed_by: Option<&'a str>,
+    tags: Vec<&'a str>,
+) -> CommandFixture<'a> {
+    CommandFixture {
+        id,
+        description: None,
+        managed_by,
+        name,
+        pinned: false,
+        program,
+        last_execute_time: None,
+        workspace_id: Some(WORKSPACE_ID),
+        shell: None,
+        tags,
+    }
+}
+
+fn local_commands<'a>() -> Vec<CommandFixture<'a>> {
+    vec![
+        command(
+            "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+            "Test",
+            "cargo test",
+            Some("repository"),
+            vec!["repo"],
+        ),
+        command(
+            "4b2e6b9c-3a57-4d2d-9d55-7a3c1a2f4e01",
+            "Lint",
+            "cargo clippy",
+            Some("repository"),
+            vec!["repo"],
+        ),
+        command(
+            "0e6e4e9e-1b9a-4c55-9d7a-6a0f7a9e2b12",
+            "Format",
+            "cargo fmt",
+            None,
+            vec![],
+        ),
+        command(
+            "7d1c2f4a-8e3b-4a6d-b5c9-2f0e1d3a4b56",
+            "Bench",
+            "cargo bench",
+            None,
+            vec!["repo"],
+        ),
+    ]
+}
+
+#[test]
+fn test_sync_repository_commands_operation_follows_repository_file() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            commands: local_commands(),
+            repository_commands: Some(vec![
+                ("Test", "cargo test --workspace"),
+                ("Build", "cargo build"),
+            ]),
+            workspace: workspace(Some("/home/ironman/src/hermione")),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(&background, WORKSPACE_ID);
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedSync {
+            created: vec!["Build"],
+            updated: vec!["Test"],
+            removed: vec!["Lint"],
+        },
+    );
+
+    test_case::assert_command(
+        &background,
+        "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+        Some("cargo test --workspace"),
+    );
+    test_case::assert_command(&background, "4b2e6b9c-3a57-4d2d-9d55-7a3c1a2f4e01", None);
+    test_case::assert_command(
+        &background,
+        "0e6e4e9e-1b9a-4c55-9d7a-6a0f7a9e2b12",
+        Some("cargo fmt"),
+    );
+}
+
+#[test]
+fn test_sync_repository_commands_operation_removes_commands_without_repository_file() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            commands: local_commands(),
+            repository_commands: None,
+            workspace: workspace(Some("/home/ironman/src/hermione")),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(&background, WORKSPACE_ID);
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedSync {
+            created: vec![],
+            updated: vec![],
+            removed: vec!["Lint", "Test"],
+        },
+    );
+
+    test_case::assert_command(
+        &background,
+        "7d1c2f4a-8e3b-4a6d-b5c9-2f0e1d3a4b56",
+        Some("cargo bench"),
+    );
+}
+
+#[test]
+fn test_sync_repository_commands_operation_skips_workspace_without_location() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+        system: MockSystem::default(),
+    };
+
+    test_case::setup(
+        &background,
+        BackgroundContext {
+            commands: local_commands(),
+            repository_commands: None,
+            workspace: workspace(None),
+        },
+    );
+
+    let operation_result = test_case::execute_operation(&background, WORKSPACE_ID);
+
+    test_case::assert_operation_result(
+        &background,
+        operation_result,
+        ExpectedSync {
+            created: vec![],
+            updated: vec![],
+            removed: vec![],
+        },
+    );
+
+    test_case::assert_command(
+        &background,
+        "4b2e6b9c-3a57-4d2d-9d55-7a3c1a2f4e01",
+        Some("cargo clippy"),
+    );
+}
@@ -0,0 +1,117 @@
+use crate::support::{self, CommandFixture, InMemoryStorage, MockSystem, WorkspaceFixture};
+use hermione_nexus::{
+    definitions::{Command, CommandsSync, RepositoryCommand, RepositoryCommandParameters},
+    operations::SyncRepositoryCommandsOperation,
+    Error,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+    pub system: MockSystem,
+}
+
+pub struct BackgroundContext<'a> {
+    pub commands: Vec<CommandFixture<'a>>,
+
+    /// Name and program of every declared command, `None` when the
+    /// location has no repository command file.
+    pub repository_commands: Option<Vec<(&'a str, &'a str)>>,
+    pub workspace: WorkspaceFixture<'a>,
+}
+
+pub struct ExpectedSync<'a> {
+    pub created: Vec<&'a str>,
+    pub updated: Vec<&'a str>,
+    pub removed: Vec<&'a str>,
+}
+
+pub fn assert_operation_result(
+    background: &Background,
+    operation_result: Result<CommandsSync, Error>,
+    expected: ExpectedSync,
+) {
+    let ExpectedSync {
+        created,
+        updated,
+        removed,
+    } = expected;
+
+    let sync = operation_result.unwrap();
+
+    let created_commands = sync
+        .created
+        .into_iter()
+        .map(|id| support::get_command(&background.storage, id))
+        .map(|command| {
+            assert!(command.is_repository_managed());
+
+            command.name().to_string()
+        })
+        .collect::<Vec<String>>();
+
+    assert_eq!(created_commands, created);
+    assert_eq!(command_names(&sync.updated), updated);
+    assert_eq!(command_names(&sync.removed), removed);
+}
+
+pub fn assert_command(background: &Background, id: &str, expected_program: Option<&str>) {
+    let command = support::maybe_get_command(&background.storage, support::parse_command_id(id));
+
+    assert_eq!(
+        command.as_ref().map(|command| command.program()),
+        expected_program
+    );
+}
+
+fn command_names(commands: &[Command]) -> Vec<&str> {
+    commands.iter().map(|command| command.name()).collect()
+}
+
+pub fn execute_operation(
+    background: &Background,
+    workspace_id: &str,
+) -> Result<CommandsSync, Error> {
+    let Background { storage, system } = background;
+
+    SyncRepositoryCommandsOperation {
+        find_workspace_provider: storage,
+        list_commands_provider: storage,
+        list_repository_commands_provider: system,
+        sync_commands_provider: storage,
+    }
+    .execute(support::parse_workspace_id(workspace_id))
+}
+
+pub fn setup(background: &Background, context: BackgroundContext) {
+    let BackgroundContext {
+        commands,
+        repository_commands,
+        workspace,
+    } = context;
+
+    if let (Some(location), Some(repository_commands)) = (workspace.location, repository_commands) {
+        background
+            .system
+            .repository_commands
+            .write()
+            .unwrap()
+            .insert(
+                location.to_string(),
+                repository_commands
+                    .into_iter()
+                    .map(|(name, program)| {
+                        RepositoryCommand::new(RepositoryCommandParameters {
+                            description: None,
+                            name: name.to_string(),
+                            program: program.to_string(),
+                            shell: None,
+                            tags: vec![],
+                        })
+                    })
+                    .collect(),
+            );
+    }
+
+    support::insert_workspace(&background.storage, workspace);
+    support::insert_commands(&background.storage, commands);
+}
@@ -0,0 +1,48 @@
+mod test_case;
+
+use crate::support::{ExpectedWorkspace, InMemoryStorage, WorkspaceFixture};
+use test_case::{Background, ExpectedOperationResult};
+
+#[test]
+fn test_unpin_workspace_operation_succeeds() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        WorkspaceFixture {
+            id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            name: "Ironman",
+            parent_id: None,
+            pinned: true,
+            location: Some("/home/ironman"),
+            last_access_time: None,
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    let operation_result =
+        test_case::execute_operation(&background, "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa");
+
+    test_case::assert_operation_result(
+        operation_result,
+        ExpectedOperationResult::Success {
+            expected_workspace: ExpectedWorkspace {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                pinned: false,
+                location: Some("/home/ironman"),
+                last_access_time: None,
+                tags: vec![],
+            },
+        },
+    );
+
+    test_case::assert_storage_contains_workspace(
+        &background,
+        "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        false,
+    );
+}
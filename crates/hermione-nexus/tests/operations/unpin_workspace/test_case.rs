@@ -0,0 +1,50 @@
+use crate::support::{self, ExpectedWorkspace, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::{definitions::Workspace, operations::UnpinWorkspaceOperation, Result};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub enum ExpectedOperationResult<'a> {
+    Success {
+        expected_workspace: ExpectedWorkspace<'a>,
+    },
+}
+
+pub fn assert_operation_result(result: Result<Workspace>, expected: ExpectedOperationResult) {
+    match expected {
+        ExpectedOperationResult::Success { expected_workspace } => {
+            assert!(result.is_ok());
+            support::assert_workspace(result.unwrap(), expected_workspace)
+        }
+    }
+}
+
+pub fn assert_storage_contains_workspace(
+    background: &Background,
+    workspace_id: &str,
+    pinned: bool,
+) {
+    let workspace = support::get_workspace(
+        &background.storage,
+        support::parse_workspace_id(workspace_id),
+    );
+
+    assert_eq!(workspace.is_pinned(), pinned);
+}
+
+pub fn execute_operation(background: &Background, workspace_id: &str) -> Result<Workspace> {
+    let Background { storage } = background;
+
+    UnpinWorkspaceOperation {
+        find_workspace_provider: storage,
+        update_workspace_provider: storage,
+    }
+    .execute(support::parse_workspace_id(workspace_id))
+}
+
+pub fn setup(background: &Background, workspace: WorkspaceFixture) {
+    let Background { storage } = background;
+
+    support::insert_workspace(storage, workspace);
+}
@@ -16,15 +16,24 @@ fn test_update_command_operation_succeeds() {
             workspace: WorkspaceFixture {
                 id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                 name: "Ironman",
+                parent_id: None,
+                pinned: false,
                 last_access_time: None,
                 location: None,
+                shell: None,
+                tags: vec![],
             },
             command: CommandFixture {
                 id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: None,
                 name: "Ping",
+                pinned: false,
                 program: "ping 1.1.1.1",
                 last_execute_time: None,
-                workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
             },
         },
     );
@@ -33,21 +42,131 @@ fn test_update_command_operation_succeeds() {
         &background,
         UpdateCommandParameters {
             id: support::parse_command_id("51280bfc-2eea-444a-8df9-a1e7158c2c6b"),
+            description: Some("Lists all files.\nIncludes hidden ones.".to_string()),
             name: "List directory items".to_string(),
             program: "ls -la".to_string(),
+            shell: None,
+            tags: vec!["filesystem".to_string()],
         },
     );
 
+    test_case::assert_command_revisions(&background, vec![("Ping", "ping 1.1.1.1")]);
+
     test_case::assert_operation_succeess(
         operation_result,
         OperationResult::Success {
             expected_command: ExpectedCommand {
                 id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: Some("Lists all files.\nIncludes hidden ones."),
                 name: "List directory items",
+                pinned: false,
                 program: "ls -la",
                 last_execute_time: None,
-                workspace_id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                tags: vec!["filesystem"],
+            },
+        },
+    );
+}
+
+#[test]
+fn test_update_command_operation_skips_revision_when_name_and_program_are_unchanged() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        ExistingStorageData {
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                last_access_time: None,
+                location: None,
+                shell: None,
+                tags: vec![],
+            },
+            command: CommandFixture {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: None,
+                name: "Ping",
+                pinned: false,
+                program: "ping 1.1.1.1",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec![],
+            },
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        UpdateCommandParameters {
+            id: support::parse_command_id("51280bfc-2eea-444a-8df9-a1e7158c2c6b"),
+            description: Some("Checks connectivity".to_string()),
+            name: "Ping".to_string(),
+            program: "ping 1.1.1.1".to_string(),
+            shell: None,
+            tags: vec!["network".to_string()],
+        },
+    );
+
+    assert!(operation_result.is_ok());
+
+    test_case::assert_command_revisions(&background, vec![]);
+}
+
+#[test]
+fn test_update_command_operation_rejects_repository_managed_command() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        ExistingStorageData {
+            workspace: WorkspaceFixture {
+                id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+                name: "Ironman",
+                parent_id: None,
+                pinned: false,
+                last_access_time: None,
+                location: Some("/home/ironman/src/hermione"),
+                shell: None,
+                tags: vec![],
+            },
+            command: CommandFixture {
+                id: "51280bfc-2eea-444a-8df9-a1e7158c2c6b",
+                description: None,
+                managed_by: Some("repository"),
+                name: "Test",
+                pinned: false,
+                program: "cargo test",
+                last_execute_time: None,
+                workspace_id: Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+                shell: None,
+                tags: vec!["repo"],
             },
         },
     );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        UpdateCommandParameters {
+            id: support::parse_command_id("51280bfc-2eea-444a-8df9-a1e7158c2c6b"),
+            description: None,
+            name: "Test".to_string(),
+            program: "cargo nextest run".to_string(),
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    assert!(operation_result.is_err_and(|err| err.is_invalid_argument()));
+
+    test_case::assert_command_revisions(&background, vec![]);
 }
@@ -32,6 +32,20 @@ pub fn assert_operation_succeess(
     }
 }
 
+pub fn assert_command_revisions(background: &Background, expected: Vec<(&str, &str)>) {
+    let revisions = support::list_command_revisions(&background.storage)
+        .iter()
+        .map(|revision| (revision.name().to_string(), revision.program().to_string()))
+        .collect::<Vec<(String, String)>>();
+
+    let expected = expected
+        .into_iter()
+        .map(|(name, program)| (name.to_string(), program.to_string()))
+        .collect::<Vec<(String, String)>>();
+
+    assert_eq!(revisions, expected);
+}
+
 pub fn execute_operation(
     background: &Background,
     parameters: UpdateCommandParameters,
@@ -39,6 +53,7 @@ pub fn execute_operation(
     let Background { storage } = background;
 
     UpdateCommandOperation {
+        create_revision_provider: storage,
         find_command_provider: storage,
         update_command_provider: storage,
     }
@@ -1,7 +1,10 @@
 mod test_case;
 
 use crate::support::{self, ExpectedWorkspace, InMemoryStorage, WorkspaceFixture};
-use hermione_nexus::operations::UpdateWorkspaceParameters;
+use hermione_nexus::{
+    definitions::{LOCATION_FIELD, NAME_FIELD},
+    operations::UpdateWorkspaceParameters,
+};
 use test_case::{Background, OperationResult};
 
 #[test]
@@ -15,8 +18,12 @@ fn test_update_workspace_operation_succeeds() {
         WorkspaceFixture {
             id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
             name: "Ironman",
+            parent_id: None,
+            pinned: false,
             location: Some("/home/ironman"),
             last_access_time: Some("2024-11-17 20:00:00"),
+            shell: None,
+            tags: vec![],
         },
     );
 
@@ -26,6 +33,9 @@ fn test_update_workspace_operation_succeeds() {
             id: support::parse_workspace_id("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
             location: Some("/home/avenger".to_string()),
             name: "Avenger".to_string(),
+            parent_id: None,
+            shell: None,
+            tags: vec!["marvel".to_string()],
         },
     );
 
@@ -35,9 +45,211 @@ fn test_update_workspace_operation_succeeds() {
             expected_workspace: ExpectedWorkspace {
                 id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
                 name: "Avenger",
+                pinned: false,
                 location: Some("/home/avenger"),
                 last_access_time: Some("2024-11-17 20:00:00"),
+                tags: vec!["marvel"],
             },
         },
     );
 }
+
+#[test]
+fn test_update_workspace_operation_nests_workspace() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    for (id, name) in [
+        ("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa", "Clients"),
+        ("637d207c-7a18-47eb-b0b4-7f27d4ecbf88", "Acme"),
+    ] {
+        test_case::setup(
+            &background,
+            WorkspaceFixture {
+                id,
+                name,
+                parent_id: None,
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+        );
+    }
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        UpdateWorkspaceParameters {
+            id: support::parse_workspace_id("637d207c-7a18-47eb-b0b4-7f27d4ecbf88"),
+            location: None,
+            name: "Acme".to_string(),
+            parent_id: Some(support::parse_workspace_id(
+                "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            )),
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    test_case::assert_operation_succeess(
+        operation_result,
+        OperationResult::Success {
+            expected_workspace: ExpectedWorkspace {
+                id: "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
+                name: "Acme",
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                tags: vec![],
+            },
+        },
+    );
+
+    test_case::assert_parent(
+        &background,
+        "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
+        Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+    );
+}
+
+#[test]
+fn test_update_workspace_operation_rejects_nesting_in_descendant() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    for (id, name, parent_id) in [
+        ("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa", "Clients", None),
+        (
+            "637d207c-7a18-47eb-b0b4-7f27d4ecbf88",
+            "Acme",
+            Some("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+        ),
+        (
+            "2b3ec7f6-4b1e-4a5b-8a6c-7c1f0a0b1e2d",
+            "Api",
+            Some("637d207c-7a18-47eb-b0b4-7f27d4ecbf88"),
+        ),
+    ] {
+        test_case::setup(
+            &background,
+            WorkspaceFixture {
+                id,
+                name,
+                parent_id,
+                pinned: false,
+                location: None,
+                last_access_time: None,
+                shell: None,
+                tags: vec![],
+            },
+        );
+    }
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        UpdateWorkspaceParameters {
+            id: support::parse_workspace_id("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            location: None,
+            name: "Clients".to_string(),
+            parent_id: Some(support::parse_workspace_id(
+                "2b3ec7f6-4b1e-4a5b-8a6c-7c1f0a0b1e2d",
+            )),
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    test_case::assert_operation_succeess(operation_result, OperationResult::InvalidArgument);
+    test_case::assert_parent(&background, "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa", None);
+}
+
+#[test]
+fn test_update_workspace_operation_rejects_blank_name() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        WorkspaceFixture {
+            id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            name: "Ironman",
+            parent_id: None,
+            pinned: false,
+            location: None,
+            last_access_time: None,
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        UpdateWorkspaceParameters {
+            id: support::parse_workspace_id("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            location: None,
+            name: "   ".to_string(),
+            parent_id: None,
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    test_case::assert_operation_succeess(
+        operation_result,
+        OperationResult::InvalidField { field: NAME_FIELD },
+    );
+    test_case::assert_name(
+        &background,
+        "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        "Ironman",
+    );
+}
+
+#[test]
+fn test_update_workspace_operation_rejects_location_with_nul_byte() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        WorkspaceFixture {
+            id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            name: "Ironman",
+            parent_id: None,
+            pinned: false,
+            location: None,
+            last_access_time: None,
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        UpdateWorkspaceParameters {
+            id: support::parse_workspace_id("9db9a48b-f075-4518-bdd5-ec9d9b05f4fa"),
+            location: Some("/home/\0ironman".to_string()),
+            name: "Avenger".to_string(),
+            parent_id: None,
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    test_case::assert_operation_succeess(
+        operation_result,
+        OperationResult::InvalidField {
+            field: LOCATION_FIELD,
+        },
+    );
+    test_case::assert_name(
+        &background,
+        "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        "Ironman",
+    );
+}
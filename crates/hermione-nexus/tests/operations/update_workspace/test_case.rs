@@ -10,6 +10,10 @@ pub struct Background {
 }
 
 pub enum OperationResult<'a> {
+    InvalidArgument,
+    InvalidField {
+        field: &'a str,
+    },
     Success {
         expected_workspace: ExpectedWorkspace<'a>,
     },
@@ -20,6 +24,15 @@ pub fn assert_operation_succeess(
     expected: OperationResult,
 ) {
     match expected {
+        OperationResult::InvalidArgument => {
+            assert!(operation_result.is_err_and(|err| err.is_invalid_argument()))
+        }
+        OperationResult::InvalidField { field } => {
+            let err = operation_result.err().unwrap();
+
+            assert!(err.is_invalid_argument());
+            assert_eq!(err.field(), Some(field));
+        }
         OperationResult::Success { expected_workspace } => {
             assert!(operation_result.is_ok());
             support::assert_workspace(operation_result.unwrap(), expected_workspace);
@@ -40,6 +53,21 @@ pub fn execute_operation(
     .execute(parameters)
 }
 
+pub fn assert_name(background: &Background, id: &str, expected_name: &str) {
+    let workspace = support::get_workspace(&background.storage, support::parse_workspace_id(id));
+
+    assert_eq!(workspace.name(), expected_name);
+}
+
+pub fn assert_parent(background: &Background, id: &str, expected_parent_id: Option<&str>) {
+    let workspace = support::get_workspace(&background.storage, support::parse_workspace_id(id));
+
+    assert_eq!(
+        workspace.parent_id(),
+        expected_parent_id.map(support::parse_workspace_id)
+    );
+}
+
 pub fn setup(background: &Background, workspace: WorkspaceFixture) {
     support::insert_workspace(&background.storage, workspace);
 }
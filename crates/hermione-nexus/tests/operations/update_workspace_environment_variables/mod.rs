@@ -0,0 +1,77 @@
+mod test_case;
+
+use crate::support::{InMemoryStorage, WorkspaceFixture};
+use test_case::{Background, OperationResult};
+
+#[test]
+fn test_update_workspace_environment_variables_operation_succeeds() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        WorkspaceFixture {
+            id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            name: "Ironman",
+            parent_id: None,
+            pinned: false,
+            last_access_time: None,
+            location: None,
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        vec![("KUBECONFIG", "~/.kube/mk"), ("AWS_PROFILE", "stark")],
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        operation_result,
+        OperationResult::Success {
+            expected_environment_variables: vec![
+                ("AWS_PROFILE", "stark"),
+                ("KUBECONFIG", "~/.kube/mk"),
+            ],
+        },
+    );
+}
+
+#[test]
+fn test_update_workspace_environment_variables_operation_rejects_invalid_name() {
+    let background = Background {
+        storage: InMemoryStorage::empty(),
+    };
+
+    test_case::setup(
+        &background,
+        WorkspaceFixture {
+            id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+            name: "Ironman",
+            parent_id: None,
+            pinned: false,
+            last_access_time: None,
+            location: None,
+            shell: None,
+            tags: vec![],
+        },
+    );
+
+    let operation_result = test_case::execute_operation(
+        &background,
+        "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        vec![("AWS PROFILE", "stark")],
+    );
+
+    test_case::assert_operation_result(
+        &background,
+        "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
+        operation_result,
+        OperationResult::InvalidArgument,
+    );
+}
@@ -0,0 +1,79 @@
+use crate::support::{self, InMemoryStorage, WorkspaceFixture};
+use hermione_nexus::{
+    definitions::{EnvironmentVariable, EnvironmentVariableParameters},
+    operations::{
+        UpdateWorkspaceEnvironmentVariablesOperation, UpdateWorkspaceEnvironmentVariablesParameters,
+    },
+    Error,
+};
+
+pub struct Background {
+    pub storage: InMemoryStorage,
+}
+
+pub enum OperationResult<'a> {
+    InvalidArgument,
+    Success {
+        expected_environment_variables: Vec<(&'a str, &'a str)>,
+    },
+}
+
+pub fn assert_operation_result(
+    background: &Background,
+    workspace_id: &str,
+    operation_result: Result<Vec<EnvironmentVariable>, Error>,
+    expected: OperationResult,
+) {
+    match expected {
+        OperationResult::InvalidArgument => {
+            assert!(operation_result.is_err_and(|err| err.is_invalid_argument()))
+        }
+        OperationResult::Success {
+            expected_environment_variables,
+        } => {
+            assert!(operation_result.is_ok());
+
+            let stored: Vec<(String, String)> = background
+                .storage
+                .get_environment_variables(support::parse_workspace_id(workspace_id))
+                .unwrap()
+                .into_iter()
+                .map(|variable| (variable.name().to_string(), variable.value().to_string()))
+                .collect();
+
+            let expected: Vec<(String, String)> = expected_environment_variables
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+
+            assert_eq!(stored, expected);
+        }
+    }
+}
+
+pub fn execute_operation(
+    background: &Background,
+    workspace_id: &str,
+    environment_variables: Vec<(&str, &str)>,
+) -> Result<Vec<EnvironmentVariable>, Error> {
+    let Background { storage } = background;
+
+    UpdateWorkspaceEnvironmentVariablesOperation {
+        find_workspace_provider: storage,
+        update_environment_variables_provider: storage,
+    }
+    .execute(UpdateWorkspaceEnvironmentVariablesParameters {
+        environment_variables: environment_variables
+            .into_iter()
+            .map(|(name, value)| EnvironmentVariableParameters {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+            .collect(),
+        workspace_id: support::parse_workspace_id(workspace_id),
+    })
+}
+
+pub fn setup(background: &Background, workspace: WorkspaceFixture) {
+    support::insert_workspace(&background.storage, workspace);
+}
@@ -15,8 +15,12 @@ fn test_visit_workspace_operation_succeds() {
         WorkspaceFixture {
             id: "9db9a48b-f075-4518-bdd5-ec9d9b05f4fa",
             name: "Ironman",
+            parent_id: None,
+            pinned: false,
             location: Some("/home/ironman"),
             last_access_time: None,
+            shell: None,
+            tags: vec![],
         },
     );
 
@@ -0,0 +1,83 @@
+use hermione_drive::Engine;
+use hermione_nexus::{
+    definitions::{Workspace, WorkspaceId},
+    operations::{
+        ExportCheatSheetOperation, ExportCheatSheetParameters, ListWorkspacesOperation,
+        ListWorkspacesParameters,
+    },
+    services::{SortOrder, WorkspaceScope},
+};
+use std::num::NonZeroU32;
+
+/// Prints the cheat sheet unless an output file is given. The workspace is
+/// looked up by ID first and by its exact name, ignoring case, otherwise.
+pub fn export(workspace: &str, output: Option<&str>) -> anyhow::Result<()> {
+    let Engine {
+        service_factory,
+        logs_worker_guard: _logs_worker_guard,
+    } = hermione_drive::start()?;
+
+    let storage = service_factory.storage();
+
+    let workspace_id = match WorkspaceId::parse_str(workspace) {
+        Ok(workspace_id) => workspace_id,
+        Err(_) => {
+            let mut workspaces: Vec<Workspace> = Vec::new();
+            let mut page_number = NonZeroU32::MIN;
+
+            // Names are matched by substring first, so every page is checked
+            loop {
+                let page = ListWorkspacesOperation { provider: &storage }.execute(
+                    ListWorkspacesParameters {
+                        created_after: None,
+                        name_contains: Some(workspace),
+                        page_number: Some(page_number),
+                        page_size: None,
+                        scope: WorkspaceScope::All,
+                        sort: SortOrder::Created,
+                        tags: None,
+                        updated_after: None,
+                    },
+                )?;
+
+                if page.is_empty() {
+                    break;
+                }
+
+                workspaces.extend(
+                    page.into_iter()
+                        .filter(|candidate| candidate.name().eq_ignore_ascii_case(workspace)),
+                );
+
+                page_number = page_number.saturating_add(1);
+            }
+
+            match workspaces.as_slice() {
+                [found] => found.id(),
+                [] => return Err(anyhow::anyhow!("Could not find workspace {}", workspace)),
+                _ => {
+                    return Err(anyhow::anyhow!(
+                        "Several workspaces are named {}, use the workspace ID",
+                        workspace
+                    ))
+                }
+            }
+        }
+    };
+
+    let cheat_sheet = ExportCheatSheetOperation {
+        list_commands_provider: &storage,
+        list_workspaces_provider: &storage,
+        write_file_provider: &service_factory.system(),
+    }
+    .execute(ExportCheatSheetParameters {
+        workspace_id,
+        path: output,
+    })?;
+
+    if output.is_none() {
+        print!("{}", cheat_sheet.render_markdown());
+    }
+
+    Ok(())
+}
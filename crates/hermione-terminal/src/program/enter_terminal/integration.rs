@@ -1,31 +1,95 @@
 use hermione_drive::{NotionBackupBuilder, ServiceFactory};
 use hermione_nexus::{
     definitions::{
-        BackupCredentials, BackupProviderKind, Command, CommandId,
-        NotionBackupCredentialsParameters, Workspace, WorkspaceId,
+        BackupCredentials, BackupProviderKind, BundleConflictStrategy, Command, CommandExecution,
+        CommandId, CommandRevision, CommandRevisionDiff, CommandRevisionId, DiffChange,
+        EnvironmentVariable, EnvironmentVariableParameters, NotionBackupCredentialsParameters,
+        Shell, ShellHistoryFormat, TrashEntry, TrashItem, TrashItemId, UndoRecord, Workspace,
+        WorkspaceId,
     },
     operations::{
-        CommandsDeleteAttribute, CopyCommandToClipboardOperation, CreateCommandOperation,
-        CreateCommandParameters, CreateWorkspaceOperation, CreateWorkspaceParameters,
-        DeleteBackupCredentialsOperation, DeleteCommandOperation, DeleteCommandsOperation,
-        DeleteCommandsParameters, DeleteWorkspaceOperation, ExecuteCommandOperation,
+        ApplyUndoOperation, BundleScope, CloneWorkspaceOperation, CloneWorkspaceParameters,
+        CopyCommandOperation, CopyCommandParameters, CopyCommandToClipboardOperation,
+        CreateCommandOperation, CreateCommandParameters, CreateWorkspaceOperation,
+        CreateWorkspaceParameters, DeleteBackupCredentialsOperation, DiffCommandRevisionsOperation,
+        DiffCommandRevisionsParameters, DiscoverWorkspacesOperation, DiscoverWorkspacesParameters,
+        ExecuteCommandOperation, ExecuteCommandParameters, ExportBundleOperation,
+        ExportBundleParameters, ExportCheatSheetOperation, ExportCheatSheetParameters,
         ExportCommandOperation, ExportCommandOperationParameters, ExportCommandParameters,
         ExportCommandsOperation, ExportCommandsOperationParameters, ExportWorkspaceOperation,
         ExportWorkspaceOperationParameters, ExportWorkspaceParameters, ExportWorkspacesOperation,
         ExportWorkspacesOperationParameters, GetCommandOperation, GetWorkspaceOperation,
-        ImportCommandsOperation, ImportCommandsOperationParameters, ImportWorkspacesOperation,
-        ImportWorkspacesOperationParameters, ListBackupCredentialsOperation, ListCommandsOperation,
-        ListCommandsParameters, ListWorkspacesOperation, ListWorkspacesParameters,
-        SaveBackupCredentialsOperation, SaveBackupCredentialsOperationParameters,
-        UpdateCommandOperation, UpdateCommandParameters, UpdateWorkspaceOperation,
+        ImportBundleOperation, ImportBundleParameters, ImportCommandsOperation,
+        ImportCommandsOperationParameters, ImportWorkspacesOperation,
+        ImportWorkspacesOperationParameters, ListBackupCredentialsOperation,
+        ListCommandExecutionsOperation, ListCommandExecutionsParameters,
+        ListCommandRevisionsOperation, ListCommandRevisionsParameters, ListCommandsOperation,
+        ListCommandsParameters, ListHistoryCommandsOperation, ListHistoryCommandsParameters,
+        ListProjectTasksOperation, ListTrashOperation, ListTrashParameters,
+        ListWorkspaceEnvironmentVariablesOperation, ListWorkspacesOperation,
+        ListWorkspacesParameters, MoveCommandOperation, MoveCommandParameters,
+        MoveToTrashOperation, PinCommandOperation, PinWorkspaceOperation, PurgeTrashOperation,
+        RestoreCommandRevisionOperation, RestoreFromTrashOperation, SaveBackupCredentialsOperation,
+        SaveBackupCredentialsOperationParameters, ShellHistorySource, SyncProjectTasksOperation,
+        SyncProjectTasksParameters, SyncRepositoryCommandsOperation, UnpinCommandOperation,
+        UnpinWorkspaceOperation, UpdateCommandOperation, UpdateCommandParameters,
+        UpdateWorkspaceEnvironmentVariablesOperation,
+        UpdateWorkspaceEnvironmentVariablesParameters, UpdateWorkspaceOperation,
         UpdateWorkspaceParameters, VisitWorkspaceLocationOperation,
     },
+    services::{CommandScope, PurgeTrashScope, SortOrder, TagsFilter, WorkspaceScope},
 };
+use std::{collections::HashMap, num::NonZeroU32, time::Duration};
+use uuid::Uuid;
 
-use crate::program_lib::{Context, ListItem, State};
+use crate::program_lib::{
+    CommandCandidate, CommandTransfer, Context, DiscoveredWorkspace, ListItem, State,
+    WorkspaceLevel,
+};
+
+pub const BUNDLE_ACTION_EXPORT: &str = "export";
+pub const BUNDLE_ACTION_IMPORT: &str = "import";
+pub const BUNDLE_SCOPE_LIBRARY: &str = "library";
+pub const BUNDLE_SCOPE_WORKSPACE: &str = "workspace";
+pub const BUNDLE_STRATEGY_DUPLICATE: &str = "duplicate";
+pub const BUNDLE_STRATEGY_KEEP_LOCAL: &str = "keep";
+pub const BUNDLE_STRATEGY_OVERWRITE: &str = "overwrite";
+
+const ANY_TAG_SEPARATOR: char = '|';
+const COMMAND_CANDIDATE_NAME_LENGTH: usize = 60;
+const COMMAND_EXECUTION_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const COMMAND_REVISION_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const ENVIRONMENT_VARIABLE_SEPARATOR: char = '=';
+const GLOBAL_MARKER: &str = "(global)";
+const INHERITED_MARKER: &str = "(inherited)";
+const PATH_SEPARATOR: char = '/';
+const PIN_MARKER: char = '*';
+const TAG_PREFIX: char = '#';
+const UNDO_STACK_LIMIT: usize = 20;
+
+/// History files of the supported shells at their default locations.
+const SHELL_HISTORY_FILES: [(ShellHistoryFormat, &str); 5] = [
+    (ShellHistoryFormat::Bash, "~/.bash_history"),
+    (ShellHistoryFormat::Zsh, "~/.zsh_history"),
+    (ShellHistoryFormat::Fish, "~/.local/share/fish/fish_history"),
+    (
+        ShellHistoryFormat::PowerShell,
+        "~/.local/share/powershell/PSReadLine/ConsoleHost_history.txt",
+    ),
+    (
+        ShellHistoryFormat::PowerShell,
+        "~/AppData/Roaming/Microsoft/Windows/PowerShell/PSReadLine/ConsoleHost_history.txt",
+    ),
+];
+
+/// All workspaces indexed by identifier, used to render hierarchy paths.
+struct WorkspaceTree {
+    workspaces: HashMap<WorkspaceId, Workspace>,
+}
 
 pub struct RunCommandOptions {
     pub no_exit: bool,
+    pub placeholder_values: HashMap<String, String>,
 }
 
 pub fn backup_commands(services: &ServiceFactory) -> anyhow::Result<()> {
@@ -94,6 +158,32 @@ pub fn backup_workspace(state: &State, services: &ServiceFactory) -> anyhow::Res
     Ok(())
 }
 
+pub fn clone_workspace(state: &State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Context::WorkspaceCloneForm = state.context else {
+        return Ok(());
+    };
+
+    let Some(workspace_id) = state.workspace_id else {
+        return Ok(());
+    };
+
+    let storage = services.storage();
+    let name = state.form.inputs[0].clone();
+    let location = state.form.inputs[1].clone();
+
+    CloneWorkspaceOperation {
+        clone_workspace_provider: &storage,
+        find_workspace_provider: &storage,
+    }
+    .execute(CloneWorkspaceParameters {
+        id: WorkspaceId::new(workspace_id)?,
+        location: Some(location),
+        name,
+    })?;
+
+    Ok(())
+}
+
 pub fn copy_command_to_clipboard(state: &State, services: &ServiceFactory) -> anyhow::Result<()> {
     let Some(command_id) = state.command_id else {
         return Ok(());
@@ -108,6 +198,29 @@ pub fn copy_command_to_clipboard(state: &State, services: &ServiceFactory) -> an
     Ok(())
 }
 
+/// Compares the selected revision with the current version of its command.
+pub fn diff_command_revision(
+    state: &State,
+    services: &ServiceFactory,
+) -> anyhow::Result<Option<String>> {
+    let Some(item) = state.list.items.get(state.list.cursor) else {
+        return Ok(None);
+    };
+
+    let storage = services.storage();
+
+    let diff = DiffCommandRevisionsOperation {
+        find_command_provider: &storage,
+        find_revision_provider: &storage,
+    }
+    .execute(DiffCommandRevisionsParameters {
+        from: CommandRevisionId::new(item.id)?,
+        to: None,
+    })?;
+
+    Ok(Some(format_command_revision_diff(&diff)))
+}
+
 pub fn get_command(
     state: &mut State,
     services: &ServiceFactory,
@@ -124,6 +237,21 @@ pub fn get_command(
     Ok(Some(command))
 }
 
+pub fn format_environment_variables(environment_variables: &[EnvironmentVariable]) -> String {
+    environment_variables
+        .iter()
+        .map(|variable| {
+            format!(
+                "{}{}{}",
+                variable.name(),
+                ENVIRONMENT_VARIABLE_SEPARATOR,
+                variable.value()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 pub fn get_notion_backup_credentials(
     services: &ServiceFactory,
 ) -> anyhow::Result<Option<BackupCredentials>> {
@@ -157,32 +285,40 @@ pub fn save_command(state: &mut State, services: &ServiceFactory) -> anyhow::Res
         return Ok(());
     };
 
-    let Some(workspace_id) = state.workspace_id else {
-        return Ok(());
-    };
-
     let storage = services.storage();
     let name = state.form.inputs[0].clone();
     let program = state.form.inputs[1].clone();
-    let workspace_id = WorkspaceId::new(workspace_id)?;
+    let tags = vec![state.form.inputs[2].clone()];
+    let shell = parse_shell(&state.form.inputs[3])?;
+    let description = Some(state.form.inputs[4].clone());
+    let workspace_id = state.workspace_id.map(WorkspaceId::new).transpose()?;
 
     if let Some(id) = state.command_id {
-        UpdateCommandOperation {
+        let (_command, undo_record) = UpdateCommandOperation {
+            create_revision_provider: &storage,
             find_command_provider: &storage,
             update_command_provider: &storage,
         }
-        .execute(UpdateCommandParameters {
+        .execute_with_undo(UpdateCommandParameters {
             id: CommandId::new(id)?,
+            description,
             program,
             name,
+            shell,
+            tags,
         })?;
+
+        remember_undo(state, undo_record);
     } else {
         CreateCommandOperation {
             storage_provider: &storage,
         }
         .execute(CreateCommandParameters {
+            description,
             name,
             program,
+            shell,
+            tags,
             workspace_id,
         })?;
     }
@@ -234,17 +370,28 @@ pub fn save_workspace(state: &mut State, services: &ServiceFactory) -> anyhow::R
     let storage = services.storage();
     let name = state.form.inputs[0].clone();
     let location = state.form.inputs[1].clone();
+    let tags = vec![state.form.inputs[2].clone()];
+    let shell = parse_shell(&state.form.inputs[3])?;
+    let environment_variables = parse_environment_variables(&state.form.inputs[4])?;
+    let parent_id = WorkspaceTree::load(services)?.find_by_path(&state.form.inputs[5])?;
 
-    if let Some(id) = state.workspace_id {
-        UpdateWorkspaceOperation {
+    let workspace = if let Some(id) = state.workspace_id {
+        let (workspace, undo_record) = UpdateWorkspaceOperation {
             find_workspace_provider: &storage,
             update_workspace_provider: &storage,
         }
-        .execute(UpdateWorkspaceParameters {
+        .execute_with_undo(UpdateWorkspaceParameters {
             id: WorkspaceId::new(id)?,
             location: Some(location),
             name,
+            parent_id,
+            shell,
+            tags,
         })?;
+
+        remember_undo(state, undo_record);
+
+        workspace
     } else {
         CreateWorkspaceOperation {
             storage_provider: &storage,
@@ -252,8 +399,20 @@ pub fn save_workspace(state: &mut State, services: &ServiceFactory) -> anyhow::R
         .execute(CreateWorkspaceParameters {
             name,
             location: Some(location),
-        })?;
+            parent_id,
+            shell,
+            tags,
+        })?
+    };
+
+    UpdateWorkspaceEnvironmentVariablesOperation {
+        find_workspace_provider: &storage,
+        update_environment_variables_provider: &storage,
     }
+    .execute(UpdateWorkspaceEnvironmentVariablesParameters {
+        environment_variables,
+        workspace_id: workspace.id(),
+    })?;
 
     Ok(())
 }
@@ -269,11 +428,14 @@ pub fn delete_command(state: &mut State, services: &ServiceFactory) -> anyhow::R
 
     let storage = services.storage();
 
-    DeleteCommandOperation {
-        find_provider: &storage,
-        delete_provider: &storage,
+    let undo_record = MoveToTrashOperation {
+        find_command_provider: &storage,
+        find_workspace_provider: &storage,
+        move_to_trash_provider: &storage,
     }
-    .execute(CommandId::new(command_id)?)?;
+    .execute_with_undo(TrashItemId::Command(CommandId::new(command_id)?))?;
+
+    remember_undo(state, undo_record);
 
     Ok(())
 }
@@ -294,51 +456,683 @@ pub fn delete_workspace(state: &mut State, services: &ServiceFactory) -> anyhow:
     let workspace_id = WorkspaceId::new(workspace_id)?;
     let storage = services.storage();
 
-    DeleteCommandsOperation {
-        delete_workspace_commands: &storage,
+    let undo_record = MoveToTrashOperation {
+        find_command_provider: &storage,
+        find_workspace_provider: &storage,
+        move_to_trash_provider: &storage,
     }
-    .execute(DeleteCommandsParameters {
-        delete_attribute: CommandsDeleteAttribute::WorkspaceId(workspace_id),
+    .execute_with_undo(TrashItemId::Workspace(workspace_id))?;
+
+    remember_undo(state, undo_record);
+
+    Ok(())
+}
+
+/// Lists runs of the selected command, or of the whole workspace when no
+/// command is selected.
+pub fn list_command_executions(
+    state: &State,
+    services: &ServiceFactory,
+) -> anyhow::Result<Vec<ListItem>> {
+    let (command_id, workspace_id) = match (state.command_id, state.workspace_id) {
+        (Some(command_id), _) => (Some(CommandId::new(command_id)?), None),
+        (None, Some(workspace_id)) => (None, Some(WorkspaceId::new(workspace_id)?)),
+        (None, None) => return Ok(Vec::new()),
+    };
+
+    let command_executions = ListCommandExecutionsOperation {
+        provider: &services.storage(),
+    }
+    .execute(ListCommandExecutionsParameters {
+        command_id,
+        page_number: None,
+        page_size: None,
+        workspace_id,
     })?;
 
-    DeleteWorkspaceOperation {
-        find_workspace_provider: &storage,
-        delete_workspace_provider: &storage,
+    Ok(command_executions.into_iter().map(Into::into).collect())
+}
+
+/// Lists earlier versions of the selected command, newest first.
+pub fn list_command_revisions(
+    state: &State,
+    services: &ServiceFactory,
+) -> anyhow::Result<Vec<ListItem>> {
+    let Some(command_id) = state.command_id else {
+        return Ok(Vec::new());
+    };
+
+    let command_revisions = ListCommandRevisionsOperation {
+        provider: &services.storage(),
     }
-    .execute(workspace_id)?;
+    .execute(ListCommandRevisionsParameters {
+        command_id: CommandId::new(command_id)?,
+        page_number: None,
+        page_size: None,
+    })?;
 
-    Ok(())
+    Ok(command_revisions.into_iter().map(Into::into).collect())
 }
 
+/// Lists commands of the selected workspace and its ancestors together with
+/// global commands, or only global commands when no workspace is selected.
 pub fn list_commands(state: &State, services: &ServiceFactory) -> anyhow::Result<Vec<ListItem>> {
-    let Some(workspace_id) = state.workspace_id else {
-        return Ok(Vec::new());
+    let workspace_id = state.workspace_id.map(WorkspaceId::new).transpose()?;
+    let scope = match workspace_id {
+        Some(workspace_id) => CommandScope::Inherited(workspace_id),
+        None => CommandScope::Global,
     };
 
+    let (program_contains, tags) = parse_list_filter(&state.list.filter);
+
     let commands = ListCommandsOperation {
         provider: &services.storage(),
     }
     .execute(ListCommandsParameters {
+        created_after: None,
+        description_contains: None,
         page_size: None,
         page_number: None,
-        program_contains: Some(&state.list.filter),
-        workspace_id: Some(WorkspaceId::new(workspace_id)?),
+        program_contains: Some(&program_contains),
+        scope,
+        sort: SortOrder::Frecency,
+        tags,
+        updated_after: None,
+    })?;
+
+    Ok(commands
+        .into_iter()
+        .map(|command| {
+            let inherited = !command.is_global() && command.workspace_id() != workspace_id;
+            let mut item = ListItem::from(command);
+
+            if inherited {
+                item.text = format!("{} {}", item.text, INHERITED_MARKER);
+            }
+
+            item
+        })
+        .collect())
+}
+
+/// Collects the most frequently run programs of all shell histories found
+/// on the machine, named after their first line.
+pub fn list_history_candidates(services: &ServiceFactory) -> anyhow::Result<Vec<CommandCandidate>> {
+    let commands = ListHistoryCommandsOperation {
+        read_file_provider: &services.system(),
+    }
+    .execute(ListHistoryCommandsParameters {
+        sources: SHELL_HISTORY_FILES
+            .iter()
+            .map(|(format, path)| ShellHistorySource {
+                format: *format,
+                path,
+            })
+            .collect(),
+        limit: None,
     })?;
 
-    Ok(commands.into_iter().map(Into::into).collect())
+    Ok(commands
+        .into_iter()
+        .map(|command| CommandCandidate {
+            name: command
+                .program()
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .take(COMMAND_CANDIDATE_NAME_LENGTH)
+                .collect(),
+            program: command.program().to_string(),
+            detail: command.count().to_string(),
+            selected: false,
+        })
+        .collect())
 }
 
-pub fn list_workspaces(state: &State, services: &ServiceFactory) -> anyhow::Result<Vec<ListItem>> {
-    let workspaces = ListWorkspacesOperation {
+/// Proposes every task defined at the workspace location.
+pub fn list_project_task_candidates(
+    state: &State,
+    services: &ServiceFactory,
+) -> anyhow::Result<Vec<CommandCandidate>> {
+    let Some(workspace_id) = state.workspace_id else {
+        return Ok(vec![]);
+    };
+
+    let tasks = ListProjectTasksOperation {
+        find_workspace_provider: &services.storage(),
+        list_project_tasks_provider: &services.system(),
+    }
+    .execute(WorkspaceId::new(workspace_id)?)?;
+
+    Ok(tasks
+        .into_iter()
+        .map(|task| CommandCandidate {
+            name: task.command_name(),
+            program: task.program().to_string(),
+            detail: task.program().to_string(),
+            selected: false,
+        })
+        .collect())
+}
+
+pub fn list_trash(services: &ServiceFactory) -> anyhow::Result<Vec<ListItem>> {
+    let trash = ListTrashOperation {
         provider: &services.storage(),
     }
-    .execute(ListWorkspacesParameters {
-        name_contains: Some(&state.list.filter),
+    .execute(ListTrashParameters {
         page_number: None,
         page_size: None,
     })?;
 
-    Ok(workspaces.into_iter().map(Into::into).collect())
+    Ok(trash.into_iter().map(Into::into).collect())
+}
+
+pub fn list_workspace_environment_variables(
+    workspace: &Workspace,
+    services: &ServiceFactory,
+) -> anyhow::Result<Vec<EnvironmentVariable>> {
+    let environment_variables = ListWorkspaceEnvironmentVariablesOperation {
+        provider: &services.storage(),
+    }
+    .execute(workspace.id())?;
+
+    Ok(environment_variables)
+}
+
+pub fn list_transfer_targets(
+    state: &State,
+    services: &ServiceFactory,
+) -> anyhow::Result<Vec<ListItem>> {
+    let global = ListItem {
+        id: Uuid::nil(),
+        text: GLOBAL_MARKER.to_string(),
+    };
+
+    let workspaces = list_workspace_items(state, services, WorkspaceScope::All)?;
+
+    Ok(std::iter::once(global).chain(workspaces).collect())
+}
+
+/// Lists children of the current workspace level, or every workspace with
+/// its full path while the list is filtered.
+pub fn list_workspaces(state: &State, services: &ServiceFactory) -> anyhow::Result<Vec<ListItem>> {
+    let scope = match &state.workspace_level {
+        _ if !state.list.filter.trim().is_empty() => WorkspaceScope::All,
+        Some(level) => WorkspaceScope::Children(WorkspaceId::new(level.id)?),
+        None => WorkspaceScope::Roots,
+    };
+
+    list_workspace_items(state, services, scope)
+}
+
+/// Creates the selected candidates in the current workspace, the whole
+/// batch is undone at once.
+pub fn create_command_candidates(
+    state: &mut State,
+    services: &ServiceFactory,
+) -> anyhow::Result<usize> {
+    let storage = services.storage();
+    let workspace_id = state.workspace_id.map(WorkspaceId::new).transpose()?;
+
+    let mut created = Vec::new();
+
+    let result = std::mem::take(&mut state.command_candidates)
+        .into_iter()
+        .filter(|candidate| candidate.selected)
+        .try_for_each(|CommandCandidate { name, program, .. }| {
+            let command = CreateCommandOperation {
+                storage_provider: &storage,
+            }
+            .execute(CreateCommandParameters {
+                description: None,
+                name,
+                program,
+                shell: None,
+                tags: vec![],
+                workspace_id,
+            })?;
+
+            created.push(command.id());
+
+            Ok::<(), anyhow::Error>(())
+        });
+
+    let count = created.len();
+
+    // Commands created before a failure can still be undone.
+    if count > 0 {
+        remember_undo(
+            state,
+            UndoRecord::CommandsImported {
+                created,
+                previous: vec![],
+            },
+        );
+    }
+
+    result.map(|()| count)
+}
+
+/// Imports the selected tasks into the current workspace and refreshes
+/// the ones imported before.
+pub fn import_project_tasks(state: &mut State, services: &ServiceFactory) -> anyhow::Result<usize> {
+    let include = std::mem::take(&mut state.command_candidates)
+        .into_iter()
+        .filter(|candidate| candidate.selected)
+        .map(|candidate| candidate.name)
+        .collect();
+
+    let Some(workspace_id) = state.workspace_id else {
+        return Ok(0);
+    };
+
+    let storage = services.storage();
+
+    let (sync, undo_record) = SyncProjectTasksOperation {
+        find_workspace_provider: &storage,
+        list_commands_provider: &storage,
+        list_project_tasks_provider: &services.system(),
+        sync_commands_provider: &storage,
+    }
+    .execute_with_undo(SyncProjectTasksParameters {
+        workspace_id: WorkspaceId::new(workspace_id)?,
+        include,
+    })?;
+
+    if !sync.is_empty() {
+        remember_undo(state, undo_record);
+    }
+
+    Ok(sync.created.len())
+}
+
+/// Exports to or imports from the bundle file named in the bundle form and
+/// describes the outcome.
+pub fn transfer_bundle(state: &State, services: &ServiceFactory) -> anyhow::Result<String> {
+    let action = state.form.inputs[0].trim();
+    let path = state.form.inputs[1].trim();
+
+    match action {
+        BUNDLE_ACTION_EXPORT => {
+            let scope = match state.form.inputs[2].trim() {
+                BUNDLE_SCOPE_LIBRARY => BundleScope::Library,
+                BUNDLE_SCOPE_WORKSPACE => {
+                    let workspace_id = state
+                        .workspace_id
+                        .ok_or_else(|| anyhow::anyhow!("No workspace selected"))?;
+
+                    BundleScope::Workspaces(vec![WorkspaceId::new(workspace_id)?])
+                }
+                scope => return Err(anyhow::anyhow!("Unknown bundle scope: {}", scope)),
+            };
+
+            let storage = services.storage();
+
+            let bundle = ExportBundleOperation {
+                list_commands_provider: &storage,
+                list_workspaces_provider: &storage,
+                write_bundle_provider: &services.system(),
+            }
+            .execute(ExportBundleParameters { path, scope })?;
+
+            Ok(format!(
+                "Exported {} workspaces and {} commands",
+                bundle.workspaces().len(),
+                bundle.commands().len()
+            ))
+        }
+        BUNDLE_ACTION_IMPORT => {
+            let strategy = match state.form.inputs[3].trim() {
+                BUNDLE_STRATEGY_DUPLICATE => BundleConflictStrategy::Duplicate,
+                BUNDLE_STRATEGY_KEEP_LOCAL => BundleConflictStrategy::KeepLocal,
+                BUNDLE_STRATEGY_OVERWRITE => BundleConflictStrategy::Overwrite,
+                strategy => return Err(anyhow::anyhow!("Unknown conflict strategy: {}", strategy)),
+            };
+
+            let storage = services.storage();
+
+            let import = ImportBundleOperation {
+                create_command_provider: &storage,
+                create_workspace_provider: &storage,
+                find_command_provider: &storage,
+                find_workspace_provider: &storage,
+                read_bundle_provider: &services.system(),
+                trash_provider: &storage,
+                upsert_commands_provider: &storage,
+                upsert_workspaces_provider: &storage,
+            }
+            .execute(ImportBundleParameters { path, strategy })?;
+
+            Ok(format!(
+                "Imported {} workspaces and {} commands, kept {} local workspaces and {} local commands",
+                import.imported_workspaces,
+                import.imported_commands,
+                import.kept_workspaces,
+                import.kept_commands
+            ))
+        }
+        action => Err(anyhow::anyhow!("Unknown bundle action: {}", action)),
+    }
+}
+
+/// Writes the cheat sheet of the selected workspace to the path from the
+/// cheat sheet form and returns that path.
+pub fn export_cheat_sheet(state: &State, services: &ServiceFactory) -> anyhow::Result<String> {
+    let Some(workspace_id) = state.workspace_id else {
+        return Err(anyhow::anyhow!("No workspace selected"));
+    };
+
+    let path = state.form.inputs[0].trim();
+    let storage = services.storage();
+
+    ExportCheatSheetOperation {
+        list_commands_provider: &storage,
+        list_workspaces_provider: &storage,
+        write_file_provider: &services.system(),
+    }
+    .execute(ExportCheatSheetParameters {
+        workspace_id: WorkspaceId::new(workspace_id)?,
+        path: Some(path),
+    })?;
+
+    Ok(path.to_string())
+}
+
+/// Scans the directory from the discovery form for repositories and project
+/// roots not yet known as workspaces.
+pub fn discover_workspaces(
+    state: &State,
+    services: &ServiceFactory,
+) -> anyhow::Result<Vec<DiscoveredWorkspace>> {
+    let parameters = DiscoverWorkspacesOperation {
+        list_directory_provider: &services.system(),
+        list_workspaces_provider: &services.storage(),
+    }
+    .execute(DiscoverWorkspacesParameters {
+        root: state.form.inputs[0].trim(),
+        max_depth: None,
+    })?;
+
+    Ok(parameters
+        .into_iter()
+        .map(|parameters| DiscoveredWorkspace {
+            name: parameters.name,
+            location: parameters.location.unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Creates the confirmed batch under the current workspace level, the whole
+/// batch is undone at once.
+pub fn create_discovered_workspaces(
+    state: &mut State,
+    services: &ServiceFactory,
+) -> anyhow::Result<usize> {
+    let storage = services.storage();
+    let parent_id = state
+        .workspace_level
+        .as_ref()
+        .map(|level| WorkspaceId::new(level.id))
+        .transpose()?;
+
+    let mut created = Vec::new();
+
+    let result = std::mem::take(&mut state.discovered_workspaces)
+        .into_iter()
+        .try_for_each(|DiscoveredWorkspace { name, location }| {
+            let workspace = CreateWorkspaceOperation {
+                storage_provider: &storage,
+            }
+            .execute(CreateWorkspaceParameters {
+                name,
+                location: Some(location),
+                parent_id,
+                shell: None,
+                tags: vec![],
+            })?;
+
+            created.push(workspace.id());
+
+            Ok::<(), anyhow::Error>(())
+        });
+
+    let count = created.len();
+
+    // Workspaces created before a failure can still be undone.
+    if count > 0 {
+        remember_undo(
+            state,
+            UndoRecord::WorkspacesImported {
+                created,
+                previous: vec![],
+            },
+        );
+    }
+
+    result.map(|()| count)
+}
+
+/// Drills the `Workspaces` context down into the selected workspace.
+pub fn enter_workspace_level(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Some(workspace) = get_workspace(state, services)? else {
+        return Ok(());
+    };
+
+    let tree = WorkspaceTree::load(services)?;
+
+    state.workspace_level = Some(WorkspaceLevel {
+        id: workspace.id().as_uuid(),
+        path: tree.path(&workspace),
+    });
+
+    Ok(())
+}
+
+/// Moves the `Workspaces` context one level up the hierarchy.
+pub fn leave_workspace_level(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Some(level) = state.workspace_level.take() else {
+        return Ok(());
+    };
+
+    let tree = WorkspaceTree::load(services)?;
+
+    state.workspace_level = tree
+        .get(WorkspaceId::new(level.id)?)
+        .and_then(Workspace::parent_id)
+        .and_then(|parent_id| tree.get(parent_id))
+        .map(|parent| WorkspaceLevel {
+            id: parent.id().as_uuid(),
+            path: tree.path(parent),
+        });
+
+    Ok(())
+}
+
+pub fn workspace_parent_path(
+    workspace: &Workspace,
+    services: &ServiceFactory,
+) -> anyhow::Result<String> {
+    let tree = WorkspaceTree::load(services)?;
+
+    let path = workspace
+        .parent_id()
+        .and_then(|parent_id| tree.get(parent_id))
+        .map(|parent| tree.path(parent))
+        .unwrap_or_default();
+
+    Ok(path)
+}
+
+pub fn transfer_command(
+    state: &mut State,
+    services: &ServiceFactory,
+    transfer: CommandTransfer,
+    keep_history: bool,
+) -> anyhow::Result<()> {
+    let Some(command_id) = state.command_id else {
+        return Ok(());
+    };
+
+    let Some(target) = state.list.items.get(state.list.cursor) else {
+        return Ok(());
+    };
+
+    let id = CommandId::new(command_id)?;
+    let workspace_id = if target.id.is_nil() {
+        None
+    } else {
+        Some(WorkspaceId::new(target.id)?)
+    };
+
+    let storage = services.storage();
+
+    match transfer {
+        CommandTransfer::Copy => {
+            CopyCommandOperation {
+                copy_command_provider: &storage,
+                find_command_provider: &storage,
+                find_workspace_provider: &storage,
+            }
+            .execute(CopyCommandParameters {
+                id,
+                keep_history,
+                workspace_id,
+            })?;
+        }
+        CommandTransfer::Move => {
+            let (_command, undo_record) = MoveCommandOperation {
+                find_command_provider: &storage,
+                find_workspace_provider: &storage,
+                move_command_provider: &storage,
+            }
+            .execute_with_undo(MoveCommandParameters {
+                id,
+                keep_history,
+                workspace_id,
+            })?;
+
+            remember_undo(state, undo_record);
+        }
+    }
+
+    Ok(())
+}
+
+/// Keeps imported project tasks of the current workspace in line with
+/// their task files, describes the changes if there were any. The sync runs
+/// on every visit, so it stays out of the undo stack.
+pub fn sync_project_tasks(
+    state: &State,
+    services: &ServiceFactory,
+) -> anyhow::Result<Option<String>> {
+    let Some(workspace_id) = state.workspace_id else {
+        return Ok(None);
+    };
+
+    let storage = services.storage();
+
+    let sync = SyncProjectTasksOperation {
+        find_workspace_provider: &storage,
+        list_commands_provider: &storage,
+        list_project_tasks_provider: &services.system(),
+        sync_commands_provider: &storage,
+    }
+    .execute(SyncProjectTasksParameters {
+        workspace_id: WorkspaceId::new(workspace_id)?,
+        include: vec![],
+    })?;
+
+    if sync.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(format!(
+        "Project tasks synced: {} updated, {} removed",
+        sync.updated.len(),
+        sync.removed.len()
+    )))
+}
+
+/// Refreshes the repository-managed commands of the selected workspace
+/// from its repository command file. Like the project tasks sync it runs on
+/// every visit and stays out of the undo stack.
+pub fn sync_repository_commands(
+    state: &State,
+    services: &ServiceFactory,
+) -> anyhow::Result<Option<String>> {
+    let Some(workspace_id) = state.workspace_id else {
+        return Ok(None);
+    };
+
+    let storage = services.storage();
+
+    let sync = SyncRepositoryCommandsOperation {
+        find_workspace_provider: &storage,
+        list_commands_provider: &storage,
+        list_repository_commands_provider: &services.system(),
+        sync_commands_provider: &storage,
+    }
+    .execute(WorkspaceId::new(workspace_id)?)?;
+
+    if sync.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(format!(
+        "Repository commands synced: {} created, {} updated, {} removed",
+        sync.created.len(),
+        sync.updated.len(),
+        sync.removed.len()
+    )))
+}
+
+pub fn toggle_command_pin(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Some(command) = get_command(state, services)? else {
+        return Ok(());
+    };
+
+    let storage = services.storage();
+
+    if command.is_pinned() {
+        UnpinCommandOperation {
+            find_command_provider: &storage,
+            update_command_provider: &storage,
+        }
+        .execute(command.id())?;
+    } else {
+        PinCommandOperation {
+            find_command_provider: &storage,
+            update_command_provider: &storage,
+        }
+        .execute(command.id())?;
+    }
+
+    Ok(())
+}
+
+pub fn toggle_workspace_pin(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Some(workspace) = get_workspace(state, services)? else {
+        return Ok(());
+    };
+
+    let storage = services.storage();
+
+    if workspace.is_pinned() {
+        UnpinWorkspaceOperation {
+            find_workspace_provider: &storage,
+            update_workspace_provider: &storage,
+        }
+        .execute(workspace.id())?;
+    } else {
+        PinWorkspaceOperation {
+            find_workspace_provider: &storage,
+            update_workspace_provider: &storage,
+        }
+        .execute(workspace.id())?;
+    }
+
+    Ok(())
 }
 
 pub fn open_terminal(state: &State, services: &ServiceFactory) -> anyhow::Result<()> {
@@ -355,32 +1149,123 @@ pub fn open_terminal(state: &State, services: &ServiceFactory) -> anyhow::Result
     Ok(())
 }
 
-pub fn restore_commands(services: &ServiceFactory) -> anyhow::Result<()> {
+/// Permanently deletes trash items deleted longer than `retention` ago.
+pub fn purge_expired_trash(services: &ServiceFactory, retention: Duration) -> anyhow::Result<()> {
     let storage = services.storage();
 
-    ImportCommandsOperation::new(ImportCommandsOperationParameters {
+    PurgeTrashOperation {
+        find_trash_item_provider: &storage,
+        purge_trash_provider: &storage,
+    }
+    .execute(PurgeTrashScope::OlderThan(retention))?;
+
+    Ok(())
+}
+
+pub fn purge_trash_item(state: &State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Some(id) = selected_trash_item_id(state, services)? else {
+        return Ok(());
+    };
+
+    let storage = services.storage();
+
+    PurgeTrashOperation {
+        find_trash_item_provider: &storage,
+        purge_trash_provider: &storage,
+    }
+    .execute(PurgeTrashScope::Item(id))?;
+
+    Ok(())
+}
+
+pub fn restore_commands(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let storage = services.storage();
+
+    let undo_record = ImportCommandsOperation::new(ImportCommandsOperationParameters {
         backup_credentials_provider: &storage,
         upsert_commands_provider: &storage,
         backup_provider_builder: &NotionBackupBuilder::default(),
     })
-    .execute(BackupProviderKind::Notion)?;
+    .execute_with_undo(BackupProviderKind::Notion)?;
+
+    remember_undo(state, undo_record);
 
     Ok(())
 }
 
-pub fn restore_workspaces(services: &ServiceFactory) -> anyhow::Result<()> {
+pub fn restore_workspaces(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
     let storage = services.storage();
 
-    ImportWorkspacesOperation::new(ImportWorkspacesOperationParameters {
+    let undo_record = ImportWorkspacesOperation::new(ImportWorkspacesOperationParameters {
         backup_credentials_provider: &storage,
         upsert_workspaces_provider: &storage,
         backup_provider_builder: &NotionBackupBuilder::default(),
     })
-    .execute(BackupProviderKind::Notion)?;
+    .execute_with_undo(BackupProviderKind::Notion)?;
+
+    remember_undo(state, undo_record);
 
     Ok(())
 }
 
+pub fn restore_command_revision(
+    state: &mut State,
+    services: &ServiceFactory,
+) -> anyhow::Result<()> {
+    let Some(item) = state.list.items.get(state.list.cursor) else {
+        return Ok(());
+    };
+
+    let storage = services.storage();
+
+    let (_command, undo_record) = RestoreCommandRevisionOperation {
+        create_revision_provider: &storage,
+        find_command_provider: &storage,
+        find_revision_provider: &storage,
+        update_command_provider: &storage,
+    }
+    .execute_with_undo(CommandRevisionId::new(item.id)?)?;
+
+    remember_undo(state, undo_record);
+
+    Ok(())
+}
+
+pub fn restore_trash_item(state: &State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Some(id) = selected_trash_item_id(state, services)? else {
+        return Ok(());
+    };
+
+    let storage = services.storage();
+
+    RestoreFromTrashOperation {
+        find_trash_item_provider: &storage,
+        restore_from_trash_provider: &storage,
+    }
+    .execute(id)?;
+
+    Ok(())
+}
+
+/// Reverts the most recent remembered mutation, returns `false` when there
+/// is nothing to undo.
+pub fn undo(state: &mut State, services: &ServiceFactory) -> anyhow::Result<bool> {
+    let Some(undo_record) = state.undo_stack.pop() else {
+        return Ok(false);
+    };
+
+    let storage = services.storage();
+
+    ApplyUndoOperation {
+        commands_provider: &storage,
+        trash_provider: &storage,
+        workspaces_provider: &storage,
+    }
+    .execute(undo_record)?;
+
+    Ok(true)
+}
+
 pub fn run_command(
     state: &mut State,
     services: &ServiceFactory,
@@ -390,40 +1275,367 @@ pub fn run_command(
         return Ok(());
     };
 
-    let RunCommandOptions { no_exit } = options;
+    let RunCommandOptions {
+        no_exit,
+        placeholder_values,
+    } = options;
 
+    let secrets = services.secrets();
     let storage = services.storage();
     let mut system = services.system();
     system.set_no_exit(no_exit);
 
     ExecuteCommandOperation {
         find_command_provider: &storage,
+        find_secret_provider: &secrets,
         find_workspace_provider: &storage,
+        list_environment_variables_provider: &storage,
         system_provider: &system,
         track_command_provider: &storage,
         track_workspace_provider: &storage,
     }
-    .execute(CommandId::new(command_id)?)?;
+    .execute(ExecuteCommandParameters {
+        command_id: CommandId::new(command_id)?,
+        placeholder_values,
+        workspace_id: state.workspace_id.map(WorkspaceId::new).transpose()?,
+    })?;
 
     Ok(())
 }
 
-impl From<Workspace> for ListItem {
-    fn from(value: Workspace) -> Self {
+impl From<Command> for ListItem {
+    fn from(value: Command) -> Self {
         ListItem {
             id: value.id().as_uuid(),
-            text: value.name().to_string(),
+            text: with_pin(
+                value.is_pinned(),
+                with_global(value.is_global(), with_tags(value.program(), value.tags())),
+            ),
         }
     }
 }
 
-impl From<Command> for ListItem {
-    fn from(value: Command) -> Self {
+impl From<CommandExecution> for ListItem {
+    fn from(value: CommandExecution) -> Self {
+        let mut text = format!(
+            "{} {}",
+            value.start_time().format(COMMAND_EXECUTION_TIME_FORMAT),
+            value.program()
+        );
+
+        if let Some(location) = value.location() {
+            text = format!("{} @ {}", text, location);
+        }
+
+        ListItem {
+            id: value
+                .command_id()
+                .map(|id| id.as_uuid())
+                .unwrap_or_default(),
+            text,
+        }
+    }
+}
+
+impl From<CommandRevision> for ListItem {
+    fn from(value: CommandRevision) -> Self {
         ListItem {
             id: value.id().as_uuid(),
-            text: value.program().to_string(),
+            text: format!(
+                "{} {}: {}",
+                value.created_at().format(COMMAND_REVISION_TIME_FORMAT),
+                value.name(),
+                value.program()
+            ),
+        }
+    }
+}
+
+impl From<TrashItem> for ListItem {
+    fn from(value: TrashItem) -> Self {
+        let entry = match value.entry() {
+            TrashEntry::Command(command) => format!("command {}", command.program()),
+            TrashEntry::Workspace(workspace) => format!("workspace {}", workspace.name()),
+        };
+
+        ListItem {
+            id: match value.id() {
+                TrashItemId::Command(id) => id.as_uuid(),
+                TrashItemId::Workspace(id) => id.as_uuid(),
+            },
+            text: format!(
+                "{} {}",
+                value.deleted_at().format(COMMAND_EXECUTION_TIME_FORMAT),
+                entry
+            ),
+        }
+    }
+}
+
+impl WorkspaceTree {
+    fn load(services: &ServiceFactory) -> anyhow::Result<Self> {
+        let workspaces = ListWorkspacesOperation {
+            provider: &services.storage(),
+        }
+        .execute(ListWorkspacesParameters {
+            created_after: None,
+            name_contains: None,
+            page_number: None,
+            page_size: Some(NonZeroU32::MAX),
+            scope: WorkspaceScope::All,
+            sort: SortOrder::Recency,
+            tags: None,
+            updated_after: None,
+        })?;
+
+        Ok(Self {
+            workspaces: workspaces
+                .into_iter()
+                .map(|workspace| (workspace.id(), workspace))
+                .collect(),
+        })
+    }
+
+    /// Resolves a `parent/child` path of the workspace form, an empty path
+    /// stands for no parent.
+    fn find_by_path(&self, path: &str) -> anyhow::Result<Option<WorkspaceId>> {
+        let path = path.trim().trim_matches(PATH_SEPARATOR);
+
+        if path.is_empty() {
+            return Ok(None);
         }
+
+        self.workspaces
+            .values()
+            .find(|workspace| self.path(workspace) == path)
+            .map(|workspace| Some(workspace.id()))
+            .ok_or_else(|| anyhow::anyhow!("Unknown parent workspace: {}", path))
+    }
+
+    fn get(&self, id: WorkspaceId) -> Option<&Workspace> {
+        self.workspaces.get(&id)
+    }
+
+    fn has_children(&self, id: WorkspaceId) -> bool {
+        self.workspaces
+            .values()
+            .any(|workspace| workspace.parent_id() == Some(id))
+    }
+
+    fn path(&self, workspace: &Workspace) -> String {
+        let mut names = vec![workspace.name()];
+        let mut parent_id = workspace.parent_id();
+
+        while let Some(parent) = parent_id.and_then(|id| self.get(id)) {
+            if names.len() > self.workspaces.len() {
+                break;
+            }
+
+            names.push(parent.name());
+            parent_id = parent.parent_id();
+        }
+
+        names.reverse();
+        names.join(&PATH_SEPARATOR.to_string())
+    }
+}
+
+/// Parses `NAME=value` lines of the workspace form.
+/// Renders changes the way `git diff --word-diff` does: removed words as
+/// `[-...-]`, added ones as `{+...+}`.
+fn format_command_revision_diff(diff: &CommandRevisionDiff) -> String {
+    if diff.is_empty() {
+        return "No changes since this revision".to_string();
+    }
+
+    let format_changes = |changes: &[DiffChange]| {
+        changes
+            .iter()
+            .map(|change| match change {
+                DiffChange::Added(text) => format!("{{+{}+}}", text),
+                DiffChange::Removed(text) => format!("[-{}-]", text),
+                DiffChange::Unchanged(text) => text.clone(),
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+
+    format!(
+        "Name: {}; Program: {}",
+        format_changes(diff.name()),
+        format_changes(diff.program())
+    )
+}
+
+fn parse_environment_variables(input: &str) -> anyhow::Result<Vec<EnvironmentVariableParameters>> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (name, value) =
+                line.split_once(ENVIRONMENT_VARIABLE_SEPARATOR)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Expected NAME=value environment variable, got: {}", line)
+                    })?;
+
+            Ok(EnvironmentVariableParameters {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn parse_shell(input: &str) -> anyhow::Result<Option<Shell>> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(Shell::parse_str(input)?))
+}
+
+/// Splits list filter into a text part and `#tag` tokens.
+///
+/// `#git #deploy` matches items tagged with both tags, `#git|deploy`
+/// matches items tagged with any of them.
+fn parse_list_filter(filter: &str) -> (String, Option<TagsFilter>) {
+    let (tags, words): (Vec<&str>, Vec<&str>) = filter
+        .split_whitespace()
+        .partition(|word| word.starts_with(TAG_PREFIX));
+
+    let tags: Vec<&str> = tags
+        .into_iter()
+        .map(|tag| tag.trim_start_matches(TAG_PREFIX))
+        .filter(|tag| !tag.is_empty())
+        .collect();
+
+    let tags = match tags.as_slice() {
+        [] => None,
+        [tag] if tag.contains(ANY_TAG_SEPARATOR) => Some(TagsFilter::AnyOf(
+            tag.split(ANY_TAG_SEPARATOR)
+                .map(ToString::to_string)
+                .collect(),
+        )),
+        tags => Some(TagsFilter::AllOf(
+            tags.iter().map(ToString::to_string).collect(),
+        )),
+    };
+
+    (words.join(" "), tags)
+}
+
+/// Resolves the kind of the trash item under the cursor, since list items
+/// only carry the raw identifier.
+fn selected_trash_item_id(
+    state: &State,
+    services: &ServiceFactory,
+) -> anyhow::Result<Option<TrashItemId>> {
+    let Some(item) = state.list.items.get(state.list.cursor) else {
+        return Ok(None);
+    };
+
+    let trash = ListTrashOperation {
+        provider: &services.storage(),
+    }
+    .execute(ListTrashParameters {
+        page_number: None,
+        page_size: None,
+    })?;
+
+    let id = trash
+        .into_iter()
+        .map(|trash_item| trash_item.id())
+        .find(|id| match id {
+            TrashItemId::Command(id) => id.as_uuid() == item.id,
+            TrashItemId::Workspace(id) => id.as_uuid() == item.id,
+        });
+
+    Ok(id)
+}
+
+/// Workspaces with nested ones are suffixed with a path separator, full
+/// paths are shown when the whole tree is listed.
+fn list_workspace_items(
+    state: &State,
+    services: &ServiceFactory,
+    scope: WorkspaceScope,
+) -> anyhow::Result<Vec<ListItem>> {
+    let (name_contains, tags) = parse_list_filter(&state.list.filter);
+
+    let workspaces = ListWorkspacesOperation {
+        provider: &services.storage(),
+    }
+    .execute(ListWorkspacesParameters {
+        created_after: None,
+        name_contains: Some(&name_contains),
+        page_number: None,
+        page_size: None,
+        scope,
+        sort: SortOrder::Frecency,
+        tags,
+        updated_after: None,
+    })?;
+
+    let tree = WorkspaceTree::load(services)?;
+
+    Ok(workspaces
+        .into_iter()
+        .map(|workspace| {
+            let mut name = match scope {
+                WorkspaceScope::All => tree.path(&workspace),
+                WorkspaceScope::Roots | WorkspaceScope::Children(_) => workspace.name().to_string(),
+            };
+
+            if tree.has_children(workspace.id()) {
+                name.push(PATH_SEPARATOR);
+            }
+
+            ListItem {
+                id: workspace.id().as_uuid(),
+                text: with_pin(workspace.is_pinned(), with_tags(&name, workspace.tags())),
+            }
+        })
+        .collect())
+}
+
+fn remember_undo(state: &mut State, undo_record: UndoRecord) {
+    if state.undo_stack.len() == UNDO_STACK_LIMIT {
+        state.undo_stack.remove(0);
     }
+
+    state.undo_stack.push(undo_record);
+}
+
+fn with_global(global: bool, text: String) -> String {
+    if !global {
+        return text;
+    }
+
+    format!("{} {}", text, GLOBAL_MARKER)
+}
+
+fn with_pin(pinned: bool, text: String) -> String {
+    if !pinned {
+        return text;
+    }
+
+    format!("{} {}", PIN_MARKER, text)
+}
+
+fn with_tags(text: &str, tags: &[String]) -> String {
+    if tags.is_empty() {
+        return text.to_string();
+    }
+
+    let tags: Vec<String> = tags
+        .iter()
+        .map(|tag| format!("{}{}", TAG_PREFIX, tag))
+        .collect();
+
+    format!("{} {}", text, tags.join(" "))
 }
 
 fn list_backup_credentials(services: &ServiceFactory) -> anyhow::Result<Vec<BackupCredentials>> {
@@ -1,17 +1,28 @@
 mod integration;
 
-use hermione_nexus::definitions::BackupCredentials;
+use hermione_nexus::definitions::{
+    BackupCredentials, LOCATION_FIELD, NAME_FIELD, PROGRAM_FIELD, REPOSITORY_COMMANDS_FILE,
+};
 use integration::RunCommandOptions;
+use std::{collections::HashMap, time::Duration};
+use uuid::Uuid;
 
 use crate::{
     keyboard,
-    program_lib::{Context, Form, List, Mode, Notice, NoticeKind, Render, State},
+    program_lib::{
+        CandidateOrigin, CommandCandidate, CommandTransfer, Context, DiscoveredWorkspace, Form,
+        List, ListItem, Mode, Notice, NoticeKind, Render, State,
+    },
     terminal,
 };
 use hermione_drive::{Engine, ServiceFactory};
 
-pub fn run() -> anyhow::Result<()> {
-    if let Err(err) = do_run() {
+const CHEAT_SHEET_FILE_NAME: &str = "COMMANDS.md";
+const DEFAULT_BUNDLE_PATH: &str = "~/hermione-bundle.json";
+const DEFAULT_DISCOVERY_ROOT: &str = "~/src";
+
+pub fn run(trash_retention: Duration) -> anyhow::Result<()> {
+    if let Err(err) = do_run(trash_retention) {
         tracing::error!(error = ?err);
 
         return Err(err);
@@ -33,7 +44,7 @@ where
     }
 }
 
-fn do_run() -> anyhow::Result<()> {
+fn do_run(trash_retention: Duration) -> anyhow::Result<()> {
     terminal::install_panic_hook();
 
     let Engine {
@@ -41,6 +52,8 @@ fn do_run() -> anyhow::Result<()> {
         logs_worker_guard: _logs_worker_guard,
     } = hermione_drive::start()?;
 
+    integration::purge_expired_trash(&service_factory, trash_retention)?;
+
     let mut terminal = terminal::init()?;
     let mut state = State::default();
 
@@ -72,10 +85,14 @@ enum InputUpdate {
 }
 
 fn setup_workspaces_context(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let items = integration::list_workspaces(state, services)?;
+
     *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
         context: Context::Workspaces,
         list: List {
-            items: integration::list_workspaces(state, services)?,
+            items,
             ..Default::default()
         },
         ..Default::default()
@@ -90,6 +107,8 @@ fn setup_workspaces_context(state: &mut State, services: &ServiceFactory) -> any
 
 fn setup_commands_context(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
     *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
         workspace_id: state.workspace_id,
         context: Context::Commands,
         list: List {
@@ -106,16 +125,280 @@ fn setup_commands_context(state: &mut State, services: &ServiceFactory) -> anyho
     Ok(())
 }
 
+fn setup_command_executions_context(
+    state: &mut State,
+    services: &ServiceFactory,
+) -> anyhow::Result<()> {
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        workspace_id: state.workspace_id,
+        command_id: state.command_id,
+        context: Context::CommandExecutions,
+        list: List {
+            items: integration::list_command_executions(state, services)?,
+            ..Default::default()
+        },
+        ..State::default()
+    };
+
+    Ok(())
+}
+
+fn setup_command_revisions_context(
+    state: &mut State,
+    services: &ServiceFactory,
+) -> anyhow::Result<()> {
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        workspace_id: state.workspace_id,
+        command_id: state.command_id,
+        context: Context::CommandRevisions,
+        list: List {
+            items: integration::list_command_revisions(state, services)?,
+            ..Default::default()
+        },
+        ..State::default()
+    };
+
+    Ok(())
+}
+
+/// Lists proposed commands by index, the selection is kept on the
+/// candidates until the import is confirmed.
+fn setup_command_candidates_context(
+    state: &mut State,
+    command_candidates: Vec<CommandCandidate>,
+    origin: CandidateOrigin,
+) {
+    let items = command_candidates
+        .iter()
+        .enumerate()
+        .map(|(index, candidate)| ListItem {
+            id: Uuid::from_u128(index as u128),
+            text: format!(
+                "[{}] {} ({})",
+                if candidate.selected { 'x' } else { ' ' },
+                candidate.name,
+                candidate.detail
+            ),
+        })
+        .collect();
+
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        workspace_id: state.workspace_id,
+        context: Context::CommandCandidates { origin },
+        command_candidates,
+        list: List {
+            items,
+            cursor: state.list.cursor,
+            ..Default::default()
+        },
+        ..State::default()
+    };
+}
+
+/// Lists proposed workspaces by index, the proposals themselves are kept
+/// in the state until the batch is confirmed.
+fn setup_discovered_workspaces_context(
+    state: &mut State,
+    discovered_workspaces: Vec<DiscoveredWorkspace>,
+) {
+    let items = discovered_workspaces
+        .iter()
+        .enumerate()
+        .map(|(index, workspace)| ListItem {
+            id: Uuid::from_u128(index as u128),
+            text: format!("{} @ {}", workspace.name, workspace.location),
+        })
+        .collect();
+
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        context: Context::DiscoveredWorkspaces,
+        discovered_workspaces,
+        list: List {
+            items,
+            ..Default::default()
+        },
+        ..State::default()
+    };
+}
+
+fn setup_workspace_picker_context(
+    state: &mut State,
+    services: &ServiceFactory,
+    transfer: CommandTransfer,
+    keep_history: bool,
+) -> anyhow::Result<()> {
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        workspace_id: state.workspace_id,
+        command_id: state.command_id,
+        context: Context::WorkspacePicker {
+            transfer,
+            keep_history,
+        },
+        list: List {
+            items: integration::list_transfer_targets(state, services)?,
+            ..Default::default()
+        },
+        ..State::default()
+    };
+
+    Ok(())
+}
+
+fn setup_trash_context(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        context: Context::Trash,
+        list: List {
+            items: integration::list_trash(services)?,
+            ..Default::default()
+        },
+        ..State::default()
+    };
+
+    Ok(())
+}
+
 fn maybe_submit_form(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
     match state.context {
-        Context::Workspaces => {}
-        Context::WorkspaceForm { .. } => {
-            integration::save_workspace(state, services)?;
+        Context::Workspaces => {
+            integration::enter_workspace_level(state, services)?;
+            state.list.filter = String::new();
             setup_workspaces_context(state, services)?;
         }
-        Context::Commands { .. } => {}
-        Context::CommandForm => {
-            integration::save_command(state, services)?;
+        Context::WorkspaceForm { .. } => match integration::save_workspace(state, services) {
+            Ok(()) => setup_workspaces_context(state, services)?,
+            Err(err) => show_form_error(state, err),
+        },
+        Context::WorkspaceCloneForm => match integration::clone_workspace(state, services) {
+            Ok(()) => setup_workspaces_context(state, services)?,
+            Err(err) => show_form_error(state, err),
+        },
+        Context::Commands { .. } | Context::CommandExecutions | Context::Trash => {}
+        Context::BundleForm => match integration::transfer_bundle(state, services) {
+            Ok(message) => {
+                setup_workspaces_context(state, services)?;
+                state.notice = Some(Notice {
+                    message,
+                    kind: NoticeKind::Success,
+                });
+            }
+            Err(err) => show_form_error(state, err),
+        },
+        Context::CheatSheetForm => match integration::export_cheat_sheet(state, services) {
+            Ok(path) => {
+                setup_workspaces_context(state, services)?;
+                state.notice = Some(Notice {
+                    message: format!("Cheat sheet written to {}", path),
+                    kind: NoticeKind::Success,
+                });
+            }
+            Err(err) => show_form_error(state, err),
+        },
+        Context::DiscoverWorkspacesForm => {
+            match integration::discover_workspaces(state, services) {
+                Ok(discovered_workspaces) => {
+                    setup_discovered_workspaces_context(state, discovered_workspaces)
+                }
+                Err(err) => show_form_error(state, err),
+            }
+        }
+        Context::DiscoveredWorkspaces => {
+            match integration::create_discovered_workspaces(state, services) {
+                Ok(count) => {
+                    setup_workspaces_context(state, services)?;
+                    state.notice = Some(Notice {
+                        message: format!("{} workspaces created", count),
+                        kind: NoticeKind::Success,
+                    });
+                }
+                Err(err) => {
+                    state.notice = Some(Notice {
+                        message: err.to_string(),
+                        kind: NoticeKind::Error,
+                    });
+                }
+            }
+        }
+        Context::CommandCandidates { origin } => {
+            let result = match origin {
+                CandidateOrigin::ProjectTasks => integration::import_project_tasks(state, services),
+                CandidateOrigin::ShellHistory => {
+                    integration::create_command_candidates(state, services)
+                }
+            };
+
+            match result {
+                Ok(count) => {
+                    setup_commands_context(state, services)?;
+                    state.notice = Some(Notice {
+                        message: format!("{} commands created", count),
+                        kind: NoticeKind::Success,
+                    });
+                }
+                Err(err) => {
+                    state.notice = Some(Notice {
+                        message: err.to_string(),
+                        kind: NoticeKind::Error,
+                    });
+                }
+            }
+        }
+        Context::CommandRevisions => match integration::restore_command_revision(state, services) {
+            Ok(()) => {
+                setup_commands_context(state, services)?;
+                state.notice = Some(Notice {
+                    message: "Command revision restored".to_string(),
+                    kind: NoticeKind::Success,
+                });
+            }
+            Err(err) => {
+                state.notice = Some(Notice {
+                    message: err.to_string(),
+                    kind: NoticeKind::Error,
+                });
+            }
+        },
+        Context::CommandForm => match integration::save_command(state, services) {
+            Ok(()) => setup_commands_context(state, services)?,
+            Err(err) => show_form_error(state, err),
+        },
+        Context::CommandPlaceholdersForm { no_exit } => {
+            let placeholder_values = state
+                .form
+                .labels
+                .iter()
+                .cloned()
+                .zip(state.form.inputs.iter().cloned())
+                .collect();
+
+            integration::run_command(
+                state,
+                services,
+                RunCommandOptions {
+                    no_exit,
+                    placeholder_values,
+                },
+            )?;
+
+            setup_commands_context(state, services)?;
+        }
+        Context::WorkspacePicker {
+            transfer,
+            keep_history,
+        } => {
+            integration::transfer_command(state, services, transfer, keep_history)?;
+            state.list.filter = String::new();
             setup_commands_context(state, services)?;
         }
         Context::NotionBackupCredentialsForm => {
@@ -139,6 +422,29 @@ fn maybe_submit_form(state: &mut State, services: &ServiceFactory) -> anyhow::Re
     Ok(())
 }
 
+/// Keeps the form open and focuses the input that failed validation.
+fn show_form_error(state: &mut State, err: anyhow::Error) {
+    let field = err
+        .downcast_ref::<hermione_nexus::Error>()
+        .and_then(hermione_nexus::Error::field);
+
+    // Workspace, clone and command forms share the first two input positions.
+    let input = match field {
+        Some(NAME_FIELD) => Some(0),
+        Some(LOCATION_FIELD) | Some(PROGRAM_FIELD) => Some(1),
+        _ => None,
+    };
+
+    if let Some(input) = input {
+        state.form.cursor = input;
+    }
+
+    state.notice = Some(Notice {
+        message: err.to_string(),
+        kind: NoticeKind::Error,
+    });
+}
+
 fn exit(state: &State, event: keyboard::Event) -> bool {
     if matches!(state.mode, Mode::Input) {
         return false;
@@ -155,11 +461,22 @@ fn focus_next_input(state: &mut State) {
     match state.context {
         Context::Workspaces => {}
         Context::WorkspaceForm { .. }
+        | Context::WorkspaceCloneForm
         | Context::CommandForm { .. }
+        | Context::CommandPlaceholdersForm { .. }
+        | Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
         | Context::NotionBackupCredentialsForm => {
             state.form.cursor = (state.form.cursor + 1) % state.form.inputs.len();
         }
-        Context::Commands { .. } => {}
+        Context::Commands { .. }
+        | Context::CommandExecutions
+        | Context::CommandRevisions
+        | Context::CommandCandidates { .. }
+        | Context::DiscoveredWorkspaces
+        | Context::WorkspacePicker { .. }
+        | Context::Trash => {}
     }
 }
 
@@ -173,7 +490,36 @@ fn maybe_follow_selected_item(state: &mut State, services: &ServiceFactory) -> a
     }
 
     state.list.filter = String::new();
+
+    // Imported project tasks and repository commands follow their files on
+    // every visit.
+    let notices = [
+        integration::sync_project_tasks(state, services),
+        integration::sync_repository_commands(state, services),
+    ]
+    .into_iter()
+    .filter_map(|result| match result {
+        Ok(message) => message.map(|message| Notice {
+            message,
+            kind: NoticeKind::Success,
+        }),
+        Err(err) => Some(Notice {
+            message: err.to_string(),
+            kind: NoticeKind::Error,
+        }),
+    })
+    .collect::<Vec<Notice>>();
+
+    let notice = notices.into_iter().reduce(|left, right| Notice {
+        message: format!("{}\n{}", left.message, right.message),
+        kind: match (left.kind, right.kind) {
+            (NoticeKind::Success, NoticeKind::Success) => NoticeKind::Success,
+            _ => NoticeKind::Error,
+        },
+    });
+
     setup_commands_context(state, services)?;
+    state.notice = notice;
 
     Ok(())
 }
@@ -194,7 +540,12 @@ fn maybe_backup(state: &mut State, services: &ServiceFactory) -> anyhow::Result<
                 });
             }
         },
-        Context::WorkspaceForm { .. } => {}
+        Context::WorkspaceForm { .. } | Context::WorkspaceCloneForm => {}
+        Context::CommandCandidates { .. } => {}
+        Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
+        | Context::DiscoveredWorkspaces => {}
         Context::Commands { .. } => match integration::backup_command(state, services) {
             Ok(_) => {
                 state.notice = Some(Notice {
@@ -209,7 +560,12 @@ fn maybe_backup(state: &mut State, services: &ServiceFactory) -> anyhow::Result<
                 });
             }
         },
-        Context::CommandForm { .. } => {}
+        Context::CommandForm { .. }
+        | Context::CommandPlaceholdersForm { .. }
+        | Context::CommandExecutions
+        | Context::CommandRevisions
+        | Context::WorkspacePicker { .. }
+        | Context::Trash => {}
         Context::NotionBackupCredentialsForm => {
             match integration::backup_workspaces(services) {
                 Ok(_) => {
@@ -249,12 +605,39 @@ fn maybe_backup(state: &mut State, services: &ServiceFactory) -> anyhow::Result<
 }
 
 fn maybe_copy_item(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    if let Context::Workspaces = state.context {
+        return maybe_clone_workspace(state, services);
+    }
+
     integration::copy_command_to_clipboard(state, services)
 }
 
+fn maybe_clone_workspace(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Some(workspace) = integration::get_workspace(state, services)? else {
+        return Ok(());
+    };
+
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        workspace_id: Some(workspace.id().as_uuid()),
+        context: Context::WorkspaceCloneForm,
+        form: Form {
+            inputs: vec![
+                workspace.name().to_string(),
+                workspace.location().unwrap_or_default().to_string(),
+            ],
+            ..Default::default()
+        },
+        ..State::default()
+    };
+
+    Ok(())
+}
+
 fn maybe_restore(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
     if let Context::NotionBackupCredentialsForm = state.context {
-        match integration::restore_workspaces(services) {
+        match integration::restore_workspaces(state, services) {
             Ok(_) => {
                 state.notice = Some(Notice {
                     message: "Workspaces backed up".to_string(),
@@ -269,7 +652,7 @@ fn maybe_restore(state: &mut State, services: &ServiceFactory) -> anyhow::Result
             }
         };
 
-        match integration::restore_commands(services) {
+        match integration::restore_commands(state, services) {
             Ok(_) => {
                 state.notice = Some(Notice {
                     message: "Commands backed up".to_string(),
@@ -295,38 +678,85 @@ fn maybe_edit_item(state: &mut State, services: &ServiceFactory) -> anyhow::Resu
                 return Ok(());
             };
 
+            let environment_variables =
+                integration::list_workspace_environment_variables(&workspace, services)?;
+            let parent_path = integration::workspace_parent_path(&workspace, services)?;
+
             *state = State {
+                undo_stack: std::mem::take(&mut state.undo_stack),
+                workspace_level: state.workspace_level.take(),
                 workspace_id: Some(workspace.id().as_uuid()),
                 context: Context::WorkspaceForm,
                 form: Form {
                     inputs: vec![
                         workspace.name().to_string(),
                         workspace.location().unwrap_or_default().to_string(),
+                        workspace.tags().join(" "),
+                        workspace
+                            .shell()
+                            .map(|shell| shell.as_str().to_string())
+                            .unwrap_or_default(),
+                        integration::format_environment_variables(&environment_variables),
+                        parent_path,
                     ],
                     ..Default::default()
                 },
                 ..State::default()
             };
         }
-        Context::WorkspaceForm { .. } => {}
+        Context::WorkspaceForm { .. } | Context::WorkspaceCloneForm => {}
+        Context::CommandCandidates { .. } => {}
+        Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
+        | Context::DiscoveredWorkspaces => {}
         Context::Commands => {
             let Some(command) = integration::get_command(state, services)? else {
                 return Ok(());
             };
 
+            if command.is_repository_managed() {
+                state.notice = Some(Notice {
+                    message: format!(
+                        "{} is managed by the {} file of the workspace location",
+                        command.name(),
+                        REPOSITORY_COMMANDS_FILE
+                    ),
+                    kind: NoticeKind::Error,
+                });
+
+                return Ok(());
+            }
+
             *state = State {
-                workspace_id: Some(command.workspace_id().as_uuid()),
+                undo_stack: std::mem::take(&mut state.undo_stack),
+                workspace_level: state.workspace_level.take(),
+                workspace_id: state.workspace_id,
                 command_id: Some(command.id().as_uuid()),
                 context: Context::CommandForm,
                 form: Form {
-                    inputs: vec![command.name().to_string(), command.program().to_string()],
+                    inputs: vec![
+                        command.name().to_string(),
+                        command.program().to_string(),
+                        command.tags().join(" "),
+                        command
+                            .shell()
+                            .map(|shell| shell.as_str().to_string())
+                            .unwrap_or_default(),
+                        command.description().unwrap_or_default().to_string(),
+                    ],
                     ..Default::default()
                 },
                 ..State::default()
             };
         }
         Context::CommandForm { .. } => {}
+        Context::CommandPlaceholdersForm { .. } => {}
+        Context::CommandExecutions => {}
+        Context::CommandRevisions => {}
+        Context::Trash => {}
         Context::NotionBackupCredentialsForm => {}
+        Context::WorkspacePicker { .. } => {}
     }
 
     Ok(())
@@ -335,29 +765,52 @@ fn maybe_edit_item(state: &mut State, services: &ServiceFactory) -> anyhow::Resu
 fn maybe_new_item(state: &mut State) -> anyhow::Result<()> {
     match state.context {
         Context::Workspaces => {
+            let parent_path = state
+                .workspace_level
+                .as_ref()
+                .map(|level| level.path.clone())
+                .unwrap_or_default();
+
+            let mut inputs = vec![String::new(); 5];
+            inputs.push(parent_path);
+
             *state = State {
+                undo_stack: std::mem::take(&mut state.undo_stack),
+                workspace_level: state.workspace_level.take(),
                 context: Context::WorkspaceForm,
                 form: Form {
-                    inputs: vec![String::new(), String::new()],
+                    inputs,
                     ..Default::default()
                 },
                 ..Default::default()
             };
         }
-        Context::WorkspaceForm { .. } => {}
+        Context::WorkspaceForm { .. } | Context::WorkspaceCloneForm => {}
+        Context::CommandCandidates { .. } => {}
+        Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
+        | Context::DiscoveredWorkspaces => {}
         Context::Commands => {
             *state = State {
+                undo_stack: std::mem::take(&mut state.undo_stack),
+                workspace_level: state.workspace_level.take(),
                 context: Context::CommandForm,
                 workspace_id: state.workspace_id,
                 form: Form {
-                    inputs: vec![String::new(), String::new()],
+                    inputs: vec![String::new(); 5],
                     ..Default::default()
                 },
                 ..Default::default()
             };
         }
         Context::CommandForm { .. } => {}
+        Context::CommandPlaceholdersForm { .. } => {}
+        Context::CommandExecutions => {}
+        Context::CommandRevisions => {}
+        Context::Trash => {}
         Context::NotionBackupCredentialsForm => {}
+        Context::WorkspacePicker { .. } => {}
     };
 
     Ok(())
@@ -369,7 +822,18 @@ fn maybe_delete_list_item(state: &mut State, services: &ServiceFactory) -> anyho
             integration::delete_workspace(state, services)?;
             setup_workspaces_context(state, services)?;
         }
-        Context::WorkspaceForm { .. } => {}
+        Context::WorkspaceForm { .. } | Context::WorkspaceCloneForm => {}
+        Context::CommandCandidates { .. } => {}
+        Context::BundleForm => {}
+        Context::CheatSheetForm => {}
+        Context::DiscoverWorkspacesForm => {}
+        Context::DiscoveredWorkspaces => {
+            if state.list.cursor < state.discovered_workspaces.len() {
+                let mut discovered_workspaces = std::mem::take(&mut state.discovered_workspaces);
+                discovered_workspaces.remove(state.list.cursor);
+                setup_discovered_workspaces_context(state, discovered_workspaces);
+            }
+        }
         Context::Commands { .. } => {
             integration::delete_command(state, services)?;
 
@@ -377,7 +841,427 @@ fn maybe_delete_list_item(state: &mut State, services: &ServiceFactory) -> anyho
             state.list.items = integration::list_commands(state, services)?;
         }
         Context::CommandForm { .. } => {}
+        Context::CommandPlaceholdersForm { .. } => {}
+        Context::CommandExecutions => {}
+        Context::CommandRevisions => {}
         Context::NotionBackupCredentialsForm => {}
+        Context::WorkspacePicker { .. } => {}
+        Context::Trash => {
+            integration::purge_trash_item(state, services)?;
+            setup_trash_context(state, services)?;
+        }
+    };
+
+    Ok(())
+}
+
+/// Opens the bundle form, exporting the highlighted workspace by default
+/// and the whole library when none is highlighted.
+fn maybe_open_bundle_form(state: &mut State) {
+    let Context::Workspaces = state.context else {
+        return;
+    };
+
+    let scope = match state.workspace_id {
+        Some(_) => integration::BUNDLE_SCOPE_WORKSPACE,
+        None => integration::BUNDLE_SCOPE_LIBRARY,
+    };
+
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        workspace_id: state.workspace_id,
+        context: Context::BundleForm,
+        form: Form {
+            inputs: vec![
+                integration::BUNDLE_ACTION_EXPORT.to_string(),
+                DEFAULT_BUNDLE_PATH.to_string(),
+                scope.to_string(),
+                integration::BUNDLE_STRATEGY_KEEP_LOCAL.to_string(),
+            ],
+            labels: vec![
+                "Action (export, import)".to_string(),
+                "Path".to_string(),
+                "Scope (library, workspace)".to_string(),
+                "On conflict (keep, overwrite, duplicate)".to_string(),
+            ],
+            ..Default::default()
+        },
+        ..State::default()
+    };
+}
+
+/// Proposes the Markdown file next to the workspace sources, or in the
+/// home directory when the workspace has no location.
+fn maybe_open_cheat_sheet_form(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Context::Workspaces = state.context else {
+        return Ok(());
+    };
+
+    let Some(workspace) = integration::get_workspace(state, services)? else {
+        return Ok(());
+    };
+
+    let path = match workspace.location() {
+        Some(location) => format!(
+            "{}/{}",
+            location.trim_end_matches(['/', '\\']),
+            CHEAT_SHEET_FILE_NAME
+        ),
+        None => format!("~/{}.md", workspace.name()),
+    };
+
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        workspace_id: state.workspace_id,
+        context: Context::CheatSheetForm,
+        form: Form {
+            inputs: vec![path],
+            labels: vec!["Path".to_string()],
+            ..Default::default()
+        },
+        ..State::default()
+    };
+
+    Ok(())
+}
+
+fn maybe_discover_workspaces(state: &mut State) {
+    let Context::Workspaces = state.context else {
+        return;
+    };
+
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        context: Context::DiscoverWorkspacesForm,
+        form: Form {
+            inputs: vec![DEFAULT_DISCOVERY_ROOT.to_string()],
+            ..Default::default()
+        },
+        ..State::default()
+    };
+}
+
+fn maybe_import_history_commands(
+    state: &mut State,
+    services: &ServiceFactory,
+) -> anyhow::Result<()> {
+    let Context::Commands = state.context else {
+        return Ok(());
+    };
+
+    match integration::list_history_candidates(services) {
+        Ok(command_candidates) => {
+            state.list.cursor = 0;
+            setup_command_candidates_context(
+                state,
+                command_candidates,
+                CandidateOrigin::ShellHistory,
+            );
+        }
+        Err(err) => {
+            state.notice = Some(Notice {
+                message: err.to_string(),
+                kind: NoticeKind::Error,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn maybe_list_project_tasks(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Context::Commands = state.context else {
+        return Ok(());
+    };
+
+    if state.workspace_id.is_none() {
+        return Ok(());
+    }
+
+    match integration::list_project_task_candidates(state, services) {
+        Ok(command_candidates) => {
+            state.list.cursor = 0;
+            setup_command_candidates_context(
+                state,
+                command_candidates,
+                CandidateOrigin::ProjectTasks,
+            );
+        }
+        Err(err) => {
+            state.notice = Some(Notice {
+                message: err.to_string(),
+                kind: NoticeKind::Error,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn maybe_show_global_commands(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Context::Workspaces = state.context else {
+        return Ok(());
+    };
+
+    state.list.filter = String::new();
+    state.workspace_id = None;
+    setup_commands_context(state, services)?;
+
+    Ok(())
+}
+
+fn maybe_show_history(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    match state.context {
+        Context::Workspaces | Context::Commands => {
+            if state.list.items.is_empty() {
+                return Ok(());
+            }
+
+            state.list.filter = String::new();
+            setup_command_executions_context(state, services)?;
+        }
+        Context::WorkspaceForm
+        | Context::WorkspaceCloneForm
+        | Context::CommandForm
+        | Context::CommandPlaceholdersForm { .. }
+        | Context::CommandExecutions
+        | Context::CommandRevisions
+        | Context::CommandCandidates { .. }
+        | Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
+        | Context::DiscoveredWorkspaces
+        | Context::NotionBackupCredentialsForm
+        | Context::WorkspacePicker { .. }
+        | Context::Trash => {}
+    };
+
+    Ok(())
+}
+
+fn maybe_show_revisions(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Context::Commands = state.context else {
+        return Ok(());
+    };
+
+    if state.command_id.is_none() {
+        return Ok(());
+    }
+
+    state.list.filter = String::new();
+    setup_command_revisions_context(state, services)
+}
+
+fn maybe_show_revision_diff(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Context::CommandRevisions = state.context else {
+        return Ok(());
+    };
+
+    match integration::diff_command_revision(state, services) {
+        Ok(Some(message)) => {
+            state.notice = Some(Notice {
+                message,
+                kind: NoticeKind::Success,
+            });
+        }
+        Ok(None) => {}
+        Err(err) => {
+            state.notice = Some(Notice {
+                message: err.to_string(),
+                kind: NoticeKind::Error,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn maybe_show_trash(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Context::Workspaces = state.context else {
+        return Ok(());
+    };
+
+    setup_trash_context(state, services)
+}
+
+fn maybe_restore_trash_item(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let Context::Trash = state.context else {
+        return Ok(());
+    };
+
+    integration::restore_trash_item(state, services)?;
+    setup_trash_context(state, services)
+}
+
+fn maybe_undo(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    let undone = match integration::undo(state, services) {
+        Ok(undone) => undone,
+        Err(err) => {
+            state.notice = Some(Notice {
+                message: err.to_string(),
+                kind: NoticeKind::Error,
+            });
+
+            return Ok(());
+        }
+    };
+
+    match state.context {
+        Context::Workspaces => setup_workspaces_context(state, services)?,
+        Context::Commands => setup_commands_context(state, services)?,
+        Context::CommandRevisions => setup_command_revisions_context(state, services)?,
+        Context::Trash => setup_trash_context(state, services)?,
+        Context::WorkspaceForm
+        | Context::WorkspaceCloneForm
+        | Context::CommandForm
+        | Context::CommandPlaceholdersForm { .. }
+        | Context::CommandExecutions
+        | Context::CommandCandidates { .. }
+        | Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
+        | Context::DiscoveredWorkspaces
+        | Context::NotionBackupCredentialsForm
+        | Context::WorkspacePicker { .. } => {}
+    };
+
+    if !undone {
+        state.notice = Some(Notice {
+            message: "Nothing to undo".to_string(),
+            kind: NoticeKind::Error,
+        });
+    }
+
+    Ok(())
+}
+
+fn maybe_pick_workspace(
+    state: &mut State,
+    services: &ServiceFactory,
+    transfer: CommandTransfer,
+) -> anyhow::Result<()> {
+    let Context::Commands = state.context else {
+        return Ok(());
+    };
+
+    if state.command_id.is_none() {
+        return Ok(());
+    }
+
+    state.list.filter = String::new();
+    setup_workspace_picker_context(state, services, transfer, true)
+}
+
+fn maybe_toggle_keep_history(state: &mut State) {
+    if let Context::WorkspacePicker {
+        transfer,
+        keep_history,
+    } = state.context
+    {
+        state.context = Context::WorkspacePicker {
+            transfer,
+            keep_history: !keep_history,
+        };
+    }
+}
+
+fn maybe_toggle_command_candidate(state: &mut State) {
+    let Context::CommandCandidates { origin } = state.context else {
+        return;
+    };
+
+    let mut command_candidates = std::mem::take(&mut state.command_candidates);
+
+    if let Some(candidate) = command_candidates.get_mut(state.list.cursor) {
+        candidate.selected = !candidate.selected;
+    }
+
+    setup_command_candidates_context(state, command_candidates, origin);
+}
+
+fn maybe_toggle_pin(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
+    if state.list.items.is_empty() {
+        return Ok(());
+    }
+
+    let selected_id = match state.context {
+        Context::Workspaces => {
+            integration::toggle_workspace_pin(state, services)?;
+            state.list.items = integration::list_workspaces(state, services)?;
+            state.workspace_id
+        }
+        Context::Commands => {
+            integration::toggle_command_pin(state, services)?;
+            state.list.items = integration::list_commands(state, services)?;
+            state.command_id
+        }
+        Context::WorkspaceForm
+        | Context::WorkspaceCloneForm
+        | Context::CommandForm
+        | Context::CommandPlaceholdersForm { .. }
+        | Context::CommandExecutions
+        | Context::CommandRevisions
+        | Context::CommandCandidates { .. }
+        | Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
+        | Context::DiscoveredWorkspaces
+        | Context::NotionBackupCredentialsForm
+        | Context::WorkspacePicker { .. }
+        | Context::Trash => return Ok(()),
+    };
+
+    state.list.cursor = state
+        .list
+        .items
+        .iter()
+        .position(|item| Some(item.id) == selected_id)
+        .unwrap_or_default();
+
+    Ok(())
+}
+
+fn maybe_run_command(
+    state: &mut State,
+    services: &ServiceFactory,
+    no_exit: bool,
+) -> anyhow::Result<()> {
+    let Context::Commands = state.context else {
+        return Ok(());
+    };
+
+    let Some(command) = integration::get_command(state, services)? else {
+        return Ok(());
+    };
+
+    let placeholders = command.placeholders();
+
+    if placeholders.is_empty() {
+        return integration::run_command(
+            state,
+            services,
+            RunCommandOptions {
+                no_exit,
+                placeholder_values: HashMap::new(),
+            },
+        );
+    }
+
+    *state = State {
+        undo_stack: std::mem::take(&mut state.undo_stack),
+        workspace_level: state.workspace_level.take(),
+        workspace_id: state.workspace_id,
+        command_id: state.command_id,
+        context: Context::CommandPlaceholdersForm { no_exit },
+        form: Form {
+            inputs: vec![String::new(); placeholders.len()],
+            labels: placeholders.into_iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        },
+        ..State::default()
     };
 
     Ok(())
@@ -389,12 +1273,35 @@ fn open_terminal(state: &mut State, services: &ServiceFactory) -> anyhow::Result
 
 fn restore_parent_context(state: &mut State, services: &ServiceFactory) -> anyhow::Result<()> {
     match state.context {
-        Context::Workspaces => {}
-        Context::CommandForm => {
+        Context::Workspaces => {
+            integration::leave_workspace_level(state, services)?;
+            state.list.filter = String::new();
+            setup_workspaces_context(state, services)?;
+        }
+        Context::CommandCandidates { .. }
+        | Context::CommandForm
+        | Context::CommandPlaceholdersForm { .. }
+        | Context::CommandRevisions
+        | Context::WorkspacePicker { .. } => {
             state.list.filter = String::new();
             setup_commands_context(state, services)?;
         }
-        Context::Commands | Context::NotionBackupCredentialsForm | Context::WorkspaceForm => {
+        Context::CommandExecutions => {
+            if state.command_id.is_some() {
+                setup_commands_context(state, services)?;
+            } else {
+                setup_workspaces_context(state, services)?;
+            }
+        }
+        Context::Commands
+        | Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
+        | Context::DiscoveredWorkspaces
+        | Context::NotionBackupCredentialsForm
+        | Context::WorkspaceForm
+        | Context::WorkspaceCloneForm
+        | Context::Trash => {
             state.list.filter = String::new();
             setup_workspaces_context(state, services)?
         }
@@ -421,7 +1328,24 @@ fn select_next_list_item(state: &mut State) {
                 state.command_id = Some(state.list.items[state.list.cursor].id);
             }
         }
-        Context::CommandForm | Context::WorkspaceForm | Context::NotionBackupCredentialsForm => {}
+        Context::CommandExecutions
+        | Context::CommandRevisions
+        | Context::CommandCandidates { .. }
+        | Context::DiscoveredWorkspaces
+        | Context::WorkspacePicker { .. }
+        | Context::Trash => {
+            if !state.list.items.is_empty() {
+                state.list.cursor = (state.list.cursor + 1) % state.list.items.len();
+            }
+        }
+        Context::CommandForm
+        | Context::CommandPlaceholdersForm { .. }
+        | Context::WorkspaceForm
+        | Context::WorkspaceCloneForm
+        | Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
+        | Context::NotionBackupCredentialsForm => {}
     }
 }
 
@@ -445,7 +1369,25 @@ fn select_previous_list_item(state: &mut State) {
                 state.command_id = Some(state.list.items[state.list.cursor].id);
             }
         }
-        Context::CommandForm | Context::WorkspaceForm | Context::NotionBackupCredentialsForm => {}
+        Context::CommandExecutions
+        | Context::CommandRevisions
+        | Context::CommandCandidates { .. }
+        | Context::DiscoveredWorkspaces
+        | Context::WorkspacePicker { .. }
+        | Context::Trash => {
+            if !state.list.items.is_empty() {
+                state.list.cursor =
+                    (state.list.cursor + state.list.items.len() - 1) % state.list.items.len();
+            }
+        }
+        Context::CommandForm
+        | Context::CommandPlaceholdersForm { .. }
+        | Context::WorkspaceForm
+        | Context::WorkspaceCloneForm
+        | Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
+        | Context::NotionBackupCredentialsForm => {}
     }
 }
 
@@ -455,9 +1397,21 @@ fn update_active_input(
     services: &ServiceFactory,
 ) -> anyhow::Result<()> {
     let active_input = match state.context {
-        Context::Workspaces | Context::Commands { .. } => &mut state.list.filter,
+        Context::Workspaces
+        | Context::Commands { .. }
+        | Context::CommandExecutions
+        | Context::CommandRevisions
+        | Context::CommandCandidates { .. }
+        | Context::DiscoveredWorkspaces
+        | Context::WorkspacePicker { .. }
+        | Context::Trash => &mut state.list.filter,
         Context::WorkspaceForm { .. }
+        | Context::WorkspaceCloneForm
         | Context::CommandForm { .. }
+        | Context::CommandPlaceholdersForm { .. }
+        | Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
         | Context::NotionBackupCredentialsForm => &mut state.form.inputs[state.form.cursor],
     };
 
@@ -481,8 +1435,25 @@ fn update_active_input(
             state.list.filter = active_input;
             state.mode = Mode::Input;
         }
-        Context::WorkspaceForm { .. } => {}
+        Context::WorkspacePicker {
+            transfer,
+            keep_history,
+        } => {
+            setup_workspace_picker_context(state, services, transfer, keep_history)?;
+            state.list.filter = active_input;
+            state.mode = Mode::Input;
+        }
+        Context::WorkspaceForm { .. } | Context::WorkspaceCloneForm => {}
+        Context::CommandCandidates { .. } => {}
+        Context::BundleForm
+        | Context::CheatSheetForm
+        | Context::DiscoverWorkspacesForm
+        | Context::DiscoveredWorkspaces => {}
         Context::CommandForm { .. } => {}
+        Context::CommandPlaceholdersForm { .. } => {}
+        Context::CommandExecutions => {}
+        Context::CommandRevisions => {}
+        Context::Trash => {}
         Context::NotionBackupCredentialsForm => {}
     };
 
@@ -502,16 +1473,27 @@ fn update_state(
             keyboard::Event::Right => maybe_follow_selected_item(state, services)?,
             keyboard::Event::Left => restore_parent_context(state, services)?,
             keyboard::Event::Space => {
-                integration::run_command(state, services, RunCommandOptions { no_exit: false })?
-            }
-            keyboard::Event::BackSlash => {
-                integration::run_command(state, services, RunCommandOptions { no_exit: true })?
+                maybe_toggle_command_candidate(state);
+                maybe_run_command(state, services, false)?
             }
+            keyboard::Event::BackSlash => maybe_run_command(state, services, true)?,
             keyboard::Event::Slash => match state.context {
-                Context::Workspaces | Context::Commands => state.mode = Mode::Input,
+                Context::Workspaces | Context::Commands | Context::WorkspacePicker { .. } => {
+                    state.mode = Mode::Input
+                }
                 Context::WorkspaceForm
+                | Context::WorkspaceCloneForm
                 | Context::CommandForm
-                | Context::NotionBackupCredentialsForm => {}
+                | Context::CommandPlaceholdersForm { .. }
+                | Context::CommandExecutions
+                | Context::CommandRevisions
+                | Context::CommandCandidates { .. }
+                | Context::BundleForm
+                | Context::CheatSheetForm
+                | Context::DiscoverWorkspacesForm
+                | Context::DiscoveredWorkspaces
+                | Context::NotionBackupCredentialsForm
+                | Context::Trash => {}
             },
             keyboard::Event::NumberOne => {
                 state.notice = None;
@@ -531,16 +1513,34 @@ fn update_state(
                 };
             }
             keyboard::Event::Char(c) => match c {
+                'a' => maybe_import_history_commands(state, services)?,
                 'b' => maybe_backup(state, services)?,
                 'c' => maybe_copy_item(state, services)?,
                 'd' => maybe_delete_list_item(state, services)?,
                 'e' => maybe_edit_item(state, services)?,
+                'f' => maybe_show_revision_diff(state, services)?,
+                'g' => maybe_show_global_commands(state, services)?,
+                'h' => maybe_show_history(state, services)?,
                 'j' => select_next_list_item(state),
                 'k' => select_previous_list_item(state),
+                'l' => maybe_list_project_tasks(state, services)?,
+                'm' => maybe_pick_workspace(state, services, CommandTransfer::Move)?,
                 'n' => maybe_new_item(state)?,
-                'r' => maybe_restore(state, services)?,
+                'o' => maybe_open_bundle_form(state),
+                'p' => maybe_toggle_pin(state, services)?,
+                's' => maybe_discover_workspaces(state),
+                'r' => {
+                    maybe_toggle_keep_history(state);
+                    maybe_restore_trash_item(state, services)?;
+                    maybe_restore(state, services)?
+                }
                 'i' => state.mode = Mode::Input,
                 't' => open_terminal(state, services)?,
+                'u' => maybe_undo(state, services)?,
+                'v' => maybe_show_revisions(state, services)?,
+                'w' => maybe_open_cheat_sheet_form(state, services)?,
+                'x' => maybe_show_trash(state, services)?,
+                'y' => maybe_pick_workspace(state, services, CommandTransfer::Copy)?,
                 _ => {}
             },
             keyboard::Event::Esc | keyboard::Event::Tab | keyboard::Event::Backspace => {}
@@ -576,14 +1576,29 @@ fn update_state(
 
             keyboard::Event::Enter => match state.context {
                 Context::CommandForm => {
-                    if state.form.cursor == 1 {
+                    if state.form.cursor == 1 || state.form.cursor == 4 {
                         update_active_input(state, InputUpdate::AddChar('\n'), services)?
                     }
                 }
                 Context::Workspaces => {}
-                Context::WorkspaceForm => {}
+                Context::WorkspaceForm => {
+                    if state.form.cursor == 4 {
+                        update_active_input(state, InputUpdate::AddChar('\n'), services)?
+                    }
+                }
                 Context::Commands => {}
+                Context::CommandPlaceholdersForm { .. } => {}
+                Context::CommandExecutions => {}
+                Context::CommandRevisions => {}
+                Context::CommandCandidates { .. } => {}
+                Context::BundleForm => {}
+                Context::CheatSheetForm => {}
+                Context::DiscoverWorkspacesForm => {}
+                Context::DiscoveredWorkspaces => {}
+                Context::Trash => {}
                 Context::NotionBackupCredentialsForm => {}
+                Context::WorkspacePicker { .. } => {}
+                Context::WorkspaceCloneForm => {}
             },
 
             keyboard::Event::Esc => state.mode = Mode::Normal,
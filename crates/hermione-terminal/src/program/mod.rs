@@ -1,28 +1,75 @@
+mod cheat_sheet;
 mod enter_terminal;
+mod secret;
 mod update;
 
 use clap::{Parser, Subcommand};
+use std::time::Duration;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
 
 #[derive(Parser)]
 #[command(version)]
 struct Cli {
     #[command(subcommand)]
     command: Option<Command>,
+
+    /// Number of days deleted workspaces and commands are kept in the trash
+    #[arg(long, default_value_t = 30, global = true)]
+    trash_retention_days: u64,
 }
 
 #[derive(Clone, Default, Subcommand)]
 enum Command {
+    /// Print commands of a workspace and its descendants as Markdown
+    CheatSheet {
+        /// Workspace name or ID
+        workspace: String,
+
+        /// Write the Markdown to this file instead of printing it
+        #[arg(long)]
+        output: Option<String>,
+    },
+
     #[default]
     Run,
+
+    /// Manage secrets referenced from programs as `{{secret:name}}`
+    Secret {
+        #[command(subcommand)]
+        action: SecretAction,
+    },
     Update,
 }
 
+#[derive(Clone, Subcommand)]
+enum SecretAction {
+    /// Delete a secret
+    Delete { name: String },
+
+    /// List names of stored secrets
+    List,
+
+    /// Save a secret, reading its value from standard input
+    Set { name: String },
+}
+
 pub fn run() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
+        Some(Command::CheatSheet { workspace, output }) => {
+            cheat_sheet::export(&workspace, output.as_deref())?
+        }
+        Some(Command::Secret { action }) => match action {
+            SecretAction::Delete { name } => secret::delete(&name)?,
+            SecretAction::List => secret::list()?,
+            SecretAction::Set { name } => secret::set(name)?,
+        },
         Some(Command::Update) => update::run()?,
-        Some(Command::Run) | None => enter_terminal::run()?,
+        Some(Command::Run) | None => enter_terminal::run(Duration::from_secs(
+            cli.trash_retention_days.saturating_mul(SECONDS_PER_DAY),
+        ))?,
     }
 
     Ok(())
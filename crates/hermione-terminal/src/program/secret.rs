@@ -0,0 +1,59 @@
+use hermione_drive::Engine;
+use hermione_nexus::operations::{
+    DeleteSecretOperation, ListSecretNamesOperation, SaveSecretOperation, SaveSecretParameters,
+};
+use std::io;
+
+pub fn delete(name: &str) -> anyhow::Result<()> {
+    let Engine {
+        service_factory,
+        logs_worker_guard: _logs_worker_guard,
+    } = hermione_drive::start()?;
+
+    DeleteSecretOperation {
+        provider: &service_factory.secrets(),
+    }
+    .execute(name)?;
+
+    Ok(())
+}
+
+pub fn list() -> anyhow::Result<()> {
+    let Engine {
+        service_factory,
+        logs_worker_guard: _logs_worker_guard,
+    } = hermione_drive::start()?;
+
+    let names = ListSecretNamesOperation {
+        provider: &service_factory.secrets(),
+    }
+    .execute()?;
+
+    for name in names {
+        println!("{}", name);
+    }
+
+    Ok(())
+}
+
+/// The value is read from standard input rather than from the arguments,
+/// so it does not end up in the shell history.
+pub fn set(name: String) -> anyhow::Result<()> {
+    let Engine {
+        service_factory,
+        logs_worker_guard: _logs_worker_guard,
+    } = hermione_drive::start()?;
+
+    let mut value = String::new();
+    io::stdin().read_line(&mut value)?;
+
+    SaveSecretOperation {
+        provider: &service_factory.secrets(),
+    }
+    .execute(SaveSecretParameters {
+        name,
+        value: value.trim_end_matches(['\r', '\n']).to_string(),
+    })?;
+
+    Ok(())
+}
@@ -10,9 +10,12 @@ pub fn run() -> anyhow::Result<()> {
     let mut system = service_factory.system();
     system.set_no_exit(false);
 
+    let storage = service_factory.storage();
+
     ExecuteProgramOperation {
         system: &system,
-        find_workspace: &service_factory.storage(),
+        find_workspace: &storage,
+        list_environment_variables: &storage,
     }
     .execute(ExecuteProgramParameters {
         program: "cargo install --git https://github.com/stsh89/hermione.git hermione-terminal",
@@ -1,3 +1,4 @@
+use hermione_nexus::definitions::UndoRecord;
 use uuid::Uuid;
 
 pub trait Render {
@@ -13,6 +14,10 @@ pub struct State {
     pub notice: Option<Notice>,
     pub workspace_id: Option<Uuid>,
     pub command_id: Option<Uuid>,
+    pub undo_stack: Vec<UndoRecord>,
+    pub workspace_level: Option<WorkspaceLevel>,
+    pub discovered_workspaces: Vec<DiscoveredWorkspace>,
+    pub command_candidates: Vec<CommandCandidate>,
 }
 
 pub struct Notice {
@@ -28,6 +33,7 @@ pub enum NoticeKind {
 #[derive(Default)]
 pub struct Form {
     pub inputs: Vec<String>,
+    pub labels: Vec<String>,
     pub cursor: usize,
 }
 
@@ -36,9 +42,40 @@ pub enum Context {
     #[default]
     Workspaces,
     WorkspaceForm,
+    WorkspaceCloneForm,
+    BundleForm,
+    CheatSheetForm,
     Commands,
+    CommandCandidates {
+        origin: CandidateOrigin,
+    },
     CommandForm,
+    CommandPlaceholdersForm {
+        no_exit: bool,
+    },
+    CommandExecutions,
+    CommandRevisions,
+    DiscoverWorkspacesForm,
+    DiscoveredWorkspaces,
     NotionBackupCredentialsForm,
+    Trash,
+    WorkspacePicker {
+        transfer: CommandTransfer,
+        keep_history: bool,
+    },
+}
+
+/// Where the proposals of the `CommandCandidates` context come from.
+#[derive(Clone, Copy)]
+pub enum CandidateOrigin {
+    ProjectTasks,
+    ShellHistory,
+}
+
+#[derive(Clone, Copy)]
+pub enum CommandTransfer {
+    Copy,
+    Move,
 }
 
 #[derive(Default)]
@@ -48,6 +85,31 @@ pub struct List {
     pub filter: String,
 }
 
+/// Program proposed for import into the current workspace, only the
+/// selected ones are created.
+pub struct CommandCandidate {
+    pub name: String,
+    pub program: String,
+
+    /// Shown next to the name, how often a program was run or what a task
+    /// runs.
+    pub detail: String,
+    pub selected: bool,
+}
+
+/// Workspace proposed by a directory scan, created once the batch is
+/// confirmed.
+pub struct DiscoveredWorkspace {
+    pub name: String,
+    pub location: String,
+}
+
+/// Workspace the `Workspaces` context is drilled down into.
+pub struct WorkspaceLevel {
+    pub id: Uuid,
+    pub path: String,
+}
+
 pub struct ListItem {
     pub id: Uuid,
     pub text: String,
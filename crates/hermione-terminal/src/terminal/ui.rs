@@ -1,4 +1,4 @@
-use crate::program_lib::{Context, Mode, NoticeKind, State};
+use crate::program_lib::{CandidateOrigin, CommandTransfer, Context, Mode, NoticeKind, State};
 use ratatui::{
     layout::{Constraint, Direction, Flex, Layout, Rect},
     style::{Color, Style},
@@ -52,15 +52,90 @@ fn help_line() -> impl Widget {
 
 fn render_content(state: &State, frame: &mut Frame, area: Rect) {
     match state.context {
-        Context::Workspaces | Context::Commands { .. } => render_list(state, frame, area),
+        Context::Workspaces
+        | Context::Commands { .. }
+        | Context::CommandCandidates { .. }
+        | Context::CommandExecutions
+        | Context::CommandRevisions
+        | Context::DiscoveredWorkspaces
+        | Context::WorkspacePicker { .. }
+        | Context::Trash => render_list(state, frame, area),
         Context::WorkspaceForm { .. } => render_workspace_form(state, frame, area),
+        Context::WorkspaceCloneForm => render_workspace_clone_form(state, frame, area),
         Context::CommandForm { .. } => render_command_form(state, frame, area),
+        Context::BundleForm | Context::CheatSheetForm | Context::CommandPlaceholdersForm { .. } => {
+            render_labeled_form(state, frame, area)
+        }
         Context::NotionBackupCredentialsForm => render_notion_form(state, frame, area),
+        Context::DiscoverWorkspacesForm => render_discover_workspaces_form(state, frame, area),
     }
 }
 
 fn render_workspace_form(state: &State, frame: &mut Frame, area: Rect) {
-    let [name_area, location_area] = ratatui::layout::Layout::default()
+    let [name_area, location_area, tags_area, shell_area, environment_area, parent_area] =
+        ratatui::layout::Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Max(3),
+                Constraint::Max(3),
+                Constraint::Max(3),
+                Constraint::Max(3),
+                Constraint::Min(3),
+                Constraint::Max(3),
+            ])
+            .areas(area);
+
+    let mut block = Block::default().borders(Borders::ALL).title("Name");
+    if matches!(state.mode, Mode::Input) && state.form.cursor == 0 {
+        block = block.border_style(Style::default().fg(Color::Yellow));
+    }
+
+    let paragraph = Paragraph::new(state.form.inputs[0].as_str()).block(block);
+    frame.render_widget(paragraph, name_area);
+
+    let mut block = Block::default().borders(Borders::ALL).title("Location");
+    if matches!(state.mode, Mode::Input) && state.form.cursor == 1 {
+        block = block.border_style(Style::default().fg(Color::Yellow));
+    }
+
+    let paragraph = Paragraph::new(state.form.inputs[1].as_str()).block(block);
+    frame.render_widget(paragraph, location_area);
+
+    let mut block = Block::default().borders(Borders::ALL).title("Tags");
+    if matches!(state.mode, Mode::Input) && state.form.cursor == 2 {
+        block = block.border_style(Style::default().fg(Color::Yellow));
+    }
+
+    let paragraph = Paragraph::new(state.form.inputs[2].as_str()).block(block);
+    frame.render_widget(paragraph, tags_area);
+
+    let mut block = Block::default().borders(Borders::ALL).title("Shell");
+    if matches!(state.mode, Mode::Input) && state.form.cursor == 3 {
+        block = block.border_style(Style::default().fg(Color::Yellow));
+    }
+
+    let paragraph = Paragraph::new(state.form.inputs[3].as_str()).block(block);
+    frame.render_widget(paragraph, shell_area);
+
+    let mut block = Block::default().borders(Borders::ALL).title("Environment");
+    if matches!(state.mode, Mode::Input) && state.form.cursor == 4 {
+        block = block.border_style(Style::default().fg(Color::Yellow));
+    }
+
+    let paragraph = Paragraph::new(state.form.inputs[4].as_str()).block(block);
+    frame.render_widget(paragraph, environment_area);
+
+    let mut block = Block::default().borders(Borders::ALL).title("Parent");
+    if matches!(state.mode, Mode::Input) && state.form.cursor == 5 {
+        block = block.border_style(Style::default().fg(Color::Yellow));
+    }
+
+    let paragraph = Paragraph::new(state.form.inputs[5].as_str()).block(block);
+    frame.render_widget(paragraph, parent_area);
+}
+
+fn render_workspace_clone_form(state: &State, frame: &mut Frame, area: Rect) {
+    let [name_area, location_area] = Layout::default()
         .direction(Direction::Vertical)
         .constraints(vec![Constraint::Max(3), Constraint::Max(3)])
         .areas(area);
@@ -82,12 +157,34 @@ fn render_workspace_form(state: &State, frame: &mut Frame, area: Rect) {
     frame.render_widget(paragraph, location_area);
 }
 
-fn render_command_form(state: &State, frame: &mut Frame, area: Rect) {
-    let [name_area, program_area] = ratatui::layout::Layout::default()
+fn render_discover_workspaces_form(state: &State, frame: &mut Frame, area: Rect) {
+    let [directory_area] = Layout::default()
         .direction(Direction::Vertical)
-        .constraints(vec![Constraint::Max(3), Constraint::Min(3)])
+        .constraints(vec![Constraint::Max(3)])
         .areas(area);
 
+    let mut block = Block::default().borders(Borders::ALL).title("Directory");
+    if matches!(state.mode, Mode::Input) {
+        block = block.border_style(Style::default().fg(Color::Yellow));
+    }
+
+    let paragraph = Paragraph::new(state.form.inputs[0].as_str()).block(block);
+    frame.render_widget(paragraph, directory_area);
+}
+
+fn render_command_form(state: &State, frame: &mut Frame, area: Rect) {
+    let [name_area, program_area, tags_area, shell_area, description_area] =
+        ratatui::layout::Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Max(3),
+                Constraint::Min(3),
+                Constraint::Max(3),
+                Constraint::Max(3),
+                Constraint::Min(3),
+            ])
+            .areas(area);
+
     let mut block = Block::default().borders(Borders::ALL).title("Name");
     if matches!(state.mode, Mode::Input) && state.form.cursor == 0 {
         block = block.border_style(Style::default().fg(Color::Yellow));
@@ -103,6 +200,53 @@ fn render_command_form(state: &State, frame: &mut Frame, area: Rect) {
 
     let paragraph = Paragraph::new(state.form.inputs[1].as_str()).block(block);
     frame.render_widget(paragraph, program_area);
+
+    let mut block = Block::default().borders(Borders::ALL).title("Tags");
+    if matches!(state.mode, Mode::Input) && state.form.cursor == 2 {
+        block = block.border_style(Style::default().fg(Color::Yellow));
+    }
+
+    let paragraph = Paragraph::new(state.form.inputs[2].as_str()).block(block);
+    frame.render_widget(paragraph, tags_area);
+
+    let mut block = Block::default().borders(Borders::ALL).title("Shell");
+    if matches!(state.mode, Mode::Input) && state.form.cursor == 3 {
+        block = block.border_style(Style::default().fg(Color::Yellow));
+    }
+
+    let paragraph = Paragraph::new(state.form.inputs[3].as_str()).block(block);
+    frame.render_widget(paragraph, shell_area);
+
+    let mut block = Block::default().borders(Borders::ALL).title("Description");
+    if matches!(state.mode, Mode::Input) && state.form.cursor == 4 {
+        block = block.border_style(Style::default().fg(Color::Yellow));
+    }
+
+    let paragraph = Paragraph::new(state.form.inputs[4].as_str()).block(block);
+    frame.render_widget(paragraph, description_area);
+}
+
+fn render_labeled_form(state: &State, frame: &mut Frame, area: Rect) {
+    let areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Max(3); state.form.inputs.len()])
+        .split(area);
+
+    for (index, (label, input)) in state
+        .form
+        .labels
+        .iter()
+        .zip(state.form.inputs.iter())
+        .enumerate()
+    {
+        let mut block = Block::default().borders(Borders::ALL).title(label.as_str());
+        if matches!(state.mode, Mode::Input) && state.form.cursor == index {
+            block = block.border_style(Style::default().fg(Color::Yellow));
+        }
+
+        let paragraph = Paragraph::new(input.as_str()).block(block);
+        frame.render_widget(paragraph, areas[index]);
+    }
 }
 
 fn render_notion_form(state: &State, frame: &mut Frame, area: Rect) {
@@ -183,17 +327,46 @@ fn render_list(state: &State, frame: &mut Frame, area: Rect) {
 }
 
 fn title(state: &State) -> impl Widget {
+    if let (Context::Workspaces, Some(level)) = (state.context, &state.workspace_level) {
+        return Paragraph::new(level.path.clone());
+    }
+
     let text = match state.context {
         Context::Workspaces => "Workspaces",
-        Context::Commands { .. } => "Commands",
+        Context::Commands { .. } => match state.workspace_id {
+            Some(_) => "Commands",
+            None => "Global",
+        },
         Context::WorkspaceForm => match state.workspace_id {
             Some(_) => "Edit workspace",
             None => "New workspace",
         },
+        Context::WorkspaceCloneForm => "Clone workspace",
+        Context::BundleForm => "Bundle",
+        Context::CheatSheetForm => "Cheat sheet",
         Context::CommandForm => match state.command_id {
             Some(_) => "Edit command",
             None => "New command",
         },
+        Context::CommandCandidates { origin } => match origin {
+            CandidateOrigin::ProjectTasks => "Project tasks",
+            CandidateOrigin::ShellHistory => "Shell history",
+        },
+        Context::CommandPlaceholdersForm { .. } => "Run command",
+        Context::CommandExecutions => "History",
+        Context::CommandRevisions => "Revisions",
+        Context::DiscoverWorkspacesForm => "Discover workspaces",
+        Context::DiscoveredWorkspaces => "Discovered workspaces",
+        Context::Trash => "Trash",
+        Context::WorkspacePicker {
+            transfer,
+            keep_history,
+        } => match (transfer, keep_history) {
+            (CommandTransfer::Copy, true) => "Copy command (keep history)",
+            (CommandTransfer::Copy, false) => "Copy command (reset history)",
+            (CommandTransfer::Move, true) => "Move command (keep history)",
+            (CommandTransfer::Move, false) => "Move command (reset history)",
+        },
         Context::NotionBackupCredentialsForm => "Notion",
     };
 